@@ -0,0 +1,132 @@
+//! This benchmarks the end-to-end verification of BLS signatures.
+//! It measures the BN256 engine, the only one of the crate whose `G2` has a
+//! hash to curve. BLS12-381 is not implemented yet.
+//!
+//! For each number of signers in `SIGNERS_RANGE` it measures:
+//!     1. the verification of an aggregate signature of distinct messages: hash
+//!        to `G2` of every message, a multi-Miller loop with one term per signer
+//!        and a final exponentiation,
+//!     2. the batch verification of signatures of the same message with random
+//!        coefficients: an MSM over the public keys and over the signatures, a
+//!        hash to `G2`, a two-term multi-Miller loop and a final exponentiation.
+//!
+//! To run this benchmark:
+//!
+//!     cargo bench --bench bls
+
+#[macro_use]
+extern crate criterion;
+
+use criterion::{black_box, BenchmarkId, Criterion};
+use ff::Field;
+use group::prime::PrimeCurveAffine;
+use group::{Curve, Group};
+use halo2curves::bn256::{Bn256, Fr, G1Affine, G2Affine, G2};
+use halo2curves::hash_to_curve::BatchHashToCurve;
+use halo2curves::msm::best_multiexp;
+use halo2curves::sig::{aggregate, public_key, sign};
+use halo2curves::CurveExt;
+use pairing::{MillerLoopResult, MultiMillerLoop};
+use rand_core::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+const SAMPLE_SIZE: usize = 10;
+const SIGNERS_RANGE: [usize; 4] = [1, 16, 128, 1024];
+const DOMAIN: &str = "halo2curves-bls-bench";
+const SEED: [u8; 16] = [
+    0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc, 0xe5,
+];
+
+// Checks `e(pk_1, H(msg_1)) ... e(pk_n, H(msg_n)) = e(G1, sig)`.
+fn aggregate_verify(pks: &[G1Affine], msgs: &[&[u8]], sig: &G2Affine) -> bool {
+    let hashes = G2::batch_hash_to_curve(DOMAIN, msgs);
+    let mut prepared = vec![G2Affine::identity(); hashes.len()];
+    G2::batch_normalize(&hashes, &mut prepared);
+    prepared.push(*sig);
+    let neg_g1 = -G1Affine::generator();
+
+    let terms = pks
+        .iter()
+        .chain(std::iter::once(&neg_g1))
+        .zip(prepared.iter())
+        .collect::<Vec<_>>();
+    bool::from(
+        Bn256::multi_miller_loop(&terms)
+            .final_exponentiation()
+            .is_identity(),
+    )
+}
+
+// Checks `e(sum r_i * pk_i, H(msg)) = e(G1, sum r_i * sig_i)`.
+fn batch_verify(pks: &[G1Affine], msg: &[u8], sigs: &[G2Affine], coeffs: &[Fr]) -> bool {
+    let pk = best_multiexp(coeffs, pks).to_affine();
+    let sig = best_multiexp(coeffs, sigs).to_affine();
+    let hash = G2::hash_to_curve(DOMAIN)(msg).to_affine();
+
+    let neg_g1 = -G1Affine::generator();
+    bool::from(
+        Bn256::multi_miller_loop(&[(&pk, &hash), (&neg_g1, &sig)])
+            .final_exponentiation()
+            .is_identity(),
+    )
+}
+
+fn bls(c: &mut Criterion) {
+    let mut rng = XorShiftRng::from_seed(SEED);
+    let max_signers = *SIGNERS_RANGE.iter().max().unwrap();
+
+    let sks = (0..max_signers)
+        .map(|_| Fr::random(&mut rng))
+        .collect::<Vec<_>>();
+    let pks = sks.iter().map(public_key::<Bn256>).collect::<Vec<_>>();
+    let msgs = (0..max_signers)
+        .map(|i| format!("message {i}").into_bytes())
+        .collect::<Vec<_>>();
+    let msgs = msgs.iter().map(|msg| &msg[..]).collect::<Vec<_>>();
+    let sigs = sks
+        .iter()
+        .zip(msgs.iter())
+        .map(|(sk, msg)| sign::<Bn256>(sk, msg, DOMAIN))
+        .collect::<Vec<_>>();
+    let same_msg_sigs = sks
+        .iter()
+        .map(|sk| sign::<Bn256>(sk, msgs[0], DOMAIN))
+        .collect::<Vec<_>>();
+    let coeffs = (0..max_signers)
+        .map(|_| Fr::random(&mut rng))
+        .collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group("BN256 BLS verification");
+    for n in SIGNERS_RANGE {
+        let agg = aggregate::<Bn256>(&sigs[..n]);
+        assert!(aggregate_verify(&pks[..n], &msgs[..n], &agg));
+        group
+            .bench_function(BenchmarkId::new("aggregate verify", n), |b| {
+                b.iter(|| aggregate_verify(black_box(&pks[..n]), &msgs[..n], black_box(&agg)))
+            })
+            .sample_size(SAMPLE_SIZE);
+
+        assert!(batch_verify(
+            &pks[..n],
+            msgs[0],
+            &same_msg_sigs[..n],
+            &coeffs[..n]
+        ));
+        group
+            .bench_function(BenchmarkId::new("batch verify same message", n), |b| {
+                b.iter(|| {
+                    batch_verify(
+                        black_box(&pks[..n]),
+                        msgs[0],
+                        black_box(&same_msg_sigs[..n]),
+                        &coeffs[..n],
+                    )
+                })
+            })
+            .sample_size(SAMPLE_SIZE);
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bls);
+criterion_main!(benches);
@@ -1,15 +1,28 @@
 //! This benchmarks the basic EC operations.
-//! It measures `G1` from the BN256 curve.
+//! It measures `G1` from the BN256 curve, the decoding of `G2` points
+//! with its subgroup check, and the GLV scalar multiplication of BN256 and
+//! secp256k1, and the homogeneous projective coordinates of the curves against
+//! their Jacobian counterparts. It also measures the decompression of points on
+//! all the curves.
 //!
 //! To run this benchmark:
 //!
 //!     cargo bench --bench curve
+//!
+//! Add `--features precompute` to benchmark the generator table and compare the
+//! square roots of the Pluto and Eris fields with their table-based `sqrt_vartime`,
+//! and the batch decompression of their points with `batch_from_compressed_vartime`.
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
 use ff::Field;
+use group::cofactor::CofactorGroup;
 use group::prime::PrimeCurveAffine;
-use halo2curves::bn256::G1;
-use pasta_curves::arithmetic::CurveExt;
+use group::{Curve, Group, GroupEncoding};
+use halo2curves::bn256::{Fr, G1Affine, G2Affine, G1, G2};
+use halo2curves::secp256k1::Secp256k1;
+use halo2curves::secp256r1::Secp256r1;
+use halo2curves::CurveEndo;
+use pasta_curves::arithmetic::{CurveAffine, CurveExt};
 use rand::SeedableRng;
 use rand_xorshift::XorShiftRng;
 
@@ -57,6 +70,9 @@ fn bench_curve_ops<G: CurveExt>(c: &mut Criterion, name: &'static str) {
         group.bench_function(&format!("{name} scalar multiplication"), move |b| {
             b.iter(|| black_box(p1) * black_box(s))
         });
+        group.bench_function(&format!("{name} affine scalar multiplication"), move |b| {
+            b.iter(|| black_box(p1_affine) * black_box(s))
+        });
         group.bench_function(&format!("{name} batch to affine n={N}"), move |b| {
             b.iter(|| {
                 G::batch_normalize(black_box(&v), black_box(&mut q));
@@ -67,8 +83,189 @@ fn bench_curve_ops<G: CurveExt>(c: &mut Criterion, name: &'static str) {
 }
 
 fn bench_bn256_ops(c: &mut Criterion) {
-    bench_curve_ops::<G1>(c, "BN256")
+    bench_curve_ops::<G1>(c, "BN256");
+
+    // Fixed-base multiplication, using the generator table with the `precompute` feature.
+    let mut rng = XorShiftRng::seed_from_u64(3141519u64);
+    let s = Fr::random(&mut rng);
+    // Builds the table outside of the measurement.
+    let _ = G1::mul_by_generator(&s);
+    c.bench_function("BN256 generator multiplication", move |b| {
+        b.iter(|| G1::mul_by_generator(black_box(&s)))
+    });
+
+    // On-curve checks of a vector of untrusted points.
+    const N: usize = 1000;
+    let points: Vec<G1Affine> = (0..N).map(|_| G1Affine::random(&mut rng)).collect();
+    let mut group = c.benchmark_group("BN256 on-curve checks");
+    group.throughput(Throughput::Elements(N as u64));
+    group.bench_function(format!("BN256 batch check on curve n={N}"), |b| {
+        b.iter(|| G1Affine::batch_is_on_curve(black_box(&points)))
+    });
+    group.bench_function(format!("BN256 check on curve n={N}"), |b| {
+        b.iter(|| {
+            black_box(&points)
+                .iter()
+                .map(|p| p.is_on_curve())
+                .collect::<Vec<_>>()
+        })
+    });
+    group.finish();
+
+    // Decoding of compressed G2 points: the subgroup check dominates the cost of
+    // `from_bytes` over the unchecked decoding.
+    let p = G2::random(&mut rng);
+    let bytes = G2Affine::from(p).to_bytes();
+    let mut group = c.benchmark_group("BN256 G2 decoding");
+    group.bench_function("BN256 G2 check torsion free", |b| {
+        b.iter(|| black_box(p).is_torsion_free())
+    });
+    group.bench_function("BN256 G2 decompress", |b| {
+        b.iter(|| G2Affine::from_bytes(black_box(&bytes)))
+    });
+    group.bench_function("BN256 G2 decompress unchecked", |b| {
+        b.iter(|| G2Affine::from_bytes_unchecked(black_box(&bytes)))
+    });
+    group.finish();
+}
+
+fn bench_glv<G: CurveEndo>(c: &mut Criterion, name: &'static str) {
+    let mut rng = XorShiftRng::seed_from_u64(3141519u64);
+    let p = G::random(&mut rng);
+    let s = G::ScalarExt::random(&mut rng);
+
+    let mut group = c.benchmark_group(format!("{name} GLV"));
+    group.bench_function(format!("{name} scalar multiplication"), |b| {
+        b.iter(|| black_box(p) * black_box(s))
+    });
+    group.bench_function(format!("{name} GLV scalar multiplication"), |b| {
+        b.iter(|| black_box(p).mul_glv(black_box(&s)))
+    });
+    group.finish();
+}
+
+fn bench_glv_ops(c: &mut Criterion) {
+    bench_glv::<G1>(c, "BN256");
+    bench_glv::<Secp256k1>(c, "secp256k1");
+}
+
+// Compares the complete homogeneous formulas of `$curve` with the Jacobian ones.
+macro_rules! bench_coordinates {
+    ($c:expr, $curve:ty, $name:literal) => {{
+        let mut rng = XorShiftRng::seed_from_u64(3141519u64);
+        let p1 = <$curve>::random(&mut rng);
+        let p2 = <$curve>::random(&mut rng);
+        let p2_affine = p2.to_affine();
+        let (j1, j2) = (p1.into_jacobian(), p2.into_jacobian());
+
+        let mut group = $c.benchmark_group(concat!($name, " coordinates"));
+        group.bench_function(concat!($name, " homogeneous add"), |b| {
+            b.iter(|| black_box(p1) + black_box(p2))
+        });
+        group.bench_function(concat!($name, " jacobian add"), |b| {
+            b.iter(|| black_box(j1) + black_box(j2))
+        });
+        group.bench_function(concat!($name, " homogeneous mixed add"), |b| {
+            b.iter(|| black_box(p1) + black_box(p2_affine))
+        });
+        group.bench_function(concat!($name, " jacobian mixed add"), |b| {
+            b.iter(|| black_box(j1) + black_box(p2_affine))
+        });
+        group.bench_function(concat!($name, " homogeneous double"), |b| {
+            b.iter(|| black_box(p1).double())
+        });
+        group.bench_function(concat!($name, " jacobian double"), |b| {
+            b.iter(|| black_box(j1).double())
+        });
+        group.finish();
+    }};
+}
+
+fn bench_coordinates_ops(c: &mut Criterion) {
+    bench_coordinates!(c, G1, "BN256");
+    bench_coordinates!(c, Secp256k1, "secp256k1");
+    bench_coordinates!(c, Secp256r1, "secp256r1");
+}
+
+// Decompression of points, dominated by the square root of the y-recovery.
+fn bench_decompress<C: CurveAffine>(c: &mut Criterion, name: &'static str) {
+    let mut rng = XorShiftRng::seed_from_u64(3141519u64);
+    let bytes = C::CurveExt::random(&mut rng).to_affine().to_bytes();
+
+    let mut group = c.benchmark_group(format!("{name} decompression"));
+    group.bench_function(format!("{name} decompress"), |b| {
+        b.iter(|| C::from_bytes(black_box(&bytes)))
+    });
+    group.bench_function(format!("{name} decompress unchecked"), |b| {
+        b.iter(|| C::from_bytes_unchecked(black_box(&bytes)))
+    });
+    group.finish();
+}
+
+fn bench_decompress_ops(c: &mut Criterion) {
+    bench_decompress::<G1Affine>(c, "BN256");
+    bench_decompress::<halo2curves::grumpkin::G1Affine>(c, "Grumpkin");
+    bench_decompress::<halo2curves::secp256k1::Secp256k1Affine>(c, "secp256k1");
+    bench_decompress::<halo2curves::secp256r1::Secp256r1Affine>(c, "secp256r1");
+    bench_decompress::<halo2curves::secq256k1::Secq256k1Affine>(c, "secq256k1");
+    bench_decompress::<halo2curves::pasta::EpAffine>(c, "Pallas");
+    bench_decompress::<halo2curves::pasta::EqAffine>(c, "Vesta");
+    bench_decompress::<halo2curves::pluto_eris::G1Affine>(c, "Pluto");
+    bench_decompress::<halo2curves::pluto_eris::ErisAffine>(c, "Eris");
+    bench_decompress::<halo2curves::pluto_eris::G2Affine>(c, "Triton");
+
+    #[cfg(feature = "precompute")]
+    {
+        use halo2curves::pluto_eris::{Fp, Fq};
+
+        let mut rng = XorShiftRng::seed_from_u64(3141519u64);
+        let x = Fp::random(&mut rng).square();
+        let y = Fq::random(&mut rng).square();
+        // Builds the tables outside of the measurement.
+        let _ = (x.sqrt_vartime(), y.sqrt_vartime());
+        let mut group = c.benchmark_group("Pluto and Eris square roots");
+        group.bench_function("Pluto Fp sqrt", |b| b.iter(|| black_box(x).sqrt()));
+        group.bench_function("Pluto Fp sqrt_vartime", |b| {
+            b.iter(|| black_box(x).sqrt_vartime())
+        });
+        group.bench_function("Eris Fq sqrt", |b| b.iter(|| black_box(y).sqrt()));
+        group.bench_function("Eris Fq sqrt_vartime", |b| {
+            b.iter(|| black_box(y).sqrt_vartime())
+        });
+        group.finish();
+
+        use halo2curves::pluto_eris::{Eris, ErisAffine, G1Affine as PlutoAffine, G1 as Pluto};
+
+        const N: usize = 1 << 10;
+        let pluto: Vec<_> = (0..N)
+            .map(|_| Pluto::random(&mut rng).to_affine().to_bytes())
+            .collect();
+        let eris: Vec<_> = (0..N)
+            .map(|_| Eris::random(&mut rng).to_affine().to_bytes())
+            .collect();
+        let mut group = c.benchmark_group("Pluto and Eris batch decompression");
+        group.throughput(Throughput::Elements(N as u64));
+        group.bench_function("Pluto batch_from_compressed", |b| {
+            b.iter(|| PlutoAffine::batch_from_compressed(black_box(&pluto)))
+        });
+        group.bench_function("Pluto batch_from_compressed_vartime", |b| {
+            b.iter(|| PlutoAffine::batch_from_compressed_vartime(black_box(&pluto)))
+        });
+        group.bench_function("Eris batch_from_compressed", |b| {
+            b.iter(|| ErisAffine::batch_from_compressed(black_box(&eris)))
+        });
+        group.bench_function("Eris batch_from_compressed_vartime", |b| {
+            b.iter(|| ErisAffine::batch_from_compressed_vartime(black_box(&eris)))
+        });
+        group.finish();
+    }
 }
 
-criterion_group!(benches, bench_bn256_ops);
+criterion_group!(
+    benches,
+    bench_bn256_ops,
+    bench_glv_ops,
+    bench_coordinates_ops,
+    bench_decompress_ops
+);
 criterion_main!(benches);
@@ -1,15 +1,24 @@
 //! This benchmarks the basic FF operations.
-//! It measures the base field `Fq` and scalar field `Fr` from the BN256 curve.
+//! It measures the base field `Fq` and scalar field `Fr` from the BN256 curve,
+//! the 7-limb fields `Fp` and `Fq` from the Pluto-Eris cycle, the 6-limb base field
+//! `Fq` of BLS12-377, and the 12-limb base field `Fq` of BW6-767.
 //!
 //! To run this benchmark:
 //!
 //!     cargo bench --bench field_arith
+//!
+//! To compare against the assembly backend:
+//!
+//!     cargo bench --bench field_arith --features asm
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
 use halo2curves::{
+    bls12_377,
     bn256::{Fq, Fr},
+    bw6_767,
     ff::Field,
     ff_ext::Legendre,
+    pluto_eris,
 };
 use rand::{RngCore, SeedableRng};
 use rand_xorshift::XorShiftRng;
@@ -65,6 +74,26 @@ fn bench_bn256_base_field(c: &mut Criterion) {
 fn bench_bn256_scalar_field(c: &mut Criterion) {
     bench_field_arithmetic::<Fr>(c, "Fr")
 }
+fn bench_pluto_eris_fp(c: &mut Criterion) {
+    bench_field_arithmetic::<pluto_eris::Fp>(c, "pluto_eris_Fp")
+}
+fn bench_pluto_eris_fq(c: &mut Criterion) {
+    bench_field_arithmetic::<pluto_eris::Fq>(c, "pluto_eris_Fq")
+}
+fn bench_bls12_377_fq(c: &mut Criterion) {
+    bench_field_arithmetic::<bls12_377::Fq>(c, "bls12_377_Fq")
+}
+fn bench_bw6_767_fq(c: &mut Criterion) {
+    bench_field_arithmetic::<bw6_767::Fq>(c, "bw6_767_Fq")
+}
 
-criterion_group!(benches, bench_bn256_base_field, bench_bn256_scalar_field);
+criterion_group!(
+    benches,
+    bench_bn256_base_field,
+    bench_bn256_scalar_field,
+    bench_pluto_eris_fp,
+    bench_pluto_eris_fq,
+    bench_bls12_377_fq,
+    bench_bw6_767_fq
+);
 criterion_main!(benches);
@@ -1,5 +1,6 @@
 //! This benchmarks basic the hash-to-curve algorithm.
-//! It measures `G1` from the BN256 curve.
+//! It measures `G1` from the BN256 curve, hashing one message at a time and
+//! a batch of messages with `BatchHashToCurve`.
 //!
 //! To run this benchmark:
 //!
@@ -7,6 +8,7 @@
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
 use halo2curves::bn256::G1;
+use halo2curves::hash_to_curve::BatchHashToCurve;
 use pasta_curves::arithmetic::CurveExt;
 use rand::SeedableRng;
 use rand_core::RngCore;
@@ -37,8 +39,34 @@ fn hash_to_curve<G: CurveExt>(c: &mut Criterion, name: &'static str) {
     }
 }
 
+fn batch_hash_to_curve<G: BatchHashToCurve>(c: &mut Criterion, name: &'static str) {
+    const BATCH: usize = 1 << 10;
+
+    let mut rng = XorShiftRng::from_seed(SEED);
+    let messages = iter::repeat_with(|| {
+        iter::repeat_with(|| rng.next_u32().to_be_bytes())
+            .take(8)
+            .flatten()
+            .collect::<Vec<_>>()
+    })
+    .take(BATCH)
+    .collect::<Vec<_>>();
+    let messages = messages.iter().map(|m| &m[..]).collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group(format!("{} batch hash-to-curve", name));
+
+    group.significance_level(0.1).sample_size(10);
+    group.throughput(Throughput::Elements(BATCH as u64));
+
+    group.bench_function(&format!("Batch hash to {name}"), |b| {
+        b.iter(|| G::batch_hash_to_curve("test", black_box(&messages)))
+    });
+    group.finish();
+}
+
 fn hash_to_bn256(c: &mut Criterion) {
     hash_to_curve::<G1>(c, "BN256");
+    batch_hash_to_curve::<G1>(c, "BN256");
 }
 
 criterion_group!(benches, hash_to_bn256);
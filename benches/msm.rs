@@ -1,5 +1,5 @@
 //! This benchmarks Multi Scalar Multiplication (MSM).
-//! It measures `G1` from the BN256 curve.
+//! It measures `G1` from the BN256 curve, with full width and 128-bit scalars.
 //!
 //! To run this benchmark:
 //!
@@ -13,10 +13,11 @@
 extern crate criterion;
 
 use criterion::{BenchmarkId, Criterion};
-use ff::Field;
+use ff::{Field, PrimeField};
 use group::prime::PrimeCurveAffine;
 use halo2curves::bn256::{Fr as Scalar, G1Affine as Point};
-use halo2curves::msm::{best_multiexp, multiexp_serial};
+use halo2curves::msm::{best_multiexp, best_multiexp_small, multiexp_serial};
+use halo2curves::small_scalar::SmallScalar;
 use rand_core::SeedableRng;
 use rand_xorshift::XorShiftRng;
 use rayon::current_thread_index;
@@ -109,6 +110,21 @@ fn msm(c: &mut Criterion) {
             })
             .sample_size(SAMPLE_SIZE);
     }
+    let small_coeffs: Vec<_> = coeffs
+        .iter()
+        .map(|a| SmallScalar(u128::from_le_bytes(a.to_repr()[..16].try_into().unwrap())))
+        .collect();
+    for k in MULTICORE_RANGE {
+        group
+            .bench_function(BenchmarkId::new("multicore_128bit", k), |b| {
+                assert!(k < 64);
+                let n: usize = 1 << k;
+                b.iter(|| {
+                    best_multiexp_small(&small_coeffs[..n], &bases[..n]);
+                })
+            })
+            .sample_size(SAMPLE_SIZE);
+    }
     group.finish();
 }
 
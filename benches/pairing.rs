@@ -1,5 +1,6 @@
 //! Benchmark pairing.
-//! It measures the pairing of the BN256 curve.
+//! It measures the pairing of the BN256 curve, and its multi-Miller loop over many
+//! terms on one thread and on the rayon pool, and the pairings of BLS12-377 and BW6-767.
 //!
 //! To run this benchmark:
 //!
@@ -8,8 +9,10 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
 use ff::Field;
 use group::prime::PrimeCurveAffine;
-use halo2curves::bn256::Bn256;
-use pairing::Engine;
+use halo2curves::bls12_377::Bls12377;
+use halo2curves::bn256::{Bn256, G1Affine, G2Affine};
+use halo2curves::bw6_767::Bw6767;
+use pairing::{Engine, MultiMillerLoop};
 use rand::SeedableRng;
 use rand_xorshift::XorShiftRng;
 
@@ -43,7 +46,43 @@ fn bench_pairing<E: Engine>(c: &mut Criterion, name: &'static str) {
 
 fn bench_bn256_pairing(c: &mut Criterion) {
     bench_pairing::<Bn256>(c, "BN256");
+
+    let mut rng = XorShiftRng::from_seed(SEED);
+    const N: usize = 64;
+    let points = (0..N)
+        .map(|_| {
+            let a = <Bn256 as Engine>::Fr::random(&mut rng);
+            (
+                G1Affine::from(G1Affine::generator() * a),
+                G2Affine::generator(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let terms = points.iter().map(|(p, q)| (p, q)).collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group("BN256 multi-Miller loop");
+    group.sample_size(10);
+    group.bench_function(format!("BN256 multi-Miller loop n={N}"), |b| {
+        b.iter(|| Bn256::multi_miller_loop(black_box(&terms)))
+    });
+    group.bench_function(format!("BN256 parallel multi-Miller loop n={N}"), |b| {
+        b.iter(|| Bn256::multi_miller_loop_par(black_box(&terms)))
+    });
+    group.finish();
+}
+
+fn bench_bls12_377_pairing(c: &mut Criterion) {
+    bench_pairing::<Bls12377>(c, "BLS12-377");
+}
+
+fn bench_bw6_767_pairing(c: &mut Criterion) {
+    bench_pairing::<Bw6767>(c, "BW6-767");
 }
 
-criterion_group!(benches, bench_bn256_pairing);
+criterion_group!(
+    benches,
+    bench_bn256_pairing,
+    bench_bls12_377_pairing,
+    bench_bw6_767_pairing
+);
 criterion_main!(benches);
@@ -1,6 +1,9 @@
+use num_bigint::BigUint;
+use num_traits::ToPrimitive;
 use proc_macro2::TokenStream;
 use quote::format_ident as fmtid;
 use quote::quote;
+use quote::ToTokens;
 
 fn select(cond: bool, this: TokenStream, other: TokenStream) -> TokenStream {
     if cond {
@@ -10,15 +13,64 @@ fn select(cond: bool, this: TokenStream, other: TokenStream) -> TokenStream {
     }
 }
 
-pub(crate) fn impl_arith(field: &syn::Ident, num_limbs: usize, inv: u64) -> TokenStream {
+/// Implementation of the Montgomery multiplication and squaring.
+pub(crate) enum MulBackend {
+    /// Unrolled 64-bit limb arithmetic.
+    Portable,
+    /// The 32-bit limb backend generated by [`impl_arith_limb32`].
+    Limb32,
+    /// Generated separately by the assembly backend.
+    #[cfg(feature = "asm")]
+    External,
+}
+
+pub(crate) fn impl_arith(
+    field: &syn::Ident,
+    num_limbs: usize,
+    inv: u64,
+    backend: MulBackend,
+) -> TokenStream {
     let impl_add = impl_add(field, num_limbs);
     let impl_sub = impl_sub(field, num_limbs);
     let impl_neg = impl_neg(field, num_limbs);
-    let impl_mont = impl_mont(field, num_limbs, inv);
     let impl_from_mont = impl_from_mont(field, num_limbs, inv);
-    let impl_mul = impl_mul(field, num_limbs, false);
-    let impl_square = impl_square(field, num_limbs);
     let wide_num_limbs = num_limbs * 2;
+    let impl_mul_square = |impl_mul: TokenStream, impl_square: TokenStream| {
+        quote! {
+            #[inline(always)]
+            pub const fn mul(&self, rhs: &Self) -> Self{
+                #impl_mul
+            }
+
+            #[inline(always)]
+            pub const fn square(&self) -> Self{
+                #impl_square
+            }
+        }
+    };
+    let impl_mul_square = match backend {
+        MulBackend::Portable => {
+            let impl_mont = impl_mont(field, num_limbs, inv);
+            let impl_mul_square = impl_mul_square(
+                impl_mul(field, num_limbs, false),
+                impl_square(field, num_limbs),
+            );
+            quote! {
+                #impl_mul_square
+
+                #[inline(always)]
+                pub(crate) const fn montgomery_reduce(r: &[u64; #wide_num_limbs]) -> Self {
+                    #impl_mont
+                }
+            }
+        }
+        MulBackend::Limb32 => impl_mul_square(
+            quote! { self.mul_limb32(rhs) },
+            quote! { self.mul_limb32(self) },
+        ),
+        #[cfg(feature = "asm")]
+        MulBackend::External => quote! {},
+    };
     quote::quote! {
         impl #field {
             #[inline(always)]
@@ -41,20 +93,7 @@ pub(crate) fn impl_arith(field: &syn::Ident, num_limbs: usize, inv: u64) -> Toke
                 #impl_neg
             }
 
-            #[inline(always)]
-            pub const fn mul(&self, rhs: &Self) -> Self{
-                #impl_mul
-            }
-
-            #[inline(always)]
-            pub const fn square(&self) -> Self{
-                #impl_square
-            }
-
-            #[inline(always)]
-            pub(crate) const fn montgomery_reduce(r: &[u64; #wide_num_limbs]) -> Self {
-                #impl_mont
-            }
+            #impl_mul_square
 
             #[inline(always)]
             pub(crate) const fn from_mont(&self) -> [u64; #num_limbs] {
@@ -95,6 +134,243 @@ pub(crate) fn impl_arith_always_const(
     }
 }
 
+/// Multiplication by a `u64` integer. The short product `t = a * k` has `num_limbs + 1`
+/// limbs and `t < 2^64 * m`, so a single quotient digit brings it back below the modulus.
+/// The digit is estimated from the top limbs of the normalized operands as in the step of
+/// Knuth's algorithm D, which overshoots by at most two. The 2-by-1 limb division uses the
+/// reciprocal of Möller and Granlund, with masks in place of branches.
+pub(crate) fn impl_mul_u64(field: &syn::Ident, num_limbs: usize, modulus: &BigUint) -> TokenStream {
+    let n = num_limbs;
+    let shift = (64 * n) as u64 - modulus.bits();
+    let d: BigUint = (modulus << shift) >> (64 * (n - 1));
+    let d = d.to_u64().unwrap();
+    let one = BigUint::from(1u8);
+    let v: BigUint = ((&one << 128usize) - 1u8) / d - (&one << 64usize);
+    let v = v.to_u64().unwrap();
+
+    let mut gen = quote! { use crate::arithmetic::{adc, sbb, mac}; };
+
+    // t := a * k
+    for i in 0..n {
+        let t_i = fmtid!("t_{}", i);
+        let carry_in = select(i == 0, quote! {0}, quote! {carry});
+        let carry_out = select(
+            i == n - 1,
+            fmtid!("t_{}", n).to_token_stream(),
+            quote! {carry},
+        );
+        gen.extend(quote! { let (#t_i, #carry_out) = mac(0, self.0[#i], rhs, #carry_in); });
+    }
+
+    // Top two limbs of t << shift.
+    let (t_n, t_n1, t_n2) = (
+        fmtid!("t_{}", n),
+        fmtid!("t_{}", n - 1),
+        fmtid!("t_{}", n - 2),
+    );
+    gen.extend(if shift == 0 {
+        quote! {
+            let u1 = #t_n;
+            let u0 = #t_n1;
+        }
+    } else {
+        let rshift = 64 - shift;
+        quote! {
+            let u1 = (#t_n << #shift) | (#t_n1 >> #rshift);
+            let u0 = (#t_n1 << #shift) | (#t_n2 >> #rshift);
+        }
+    });
+
+    // q := min(floor((u1, u0) / d), 2^64 - 1)
+    gen.extend(quote! {
+        const D: u64 = #d;
+        const V: u64 = #v;
+        let saturate = ((u1 == D) as u64).wrapping_neg();
+        let u1 = u1 & !saturate;
+        let q = ((V as u128) * (u1 as u128)).wrapping_add(((u1 as u128) << 64) | (u0 as u128));
+        let (q1, q0) = (((q >> 64) as u64).wrapping_add(1), q as u64);
+        let r = u0.wrapping_sub(q1.wrapping_mul(D));
+        let mask = ((r > q0) as u64).wrapping_neg();
+        let q1 = q1.wrapping_add(mask);
+        let r = r.wrapping_add(D & mask);
+        let q = (q1 + (r >= D) as u64) | saturate;
+    });
+
+    // t := t - q * m, which lies in (-2m, m)
+    for i in 0..n {
+        let s_i = fmtid!("s_{}", i);
+        let carry_in = select(i == 0, quote! {0}, quote! {carry});
+        let carry_out = select(
+            i == n - 1,
+            fmtid!("s_{}", n).to_token_stream(),
+            quote! {carry},
+        );
+        gen.extend(
+            quote! { let (#s_i, #carry_out) = mac(0, q, Self::MODULUS_LIMBS[#i], #carry_in); },
+        );
+    }
+    for i in 0..=n {
+        let (t_i, s_i) = (fmtid!("t_{}", i), fmtid!("s_{}", i));
+        let borrow_in = select(i == 0, quote! {0}, quote! {borrow});
+        let borrow_out = select(i == n, quote! {_}, quote! {borrow});
+        gen.extend(quote! { let (#t_i, #borrow_out) = sbb(#t_i, #s_i, #borrow_in); });
+    }
+
+    // Add the modulus back while t is negative.
+    for _ in 0..2 {
+        gen.extend(quote! { let mask = ((#t_n as i64) >> 63) as u64; });
+        for i in 0..=n {
+            let t_i = fmtid!("t_{}", i);
+            let carry_in = select(i == 0, quote! {0}, quote! {carry});
+            let carry_out = select(i == n, quote! {_}, quote! {carry});
+            let m_i = select(i == n, quote! {0}, quote! {Self::MODULUS_LIMBS[#i] & mask});
+            gen.extend(quote! { let (#t_i, #carry_out) = adc(#t_i, #m_i, #carry_in); });
+        }
+    }
+
+    let ret: Vec<_> = (0..n).map(|i| fmtid!("t_{}", i)).collect();
+    quote! {
+        impl #field {
+            /// Multiplies `self` by the integer `rhs`, which is cheaper than a full
+            /// multiplication by `Self::from(rhs)`.
+            #[inline]
+            pub const fn mul_u64(&self, rhs: u64) -> Self {
+                #gen
+                #field([#(#ret),*])
+            }
+        }
+    }
+}
+
+/// Division by two. The Montgomery form is linear, so halving the representation halves
+/// the element: odd representatives get the (odd) modulus added first, and the
+/// `num_limbs + 1` limb sum is shifted right by one bit.
+pub(crate) fn impl_halve(field: &syn::Ident, num_limbs: usize) -> TokenStream {
+    let n = num_limbs;
+    let mut gen = quote! {
+        use crate::arithmetic::adc;
+        let mask = (self.0[0] & 1).wrapping_neg();
+    };
+
+    // t := self + (m & mask)
+    for i in 0..n {
+        let t_i = fmtid!("t_{}", i);
+        let carry_in = select(i == 0, quote! {0}, quote! {carry});
+        let carry_out = select(
+            i == n - 1,
+            fmtid!("t_{}", n).to_token_stream(),
+            quote! {carry},
+        );
+        gen.extend(
+            quote! { let (#t_i, #carry_out) = adc(self.0[#i], Self::MODULUS_LIMBS[#i] & mask, #carry_in); },
+        );
+    }
+
+    let ret: Vec<_> = (0..n)
+        .map(|i| {
+            let (t_i, t_i1) = (fmtid!("t_{}", i), fmtid!("t_{}", i + 1));
+            quote! { (#t_i >> 1) | (#t_i1 << 63) }
+        })
+        .collect();
+    quote! {
+        impl #field {
+            /// Returns `self / 2`, which is cheaper than a multiplication by `TWO_INV`.
+            #[inline]
+            pub const fn halve(&self) -> Self {
+                #gen
+                #field([#(#ret),*])
+            }
+
+            /// Returns `2^(-k)`, e.g. the normalization factor of an inverse FFT of
+            /// size `2^k`, by halving one `k` times instead of inverting `2^k`.
+            pub const fn two_inv_pow(k: u32) -> Self {
+                let mut res = Self::R;
+                let mut i = 0;
+                while i < k {
+                    res = res.halve();
+                    i += 1;
+                }
+                res
+            }
+        }
+    }
+}
+
+/// Montgomery multiplication over `2 * num_limbs` 32-bit limbs, for targets where
+/// 64x64 bit multiplication is emulated (e.g. `wasm32`). Both backends share the
+/// Montgomery radix `2^(64 * num_limbs)`, so results are bit-identical.
+pub(crate) fn impl_arith_limb32(field: &syn::Ident, num_limbs: usize, inv: u64) -> TokenStream {
+    let n = num_limbs * 2;
+    let inv = inv as u32;
+    quote! {
+        impl #field {
+            #[inline(always)]
+            const fn to_limbs32(limbs: &[u64; #num_limbs]) -> [u32; #n] {
+                let mut res = [0u32; #n];
+                let mut i = 0;
+                while i < #num_limbs {
+                    res[2 * i] = limbs[i] as u32;
+                    res[2 * i + 1] = (limbs[i] >> 32) as u32;
+                    i += 1;
+                }
+                res
+            }
+
+            /// Coarsely integrated operand scanning Montgomery multiplication on
+            /// 32-bit limbs.
+            #[allow(dead_code)]
+            pub(crate) const fn mul_limb32(&self, rhs: &Self) -> Self {
+                let a = Self::to_limbs32(&self.0);
+                let b = Self::to_limbs32(&rhs.0);
+                let m = Self::to_limbs32(&Self::MODULUS_LIMBS);
+
+                let mut t = [0u32; #n + 2];
+                let mut i = 0;
+                while i < #n {
+                    // t += a * b[i]
+                    let mut carry = 0u64;
+                    let mut j = 0;
+                    while j < #n {
+                        let uv = t[j] as u64 + (a[j] as u64) * (b[i] as u64) + carry;
+                        t[j] = uv as u32;
+                        carry = uv >> 32;
+                        j += 1;
+                    }
+                    let uv = t[#n] as u64 + carry;
+                    t[#n] = uv as u32;
+                    t[#n + 1] = (uv >> 32) as u32;
+
+                    // t = (t + k * m) / 2^32
+                    let k = t[0].wrapping_mul(#inv);
+                    let uv = t[0] as u64 + (k as u64) * (m[0] as u64);
+                    let mut carry = uv >> 32;
+                    let mut j = 1;
+                    while j < #n {
+                        let uv = t[j] as u64 + (k as u64) * (m[j] as u64) + carry;
+                        t[j - 1] = uv as u32;
+                        carry = uv >> 32;
+                        j += 1;
+                    }
+                    let uv = t[#n] as u64 + carry;
+                    t[#n - 1] = uv as u32;
+                    t[#n] = t[#n + 1] + (uv >> 32) as u32;
+                    i += 1;
+                }
+
+                let mut res = [0u64; #num_limbs];
+                let mut i = 0;
+                while i < #num_limbs {
+                    res[i] = (t[2 * i] as u64) | ((t[2 * i + 1] as u64) << 32);
+                    i += 1;
+                }
+                // The modulus is smaller than 2^(64 * num_limbs - 1), so `t < 2m` fits
+                // in `num_limbs` limbs and the top word is zero. `sub` reduces it below `m`.
+                #field(res).sub(&#field(Self::MODULUS_LIMBS))
+            }
+        }
+    }
+}
+
 fn impl_mul(field: &syn::Ident, num_limbs: usize, constant: bool) -> TokenStream {
     let mut gen = quote! { use crate::arithmetic::{adc, sbb, mac}; };
     for i in 0..num_limbs {
@@ -0,0 +1,94 @@
+use proc_macro2::TokenStream;
+
+/// Montgomery multiplication for 7-limb fields with the BMI2/ADX instructions.
+///
+/// The inner loops interleave two carry chains with `adcx` and `adox`. All 13
+/// usable general purpose registers are taken, so `b_ptr` is spilled to the
+/// stack. The final conditional subtraction of the modulus is left to `sub`.
+pub(crate) fn impl_mul(field: &syn::Ident, inv: u64) -> TokenStream {
+    const N: usize = 7;
+    // t[0..=N], rotated by one position after every reduction step.
+    const T: [&str; N + 1] = ["r8", "r9", "r10", "r11", "r12", "r13", "r14", "r15"];
+    let t = |i: usize, j: usize| T[(i + j) % (N + 1)];
+
+    let mut lines = vec![
+        // Algorithm
+        // -----------------------------------------
+        // for i=0 to N-1
+        //   t := t + a * b[i]
+        //   m := t[0] * m0ninv mod W
+        //   t := (t + m * M) / W
+        //
+        // With a, b < M we have t < 2M after every step, so N + 1 words are enough.
+        "push rcx".to_string(),
+    ];
+    lines.extend(T.iter().map(|r| format!("xor {r}, {r}")));
+
+    for i in 0..N {
+        // t := t + a * b[i]
+        lines.push("mov rdx, qword ptr [rsp]".to_string());
+        lines.push(format!("mov rdx, qword ptr [rdx + {}]", 8 * i));
+        // Clears CF and OF, t[N] is already zero.
+        lines.push(format!("xor {0}, {0}", t(i, N)));
+        for j in 0..N {
+            lines.push(format!("mulx rcx, rax, qword ptr [rsi + {}]", 8 * j));
+            lines.push(format!("adcx {}, rax", t(i, j)));
+            lines.push(format!("adox {}, rcx", t(i, j + 1)));
+        }
+        lines.push(format!("adc {}, 0", t(i, N)));
+
+        // t := (t + m * M) / W, the division is the rotation of the registers.
+        lines.push(format!("mov rdx, {}", t(i, 0)));
+        lines.push(format!("imul rdx, qword ptr [rdi + {}]", 8 * N));
+        lines.push("xor eax, eax".to_string());
+        for j in 0..N {
+            lines.push(format!("mulx rcx, rax, qword ptr [rdi + {}]", 8 * j));
+            lines.push(format!("adcx {}, rax", t(i, j)));
+            lines.push(format!("adox {}, rcx", t(i, j + 1)));
+        }
+        lines.push(format!("adc {}, 0", t(i, N)));
+    }
+    lines.push("pop rcx".to_string());
+
+    let r = (0..N)
+        .map(|j| quote::format_ident!("r{}", j))
+        .collect::<Vec<_>>();
+    let out = (0..N).map(|j| t(N, j)).collect::<Vec<_>>();
+    let spare = t(N, N);
+
+    quote::quote! {
+        impl #field {
+            /// Modulus limbs followed by `-m^{-1} mod 2^64`.
+            const MONT_CONSTANTS: [u64; 8] = {
+                let m = Self::MODULUS_LIMBS;
+                [m[0], m[1], m[2], m[3], m[4], m[5], m[6], #inv]
+            };
+
+            /// Multiplies `rhs` by `self`, returning the result.
+            #[inline]
+            pub fn mul(&self, rhs: &Self) -> #field {
+                #(let #r: u64;)*
+                unsafe {
+                    core::arch::asm!(
+                        #(#lines),*,
+                        in("rsi") self.0.as_ptr(),
+                        in("rdi") Self::MONT_CONSTANTS.as_ptr(),
+                        inout("rcx") rhs.0.as_ptr() => _,
+                        out("rax") _,
+                        out("rdx") _,
+                        #(out(#out) #r,)*
+                        out(#spare) _,
+                        options(pure, readonly)
+                    );
+                }
+                #field([#(#r),*]).sub(&#field(Self::MODULUS_LIMBS))
+            }
+
+            /// Squares this element.
+            #[inline]
+            pub fn square(&self) -> #field {
+                self.mul(self)
+            }
+        }
+    }
+}
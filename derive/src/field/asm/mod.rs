@@ -1 +1,2 @@
 pub(crate) mod limb4;
+pub(crate) mod limb7;
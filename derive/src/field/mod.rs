@@ -15,7 +15,7 @@ struct FieldConfig {
     field: syn::Ident,
     modulus: BigUint,
     mul_gen: BigUint,
-    zeta: BigUint,
+    zeta: Option<BigUint>,
     endian: String,
     from_uniform: Vec<usize>,
 }
@@ -70,7 +70,12 @@ impl syn::parse::Parse for FieldConfig {
 
         let modulus = get_big("modulus")?;
         let mul_gen = get_big("mul_gen")?;
-        let zeta = get_big("zeta")?;
+        // Fields without cube roots of unity, `p = 2 mod 3`, have no `zeta`.
+        let has_zeta = input
+            .fork()
+            .parse::<syn::Ident>()
+            .map_or(false, |key| key == "zeta");
+        let zeta = has_zeta.then(|| get_big("zeta")).transpose()?;
         let from_uniform = get_usize_list("from_uniform")?;
         let endian = get_str("endian")?;
         assert!(endian == "little" || endian == "big");
@@ -127,6 +132,8 @@ pub(crate) fn impl_field(input: TokenStream) -> TokenStream {
     let r1 = to_token(&r1);
     let r2 = to_token(&r2);
     let r3 = to_token(&r3);
+    let modulus_minus_two = crate::utils::big_to_limbs(&(&modulus - 2u64), num_limbs);
+    let modulus_minus_two = quote! {[#(#modulus_minus_two,)*]};
 
     // inv = -(r^{-1} mod 2^64) mod 2^64
     let mut inv64 = 1u64;
@@ -163,6 +170,29 @@ pub(crate) fn impl_field(input: TokenStream) -> TokenStream {
 
     let two_inv = mod_inv(&BigUint::from(2usize), &modulus);
 
+    // Sarkar's tables, see `crate::ff_ext::sqrt_table`.
+    let impl_sqrt_vartime = if &modulus % 16u64 == BigUint::from(1u64) && s == 32 {
+        quote! {
+            #[cfg(feature = "precompute")]
+            impl #field {
+                /// Returns the square root of the element, if it exists, from
+                /// tables of the powers of `ROOT_OF_UNITY` built on first use.
+                ///
+                /// Unlike [`ff::Field::sqrt`], this runs in variable time: only
+                /// use it on public values, e.g. when decompressing public points.
+                pub fn sqrt_vartime(&self) -> subtle::CtOption<Self> {
+                    lazy_static::lazy_static! {
+                        static ref SQRT_TABLES: crate::ff_ext::sqrt_table::SqrtTables<#field> =
+                            crate::ff_ext::sqrt_table::SqrtTables::new();
+                    }
+                    SQRT_TABLES.sqrt_vartime(self)
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     let sqrt_impl = {
         if &modulus % 16u64 == BigUint::from(1u64) {
             let tm1o2 = ((&t - 1usize) * &two_inv) % &modulus;
@@ -196,7 +226,7 @@ pub(crate) fn impl_field(input: TokenStream) -> TokenStream {
     let two_inv = to_token(&mont(&two_inv));
     let mul_gen = to_token(&mont(&mul_gen));
     let delta = to_token(&mont(&delta));
-    let zeta = to_token(&mont(&zeta));
+    let zeta = zeta.map(|zeta| to_token(&mont(&zeta)));
 
     let endian = match endian.as_str() {
         "little" => {
@@ -210,15 +240,40 @@ pub(crate) fn impl_field(input: TokenStream) -> TokenStream {
         }
     };
 
+    let from_wide = syn::Ident::new(&format!("from_u{}", num_limbs * 128), Span::call_site());
+
     let impl_field = quote! {
         #[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+        #[repr(transparent)]
         pub struct #field(pub(crate) [u64; #num_limbs]);
 
+        // The element is its limbs, so that slices can be handed to FFI as limbs.
+        const _: () = assert!(
+            core::mem::size_of::<#field>() == #num_limbs * 8
+                && core::mem::align_of::<#field>() == core::mem::align_of::<u64>()
+        );
+
         impl core::fmt::Debug for #field {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "{:#x}", self)
+            }
+        }
+
+        impl core::fmt::Display for #field {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "{:#x}", self)
+            }
+        }
+
+        /// The canonical integer of the element in lowercase hexadecimal, with
+        /// all its leading zeros, and with a `0x` prefix in the alternate form.
+        impl core::fmt::LowerHex for #field {
             fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                 use ff::PrimeField;
                 let tmp = self.to_repr();
-                write!(f, "0x")?;
+                if f.alternate() {
+                    write!(f, "0x")?;
+                }
                 for &b in tmp.as_ref().iter().rev() {
                     write!(f, "{:02x}", b)?;
                 }
@@ -226,6 +281,9 @@ pub(crate) fn impl_field(input: TokenStream) -> TokenStream {
             }
         }
 
+        #[cfg(feature = "zeroize")]
+        impl crate::zeroize::DefaultIsZeroes for #field {}
+
         impl ConstantTimeEq for #field {
             fn ct_eq(&self, other: &Self) -> Choice {
                 Choice::from(
@@ -241,7 +299,7 @@ pub(crate) fn impl_field(input: TokenStream) -> TokenStream {
             fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
                 let limbs = (0..#num_limbs)
                     .map(|i| u64::conditional_select(&a.0[i], &b.0[i], choice))
-                    .collect::<Vec<_>>()
+                    .collect::<alloc::vec::Vec<_>>()
                     .try_into()
                     .unwrap();
                 #field(limbs)
@@ -291,6 +349,7 @@ pub(crate) fn impl_field(input: TokenStream) -> TokenStream {
             const R: Self = Self(#r1);
             const R2: Self = Self(#r2);
             const R3: Self = Self(#r3);
+            pub(crate) const INV: u64 = #inv64;
 
             /// Returns zero, the additive identity.
             #[inline(always)]
@@ -306,10 +365,101 @@ pub(crate) fn impl_field(input: TokenStream) -> TokenStream {
 
             /// Converts from an integer represented in little endian
             /// into its (congruent) `$field` representation.
+            ///
+            /// Same as [`Self::from_raw_unchecked`], use [`Self::from_raw_checked`]
+            /// for inputs that must be canonical.
             pub const fn from_raw(val: [u64; Self::NUM_LIMBS]) -> Self {
+                Self::from_raw_unchecked(val)
+            }
+
+            /// Converts from an integer represented in little endian, failing if
+            /// it is not smaller than the modulus.
+            pub fn from_raw_checked(val: [u64; Self::NUM_LIMBS]) -> subtle::CtOption<Self> {
+                subtle::CtOption::new(
+                    Self::from_raw_unchecked(val),
+                    subtle::Choice::from(Self::is_less_than_modulus(&val) as u8),
+                )
+            }
+
+            /// Converts from an integer represented in little endian without
+            /// checking that it is smaller than the modulus. Larger inputs are
+            /// silently reduced. Meant for constants that are known to be canonical.
+            pub const fn from_raw_unchecked(val: [u64; Self::NUM_LIMBS]) -> Self {
                 Self(val).mul_const(&Self::R2)
             }
 
+            /// Returns the limbs of the canonical integer of the element, in
+            /// `[0, p)` and in little endian, the inverse of [`Self::from_raw`].
+            /// These are the limbs of the `BigInt` of the element in other
+            /// libraries, e.g. arkworks' `into_bigint`.
+            pub fn to_raw(&self) -> [u64; Self::NUM_LIMBS] {
+                self.from_mont()
+            }
+
+            /// Returns the limbs of the Montgomery form `a R mod p` of the element,
+            /// with `R = 2^(64 * NUM_LIMBS)`, in little endian. This is the internal
+            /// representation, which [`crate::serde::SerdeObject::to_raw_bytes`]
+            /// writes, and the one expected by most GPU and C libraries working on
+            /// the limbs directly.
+            pub const fn to_montgomery_limbs(&self) -> [u64; Self::NUM_LIMBS] {
+                self.0
+            }
+
+            /// Views a slice of elements as their Montgomery limbs, `NUM_LIMBS`
+            /// limbs per element in little endian, without copying them.
+            pub fn slice_as_limbs(elements: &[Self]) -> &[u64] {
+                // SAFETY: `Self` is `#[repr(transparent)]` over `[u64; NUM_LIMBS]`.
+                unsafe {
+                    core::slice::from_raw_parts(
+                        elements.as_ptr() as *const u64,
+                        elements.len() * Self::NUM_LIMBS,
+                    )
+                }
+            }
+
+            /// Converts from the limbs of a Montgomery form, the inverse of
+            /// [`Self::to_montgomery_limbs`], failing if they are not smaller than
+            /// the modulus.
+            pub fn from_montgomery_limbs(limbs: [u64; Self::NUM_LIMBS]) -> subtle::CtOption<Self> {
+                subtle::CtOption::new(
+                    Self(limbs),
+                    subtle::Choice::from(Self::is_less_than_modulus(&limbs) as u8),
+                )
+            }
+
+            /// Returns the canonical integer of the element, in `[0, p)`.
+            #[cfg(feature = "num-bigint")]
+            pub fn to_canonical_biguint(&self) -> num_bigint::BigUint {
+                let bytes = self
+                    .to_raw()
+                    .iter()
+                    .flat_map(|limb| limb.to_le_bytes())
+                    .collect::<alloc::vec::Vec<_>>();
+                num_bigint::BigUint::from_bytes_le(&bytes)
+            }
+
+            /// Converts from an integer, failing if it is not smaller than the modulus.
+            #[cfg(feature = "num-bigint")]
+            pub fn from_biguint(value: &num_bigint::BigUint) -> Option<Self> {
+                let digits = value.to_u64_digits();
+                if digits.len() > Self::NUM_LIMBS {
+                    return None;
+                }
+                let mut limbs = [0u64; Self::NUM_LIMBS];
+                limbs[..digits.len()].copy_from_slice(&digits);
+                Self::from_raw_checked(limbs).into()
+            }
+
+            /// Reduces an integer of twice the size of the modulus, given by its
+            /// limbs in little endian, as needed to sample elements without bias.
+            pub fn #from_wide(limbs: [u64; Self::NUM_LIMBS * 2]) -> Self {
+                let (lo, hi) = limbs.split_at(Self::NUM_LIMBS);
+                let lo: [u64; Self::NUM_LIMBS] = lo.try_into().unwrap();
+                let hi: [u64; Self::NUM_LIMBS] = hi.try_into().unwrap();
+                // lo * R^2 / R + hi * R^3 / R = (lo + hi * 2^(64 * NUM_LIMBS)) * R
+                Self(lo).mul_const(&Self::R2) + Self(hi).mul_const(&Self::R3)
+            }
+
             /// Attempts to convert a <#endian>-endian byte representation of
             /// a scalar into a `$field`, failing if the input is not canonical.
             pub fn from_bytes(bytes: &[u8; Self::SIZE]) -> subtle::CtOption<Self> {
@@ -341,17 +491,32 @@ pub(crate) fn impl_field(input: TokenStream) -> TokenStream {
                 crate::ff_ext::jacobi::jacobi::<#jacobi_constant>(&self.0, &#modulus_limbs_ident)
             }
 
-            // Returns the multiplicative inverse of the element. If it is zero, the method fails.
+            const BYINVERTER: crate::ff_ext::inverse::BYInverter<#by_inverter_constant> =
+                crate::ff_ext::inverse::BYInverter::<#by_inverter_constant>::new(&#modulus_limbs_ident, &#r2);
+
+            // Returns the multiplicative inverse of the element with the constant time
+            // Bernstein-Yang method, or with Fermat's little theorem with the
+            // `fermat-inversion` feature. If it is zero, the method fails.
             #[inline(always)]
             fn invert(&self) -> subtle::CtOption<Self> {
-                const BYINVERTOR: crate::ff_ext::inverse::BYInverter<#by_inverter_constant> =
-                    crate::ff_ext::inverse::BYInverter::<#by_inverter_constant>::new(&#modulus_limbs_ident, &#r2);
-
-                if let Some(inverse) = BYINVERTOR.invert::<{ Self::NUM_LIMBS }>(&self.0) {
-                    subtle::CtOption::new(Self(inverse), subtle::Choice::from(1))
-                } else {
-                    subtle::CtOption::new(Self::zero(), subtle::Choice::from(0))
+                #[cfg(not(feature = "fermat-inversion"))]
+                {
+                    let (inverse, is_invertible) =
+                        Self::BYINVERTER.invert_ct::<{ Self::NUM_LIMBS }>(&self.0);
+                    subtle::CtOption::new(Self(inverse), is_invertible)
                 }
+                #[cfg(feature = "fermat-inversion")]
+                {
+                    use ff::Field;
+                    subtle::CtOption::new(self.pow_vartime(#modulus_minus_two), !self.is_zero())
+                }
+            }
+
+            /// Returns the multiplicative inverse of the element, or `None` if it is zero.
+            /// This runs in variable time, and is faster than [`ff::Field::invert`] for
+            /// public elements.
+            pub fn invert_vartime(&self) -> Option<Self> {
+                Self::BYINVERTER.invert::<{ Self::NUM_LIMBS }>(&self.0).map(Self)
             }
 
             #[inline(always)]
@@ -388,14 +553,7 @@ pub(crate) fn impl_field(input: TokenStream) -> TokenStream {
             // Returns the multiplicative inverse of the element. If it is zero, the method fails.
             #[inline(always)]
             fn invert(&self) -> CtOption<Self> {
-                const BYINVERTOR: crate::ff_ext::inverse::BYInverter<#by_inverter_constant> =
-                crate::ff_ext::inverse::BYInverter::<#by_inverter_constant>::new(&#modulus_limbs_ident, &#r2);
-
-                if let Some(inverse) = BYINVERTOR.invert::<{ Self::NUM_LIMBS }>(&self.0) {
-                    subtle::CtOption::new(Self(inverse), subtle::Choice::from(1))
-                } else {
-                    subtle::CtOption::new(Self::zero(), subtle::Choice::from(0))
-                }
+                self.invert()
             }
 
             #sqrt_impl
@@ -408,6 +566,23 @@ pub(crate) fn impl_field(input: TokenStream) -> TokenStream {
 
     let impl_prime_field = quote! {
 
+        #[cfg(feature = "num-bigint")]
+        impl From<#field> for num_bigint::BigUint {
+            fn from(value: #field) -> num_bigint::BigUint {
+                value.to_canonical_biguint()
+            }
+        }
+
+        /// Fails with the input if it is not smaller than the modulus.
+        #[cfg(feature = "num-bigint")]
+        impl TryFrom<num_bigint::BigUint> for #field {
+            type Error = num_bigint::BigUint;
+
+            fn try_from(value: num_bigint::BigUint) -> Result<Self, Self::Error> {
+                Self::from_biguint(&value).ok_or(value)
+            }
+        }
+
         // TODO use ::core::borrow::Borrow or AsRef
         impl From<#field> for crate::serde::Repr<{ #field::SIZE }> {
             fn from(value: #field) -> crate::serde::Repr<{ #field::SIZE }> {
@@ -440,9 +615,9 @@ pub(crate) fn impl_field(input: TokenStream) -> TokenStream {
                 Self::R2 * Self(
                     [v as u64, (v >> 64) as u64]
                         .into_iter()
-                        .chain(std::iter::repeat(0))
+                        .chain(core::iter::repeat(0))
                         .take(Self::NUM_LIMBS)
-                        .collect::<Vec<_>>()
+                        .collect::<alloc::vec::Vec<_>>()
                         .try_into()
                         .unwrap(),
                 )
@@ -478,7 +653,7 @@ pub(crate) fn impl_field(input: TokenStream) -> TokenStream {
                     .map(|off| {
                         u64::from_le_bytes(bytes[off * 8..(off + 1) * 8].try_into().unwrap())
                     })
-                    .collect::<Vec<_>>();
+                    .collect::<alloc::vec::Vec<_>>();
                 Self(inner.try_into().unwrap())
             }
 
@@ -490,14 +665,15 @@ pub(crate) fn impl_field(input: TokenStream) -> TokenStream {
                 Self::is_less_than_modulus(&elt.0).then(|| elt)
             }
 
-            fn to_raw_bytes(&self) -> Vec<u8> {
-                let mut res = Vec::with_capacity(#num_limbs * 4);
+            fn to_raw_bytes(&self) -> alloc::vec::Vec<u8> {
+                let mut res = alloc::vec::Vec::with_capacity(#num_limbs * 4);
                 for limb in self.0.iter() {
                     res.extend_from_slice(&limb.to_le_bytes());
                 }
                 res
             }
 
+            #[cfg(feature = "std")]
             fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
                 let inner = [(); #num_limbs].map(|_| {
                     let mut buf = [0; 8];
@@ -507,6 +683,7 @@ pub(crate) fn impl_field(input: TokenStream) -> TokenStream {
                 Self(inner)
             }
 
+            #[cfg(feature = "std")]
             fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
                 let mut inner = [0u64; #num_limbs];
                 for limb in inner.iter_mut() {
@@ -517,13 +694,10 @@ pub(crate) fn impl_field(input: TokenStream) -> TokenStream {
                 let elt = Self(inner);
                 Self::is_less_than_modulus(&elt.0)
                     .then(|| elt)
-                    .ok_or_else(|| {
-                        std::io::Error::new(
-                            std::io::ErrorKind::InvalidData,
-                            "input number is not less than field modulus",
-                        )
-                    })
+                    .ok_or_else(|| crate::Error::NotCanonical.into())
             }
+
+            #[cfg(feature = "std")]
             fn write_raw<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
                 for limb in self.0.iter() {
                     writer.write_all(&limb.to_le_bytes())?;
@@ -533,19 +707,37 @@ pub(crate) fn impl_field(input: TokenStream) -> TokenStream {
         }
     };
 
+    // The 32-bit limb backend is available for the 7-limb Pluto-Eris fields.
+    let has_limb32 = num_limbs == 7;
+    let impl_arith_limb32 = has_limb32.then(|| arith::impl_arith_limb32(&field, num_limbs, inv64));
+    let backend = if cfg!(feature = "limb32") && has_limb32 {
+        arith::MulBackend::Limb32
+    } else {
+        arith::MulBackend::Portable
+    };
+
     #[cfg(feature = "asm")]
     let impl_arith = {
         if num_limbs == 4 && num_bits < 256 {
-            println!("implementing asm, {}", identifier);
             asm::limb4::impl_arith(&field, inv64)
+        } else if num_limbs == 7 && !cfg!(feature = "limb32") {
+            let impl_arith =
+                arith::impl_arith(&field, num_limbs, inv64, arith::MulBackend::External);
+            let impl_mul = asm::limb7::impl_mul(&field, inv64);
+            quote! {
+                #impl_arith
+                #impl_mul
+            }
         } else {
-            arith::impl_arith(&field, num_limbs, inv64)
+            arith::impl_arith(&field, num_limbs, inv64, backend)
         }
     };
     #[cfg(not(feature = "asm"))]
-    let impl_arith = arith::impl_arith(&field, num_limbs, inv64);
+    let impl_arith = arith::impl_arith(&field, num_limbs, inv64, backend);
 
     let impl_arith_always_const = arith::impl_arith_always_const(&field, num_limbs, inv64);
+    let impl_mul_u64 = arith::impl_mul_u64(&field, num_limbs, &modulus);
+    let impl_halve = arith::impl_halve(&field, num_limbs);
 
     let impl_from_uniform_bytes = from_uniform
         .iter()
@@ -557,45 +749,65 @@ pub(crate) fn impl_field(input: TokenStream) -> TokenStream {
                     fn from_uniform_bytes(bytes: &[u8; #input_size]) -> Self {
                         let mut wide = [0u8; Self::SIZE * 2];
                         wide[..#input_size].copy_from_slice(bytes);
-                        let (a0, a1) = wide.split_at(Self::SIZE);
-
-                        let a0: [u64; Self::NUM_LIMBS] = (0..Self::NUM_LIMBS)
-                            .map(|off| u64::from_le_bytes(a0[off * 8..(off + 1) * 8].try_into().unwrap()))
-                            .collect::<Vec<_>>()
-                            .try_into()
-                            .unwrap();
-                        let a0 = #field(a0);
-
-                        let a1: [u64; Self::NUM_LIMBS] = (0..Self::NUM_LIMBS)
-                            .map(|off| u64::from_le_bytes(a1[off * 8..(off + 1) * 8].try_into().unwrap()))
-                            .collect::<Vec<_>>()
+                        let limbs: [u64; Self::NUM_LIMBS * 2] = (0..Self::NUM_LIMBS * 2)
+                            .map(|off| u64::from_le_bytes(wide[off * 8..(off + 1) * 8].try_into().unwrap()))
+                            .collect::<alloc::vec::Vec<_>>()
                             .try_into()
                             .unwrap();
-                        let a1 = #field(a1);
-
-                        // enforce non assembly impl since asm is likely to be optimized for sparse fields
-                        a0.mul_const(&Self::R2) + a1.mul_const(&Self::R3)
-
+                        Self::#from_wide(limbs)
                     }
                 }
             }
         })
         .collect::<proc_macro2::TokenStream>();
 
-    let impl_zeta = quote! {
-        impl ff::WithSmallOrderMulGroup<3> for #field {
-            const ZETA: Self = Self(#zeta);
-        }
+    // Sanity checks of the Montgomery constants, evaluated at compile time.
+    let impl_montgomery_checks = quote! {
+        const _: () = {
+            const fn limbs_eq(a: &[u64; #num_limbs], b: &[u64; #num_limbs]) -> bool {
+                let mut i = 0;
+                while i < #num_limbs {
+                    if a[i] != b[i] {
+                        return false;
+                    }
+                    i += 1;
+                }
+                true
+            }
+
+            let mut one = [0u64; #num_limbs];
+            one[0] = 1;
+
+            // INV = -p^{-1} mod 2^64
+            assert!(#field::MODULUS_LIMBS[0].wrapping_mul(#field::INV) == u64::MAX);
+            // mont(1 * R^2) = R
+            assert!(limbs_eq(&#field(one).mul_const(&#field::R2).0, &#field::R.0));
+            // mont(R^2 * R^2) = R^3
+            assert!(limbs_eq(&#field::R2.mul_const(&#field::R2).0, &#field::R3.0));
+        };
     };
 
+    let impl_zeta = zeta.map(|zeta| {
+        quote! {
+            impl ff::WithSmallOrderMulGroup<3> for #field {
+                const ZETA: Self = Self(#zeta);
+            }
+        }
+    });
+
     let output = quote! {
         #impl_arith
+        #impl_arith_limb32
         #impl_arith_always_const
+        #impl_mul_u64
+        #impl_halve
         #impl_field
+        #impl_sqrt_vartime
         #impl_prime_field
         #impl_serde_object
         #impl_from_uniform_bytes
         #impl_zeta
+        #impl_montgomery_checks
     };
 
     output.into()
@@ -0,0 +1,140 @@
+# This file derives the parameters of the BLS12-377 curve in src/bls12_377 and of
+# its embedded twisted Edwards curve in src/ed_on_bls12_377.
+#
+# BLS12 curves are generated by x with r = x^4 - x^2 + 1 and p = (x - 1)^2 r / 3 + x.
+# For x = 0x8508c00000000001, both p - 1 and r - 1 are divisible by a large power of
+# two, 2^46 and 2^47.
+#
+# Run from the root of the repository with `python3 script/bls12_377.py`.
+
+x = 0x8508C00000000001
+r = x**4 - x**2 + 1
+p = (x - 1) ** 2 * r // 3 + x
+assert p == 0x1AE3A4617C510EAC63B05C06CA1493B1A22D9F300F5138F1EF3622FBA094800170B5D44300000008508C00000000001
+assert r == 0x12AB655E9A2CA55660B44D1E5C37B00159AA76FED00000010A11800000000001
+assert (p - 1) % 2**46 == 0 and (r - 1) % 2**47 == 0
+
+
+# Fq2 = Fq[u]/(u^2 + 5), elements as pairs (c0, c1).
+def f2_mul(a, b):
+    return ((a[0] * b[0] - 5 * a[1] * b[1]) % p, (a[0] * b[1] + a[1] * b[0]) % p)
+
+
+def f2_pow(a, e):
+    res = (1, 0)
+    while e:
+        if e & 1:
+            res = f2_mul(res, a)
+        a = f2_mul(a, a)
+        e >>= 1
+    return res
+
+
+def f2_inv(a):
+    n = pow((a[0] * a[0] + 5 * a[1] * a[1]) % p, -1, p)
+    return (a[0] * n % p, -a[1] * n % p)
+
+
+def f2_is_square(a):
+    return pow((a[0] * a[0] + 5 * a[1] * a[1]) % p, (p - 1) // 2, p) == 1
+
+
+# -5 is not a square in Fq, u is neither a square nor a cube in Fq2.
+u = (0, 1)
+assert pow(p - 5, (p - 1) // 2, p) != 1
+assert not f2_is_square(u) and f2_pow(u, (p * p - 1) // 3) != (1, 0)
+
+# G1: y^2 = x^3 + 1 with cofactor (x - 1)^2 / 3, and its D-type twist
+# G2: y^2 = x^3 + 1/u.
+h1 = (x - 1) ** 2 // 3
+assert (p + 1 - (x + 1)) == h1 * r
+b2 = f2_inv(u)
+
+# The multiplicative generators of Arkworks, 15 and 22, are not squares, so that
+# their odd powers generate the 2-adic roots of unity.
+assert pow(15, (p - 1) // 2, p) == p - 1 and pow(22, (r - 1) // 2, r) == r - 1
+
+# The cube roots of unity for the GLV endomorphism (x, y) -> (zeta_q x, y), acting
+# as the multiplication by zeta_r = x^2 - 1 on G1.
+zeta_r = x * x - 1
+assert (zeta_r * zeta_r + zeta_r + 1) == r
+zeta_q = f2_pow(u, (p - 1) // 3)[0] - 1
+assert pow(zeta_q, 3, p) == 1 and zeta_q != 1
+
+
+def add(P, Q):
+    if P is None:
+        return Q
+    if Q is None:
+        return P
+    (x1, y1), (x2, y2) = P, Q
+    if x1 == x2:
+        if (y1 + y2) % p == 0:
+            return None
+        lam = 3 * x1 * x1 * pow(2 * y1, -1, p) % p
+    else:
+        lam = (y2 - y1) * pow(x2 - x1, -1, p) % p
+    x3 = (lam * lam - x1 - x2) % p
+    return (x3, (lam * (x1 - x3) - y1) % p)
+
+
+def mul(k, P):
+    R = None
+    for b in bin(k)[2:]:
+        R = add(R, R)
+        if b == "1":
+            R = add(R, P)
+    return R
+
+
+g1 = (
+    0x008848DEFE740A67C8FC6225BF87FF5485951E2CAA9D41BB188282C8BD37CB5CD5481512FFCD394EEAB9B16EB21BE9EF,
+    0x01914A69C5102EFF1F674F5D30AFEEC4BD7FB348CA3E52D96D182AD44FB82305C2FE3D3634A9591AFD82DE55559C8EA6,
+)
+assert (g1[1] ** 2 - g1[0] ** 3 - 1) % p == 0 and mul(r, g1) is None
+assert mul(zeta_r, g1) == (g1[0] * zeta_q % p, g1[1])
+
+# The untwist-Frobenius-twist endomorphism psi(x, y) = (cx conj(x), cy conj(y)).
+cx = f2_pow(u, (p - 1) // 3)
+cy = f2_pow(u, (p - 1) // 2)
+assert cx[1] == 0 and cy[1] == 0
+
+# The SvdW constant Z of RFC 9380 for G1 is 1, and 2 for G2 as 1 and -1 do not
+# satisfy the conditions on g(Z) = Z^3 + b2 and h(Z) = -3 Z^2 / (4 g(Z)).
+def g2_rhs(z):
+    return ((z * z * z + b2[0]) % p, b2[1])
+
+
+def is_svdw_z(z):
+    gz = g2_rhs(z)
+    h = f2_mul((-3 * z * z % p, 0), f2_inv(f2_mul((4, 0), gz)))
+    half = g2_rhs(-z * pow(2, -1, p) % p)
+    return f2_is_square(h) and (f2_is_square(gz) or f2_is_square(half))
+
+
+assert [is_svdw_z(z) for z in [1, p - 1, 2]] == [False, False, True]
+
+# GLV decomposition with b1 = 1 and b2 = x^2 - 1, so that the halves of the
+# scalars are less than 128 bits.
+gamma1 = ((1 << 256) + r // 2) // r
+gamma2 = ((1 << 256) * (x * x - 1) + r // 2) // r
+
+# The embedded curve: -x^2 + y^2 = 1 + 3021 x^2 y^2 over Fr, of order 4 l.
+a, d = r - 1, 3021
+l = 0x04AAD957A68B2955982D1347970DEC005293A3AFC43C8AFEB95AEE9AC33FD9FF
+assert pow(a, (r - 1) // 2, r) == 1 and pow(d, (r - 1) // 2, r) != 1
+assert l % 3 == 2
+mont_a = 2 * (a + d) * pow(a - d, -1, r) % r
+mont_b = 4 * pow(a - d, -1, r) % r
+
+print("p      =", hex(p))
+print("r      =", hex(r))
+print("h1     =", hex(h1))
+print("zeta_q =", hex(zeta_q))
+print("zeta_r =", hex(zeta_r))
+print("gamma1 =", hex(gamma1))
+print("gamma2 =", hex(gamma2))
+print("cx     =", hex(cx[0]))
+print("cy     =", hex(cy[0]))
+print("A      =", hex(mont_a))
+print("B      =", hex(mont_b))
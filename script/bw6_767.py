@@ -0,0 +1,116 @@
+# This file derives the parameters of the BW6-767 curve in src/bw6_767, a BW6
+# curve whose scalar field is the base field of BLS12-381. It is the curve of
+# arkworks' ark-bw6-767.
+#
+# Brezing-Weng method for embedding degree 6 over r = q_BLS12-381: with zeta a
+# primitive cube root of unity modulo r, the trace t = zeta + 1 (mod r) and
+# y = (t - 2) / sqrt(-3) (mod r) give p = (t^2 + 3 y^2) / 4 with r | p + 1 - t
+# and r | p^2 - p + 1. Among the lifts t + ht r and y + hy r with |ht|, |hy| <= 20,
+# (ht, hy) = (-4, 6) gives the smallest prime p.
+#
+# Run from the root of the repository with `python3 script/bw6_767.py`.
+
+import random
+
+x = -0xD201000000010000
+r = (x - 1) ** 2 * (x**4 - x**2 + 1) // 3 + x
+assert r == 0x1A0111EA397FE69A4B1BA7B6434BACD764774B84F38512BF6730D2A0F6B0F6241EABFFFEB153FFFFB9FEFFFFFFFFAAAB
+
+
+def is_probable_prime(n, rounds=40):
+    if n < 2:
+        return False
+    for q in [2, 3, 5, 7, 11, 13, 17, 19, 23, 29]:
+        if n % q == 0:
+            return n == q
+    d, s = n - 1, 0
+    while d % 2 == 0:
+        d, s = d // 2, s + 1
+    for _ in range(rounds):
+        a = pow(random.randrange(2, n - 1), d, n)
+        if a in (1, n - 1):
+            continue
+        for _ in range(s - 1):
+            a = a * a % n
+            if a == n - 1:
+                break
+        else:
+            return False
+    return True
+
+
+assert is_probable_prime(r) and r % 4 == 3
+sqrt_m3 = pow(-3 % r, (r + 1) // 4, r)
+sqrt_m3 = min(sqrt_m3, r - sqrt_m3)
+assert sqrt_m3 * sqrt_m3 % r == r - 3
+zeta = (1 + sqrt_m3) * pow(2, -1, r) % r
+t0 = zeta + 1
+y0 = (t0 - 2) * pow(sqrt_m3, -1, r) % r
+
+t = t0 - 4 * r
+y = y0 + 6 * r
+p = (t * t + 3 * y * y) // 4
+assert (t * t + 3 * y * y) % 4 == 0
+assert is_probable_prime(p) and p.bit_length() == 767
+assert p % 4 == 3 and p % 3 == 1
+
+# G1: y^2 = x^3 + 1 has trace t, and its M-type twist y^2 = x^3 + xi for
+# xi = 3, neither a square nor a cube, has trace t + 11 r.
+xi = 3
+assert pow(xi, (p - 1) // 2, p) != 1 and pow(xi, (p - 1) // 3, p) != 1
+t_twist = t + 11 * r
+h1, rem1 = divmod(p + 1 - t, r)
+h2, rem2 = divmod(p + 1 - t_twist, r)
+assert rem1 == 0 and rem2 == 0
+assert (p * p - p + 1) % r == 0
+
+
+def add(P, Q):
+    if P is None:
+        return Q
+    if Q is None:
+        return P
+    (x1, y1), (x2, y2) = P, Q
+    if x1 == x2:
+        if (y1 + y2) % p == 0:
+            return None
+        lam = 3 * x1 * x1 * pow(2 * y1, -1, p) % p
+    else:
+        lam = (y2 - y1) * pow(x2 - x1, -1, p) % p
+    x3 = (lam * lam - x1 - x2) % p
+    return (x3, (lam * (x1 - x3) - y1) % p)
+
+
+def mul(k, P):
+    R = None
+    for b in bin(k)[2:]:
+        R = add(R, R)
+        if b == "1":
+            R = add(R, P)
+    return R
+
+
+# The generators of arkworks' ark-bw6-767.
+g1 = (
+    0x150E067C2AFCCBAC6F5A0DEE58BC4A180A5DE79876B516A091E03693E3ED8FFB6212C31CFD30B17D335A0A48CE788B3E61C53C6EA4FA67AFCD6B4EF2E6613C3C32D2DFE4485DA5E444F87B2886DBFDB651ABFBAECBF011DA75A2B2A99E774012,
+    0x44865BFC43A69006E1F51B80B95B10DA2F2964ED6C5E9D602CE88663261F5AB0B612BFDCD6F9E857C3E1613D51CD0AB532D0E963D40E4287457BEC4BD8F0354EBC5C3E3FC57F6F3721403FE88F0AA57036913C481AC278E9D220C004D71F2E74,
+)
+g2 = (
+    0x3D1C80BB58974FCF1AE9A7915113F900E6C917DD254FB1F98AB179813B2646F8D9A65E6D17539FA939F6534595A6C8C68DDBB43C37B7BD131D02CB318337D1F5765B5D05A12CDF086B2CC86971AD33C2437BD02B1EF04FFDCFFF404BB69DAACE,
+    0x4B0CE0388F6863DA2A67A8A56403FF1B16E32CF8F5A3020E1132991C7D1DD6BC097213159DA7F0BD69D56DD25BEF2D3DBDD421B7B3A63B5C0173884DEA977EE5228710A92FF3F52D3FC995525852BD902FEDE463CC95B2C167E9258BEC46D5FB,
+)
+for (gx, gy), b in [(g1, 1), (g2, xi)]:
+    assert (gy * gy - gx**3 - b) % p == 0
+    assert mul(r, (gx, gy)) is None
+
+# The optimal ate pairing loops over x and x^2 - x - 1.
+X_MINUS_1_DIV_3 = (x - 1) // 3
+assert (x - 1) % 3 == 0
+
+print("p  =", hex(p))
+print("r  =", hex(r))
+print("t  =", t)
+print("h1 =", hex(h1))
+print("h2 =", hex(h2))
+print("(x - 1) / 3 =", hex(X_MINUS_1_DIV_3))
+print("x^2 - x - 1 =", hex(x * x - x - 1))
@@ -0,0 +1,88 @@
+// This file generates the BW6-767 test vectors in src/tests/vectors/bw6_767_*
+// with arkworks' ark-bw6-767, which shares no code with the crate.
+//
+// Run it as the `main.rs` of a binary crate depending on ark-bw6-767, ark-ec,
+// ark-ff and ark-serialize 0.5, with `cargo run -- <path to src/tests/vectors>`.
+
+use ark_bw6_767::{Fr, G1Affine, G1Projective, G2Affine, G2Projective, BW6_767};
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup, PrimeGroup};
+use ark_ff::{BigInteger, PrimeField};
+use ark_serialize::CanonicalSerialize;
+use std::str::FromStr;
+
+fn be_hex<F: PrimeField>(f: &F) -> String {
+    hex(&f.into_bigint().to_bytes_be())
+}
+
+fn hex(b: &[u8]) -> String {
+    b.iter().map(|x| format!("{x:02x}")).collect()
+}
+
+fn main() {
+    let out = std::env::args().nth(1).unwrap();
+    let r_minus_1 = (-Fr::from(1u64)).into_bigint().to_string();
+    let scalars = [
+        "1".to_string(),
+        "2".to_string(),
+        "3".to_string(),
+        "1000000007".to_string(),
+        "2707545001573735802296287960453446232227133355848154963287597453843219478390745296599437087153474519853802117434807".to_string(),
+        r_minus_1.clone(),
+    ];
+
+    let mut g1 = String::from("k,x,y\n");
+    let mut g2 = String::from("k,x,y\n");
+    for k in scalars.iter() {
+        let s = Fr::from_str(k).unwrap();
+        let p = (G1Projective::generator() * s).into_affine();
+        g1 += &format!("{k},0x{},0x{}\n", be_hex(&p.x), be_hex(&p.y));
+        let q = (G2Projective::generator() * s).into_affine();
+        g2 += &format!("{k},0x{},0x{}\n", be_hex(&q.x), be_hex(&q.y));
+    }
+    std::fs::write(format!("{out}/bw6_767_g1_mul.csv"), g1).unwrap();
+    std::fs::write(format!("{out}/bw6_767_g2_mul.csv"), g2).unwrap();
+
+    let pairs = [
+        ("1", "1"),
+        ("2", "3"),
+        ("1000000007", scalars[4].as_str()),
+        (r_minus_1.as_str(), "5"),
+    ];
+    let mut points = vec![];
+    let mut cases = vec![];
+    for (a, b) in pairs.iter() {
+        let p: G1Affine = (G1Projective::generator() * Fr::from_str(a).unwrap()).into_affine();
+        let q: G2Affine = (G2Projective::generator() * Fr::from_str(b).unwrap()).into_affine();
+        let gt = BW6_767::pairing(p, q).0;
+        let mut bytes = vec![];
+        gt.serialize_uncompressed(&mut bytes).unwrap();
+        cases.push(format!(
+            "    {{\n      \"g1_scalar\": \"{a}\",\n      \"g2_scalar\": \"{b}\",\n      \"g1\": {{\n        \"x\": \"{}\",\n        \"y\": \"{}\"\n      }},\n      \"g2\": {{\n        \"x\": \"{}\",\n        \"y\": \"{}\"\n      }},\n      \"gt\": \"{}\"\n    }}",
+            be_hex(&p.x().unwrap()), be_hex(&p.y().unwrap()), be_hex(&q.x().unwrap()), be_hex(&q.y().unwrap()), hex(&bytes)
+        ));
+        points.push((p, q));
+    }
+    let mut multi = vec![];
+    for terms in [vec![0usize, 1], vec![0, 1, 2, 3]] {
+        let ps = terms.iter().map(|&i| points[i].0).collect::<Vec<_>>();
+        let qs = terms.iter().map(|&i| points[i].1).collect::<Vec<_>>();
+        let gt = BW6_767::multi_pairing(ps, qs).0;
+        let mut bytes = vec![];
+        gt.serialize_uncompressed(&mut bytes).unwrap();
+        multi.push(format!(
+            "    {{\n      \"terms\": [{}],\n      \"gt\": \"{}\"\n    }}",
+            terms
+                .iter()
+                .map(|i| i.to_string())
+                .collect::<Vec<_>>()
+                .join(", "),
+            hex(&bytes)
+        ));
+    }
+    let json = format!(
+        "{{\n  \"pairing\": [\n{}\n  ],\n  \"multi_miller_loop\": [\n{}\n  ]\n}}\n",
+        cases.join(",\n"),
+        multi.join(",\n")
+    );
+    std::fs::write(format!("{out}/bw6_767_pairing.json"), json).unwrap();
+}
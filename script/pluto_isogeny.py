@@ -0,0 +1,259 @@
+# This file derives the 3-isogeny to Pluto used by its SSWU hash to curve suite in
+# src/pluto_eris/curve.rs, and prints its constants and test vectors.
+#
+# Pluto, y^2 = x^3 + 57, has A = 0, so the simplified SWU map applies to a curve
+# E': y^2 = x^3 + A' x + B' with A' B' != 0 and an isogeny E' -> Pluto. As p = 1
+# mod 9 and -4 * 57 is a cube, Pluto has rational 3-isogenies, computed with the
+# formulas of Velu:
+#
+# 1. the kernel x0, the smallest root of x^3 + 4 * 57, gives E' = Pluto / <x0>,
+#    with A' = -30 x0^2 and B' = 253 * 57;
+# 2. the smallest root x1 of the 3-division polynomial of E' such that E' / <x1>
+#    has A = 0 gives the isogeny E' -> E' / <x1>;
+# 3. which composes with the isomorphism (x, y) -> (l^2 x, l^3 y) to Pluto, for the
+#    smallest l with l^6 = 57 / B.
+#
+# Z is chosen with the criteria of
+# <https://www.rfc-editor.org/rfc/rfc9380.html#name-finding-z-for-simplified-sw>.
+#
+# Run from the root of the repository with `python3 script/pluto_isogeny.py`.
+
+import hashlib
+import random
+
+p = 0x24000000000024000130E0000D7F70E4A803CA76F439266F443F9A5CDA8A6C7BE4A7A5FE8FADFFD6A2A7E8C30006B9459FFFFCD300000001
+B = 57
+LIMBS = 7
+
+
+def inv(x):
+    return pow(x, p - 2, p)
+
+
+def is_square(x):
+    return x % p == 0 or pow(x, (p - 1) // 2, p) == 1
+
+
+# Polynomials over Fp are lists of coefficients, from the constant term up.
+def poly_trim(f):
+    while f and f[-1] % p == 0:
+        f = f[:-1]
+    return f
+
+
+def poly_sub(f, g):
+    n = max(len(f), len(g))
+    f = f + [0] * (n - len(f))
+    g = g + [0] * (n - len(g))
+    return poly_trim([(a - b) % p for a, b in zip(f, g)])
+
+
+def poly_mul(f, g):
+    res = [0] * (len(f) + len(g) - 1) if f and g else []
+    for i, a in enumerate(f):
+        for j, b in enumerate(g):
+            res[i + j] = (res[i + j] + a * b) % p
+    return poly_trim(res)
+
+
+def poly_divmod(f, g):
+    f, g = poly_trim(f), poly_trim(g)
+    q = [0] * max(len(f) - len(g) + 1, 0)
+    c = inv(g[-1])
+    while len(f) >= len(g):
+        k = f[-1] * c % p
+        d = len(f) - len(g)
+        q[d] = k
+        f = poly_sub(f, [0] * d + [k * a % p for a in g])
+    return q, f
+
+
+def poly_gcd(f, g):
+    while poly_trim(g):
+        f, g = g, poly_divmod(f, g)[1]
+    c = inv(f[-1])
+    return [a * c % p for a in f]
+
+
+def poly_powmod(f, e, m):
+    res = [1]
+    while e:
+        if e & 1:
+            res = poly_divmod(poly_mul(res, f), m)[1]
+        f = poly_divmod(poly_mul(f, f), m)[1]
+        e >>= 1
+    return res
+
+
+def roots(f):
+    # The product of the linear factors of `f`, split with Cantor-Zassenhaus.
+    f = poly_gcd(f, poly_sub(poly_powmod([0, 1], p, f), [0, 1]))
+
+    def split(f):
+        if len(f) == 2:
+            return [-f[0] * inv(f[1]) % p]
+        if len(f) < 2:
+            return []
+        while True:
+            g = poly_sub(poly_powmod([random.randrange(p), 1], (p - 1) // 2, f), [1])
+            g = poly_gcd(f, g) if g else f
+            if 1 < len(g) < len(f):
+                return split(g) + split(poly_divmod(f, g)[0])
+
+    return sorted(split(f))
+
+
+def velu(a, b, x0):
+    # Velu's formulas for the kernel of order 3 with abscissa x0 of
+    # y^2 = x^3 + a x + b: the codomain and the rational maps
+    # X = x + v / (x - x0) + u / (x - x0)^2, Y = y (1 - v / (x - x0)^2 - 2 u / (x - x0)^3).
+    v = 2 * (3 * x0 * x0 + a) % p
+    u = 4 * (x0 * x0 * x0 + a * x0 + b) % p
+    w = (u + x0 * v) % p
+    return (a - 5 * v) % p, (b - 7 * w) % p, v, u
+
+
+# 1.
+x0 = roots([4 * B, 0, 0, 1])[0]
+a_iso, b_iso, _, _ = velu(0, B, x0)
+assert a_iso == -30 * x0 * x0 % p and b_iso == 253 * B
+
+# 2. The 3-division polynomial 3 x^4 + 6 A x^2 + 12 B x - A^2.
+psi3 = [-a_iso * a_iso % p, 12 * b_iso % p, 6 * a_iso % p, 0, 3]
+x1 = next(x for x in roots(psi3) if velu(a_iso, b_iso, x)[0] == 0)
+_, b_codomain, v, u = velu(a_iso, b_iso, x1)
+
+# 3.
+l = roots([-B * inv(b_codomain) % p, 0, 0, 0, 0, 0, 1])[0]
+l2, l3 = l * l % p, l * l * l % p
+
+# The isogeny E' -> Pluto as x = x_num / x_den, y = y * y_num / y_den, where
+#   x_num = l^2 (x (x - x1)^2 + v (x - x1) + u), x_den = (x - x1)^2,
+#   y_num = l^3 ((x - x1)^3 - v (x - x1) - 2 u), y_den = (x - x1)^3.
+t = [-x1 % p, 1]
+t2 = poly_mul(t, t)
+t3 = poly_mul(t2, t)
+x_num = [c * l2 % p for c in poly_sub(poly_sub(poly_mul([0, 1], t2), [-c % p for c in poly_mul([v], t)]), [-u % p])]
+x_den = t2
+y_num = [c * l3 % p for c in poly_sub(poly_sub(t3, poly_mul([v], t)), [2 * u % p])]
+y_den = t3
+
+
+def iso_map(x, y):
+    ev = lambda f, x: sum(c * pow(x, i, p) for i, c in enumerate(f)) % p
+    return (
+        ev(x_num, x) * inv(ev(x_den, x)) % p,
+        y * ev(y_num, x) * inv(ev(y_den, x)) % p,
+    )
+
+
+# The SSWU constant Z.
+def find_z():
+    def g(x):
+        return (x * x * x + a_iso * x + b_iso) % p
+
+    ctr = 1
+    while True:
+        for z in [ctr, -ctr % p]:
+            if is_square(z) or z == p - 1:
+                continue
+            if roots([(b_iso - z) % p, a_iso, 0, 1]):
+                continue
+            if is_square(g(b_iso * inv(z * a_iso % p) % p)):
+                return z
+        ctr += 1
+
+
+Z = find_z()
+
+
+# hash_to_curve with expand_message_xmd and SHA-256, as in RFC 9380.
+L = 72
+DST = b"QUUX-V01-CS02-with-pluto_XMD:SHA-256_SSWU_RO_"
+
+
+def expand_message_xmd(msg, dst, n):
+    ell = (n + 31) // 32
+    dst_prime = dst + bytes([len(dst)])
+    b0 = hashlib.sha256(bytes(64) + msg + n.to_bytes(2, "big") + b"\0" + dst_prime).digest()
+    bs = [hashlib.sha256(b0 + b"\1" + dst_prime).digest()]
+    for i in range(2, ell + 1):
+        prev = bytes(a ^ b for a, b in zip(b0, bs[-1]))
+        bs.append(hashlib.sha256(prev + bytes([i]) + dst_prime).digest())
+    return b"".join(bs)[:n]
+
+
+def sqrt(x):
+    r = roots([-x % p, 0, 1])
+    return r[0] if r else None
+
+
+def sgn0(x):
+    return x % 2
+
+
+def map_to_curve_sswu(u):
+    tv1 = inv((Z * Z * pow(u, 4, p) + Z * u * u) % p)
+    x1 = -b_iso * inv(a_iso) * (1 + tv1) % p if tv1 else b_iso * inv(Z * a_iso) % p
+    gx1 = (x1**3 + a_iso * x1 + b_iso) % p
+    if is_square(gx1):
+        x, y = x1, sqrt(gx1)
+    else:
+        x = Z * u * u * x1 % p
+        y = sqrt((x**3 + a_iso * x + b_iso) % p)
+    if sgn0(u) != sgn0(y):
+        y = -y % p
+    return x, y
+
+
+def add(P, Q):
+    if P is None:
+        return Q
+    if Q is None:
+        return P
+    if P[0] == Q[0] and (P[1] + Q[1]) % p == 0:
+        return None
+    if P == Q:
+        m = 3 * P[0] * P[0] * inv(2 * P[1]) % p
+    else:
+        m = (Q[1] - P[1]) * inv(Q[0] - P[0]) % p
+    x = (m * m - P[0] - Q[0]) % p
+    return x, (m * (P[0] - x) - P[1]) % p
+
+
+def hash_to_curve(msg):
+    bytes_ = expand_message_xmd(msg, DST, 2 * L)
+    u0 = int.from_bytes(bytes_[:L], "big") % p
+    u1 = int.from_bytes(bytes_[L:], "big") % p
+    # Pluto has prime order, clear_cofactor is the identity.
+    return add(iso_map(*map_to_curve_sswu(u0)), iso_map(*map_to_curve_sswu(u1)))
+
+
+def on_curve(P, a, b):
+    return (P[1] * P[1] - P[0] ** 3 - a * P[0] - b) % p == 0
+
+
+# Checks that the map sends E' to Pluto and is a homomorphism.
+random.seed(0)
+for _ in range(8):
+    P, Q = map_to_curve_sswu(random.randrange(p)), map_to_curve_sswu(random.randrange(p))
+    assert on_curve(P, a_iso, b_iso) and on_curve(iso_map(*P), 0, B)
+    assert iso_map(*add(P, Q)) == add(iso_map(*P), iso_map(*Q))
+
+
+def limbs(x):
+    return ", ".join("0x%016x" % ((x >> (64 * i)) % 2**64) for i in range(LIMBS))
+
+
+print("x0 =", hex(x0))
+print("x1 =", hex(x1))
+print("Z =", Z if Z < p // 2 else -(p - Z))
+print("A' = [%s]" % limbs(a_iso))
+print("B' = [%s]" % limbs(b_iso))
+for name, f in [("x_num", x_num), ("x_den", x_den), ("y_num", y_num), ("y_den", y_den)]:
+    print(name)
+    for c in f:
+        print("    [%s]" % limbs(c))
+for msg in [b"", b"abc", b"abcdef0123456789", b"q128_" + b"q" * 128, b"a512_" + b"a" * 512]:
+    P = hash_to_curve(msg)
+    print(msg[:16], "%0112x" % P[0], "%0112x" % P[1])
@@ -0,0 +1,324 @@
+# This file generates the Pluto pairing test vectors in
+# src/tests/vectors/pluto_pairing.json
+#
+# It does not share any code or representation with the crate: the degree 12
+# extension is Fp[w]/(w^12 - 2 a w^6 + a^2 + 5 b^2), where xi = a + b u = w^6,
+# and the optimal ate pairing is computed with textbook affine arithmetic on
+# E(Fp12), the Miller loop on the binary expansion of |6 z + 2| and the final
+# exponentiation to the full (p^12 - 1) / r.
+#
+# Run from the root of the repository with `python3 script/pluto_pairing_vectors.py`.
+
+import json
+
+p = 0x24000000000024000130E0000D7F70E4A803CA76F439266F443F9A5CDA8A6C7BE4A7A5FE8FADFFD6A2A7E8C30006B9459FFFFCD300000001
+r = 0x24000000000024000130E0000D7F70E4A803CA76F439266F443F9A5C7A8A6C7BE4A775FE8E177FD69CA7E85D60050AF41FFFFCD300000001
+z = -0x4000000000001000008780000000
+b = 57
+SIZE = 56
+
+assert p == 36 * z**4 + 36 * z**3 + 24 * z**2 + 6 * z + 1
+assert r == 36 * z**4 + 36 * z**3 + 18 * z**2 + 6 * z + 1
+
+
+def inv(x):
+    return pow(x, p - 2, p)
+
+
+# Fp2 = Fp[u]/(u^2 + 5), elements are pairs (c0, c1).
+def fp2_add(x, y):
+    return ((x[0] + y[0]) % p, (x[1] + y[1]) % p)
+
+
+def fp2_mul(x, y):
+    return ((x[0] * y[0] - 5 * x[1] * y[1]) % p, (x[0] * y[1] + x[1] * y[0]) % p)
+
+
+def fp2_inv(x):
+    n = inv((x[0] * x[0] + 5 * x[1] * x[1]) % p)
+    return (x[0] * n % p, -x[1] * n % p)
+
+
+# The sextic twist is y^2 = x^3 + b / xi with b / xi = u + 3.
+b2 = (3, 1)
+xi = fp2_mul((b, 0), fp2_inv(b2))
+
+# Fp12 = Fp[w]/(w^12 - 2 a w^6 + a^2 + 5 b^2), elements are lists of 12 coefficients.
+xa, xb = xi
+MOD = [(xa * xa + 5 * xb * xb) % p] + [0] * 5 + [(-2 * xa) % p] + [0] * 5 + [1]
+
+
+def poly_trim(x):
+    x = list(x)
+    while x and x[-1] == 0:
+        x.pop()
+    return x
+
+
+def poly_mul(x, y):
+    res = [0] * (len(x) + len(y) - 1) if x and y else []
+    for i, xi_ in enumerate(x):
+        if xi_:
+            for j, yj in enumerate(y):
+                res[i + j] += xi_ * yj
+    return [c % p for c in res]
+
+
+def poly_divmod(x, y):
+    x = poly_trim(x)
+    y = poly_trim(y)
+    q = [0] * max(len(x) - len(y) + 1, 1)
+    lead = inv(y[-1])
+    while len(x) >= len(y):
+        c = x[-1] * lead % p
+        d = len(x) - len(y)
+        q[d] = c
+        for i, yi in enumerate(y):
+            x[i + d] = (x[i + d] - c * yi) % p
+        x = poly_trim(x)
+    return q, x
+
+
+def f12(x):
+    x = poly_trim(x)
+    if len(x) > 12:
+        x = poly_divmod(x, MOD)[1]
+    return x + [0] * (12 - len(x))
+
+
+def f12_add(x, y):
+    return [(a + c) % p for a, c in zip(x, y)]
+
+
+def f12_sub(x, y):
+    return [(a - c) % p for a, c in zip(x, y)]
+
+
+def f12_mul(x, y):
+    return f12(poly_mul(poly_trim(x), poly_trim(y)))
+
+
+def f12_inv(x):
+    # Extended Euclid on (MOD, x).
+    r0, r1 = MOD, poly_trim(x)
+    s0, s1 = [], [1]
+    while r1:
+        q, rem = poly_divmod(r0, r1)
+        qs = poly_mul(q, s1)
+        n = max(len(s0), len(qs))
+        s = [((s0[i] if i < len(s0) else 0) - (qs[i] if i < len(qs) else 0)) % p for i in range(n)]
+        r0, r1 = r1, rem
+        s0, s1 = s1, poly_trim(s)
+    assert len(r0) == 1
+    return f12([c * inv(r0[0]) % p for c in s0])
+
+
+def f12_pow(x, e):
+    res = f12([1])
+    for bit in bin(e)[2:]:
+        res = f12_mul(res, res)
+        if bit == "1":
+            res = f12_mul(res, x)
+    return res
+
+
+ONE = f12([1])
+W = f12([0, 1])
+
+
+# Embeds c0 + c1 u in Fp12, with u = (w^6 - a) / b.
+def from_fp2(x):
+    c = x[1] * inv(xb) % p
+    return f12([(x[0] - c * xa) % p] + [0] * 5 + [c])
+
+
+assert f12_mul(from_fp2((0, 1)), from_fp2((0, 1))) == from_fp2((-5 % p, 0))
+assert from_fp2(xi) == f12_pow(W, 6)
+
+
+# Decomposes an element of Fp12 in the tower of the crate, Fp6 = Fp2[v]/(v^3 - xi)
+# and Fp12 = Fp6[w]/(w^2 - v), and returns the six quadratic coefficients in the
+# order of `Gt::to_bytes`.
+def to_tower(x):
+    # x = sum_{i < 6} (e_i + e_{i + 6} w^6) w^i, with w^6 = (a, b) in Fp2.
+    # e + e' (a + b u) = (e + a e') + b e' u.
+    coeffs = [((x[i] + xa * x[i + 6]) % p, xb * x[i + 6] % p) for i in range(6)]
+    return [coeffs[i] for i in [0, 2, 4, 1, 3, 5]]
+
+
+# Affine short Weierstrass arithmetic with a = 0, `None` is the identity.
+def ec_add(P, Q, add, sub, mul, inv_, three):
+    if P is None:
+        return Q
+    if Q is None:
+        return P
+    if P[0] == Q[0]:
+        if add(P[1], Q[1]) == sub(P[1], P[1]):
+            return None
+        lam = mul(mul(three, mul(P[0], P[0])), inv_(add(P[1], P[1])))
+    else:
+        lam = mul(sub(Q[1], P[1]), inv_(sub(Q[0], P[0])))
+    x = sub(sub(mul(lam, lam), P[0]), Q[0])
+    return (x, sub(mul(lam, sub(P[0], x)), P[1]))
+
+
+def ec_mul(P, k, ops):
+    res = None
+    for bit in bin(k)[2:]:
+        res = ec_add(res, res, *ops)
+        if bit == "1":
+            res = ec_add(res, P, *ops)
+    return res
+
+
+FP = (
+    lambda x, y: (x + y) % p,
+    lambda x, y: (x - y) % p,
+    lambda x, y: x * y % p,
+    inv,
+    3,
+)
+FP2 = (
+    fp2_add,
+    lambda x, y: ((x[0] - y[0]) % p, (x[1] - y[1]) % p),
+    fp2_mul,
+    fp2_inv,
+    (3, 0),
+)
+FP12 = (f12_add, f12_sub, f12_mul, f12_inv, f12([3]))
+
+G1 = (p - 2, 7)
+G2 = (
+    (
+        0x13576C81FAF3A13FD815D0E9BD54B845EE935948B84498B27CA972BFB93722E223C9E276A4EBE7559CFC86DD865F07D64F2B5FE6556F9066,
+        0x142164CB875DB0465E5092F9380F44F555243D011699B7393029F2D201554727AEB383298FDF5847B9B3DFF01BBE8D63FE7C781A8FD7BF21,
+    ),
+    (
+        0x2239F7408EAD478C58E88D4DF1E7418C42FDBB92E64BA85AA4DC17D7DACE3F32EB471C004DB774BFE78574ACA67B3898CD1B78AD106AB9FE,
+        0x1260B04D51136590DBB53DFD7CAF450AECA714555BBE4F079CA65D97EB28FC9FC697B4E10BBCD9E0539EF82A731FB88ED49E3C080E6D945D,
+    ),
+)
+
+assert (G1[1] ** 2 - G1[0] ** 3 - b) % p == 0
+assert fp2_mul(G2[1], G2[1]) == fp2_add(fp2_mul(G2[0], fp2_mul(G2[0], G2[0])), b2)
+assert ec_mul(G1, r, FP) is None
+assert ec_mul(G2, r, FP2) is None
+
+
+# Maps a point of the twist to E(Fp12): (x, y) -> (x w^2, y w^3).
+def untwist(Q):
+    return (
+        f12_mul(from_fp2(Q[0]), f12_pow(W, 2)),
+        f12_mul(from_fp2(Q[1]), f12_pow(W, 3)),
+    )
+
+
+def frobenius(Q):
+    return (f12_pow(Q[0], p), f12_pow(Q[1], p))
+
+
+def neg(Q):
+    return (Q[0], f12_sub(f12([0]), Q[1]))
+
+
+# Evaluates at P the line through T and Q, the tangent if T = Q.
+def line(T, Q, P):
+    if T[0] == Q[0] and T[1] == Q[1]:
+        lam = f12_mul(f12_mul(f12([3]), f12_mul(T[0], T[0])), f12_inv(f12_add(T[1], T[1])))
+    else:
+        lam = f12_mul(f12_sub(Q[1], T[1]), f12_inv(f12_sub(Q[0], T[0])))
+    return f12_sub(f12_sub(f12([P[1]]), T[1]), f12_mul(lam, f12_sub(f12([P[0]]), T[0])))
+
+
+# The Miller loop of the optimal ate pairing, adapted to negative z as in
+# Algorithm 1 of https://eprint.iacr.org/2013/722.pdf.
+def miller_loop(P, Q):
+    Q = untwist(Q)
+    n = -(6 * z + 2)
+    f = ONE
+    T = Q
+    for bit in bin(n)[3:]:
+        f = f12_mul(f12_mul(f, f), line(T, T, P))
+        T = ec_add(T, T, *FP12)
+        if bit == "1":
+            f = f12_mul(f, line(T, Q, P))
+            T = ec_add(T, Q, *FP12)
+    Q1 = neg(frobenius(Q))
+    f = f12_mul(f, line(T, Q1, P))
+    T = ec_add(T, Q1, *FP12)
+    Q2 = frobenius(frobenius(Q))
+    return f12_mul(f, line(T, Q2, P))
+
+
+def final_exponentiation(f):
+    return f12_pow(f, (p**12 - 1) // r)
+
+
+def fp_bytes(x):
+    return x.to_bytes(SIZE, "little").hex()
+
+
+def gt_bytes(x):
+    return "".join(fp_bytes(c0) + fp_bytes(c1) for c0, c1 in to_tower(x))
+
+
+def g1_json(P):
+    return {"x": fp_bytes(P[0]), "y": fp_bytes(P[1])}
+
+
+def g2_json(Q):
+    return {
+        "x": fp_bytes(Q[0][0]) + fp_bytes(Q[0][1]),
+        "y": fp_bytes(Q[1][0]) + fp_bytes(Q[1][1]),
+    }
+
+
+# Pairs of scalars of the G1 and G2 inputs.
+SCALARS = [
+    (1, 1),
+    (2, 1),
+    (1, 3),
+    (0x1234567890ABCDEF, 0xFEDCBA0987654321),
+    (r - 1, 0x2A),
+    (
+        0x1F0E2D3C4B5A69788796A5B4C3D2E1F00F1E2D3C4B5A69788796A5B4C3D2E1F00F1E2D3C4B5A6978,
+        0x0123456789ABCDEF0123456789ABCDEF0123456789ABCDEF0123456789ABCDEF0123456789ABCDEF,
+    ),
+]
+
+pairings = []
+loops = {}
+for a, c in SCALARS:
+    P = ec_mul(G1, a, FP)
+    Q = ec_mul(G2, c, FP2)
+    f = miller_loop(P, Q)
+    loops[(a, c)] = f
+    pairings.append(
+        {
+            "g1_scalar": str(a),
+            "g2_scalar": str(c),
+            "g1": g1_json(P),
+            "g2": g2_json(Q),
+            "gt": gt_bytes(final_exponentiation(f)),
+        }
+    )
+
+# Bilinearity and non degeneracy.
+e11 = final_exponentiation(loops[(1, 1)])
+assert e11 != ONE
+assert f12_pow(e11, r) == ONE
+assert final_exponentiation(loops[(2, 1)]) == f12_mul(e11, e11)
+assert final_exponentiation(loops[(1, 3)]) == f12_pow(e11, 3)
+
+# The multi-Miller loops are products of Miller loops.
+MULTI = [[0, 1, 2], [3, 4, 5], [1, 4]]
+multi_miller_loops = []
+for terms in MULTI:
+    f = ONE
+    for i in terms:
+        f = f12_mul(f, loops[SCALARS[i]])
+    multi_miller_loops.append({"terms": terms, "gt": gt_bytes(final_exponentiation(f))})
+
+with open("src/tests/vectors/pluto_pairing.json", "w") as out:
+    json.dump({"pairing": pairings, "multi_miller_loop": multi_miller_loops}, out, indent=2)
+    out.write("\n")
@@ -4,7 +4,10 @@
 //! This module is temporary, and the extension traits defined here are expected to be
 //! upstreamed into the `ff` and `group` crates after some refactoring.
 
+use alloc::vec::Vec;
+
 use crate::CurveExt;
+use subtle::{Choice, ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
 
 pub(crate) struct EndoParameters {
     pub(crate) gamma1: [u64; 4],
@@ -13,8 +16,51 @@ pub(crate) struct EndoParameters {
     pub(crate) b2: [u64; 4],
 }
 
+/// The GLV decomposition of scalars along the endomorphism [`CurveExt::endo`], for
+/// callers that schedule the halves themselves, e.g. in an MSM.
+///
+/// It is implemented for the curves with an efficient endomorphism and 256-bit
+/// scalars: the G1 of BN256 and BLS12-377, Grumpkin, secp256k1, secq256k1 and the
+/// Pasta curves. The halves of the larger scalars of Pluto, Eris and BW6-767 do
+/// not fit in 128 bits.
 pub trait CurveEndo: CurveExt {
+    /// Splits `e` into `(k1, k1_neg, k2, k2_neg)` with `k1` and `k2` of 128 bits,
+    /// such that `e = ±k1 ∓ ζ k2`, where `ζ` is [`Self::ScalarExt::ZETA`](ff::WithSmallOrderMulGroup::ZETA)
+    /// and the signs are given by the flags. Note that `k2` is subtracted when
+    /// `k2_neg` is false; [`Self::glv_recode`] applies the signs to the points.
     fn decompose_scalar(e: &Self::ScalarExt) -> (u128, bool, u128, bool);
+
+    /// Recodes `k * self` as `k1 * p1 + k2 * p2` with the 128-bit halves of
+    /// [`Self::decompose_scalar`], where `p1` and `p2` are `self` and its
+    /// endomorphism, conditionally negated in constant time so that `k1` and
+    /// `k2` are positive.
+    fn glv_recode(&self, k: &Self::ScalarExt) -> [(u128, Self); 2] {
+        let (k1, k1_neg, k2, k2_neg) = Self::decompose_scalar(k);
+        let p1 = Self::conditional_select(self, &-*self, Choice::from(k1_neg as u8));
+        let p2 = self.endo();
+        let p2 = Self::conditional_select(&-p2, &p2, Choice::from(k2_neg as u8));
+        [(k1, p1), (k2, p2)]
+    }
+
+    /// Multiplies `self` by `k` with the GLV method: `k` is recoded with
+    /// [`Self::glv_recode`] into two halves of 128 bits, which multiply `self`
+    /// and its endomorphism in a single double-and-add loop.
+    fn mul_glv(&self, k: &Self::ScalarExt) -> Self {
+        let [(k1, p1), (k2, p2)] = self.glv_recode(k);
+        let table = [Self::identity(), p1, p2, p1 + p2];
+
+        let mut acc = Self::identity();
+        for i in (0..128).rev() {
+            acc = acc.double();
+            let index = (((k1 >> i) & 1) | (((k2 >> i) & 1) << 1)) as u8;
+            let mut t = Self::identity();
+            for (j, p) in table.iter().enumerate() {
+                t.conditional_assign(p, (j as u8).ct_eq(&index));
+            }
+            acc += t;
+        }
+        acc
+    }
 }
 
 /// Compute a + b + carry, returning the result and the new carry over.
@@ -45,6 +91,49 @@ pub(crate) const fn macx(a: u64, b: u64, c: u64) -> (u64, u64) {
     (res as u64, (res >> 64) as u64)
 }
 
+/// Recodes a little-endian scalar into signed radix-16 digits in `[-8, 8)`, least
+/// significant first. The extra top digit takes the final carry.
+pub(crate) fn signed_radix16(bytes: &[u8]) -> Vec<i8> {
+    crate::wnaf::signed_windows::<4>(bytes)
+        .into_iter()
+        .map(|digit| digit as i8)
+        .collect()
+}
+
+/// Constant time lookup of `digit * P` in `table = [P, 2P, ..., nP]`, for `|digit| <= n`.
+pub(crate) fn select_signed<T>(table: &[T], identity: T, digit: i8) -> T
+where
+    T: ConditionallySelectable + ConditionallyNegatable,
+{
+    let mask = (digit >> 7) as u8;
+    let abs = (digit as u8 ^ mask).wrapping_sub(mask);
+    let mut p = identity;
+    for (j, entry) in table.iter().enumerate() {
+        p.conditional_assign(entry, (j as u8 + 1).ct_eq(&abs));
+    }
+    p.conditional_negate(Choice::from(mask & 1));
+    p
+}
+
+/// Applies `f` to chunks of `items` and concatenates the results. With the `std`
+/// feature there is one chunk per thread, mapped in parallel.
+pub(crate) fn par_map_chunks<T: Sync, R: Send>(
+    items: &[T],
+    f: impl Fn(&[T]) -> Vec<R> + Send + Sync,
+) -> Vec<R> {
+    #[cfg(feature = "std")]
+    {
+        use rayon::prelude::*;
+
+        let num_threads = rayon::current_num_threads();
+        let chunk = ((items.len() + num_threads - 1) / num_threads).max(1);
+        items.par_chunks(chunk).flat_map_iter(f).collect()
+    }
+
+    #[cfg(not(feature = "std"))]
+    f(items)
+}
+
 /// Compute a * b, returning the result.
 #[inline(always)]
 pub(crate) fn mul_512(a: [u64; 4], b: [u64; 4]) -> [u64; 8] {
@@ -0,0 +1,91 @@
+use crate::babyjubjub::{Fq, Fr};
+use crate::ff::{BatchInverter, Field, PrimeField};
+use crate::group::cofactor::CofactorGroup;
+use crate::group::prime::PrimeGroup;
+use crate::group::{Curve, Group, GroupEncoding};
+use alloc::vec::Vec;
+use core::iter::Sum;
+use core::ops::{Add, Mul, Neg, Sub};
+use rand_core::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+const EDWARDS_A: Fq = Fq::from_raw([168700, 0, 0, 0]);
+const EDWARDS_D: Fq = Fq::from_raw([168696, 0, 0, 0]);
+const MONTGOMERY_A: Fq = Fq::from_raw([168698, 0, 0, 0]);
+const MONTGOMERY_B: Fq = Fq::ONE;
+
+// `Base8` of EIP-2494, the generator of the subgroup of order `l`.
+const GENERATOR_X: Fq = Fq::from_raw([
+    0x2893f3f6bb957051,
+    0x2ab8d8010534e0b6,
+    0x4eacb2e09d6277c1,
+    0x0bb77a6ad63e739b,
+]);
+const GENERATOR_Y: Fq = Fq::from_raw([
+    0x4b3c257a872d7d8b,
+    0xfce0051fb9e13377,
+    0x25572e1cd16bf9ed,
+    0x25797203f7a0b249,
+]);
+
+crate::new_edwards_curve_impl!(
+    (pub),
+    BabyJubjub,
+    BabyJubjubAffine,
+    Fq,
+    Fr,
+    (GENERATOR_X, GENERATOR_Y),
+    EDWARDS_A,
+    EDWARDS_D,
+    (MONTGOMERY_A, MONTGOMERY_B),
+    3,
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    crate::edwards_testing_suite!(BabyJubjub, BabyJubjubAffine, Fq, Fr);
+
+    fn point(x: &str, y: &str) -> BabyJubjubAffine {
+        BabyJubjubAffine {
+            x: Fq::from_str_vartime(x).unwrap(),
+            y: Fq::from_str_vartime(y).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_generator() {
+        let g = BabyJubjubAffine::generator();
+        assert!(bool::from(g.is_on_curve()));
+        assert!(bool::from(BabyJubjub::generator().is_on_curve()));
+        assert!(bool::from(BabyJubjub::generator().is_torsion_free()));
+        assert!(!bool::from(BabyJubjub::generator().is_identity()));
+
+        // `Base8 = 8 G` for the generator `G` of the full group in EIP-2494.
+        let full = point(
+            "995203441582195749578291179787384436505546430278305826713579947235728471134",
+            "5472060717959818805561601436314318772137091100104008585924551046643952123905",
+        );
+        assert!(bool::from(full.is_on_curve()));
+        assert!(!bool::from(BabyJubjub::from(full).is_torsion_free()));
+        assert_eq!(BabyJubjub::from(full).clear_cofactor(), g.into());
+    }
+
+    #[test]
+    fn test_eip2494_vectors() {
+        let p1 = point(
+            "17777552123799933955779906779655732241715742912184938656739573121738514868268",
+            "2626589144620713026669568689430873010625803728049924121243784502389097019475",
+        );
+        let p2 = point(
+            "16540640123574156134436876038791482806971768689494387082833631921987005038935",
+            "20819045374670962167435360035096875258406992893633759881276124905556507972311",
+        );
+        let sum = point(
+            "7916061937171219682591368294088513039687205273691143098332585753343424131937",
+            "14035240266687799601661095864649209771790948434046947201833777492504781204499",
+        );
+        assert_eq!((BabyJubjub::from(p1) + p2).to_affine(), sum);
+    }
+}
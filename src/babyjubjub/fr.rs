@@ -0,0 +1,50 @@
+use core::convert::TryInto;
+use halo2derive::impl_field;
+use rand_core::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+impl_field!(
+    babyjubjub_scalar,
+    Fr,
+    modulus = "060c89ce5c263405370a08b6d0302b0bab3eedb83920ee0a677297dc392126f1",
+    mul_gen = "1f",
+    zeta = "05ad052060e4f041ee0dfa0397420b7161c35148b65ec763034a42b2c7fe2d32",
+    from_uniform = [64],
+    endian = "little",
+);
+
+crate::extend_field_legendre!(Fr);
+crate::impl_binops_calls!(Fr);
+crate::impl_binops_additive!(Fr, Fr);
+crate::impl_binops_multiplicative!(Fr, Fr);
+crate::field_bits!(Fr);
+crate::serialize_deserialize_primefield!(Fr);
+crate::impl_canonical_encoding_field!(Fr, Fr::SIZE);
+crate::impl_from_u64!(Fr);
+crate::impl_random_seeded!(Fr, 64);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    crate::field_testing_suite!(Fr, "field_arithmetic");
+    crate::field_testing_suite!(Fr, "conversion");
+    crate::field_testing_suite!(Fr, "serialization");
+    crate::field_testing_suite!(Fr, "quadratic_residue");
+    crate::field_testing_suite!(Fr, "bits");
+    crate::field_testing_suite!(Fr, "serialization_check");
+    crate::field_testing_suite!(Fr, "constants");
+    crate::field_testing_suite!(Fr, "montgomery_constants");
+    crate::field_testing_suite!(Fr, "mul_u64");
+    crate::field_testing_suite!(Fr, "halve");
+    crate::field_testing_suite!(Fr, "invert");
+    crate::field_testing_suite!(Fr, "from_raw");
+    crate::field_testing_suite!(Fr, "from_wide", from_u512);
+    crate::field_testing_suite!(Fr, "sqrt");
+    crate::field_testing_suite!(Fr, "zeta");
+    crate::field_testing_suite!(Fr, "from_uniform_bytes", 64);
+    crate::field_testing_suite!(
+        Fr,
+        "random_seeded",
+        "546332589f1bbc59ca6e4cff75819d4963c8142a5a89cce0d38b2cd70585117"
+    );
+}
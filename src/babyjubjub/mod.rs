@@ -0,0 +1,24 @@
+//! # `Baby Jubjub`
+//!
+//! Baby Jubjub, [`BabyJubjub`], is the twisted Edwards curve
+//! `a x^2 + y^2 = 1 + d x^2 y^2` with `a = 168700` and `d = 168696` over [`Fq`],
+//! the scalar field of BN254, so that its arithmetic is native in circuits over
+//! BN254. It is specified in [EIP-2494](https://eips.ethereum.org/EIPS/eip-2494).
+//!
+//! The curve has order `8 l`, and [`Fr`] is the scalar field of the subgroup of
+//! prime order `l`, which is generated by [`BabyJubjub::generator`]. Since `a` is
+//! a square and `d` is not, the addition formulas are complete.
+//!
+//! The curve is birationally equivalent to the Montgomery curve
+//! `v^2 = u^3 + 168698 u^2 + u`, see [`BabyJubjubAffine::to_montgomery`].
+//!
+//! Edwards points don't have the short Weierstrass coordinates of
+//! [`CurveExt`](crate::CurveExt) and [`CurveAffine`](crate::CurveAffine), so they
+//! implement the traits of the `group` crate only.
+
+mod curve;
+mod fr;
+
+pub use crate::bn256::Fr as Fq;
+pub use curve::*;
+pub use fr::*;
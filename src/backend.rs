@@ -0,0 +1,198 @@
+//! Hooks to offload multi-scalar multiplications and FFTs to external
+//! accelerators.
+//!
+//! A backend implements [`MsmBackend`] or [`NttBackend`] for the types it
+//! supports and is registered once with [`register_msm_backend`] or
+//! [`register_ntt_backend`]. From then on [`best_multiexp`],
+//! [`best_multiexp_independent_points`] and [`best_fft`] dispatch to it, so the
+//! call sites don't change. Types without a registered backend run on the
+//! [`Cpu`].
+//!
+//! [`best_multiexp`]: crate::msm::best_multiexp
+//! [`best_multiexp_independent_points`]: crate::msm::best_multiexp_independent_points
+//! [`best_fft`]: crate::fft::best_fft
+
+use crate::fft::FftGroup;
+use crate::CurveAffine;
+use core::any::{Any, TypeId};
+use ff::Field;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
+
+/// A multi-scalar multiplication backend for the curve `C`.
+pub trait MsmBackend<C: CurveAffine>: Send + Sync {
+    /// Returns `sum coeffs[i] * bases[i]`. Both slices have the same length.
+    fn msm(&self, coeffs: &[C::Scalar], bases: &[C]) -> C::Curve;
+}
+
+/// A radix-2 FFT backend over the group `G` with scalars `F`.
+pub trait NttBackend<F: Field, G: FftGroup<F>>: Send + Sync {
+    /// Transforms `a`, of length `2^log_n`, in place as described in
+    /// [`best_fft`](crate::fft::best_fft).
+    fn fft(&self, a: &mut [G], omega: F, log_n: u32);
+}
+
+/// The default backend, the multithreaded implementations of the crate.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Cpu;
+
+impl<C: CurveAffine> MsmBackend<C> for Cpu {
+    fn msm(&self, coeffs: &[C::Scalar], bases: &[C]) -> C::Curve {
+        crate::msm::cpu_multiexp(coeffs, bases)
+    }
+}
+
+impl<F: Field, G: FftGroup<F>> NttBackend<F, G> for Cpu {
+    fn fft(&self, a: &mut [G], omega: F, log_n: u32) {
+        crate::fft::cpu_fft(a, omega, log_n)
+    }
+}
+
+// Backends are stored as `Arc<dyn MsmBackend<C>>` or `Arc<dyn NttBackend<F, G>>`
+// keyed by the type ids of their parameters. `len` mirrors the number of
+// entries so that lookups skip the lock while no backend is registered.
+struct Registry {
+    backends: RwLock<Vec<(TypeId, Box<dyn Any + Send + Sync>)>>,
+    len: AtomicUsize,
+}
+
+impl Registry {
+    const fn new() -> Self {
+        Self {
+            backends: RwLock::new(Vec::new()),
+            len: AtomicUsize::new(0),
+        }
+    }
+}
+
+static MSM_BACKENDS: Registry = Registry::new();
+static NTT_BACKENDS: Registry = Registry::new();
+
+fn register<T: Any + Send + Sync>(registry: &Registry, key: TypeId, backend: Option<T>) {
+    let mut backends = registry.backends.write().unwrap();
+    backends.retain(|(k, _)| *k != key);
+    if let Some(backend) = backend {
+        backends.push((key, Box::new(backend)));
+    }
+    registry.len.store(backends.len(), Ordering::Release);
+}
+
+fn lookup<T: Any + Clone>(registry: &Registry, key: TypeId) -> Option<T> {
+    if registry.len.load(Ordering::Acquire) == 0 {
+        return None;
+    }
+    let backends = registry.backends.read().unwrap();
+    backends
+        .iter()
+        .find(|(k, _)| *k == key)
+        .map(|(_, backend)| backend.downcast_ref::<T>().unwrap().clone())
+}
+
+/// Registers the backend of the multi-scalar multiplications over `C`,
+/// replacing the previous one.
+pub fn register_msm_backend<C: CurveAffine>(backend: impl MsmBackend<C> + 'static) {
+    let backend: Arc<dyn MsmBackend<C>> = Arc::new(backend);
+    register(&MSM_BACKENDS, TypeId::of::<C>(), Some(backend));
+}
+
+/// Removes the backend of the multi-scalar multiplications over `C`, which
+/// run on the [`Cpu`] again.
+pub fn unregister_msm_backend<C: CurveAffine>() {
+    register::<Arc<dyn MsmBackend<C>>>(&MSM_BACKENDS, TypeId::of::<C>(), None);
+}
+
+/// Registers the backend of the FFTs over `G` with scalars `F`, replacing the
+/// previous one.
+pub fn register_ntt_backend<F: Field, G: FftGroup<F>>(backend: impl NttBackend<F, G> + 'static) {
+    let backend: Arc<dyn NttBackend<F, G>> = Arc::new(backend);
+    register(&NTT_BACKENDS, TypeId::of::<(F, G)>(), Some(backend));
+}
+
+/// Removes the backend of the FFTs over `G` with scalars `F`, which run on the
+/// [`Cpu`] again.
+pub fn unregister_ntt_backend<F: Field, G: FftGroup<F>>() {
+    register::<Arc<dyn NttBackend<F, G>>>(&NTT_BACKENDS, TypeId::of::<(F, G)>(), None);
+}
+
+pub(crate) fn msm_backend<C: CurveAffine>() -> Option<Arc<dyn MsmBackend<C>>> {
+    lookup(&MSM_BACKENDS, TypeId::of::<C>())
+}
+
+pub(crate) fn ntt_backend<F: Field, G: FftGroup<F>>() -> Option<Arc<dyn NttBackend<F, G>>> {
+    lookup(&NTT_BACKENDS, TypeId::of::<(F, G)>())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bn256::{Fr, G1Affine};
+    use crate::fft::best_fft;
+    use crate::msm::best_multiexp;
+    use ff::PrimeField;
+    use group::{Curve, Group};
+    use rand_core::OsRng;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    // Counts the calls and forwards them to the CPU.
+    #[derive(Default)]
+    struct Mock(AtomicUsize);
+
+    impl MsmBackend<G1Affine> for Arc<Mock> {
+        fn msm(&self, coeffs: &[Fr], bases: &[G1Affine]) -> crate::bn256::G1 {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Cpu.msm(coeffs, bases)
+        }
+    }
+
+    impl NttBackend<Fr, Fr> for Arc<Mock> {
+        fn fft(&self, a: &mut [Fr], omega: Fr, log_n: u32) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Cpu.fft(a, omega, log_n)
+        }
+    }
+
+    #[test]
+    fn test_msm_backend() {
+        let coeffs = (0..100).map(|_| Fr::random(OsRng)).collect::<Vec<_>>();
+        let bases = (0..100)
+            .map(|_| crate::bn256::G1::random(OsRng).to_affine())
+            .collect::<Vec<_>>();
+        let expected = Cpu.msm(&coeffs, &bases);
+
+        let mock = Arc::new(Mock::default());
+        register_msm_backend::<G1Affine>(mock.clone());
+        assert_eq!(best_multiexp(&coeffs, &bases), expected);
+        // Other tests may run MSMs over the same curve concurrently.
+        assert!(mock.0.load(Ordering::SeqCst) >= 1);
+
+        // An MSM that fetched the mock before it is unregistered may still call
+        // it, so the registry is checked instead of the counter.
+        unregister_msm_backend::<G1Affine>();
+        assert!(msm_backend::<G1Affine>().is_none());
+        assert_eq!(best_multiexp(&coeffs, &bases), expected);
+    }
+
+    #[test]
+    fn test_ntt_backend() {
+        let log_n = 8;
+        let omega = (0..Fr::S - log_n).fold(Fr::ROOT_OF_UNITY, |acc, _| acc.square());
+        let a = (0..1 << log_n)
+            .map(|_| Fr::random(OsRng))
+            .collect::<Vec<_>>();
+        let mut expected = a.clone();
+        Cpu.fft(&mut expected, omega, log_n);
+
+        let mock = Arc::new(Mock::default());
+        register_ntt_backend::<Fr, Fr>(mock.clone());
+        let mut b = a.clone();
+        best_fft(&mut b, omega, log_n);
+        assert_eq!(b, expected);
+        assert!(mock.0.load(Ordering::SeqCst) >= 1);
+
+        unregister_ntt_backend::<Fr, Fr>();
+        assert!(ntt_backend::<Fr, Fr>().is_none());
+        let mut b = a;
+        best_fft(&mut b, omega, log_n);
+        assert_eq!(b, expected);
+    }
+}
@@ -0,0 +1,451 @@
+use crate::arithmetic::mul_512;
+use crate::arithmetic::sbb;
+use crate::arithmetic::CurveEndo;
+use crate::arithmetic::EndoParameters;
+use crate::bls12_377::{Fq, Fq2, Fr, FROBENIUS_COEFF_FQ12_C1, FROBENIUS_COEFF_FQ6_C1};
+use crate::derive::curve::{IDENTITY_MASK, IDENTITY_SHIFT, SIGN_MASK, SIGN_SHIFT};
+use crate::endo;
+use crate::ff::WithSmallOrderMulGroup;
+use crate::ff::{Field, PrimeField};
+use crate::group::Curve;
+use crate::group::{cofactor::CofactorGroup, prime::PrimeCurveAffine, Group, GroupEncoding};
+use crate::{
+    impl_binops_additive, impl_binops_additive_specify_output, impl_binops_multiplicative,
+    impl_binops_multiplicative_mixed, new_curve_impl,
+};
+use crate::{Coordinates, CurveAffine, CurveExt};
+use alloc::boxed::Box;
+use core::cmp;
+use core::convert::TryInto;
+use core::fmt::Debug;
+use core::iter::Sum;
+use core::ops::{Add, Mul, Neg, Sub};
+use rand_core::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+#[cfg(feature = "derive_serde")]
+use serde::{Deserialize, Serialize};
+
+new_curve_impl!(
+    (pub),
+    G1,
+    G1Affine,
+    Fq,
+    Fr,
+    (G1_GENERATOR_X,G1_GENERATOR_Y),
+    G1_A,
+    G1_B,
+    "bls12_377_g1",
+    |domain_prefix| hash_to_curve_g1(domain_prefix),
+);
+
+new_curve_impl!(
+    (pub),
+    G2,
+    G2Affine,
+    Fq2,
+    Fr,
+    (G2_GENERATOR_X, G2_GENERATOR_Y),
+    G2_A,
+    G2_B,
+    "bls12_377_g2",
+    |domain_prefix| hash_to_curve_g2(domain_prefix),
+);
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn hash_to_curve_g1<'a>(domain_prefix: &'a str) -> Box<dyn Fn(&[u8]) -> G1 + 'a> {
+    let suite = G1::default_hash_to_curve_suite();
+    Box::new(move |message| {
+        let r0 = suite.hash_to_curve(domain_prefix, message);
+        r0.clear_cofactor()
+    })
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn hash_to_curve_g2<'a>(domain_prefix: &'a str) -> Box<dyn Fn(&[u8]) -> G2 + 'a> {
+    let suite = G2::default_hash_to_curve_suite();
+    Box::new(move |message| {
+        let r0 = suite.hash_to_curve(domain_prefix, message);
+        r0.clear_cofactor()
+    })
+}
+
+// Generators of Arkworks and Zexe.
+const G1_GENERATOR_X: Fq = Fq::from_raw_unchecked([
+    0xeab9b16eb21be9ef,
+    0xd5481512ffcd394e,
+    0x188282c8bd37cb5c,
+    0x85951e2caa9d41bb,
+    0xc8fc6225bf87ff54,
+    0x008848defe740a67,
+]);
+const G1_GENERATOR_Y: Fq = Fq::from_raw_unchecked([
+    0xfd82de55559c8ea6,
+    0xc2fe3d3634a9591a,
+    0x6d182ad44fb82305,
+    0xbd7fb348ca3e52d9,
+    0x1f674f5d30afeec4,
+    0x01914a69c5102eff,
+]);
+const G1_A: Fq = Fq::ZERO;
+const G1_B: Fq = Fq::ONE;
+
+const G2_A: Fq2 = Fq2 {
+    c0: Fq::ZERO,
+    c1: Fq::ZERO,
+};
+
+// b/u = 1/u
+const G2_B: Fq2 = Fq2 {
+    c0: Fq::ZERO,
+    c1: Fq::from_raw_unchecked([
+        0x1c9ed9999999999a,
+        0x0dd39e5c1ccccccd,
+        0x129207b63c6bf800,
+        0xdc7b4f91cd5fd889,
+        0x43bd03737460c589,
+        0x010222f6db0fd6f3,
+    ]),
+};
+
+const G2_GENERATOR_X: Fq2 = Fq2 {
+    c0: Fq::from_raw_unchecked([
+        0x74e3e48f7c005196,
+        0x71889f52bb535402,
+        0x7ea501f557db6b9b,
+        0xc565f071203e5031,
+        0xc89630a2a3841d01,
+        0x018480be71c785fe,
+    ]),
+    c1: Fq::from_raw_unchecked([
+        0xb26bfefa6ea16afe,
+        0x5cf89984bff76fe6,
+        0xe7223ece0799c9de,
+        0x532777ee6651cecb,
+        0x70dc5a51b1b140d5,
+        0x00ea6040e7004031,
+    ]),
+};
+
+const G2_GENERATOR_Y: Fq2 = Fq2 {
+    c0: Fq::from_raw_unchecked([
+        0xf094094409fd4ddf,
+        0xf2cf88886d8c7c2e,
+        0xe458c282f832d204,
+        0xde03ed7274b49a58,
+        0xd960736bcbb2efb4,
+        0x00690d665d446f7b,
+    ]),
+    c1: Fq::from_raw_unchecked([
+        0xd9a1cdd185eb8f93,
+        0x4279b83f5e52270b,
+        0x2463b01acee304c2,
+        0x61ef11ac3d591bf1,
+        0x9e549da3151a70aa,
+        0x00f8169fd2835518,
+    ]),
+};
+
+// `ZETA` of [`Fr`] is `x^2 - 1`, a root of `X^2 + X + 1` modulo the group order `n`.
+// With `b1 = 1` and `b2 = x^2 - 1`, the halves of the decomposition are less than
+// 128 bits. See `script/bls12_377.py`.
+const ENDO_PARAMS_BLS: EndoParameters = EndoParameters {
+    // round(2^256 b1 / n)
+    gamma1: [0xe, 0, 0, 0],
+    // round(2^256 b2 / n)
+    gamma2: [0x7f72ed32af90181e, 0xb3f7aa969fd37160, 0x3, 0],
+    b1: [0x1, 0, 0, 0],
+    b2: [0x0a11800000000000, 0x452217cc90000001, 0, 0],
+};
+
+endo!(G1, Fr, ENDO_PARAMS_BLS);
+
+/// `(x - 1)^2 / 3`, the cofactor of G1.
+const G1_COFACTOR: u128 = 0x170b5d44300000000000000000000000;
+
+/// `x^2 - 1`, the eigenvalue of the endomorphism `(x, y) -> (ζ x, y)` on G1.
+const X_SQUARED_MINUS_ONE: u128 = 0x452217cc900000010a11800000000000;
+
+// Multiplies `p` by the public constant `e`, in variable time with respect to `e`.
+fn mul_by_u128<C: Group>(p: &C, e: u128) -> C {
+    let mut acc = C::identity();
+    for i in (0..128 - e.leading_zeros()).rev() {
+        acc = acc.double();
+        if ((e >> i) & 1) == 1 {
+            acc += p;
+        }
+    }
+    acc
+}
+
+impl CofactorGroup for G1 {
+    type Subgroup = G1;
+
+    fn clear_cofactor(&self) -> Self {
+        mul_by_u128(self, G1_COFACTOR)
+    }
+
+    fn into_subgroup(self) -> CtOption<Self::Subgroup> {
+        CtOption::new(self, self.is_torsion_free())
+    }
+
+    fn is_torsion_free(&self) -> Choice {
+        // As `x^2 - 1` is a root of `X^2 + X + 1` in the integers modulo the group
+        // order `r = (x^2 - 1)^2 + (x^2 - 1) + 1`, and the cofactor is coprime to
+        // `r`, the points on which the endomorphism acts as `[x^2 - 1]` are exactly
+        // the points of G1 (https://eprint.iacr.org/2021/1130).
+        mul_by_u128(self, X_SQUARED_MINUS_ONE).ct_eq(&self.endo())
+    }
+}
+
+impl G2 {
+    /// The untwist-Frobenius-twist endomorphism of the twist,
+    /// `psi: (x, y) -> (u^((p - 1) / 3) x^p, u^((p - 1) / 2) y^p)`. It acts on G2 as
+    /// the multiplication by `p`, that is, by `BLS_X` modulo the group order.
+    // Both coefficients are in Fq, and are also the Frobenius coefficients of `v` in Fq6 and
+    // of `w^3` in Fq12.
+    pub fn psi(&self) -> G2 {
+        let mut g2 = *self;
+        g2.x.conjugate();
+        g2.y.conjugate();
+        g2.z.conjugate();
+
+        g2.x *= FROBENIUS_COEFF_FQ6_C1[1];
+        g2.y *= FROBENIUS_COEFF_FQ12_C1[3];
+
+        g2
+    }
+
+    /// `psi^2: (x, y) -> (w * x, -y)`, with `w` a primitive cube root of unity of
+    /// Fq. The Frobenius of Fq2 is an involution, so it needs no conjugation.
+    pub fn psi2(&self) -> G2 {
+        G2 {
+            x: self.x * FROBENIUS_COEFF_FQ6_C1[2],
+            y: -self.y,
+            z: self.z,
+        }
+    }
+}
+
+impl G2Affine {
+    /// [`G2::psi`] on affine coordinates.
+    pub fn psi(&self) -> G2Affine {
+        let mut g2 = *self;
+        g2.x.conjugate();
+        g2.y.conjugate();
+
+        g2.x *= FROBENIUS_COEFF_FQ6_C1[1];
+        g2.y *= FROBENIUS_COEFF_FQ12_C1[3];
+
+        g2
+    }
+
+    /// [`G2::psi2`] on affine coordinates.
+    pub fn psi2(&self) -> G2Affine {
+        G2Affine {
+            x: self.x * FROBENIUS_COEFF_FQ6_C1[2],
+            y: -self.y,
+        }
+    }
+}
+
+impl CofactorGroup for G2 {
+    type Subgroup = G2;
+
+    fn clear_cofactor(&self) -> Self {
+        // Budroni-Pintore (https://eprint.iacr.org/2017/419), the multiplication by
+        // `h(psi) = (x^2 - x - 1) + (x - 1) psi + 2 psi^2`, that is, by the effective
+        // cofactor `3 (x^2 - 1) h2`.
+        let t1 = mul_by_u128(self, super::BLS_X as u128);
+        let t2 = self.psi();
+        let t3 = self.double().psi().psi() - t2;
+        let t2 = mul_by_u128(&(t1 + t2), super::BLS_X as u128);
+
+        t3 + t2 - t1 - self
+    }
+
+    fn into_subgroup(self) -> CtOption<Self::Subgroup> {
+        CtOption::new(self, self.is_torsion_free())
+    }
+
+    fn is_torsion_free(&self) -> Choice {
+        // The endomorphism `psi` acts on G2 as the multiplication by `p`, which is
+        // `t - 1 = x` modulo the group order. Following
+        // https://eprint.iacr.org/2022/352, since the cofactors of G1 and G2 are
+        // coprime, the points with `psi(P) = [x] P` are exactly the points of G2.
+        mul_by_u128(self, super::BLS_X as u128).ct_eq(&self.psi())
+    }
+}
+
+impl G1 {
+    const SVDW_Z: Fq = Fq::ONE;
+
+    fn default_hash_to_curve_suite(
+    ) -> crate::hash_to_curve::Suite<Self, crate::hash_to_curve::ExpandMsgXmd<sha2::Sha256>, 64>
+    {
+        crate::hash_to_curve::Suite::<G1, crate::hash_to_curve::ExpandMsgXmd<sha2::Sha256>, 64>::new(
+            b"BLS12377G1_XMD:SHA-256_SVDW_RO_",
+            Self::SVDW_Z,
+            crate::hash_to_curve::Method::SVDW,
+        )
+    }
+}
+
+impl G2 {
+    // The smallest `Z` of RFC 9380 for the SvdW method, `1` and `-1` are not suitable.
+    const SVDW_Z: Fq2 = Fq2 {
+        c0: Fq::from_raw_unchecked([2, 0, 0, 0, 0, 0]),
+        c1: Fq::ZERO,
+    };
+
+    fn default_hash_to_curve_suite(
+    ) -> crate::hash_to_curve::Suite<Self, crate::hash_to_curve::ExpandMsgXmd<sha2::Sha256>, 128>
+    {
+        crate::hash_to_curve::Suite::<G2, crate::hash_to_curve::ExpandMsgXmd<sha2::Sha256>, 128>::new(
+            b"BLS12377G2_XMD:SHA-256_SVDW_RO_",
+            Self::SVDW_Z,
+            crate::hash_to_curve::Method::SVDW,
+        )
+    }
+}
+
+impl crate::hash_to_curve::BatchHashToCurve for G1 {
+    fn batch_hash_to_curve(domain_prefix: &str, messages: &[&[u8]]) -> alloc::vec::Vec<Self> {
+        let mut points =
+            Self::default_hash_to_curve_suite().batch_hash_to_curve(domain_prefix, messages);
+        clear_cofactors(&mut points);
+        points
+    }
+}
+
+impl crate::hash_to_curve::MapToCurve for G1 {
+    fn hash_to_field(domain_prefix: &str, message: &[u8]) -> [Self::Base; 2] {
+        Self::default_hash_to_curve_suite().hash_to_field(domain_prefix, message)
+    }
+
+    fn map_to_curve(u: &Self::Base) -> Self {
+        Self::default_hash_to_curve_suite().map_to_curve(u)
+    }
+}
+
+impl crate::hash_to_curve::BatchHashToCurve for G2 {
+    fn batch_hash_to_curve(domain_prefix: &str, messages: &[&[u8]]) -> alloc::vec::Vec<Self> {
+        let mut points =
+            Self::default_hash_to_curve_suite().batch_hash_to_curve(domain_prefix, messages);
+        clear_cofactors(&mut points);
+        points
+    }
+}
+
+impl crate::hash_to_curve::MapToCurve for G2 {
+    fn hash_to_field(domain_prefix: &str, message: &[u8]) -> [Self::Base; 2] {
+        Self::default_hash_to_curve_suite().hash_to_field(domain_prefix, message)
+    }
+
+    fn map_to_curve(u: &Self::Base) -> Self {
+        Self::default_hash_to_curve_suite().map_to_curve(u)
+    }
+}
+
+fn clear_cofactors<C: CofactorGroup<Subgroup = C> + Send>(points: &mut [C]) {
+    #[cfg(feature = "std")]
+    {
+        use rayon::prelude::*;
+        points
+            .par_iter_mut()
+            .for_each(|point| *point = point.clear_cofactor());
+    }
+
+    #[cfg(not(feature = "std"))]
+    points
+        .iter_mut()
+        .for_each(|point| *point = point.clear_cofactor());
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use group::UncompressedEncoding;
+
+    crate::curve_testing_suite!(G1, G2, "clear_cofactor");
+    crate::curve_testing_suite!(G1, "torsion_free");
+    crate::curve_testing_suite!(G1, G2, "batch_hash_to_curve");
+    crate::curve_testing_suite!(G1, G2, "map_to_curve");
+    crate::curve_testing_suite!(G1, G2);
+    crate::curve_testing_suite!(G1, "endo_consistency");
+    crate::curve_testing_suite!(G1, "endo");
+    crate::curve_testing_suite!(
+        G1,
+        "constants",
+        Fq::MODULUS,
+        G1_A,
+        G1_B,
+        G1_GENERATOR_X,
+        G1_GENERATOR_Y,
+        Fr::MODULUS
+    );
+    crate::curve_testing_suite!(
+        G2,
+        "constants",
+        Fq2::MODULUS,
+        G2_A,
+        G2_B,
+        G2_GENERATOR_X,
+        G2_GENERATOR_Y,
+        Fr::MODULUS
+    );
+
+    mod g2 {
+        use super::*;
+        crate::curve_testing_suite!(G2, "torsion_free");
+        crate::curve_testing_suite!(G2, "psi", Fr::from(crate::bls12_377::BLS_X));
+    }
+
+    #[test]
+    fn test_parameters() {
+        use num_bigint::BigUint;
+
+        // r = x^4 - x^2 + 1 and p = (x - 1)^2 r / 3 + x.
+        let x = BigUint::from(crate::bls12_377::BLS_X);
+        let r = x.pow(4) - x.pow(2) + 1u64;
+        let p = (&x - 1u64).pow(2) * &r / 3u64 + &x;
+        assert_eq!(r, crate::tests::modulus::<Fr>());
+        assert_eq!(p, crate::tests::modulus::<Fq>());
+        assert_eq!(BigUint::from(G1_COFACTOR), (&x - 1u64).pow(2) / 3u64);
+        assert_eq!(BigUint::from(X_SQUARED_MINUS_ONE), x.pow(2) - 1u64);
+
+        // G2 is the D-type twist y^2 = x^3 + b/u.
+        assert_eq!(G2_B * Fq2::new(Fq::ZERO, Fq::ONE), Fq2::ONE);
+    }
+
+    #[test]
+    fn test_psi() {
+        use rand_core::OsRng;
+
+        let x = Fr::from(crate::bls12_377::BLS_X);
+        for _ in 0..10 {
+            // An endomorphism of the whole twist, acting as `[x]` on G2.
+            let p = G2::random(OsRng);
+            // A point of the whole twist, out of G2 with overwhelming probability.
+            let q = loop {
+                let x = Fq2::random(OsRng);
+                if let Some(y) = Option::<Fq2>::from((x.square() * x + G2_B).sqrt()) {
+                    break G2Affine::from_xy(x, y).unwrap().to_curve();
+                }
+            };
+            assert!(bool::from(q.psi().is_on_curve()));
+            assert_eq!((p + q).psi(), p.psi() + q.psi());
+            assert_eq!(p.psi(), p * x);
+        }
+    }
+
+    #[test]
+    fn test_hash_to_curve() {
+        for i in 0..20u32 {
+            let p = G1::hash_to_curve("test")(&i.to_be_bytes());
+            assert!(bool::from(p.is_on_curve() & p.is_torsion_free()));
+            let q = G2::hash_to_curve("test")(&i.to_be_bytes());
+            assert!(bool::from(q.is_on_curve() & q.is_torsion_free()));
+        }
+    }
+}
@@ -0,0 +1,189 @@
+use crate::bls12_377::curve::*;
+use crate::bls12_377::fq12::*;
+use crate::bls12_377::fq2::*;
+use crate::bls12_377::fq6::Fq6;
+use crate::bls12_377::fr::*;
+use crate::ff::PrimeField;
+use crate::ff_ext::quadratic::QuadSparseMul;
+use crate::ff_ext::ExtField;
+use crate::group::cofactor::CofactorCurveAffine;
+use crate::group::Group;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::iter::Sum;
+use core::ops::MulAssign;
+use core::ops::{Add, Mul, Neg, Sub};
+use pairing::{Engine, MillerLoopResult, MultiMillerLoop, PairingCurveAffine};
+use rand_core::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+crate::impl_gt!(Gt, Fq12, Fq6, Fq2, Fr);
+crate::impl_miller_loop_components!(
+    Bls12377,
+    G1,
+    G1Affine,
+    G2,
+    G2Affine,
+    Fq12,
+    Gt,
+    Fr,
+    super::BLS_X_NAF
+);
+
+impl MillerLoopResult for Fq12 {
+    type Gt = Gt;
+
+    fn final_exponentiation(&self) -> Self::Gt {
+        fn exp_by_x(f: &Fq12) -> Fq12 {
+            let x = super::BLS_X;
+            let mut res = Fq12::one();
+            for i in (0..64).rev() {
+                res.cyclotomic_square();
+                if ((x >> i) & 1) == 1 {
+                    res.mul_assign(f);
+                }
+            }
+            res
+        }
+
+        let r = *self;
+        let mut f1 = *self;
+        f1.conjugate();
+
+        use ff::Field;
+        Gt(r.invert()
+            .map(|mut f2| {
+                // Easy part, f^((p^6 - 1)(p^2 + 1)).
+                let mut r = f1;
+                r.mul_assign(&f2);
+                f2 = r;
+                r.frobenius_map_const::<2>();
+                r.mul_assign(&f2);
+
+                // Hard part, r^(3 (p^4 - p^2 + 1) / r), with
+                // 3 (p^4 - p^2 + 1) / r = (x - 1)^2 (x + p) (x^2 + p^2 - 1) + 3
+                // (https://eprint.iacr.org/2020/875). As x is positive, the powers
+                // need no conjugation.
+                let mut y0 = r;
+                y0.cyclotomic_square();
+
+                // y1 = r^((x - 1)^2)
+                let mut y1 = exp_by_x(&r);
+                let mut r_inv = r;
+                r_inv.conjugate();
+                y1.mul_assign(&r_inv);
+                let mut y2 = exp_by_x(&y1);
+                y1.conjugate();
+                y1.mul_assign(&y2);
+
+                // y1 = r^((x - 1)^2 (x + p))
+                y2 = exp_by_x(&y1);
+                y1.frobenius_map_const::<1>();
+                y1.mul_assign(&y2);
+
+                r.mul_assign(&y0);
+
+                // y1 = y1^(x^2 + p^2 - 1)
+                let y0 = exp_by_x(&y1);
+                let y2 = exp_by_x(&y0);
+                let mut y0 = y1;
+                y0.frobenius_map_const::<2>();
+                y1.conjugate();
+                y1.mul_assign(&y2);
+                y1.mul_assign(&y0);
+
+                r.mul_assign(&y1);
+                r
+            })
+            .unwrap())
+    }
+}
+
+pub fn multi_miller_loop(terms: &[(&G1Affine, &G2Affine)]) -> Fq12 {
+    let terms = terms
+        .iter()
+        .filter_map(|&(p, q)| {
+            if bool::from(p.is_identity()) || bool::from(q.is_identity()) {
+                None
+            } else {
+                Some((*p, *q))
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let mut f = Fq12::one();
+    let mut r = terms
+        .iter()
+        .map(|(_, q)| crate::line::JacobianPoint::from(q))
+        .collect::<Vec<_>>();
+
+    for (i, x) in super::BLS_X_NAF.iter().rev().skip(1).enumerate() {
+        (i != 0).then(|| f.square_assign());
+
+        for ((p, _), r) in terms.iter().zip(r.iter_mut()) {
+            double(&mut f, r, p);
+        }
+
+        match x {
+            &val @ (1 | -1) => {
+                for ((p, q), r) in terms.iter().zip(r.iter_mut()) {
+                    if val == 1 {
+                        add(&mut f, r, q, p);
+                    } else {
+                        add(&mut f, r, &q.neg(), p);
+                    }
+                }
+            }
+            _ => continue,
+        }
+    }
+
+    f
+}
+
+// The optimal ate loop of BLS12 curves, over the positive `x`, is not followed by
+// Frobenius additions nor by a conjugation.
+fn frobenius_points(_: &G2Affine) -> [G2Affine; 0] {
+    []
+}
+
+// Final steps of the line function on prepared coefficients
+fn ell(f: &mut Fq12, coeffs: &crate::line::Line<Fq2>, p: &G1Affine) {
+    let mut c0 = coeffs.c0;
+    let mut c1 = coeffs.c1;
+    c0.c0.mul_assign(&p.y);
+    c0.c1.mul_assign(&p.y);
+    c1.c0.mul_assign(&p.x);
+    c1.c1.mul_assign(&p.x);
+    Fq12::mul_by_line(f, &c0, &c1, &coeffs.c2);
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{Bls12377, Fr, G1, G2};
+    use super::{multi_miller_loop, Fq12, G1Affine, G2Affine, Gt};
+    use ff::Field;
+    use group::{prime::PrimeCurveAffine, Curve, Group};
+    use pairing::{Engine, MillerLoopResult, PairingCurveAffine};
+    use rand_core::OsRng;
+    crate::test_pairing!(Bls12377, G1, G1Affine, G2, G2Affine, Fq12, Gt, Fr);
+    // 63 doublings and 6 additions for the non-zero NAF digits.
+    crate::test_pairing_op_count!(
+        G1Affine,
+        G2Affine,
+        miller_loop: { sparse_mul: 69, square: 62 },
+        final_exponentiation: { dense_mul: 44, cyclotomic_square: 321 }
+    );
+
+    #[test]
+    fn test_final_exponentiation() {
+        use crate::bls12_377::Fq;
+
+        // 3 (p^12 - 1) / r
+        let p = crate::tests::modulus::<Fq>();
+        let r = crate::tests::modulus::<Fr>();
+        let e = (p.pow(12) - 1u64) / r * 3u64;
+        let f = Fq12::random(OsRng);
+        assert_eq!(f.final_exponentiation().0, f.pow_vartime(e.to_u64_digits()));
+    }
+}
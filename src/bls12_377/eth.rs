@@ -0,0 +1,210 @@
+//! The encodings and the pairing check of the BLS12-377 precompiles of
+//! [EIP-2539](https://eips.ethereum.org/EIPS/eip-2539), which follows the ABI of
+//! the BLS12-381 precompiles of [EIP-2537](https://eips.ethereum.org/EIPS/eip-2537).
+//!
+//! Field elements are 64 byte big-endian integers, whose 16 most significant bytes
+//! must be zero and which must be smaller than the modulus. A point of `G1` is
+//! `x || y`, and a point of `G2` is `x.c0 || x.c1 || y.c0 || y.c1`. The identity is
+//! encoded with zero coordinates. Decoding fails on points off the curve or outside
+//! of the subgroup.
+
+use crate::bls12_377::{Bls12377, Fq, Fq2, G1Affine, G2Affine, G1, G2};
+use crate::group::cofactor::CofactorGroup;
+use crate::group::Group;
+use crate::CurveAffine;
+use alloc::vec::Vec;
+use pairing::{MillerLoopResult, MultiMillerLoop};
+
+// Size of an encoded field element, with 16 bytes of zero padding.
+const FQ_SIZE: usize = 64;
+
+fn fq_from_bytes(bytes: &[u8]) -> Option<Fq> {
+    let (padding, bytes) = bytes.split_at(FQ_SIZE - Fq::SIZE);
+    if padding.iter().any(|b| *b != 0) {
+        return None;
+    }
+    let mut le = [0u8; Fq::SIZE];
+    le.copy_from_slice(bytes);
+    le.reverse();
+    Fq::from_bytes(&le).into()
+}
+
+fn fq_to_bytes(x: &Fq, bytes: &mut [u8]) {
+    let mut le = x.to_bytes();
+    le.reverse();
+    bytes[FQ_SIZE - Fq::SIZE..].copy_from_slice(&le);
+}
+
+/// Decodes a point of `G1`, failing if a coordinate is not canonical or if the
+/// point is not in `G1`.
+pub fn g1_from_bytes(bytes: &[u8; 128]) -> Option<G1Affine> {
+    let x = fq_from_bytes(&bytes[..64])?;
+    let y = fq_from_bytes(&bytes[64..])?;
+    let p = G1Affine { x, y };
+    bool::from(p.is_on_curve() & G1::from(p).is_torsion_free()).then_some(p)
+}
+
+/// Encodes a point of `G1`.
+pub fn g1_to_bytes(p: &G1Affine) -> [u8; 128] {
+    let mut res = [0; 128];
+    fq_to_bytes(&p.x, &mut res[..64]);
+    fq_to_bytes(&p.y, &mut res[64..]);
+    res
+}
+
+/// Decodes a point of `G2`, failing if a coordinate is not canonical or if the
+/// point is not in `G2`.
+pub fn g2_from_bytes(bytes: &[u8; 256]) -> Option<G2Affine> {
+    let x = Fq2::new(
+        fq_from_bytes(&bytes[..64])?,
+        fq_from_bytes(&bytes[64..128])?,
+    );
+    let y = Fq2::new(
+        fq_from_bytes(&bytes[128..192])?,
+        fq_from_bytes(&bytes[192..])?,
+    );
+    let p = G2Affine { x, y };
+    bool::from(p.is_on_curve() & G2::from(p).is_torsion_free()).then_some(p)
+}
+
+/// Encodes a point of `G2`.
+pub fn g2_to_bytes(p: &G2Affine) -> [u8; 256] {
+    let mut res = [0; 256];
+    fq_to_bytes(&p.x.c0, &mut res[..64]);
+    fq_to_bytes(&p.x.c1, &mut res[64..128]);
+    fq_to_bytes(&p.y.c0, &mut res[128..192]);
+    fq_to_bytes(&p.y.c1, &mut res[192..]);
+    res
+}
+
+/// The pairing check precompile: returns 1 as a 32 byte big-endian integer if the
+/// product of the pairings of the pairs of `input` is the identity, and 0
+/// otherwise. Fails if `input` is empty or not a sequence of 384 byte pairs of a
+/// point of `G1` and a point of `G2`, or on an invalid point.
+pub fn pairing_check(input: &[u8]) -> Option<[u8; 32]> {
+    if input.is_empty() || input.len() % 384 != 0 {
+        return None;
+    }
+    let terms = input
+        .chunks(384)
+        .map(|pair| {
+            let p = g1_from_bytes(pair[..128].try_into().unwrap())?;
+            let q = g2_from_bytes(pair[128..].try_into().unwrap())?;
+            Some((p, q))
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let terms = terms.iter().map(|(p, q)| (p, q)).collect::<Vec<_>>();
+    let res = Bls12377::multi_miller_loop(&terms).final_exponentiation();
+
+    let mut out = [0; 32];
+    out[31] = bool::from(res.is_identity()) as u8;
+    Some(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bls12_377::Fr;
+    use crate::ff::Field;
+    use crate::group::{prime::PrimeCurveAffine, Curve};
+    use crate::CurveExt;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_encodings() {
+        let g1 = G1Affine::generator();
+        let bytes = g1_to_bytes(&g1);
+        assert_eq!(
+            hex::encode(&bytes[..64]),
+            "00000000000000000000000000000000\
+             008848defe740a67c8fc6225bf87ff5485951e2caa9d41bb188282c8bd37cb5cd5481512ffcd394eeab9b16eb21be9ef"
+        );
+        assert_eq!(g1_from_bytes(&bytes).unwrap(), g1);
+        let g2 = G2Affine::generator();
+        assert_eq!(g2_from_bytes(&g2_to_bytes(&g2)).unwrap(), g2);
+
+        // c0 is first.
+        let mut x = [0; 64];
+        fq_to_bytes(&g2.x.c0, &mut x);
+        assert_eq!(g2_to_bytes(&g2)[..64], x);
+
+        // The identity.
+        assert_eq!(g1_to_bytes(&G1Affine::identity()), [0; 128]);
+        assert_eq!(g1_from_bytes(&[0; 128]).unwrap(), G1Affine::identity());
+        assert_eq!(g2_to_bytes(&G2Affine::identity()), [0; 256]);
+        assert_eq!(g2_from_bytes(&[0; 256]).unwrap(), G2Affine::identity());
+
+        for _ in 0..10 {
+            let p = G1::random(OsRng).to_affine();
+            let q = G2::random(OsRng).to_affine();
+            assert_eq!(g1_from_bytes(&g1_to_bytes(&p)).unwrap(), p);
+            assert_eq!(g2_from_bytes(&g2_to_bytes(&q)).unwrap(), q);
+        }
+
+        // Nonzero padding, non-canonical coordinates and points off the curve.
+        let mut invalid = bytes;
+        invalid[0] = 1;
+        assert!(g1_from_bytes(&invalid).is_none());
+        let mut invalid = bytes;
+        for (chunk, limb) in invalid[80..128]
+            .chunks_mut(8)
+            .zip(Fq::MODULUS_LIMBS.iter().rev())
+        {
+            chunk.copy_from_slice(&limb.to_be_bytes());
+        }
+        assert!(g1_from_bytes(&invalid).is_none());
+        let mut invalid = bytes;
+        invalid[127] ^= 1;
+        assert!(g1_from_bytes(&invalid).is_none());
+
+        // Points outside of the subgroups.
+        let mut x = Fq::ZERO;
+        let p = loop {
+            x += Fq::ONE;
+            if let Some(y) = Option::from((x.square() * x + G1::b()).sqrt()) {
+                break G1Affine { x, y };
+            }
+        };
+        assert!(!bool::from(G1::from(p).is_torsion_free()));
+        assert!(g1_from_bytes(&g1_to_bytes(&p)).is_none());
+        let mut x = Fq2::ZERO;
+        let q = loop {
+            x += Fq2::ONE;
+            if let Some(y) = Option::from((x.square() * x + G2::b()).sqrt()) {
+                break G2Affine { x, y };
+            }
+        };
+        assert!(!bool::from(G2::from(q).is_torsion_free()));
+        assert!(g2_from_bytes(&g2_to_bytes(&q)).is_none());
+    }
+
+    #[test]
+    fn test_pairing_check() {
+        let one = {
+            let mut one = [0; 32];
+            one[31] = 1;
+            one
+        };
+        let a = Fr::random(OsRng);
+        let b = Fr::random(OsRng);
+        let p = |s: Fr| g1_to_bytes(&(G1Affine::generator() * s).to_affine());
+        let q = |s: Fr| g2_to_bytes(&(G2Affine::generator() * s).to_affine());
+
+        // e(a P, b Q) e(-ab P, Q) = 1
+        let input = [&p(a)[..], &q(b), &p(-(a * b)), &q(Fr::ONE)].concat();
+        assert_eq!(pairing_check(&input).unwrap(), one);
+        let input = [&p(a)[..], &q(b), &p(a * b), &q(Fr::ONE)].concat();
+        assert_eq!(pairing_check(&input).unwrap(), [0; 32]);
+
+        // Pairs with the identity.
+        let input = [&[0; 128][..], &q(b), &p(a), &[0; 256]].concat();
+        assert_eq!(pairing_check(&input).unwrap(), one);
+
+        // Malformed inputs.
+        assert!(pairing_check(&[]).is_none());
+        assert!(pairing_check(&input[..383]).is_none());
+        let mut invalid = input.clone();
+        invalid[127] = 1;
+        assert!(pairing_check(&invalid).is_none());
+    }
+}
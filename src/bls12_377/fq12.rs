@@ -0,0 +1,224 @@
+use super::fq::Fq;
+use super::fq2::Fq2;
+use super::fq6::Fq6;
+use crate::ff_ext::{
+    quadratic::{QuadExtField, QuadExtFieldArith, QuadSparseMul, TwistType, SQRT},
+    ExtField,
+};
+use ff::Field;
+
+/// v is a quadratic non-residue in Fq6. Fq12 = Fq6[X]/(X^2 - v)
+/// We introduce the variable w such that w^2 = v
+/// An element of Fq12, represented by c0 + c1 * w.
+pub type Fq12 = QuadExtField<Fq6>;
+
+impl QuadExtFieldArith for Fq12 {
+    type Base = Fq6;
+    // w
+    const NON_SQUARE: Fq12 = Fq12::new(Fq6::ZERO, Fq6::ONE);
+    const SQRT: SQRT<Self::Base> = SQRT::Complex;
+    #[cfg(test)]
+    const COUNT_OPS: bool = true;
+}
+
+impl QuadSparseMul for Fq12 {
+    type Base = Fq2;
+    const TWIST_TYPE: TwistType = TwistType::D;
+}
+
+impl ExtField for Fq12 {
+    type BaseField = Fq6;
+
+    const NON_RESIDUE: Self = Fq12::zero(); // no needs
+
+    fn frobenius_map(&mut self, power: usize) {
+        self.c0.frobenius_map(power);
+        self.c1.frobenius_map(power);
+        self.c1.c0 *= &FROBENIUS_COEFF_FQ12_C1[power % 12];
+        self.c1.c1 *= &FROBENIUS_COEFF_FQ12_C1[power % 12];
+        self.c1.c2 *= &FROBENIUS_COEFF_FQ12_C1[power % 12];
+    }
+
+    #[inline(always)]
+    fn frobenius_map_const<const POWER: usize>(&mut self) {
+        if POWER % 12 != 0 {
+            self.c0.frobenius_map_const::<POWER>();
+            self.c1.frobenius_map_const::<POWER>();
+            self.c1.c0 *= &FROBENIUS_COEFF_FQ12_C1[POWER % 12];
+            self.c1.c1 *= &FROBENIUS_COEFF_FQ12_C1[POWER % 12];
+            self.c1.c2 *= &FROBENIUS_COEFF_FQ12_C1[POWER % 12];
+        }
+    }
+
+    fn norm(&self) -> Fq6 {
+        QuadExtField::norm(self)
+    }
+}
+
+crate::impl_binops_additive!(Fq12, Fq12);
+crate::impl_binops_multiplicative!(Fq12, Fq12);
+crate::impl_binops_calls!(Fq12);
+crate::impl_sum_prod!(Fq12);
+crate::impl_cyclotomic_square!(Fq2, Fq12);
+
+/// u^((p^i - 1) / 6) for i = 0,...,11
+pub const FROBENIUS_COEFF_FQ12_C1: [Fq2; 12] = [
+    // u^((p^0 - 1) / 6)
+    Fq2 {
+        c0: Fq::ONE,
+        c1: Fq::ZERO,
+    },
+    // u^((p^1 - 1) / 6)
+    Fq2 {
+        c0: Fq::from_raw_unchecked([
+            0xe938a9d1104f2031,
+            0xb57668e558eb0188,
+            0xc681bf34a3aa559d,
+            0x5c8a45e0f94ebc8e,
+            0x33c1e30682567f91,
+            0x009a9975399c0196,
+        ]),
+        c1: Fq::ZERO,
+    },
+    // u^((p^2 - 1) / 6)
+    Fq2 {
+        c0: Fq::from_raw_unchecked([
+            0x8508c00000000002,
+            0x452217cc90000000,
+            0xc5ed1347970dec00,
+            0x619aaf7d34594aab,
+            0x09b3af05dd14f6ec,
+            0x0000000000000000,
+        ]),
+        c1: Fq::ZERO,
+    },
+    // u^((p^3 - 1) / 6)
+    Fq2 {
+        c0: Fq::from_raw_unchecked([
+            0x6e76d5ecf1391c63,
+            0x99588459bff27d8e,
+            0xbce649cf436b0f62,
+            0x400398f50ad1dec1,
+            0xc0c534db1a79beb1,
+            0x01680a40796537ca,
+        ]),
+        c1: Fq::ZERO,
+    },
+    // u^((p^4 - 1) / 6)
+    Fq2 {
+        c0: Fq::from_raw_unchecked([
+            0x8508c00000000001,
+            0x452217cc90000000,
+            0xc5ed1347970dec00,
+            0x619aaf7d34594aab,
+            0x09b3af05dd14f6ec,
+            0x0000000000000000,
+        ]),
+        c1: Fq::ZERO,
+    },
+    // u^((p^5 - 1) / 6)
+    Fq2 {
+        c0: Fq::from_raw_unchecked([
+            0x853e2c1be0e9fc32,
+            0xe3e21b7467077c05,
+            0xf6648a9a9fc0b9c4,
+            0xe379531411832232,
+            0x8d0351d498233f1f,
+            0x00cd70cb3fc93634,
+        ]),
+        c1: Fq::ZERO,
+    },
+    // u^((p^6 - 1) / 6)
+    Fq2 {
+        c0: Fq::from_raw_unchecked([
+            0x8508c00000000000,
+            0x170b5d4430000000,
+            0x1ef3622fba094800,
+            0x1a22d9f300f5138f,
+            0xc63b05c06ca1493b,
+            0x01ae3a4617c510ea,
+        ]),
+        c1: Fq::ZERO,
+    },
+    // u^((p^7 - 1) / 6)
+    Fq2 {
+        c0: Fq::from_raw_unchecked([
+            0x9bd0162eefb0dfd0,
+            0x6194f45ed714fe77,
+            0x5871a2fb165ef262,
+            0xbd98941207a65700,
+            0x927922b9ea4ac9a9,
+            0x0113a0d0de290f54,
+        ]),
+        c1: Fq::ZERO,
+    },
+    // u^((p^8 - 1) / 6)
+    Fq2 {
+        c0: Fq::from_raw_unchecked([
+            0xffffffffffffffff,
+            0xd1e945779fffffff,
+            0x59064ee822fb5bff,
+            0xb8882a75cc9bc8e3,
+            0xbc8756ba8f8c524e,
+            0x01ae3a4617c510ea,
+        ]),
+        c1: Fq::ZERO,
+    },
+    // u^((p^9 - 1) / 6)
+    Fq2 {
+        c0: Fq::from_raw_unchecked([
+            0x1691ea130ec6e39e,
+            0x7db2d8ea700d8272,
+            0x620d1860769e389d,
+            0xda1f40fdf62334cd,
+            0x0575d0e552278a89,
+            0x004630059e5fd920,
+        ]),
+        c1: Fq::ZERO,
+    },
+    // u^((p^10 - 1) / 6)
+    Fq2 {
+        c0: Fq::from_raw_unchecked([
+            0x0000000000000000,
+            0xd1e94577a0000000,
+            0x59064ee822fb5bff,
+            0xb8882a75cc9bc8e3,
+            0xbc8756ba8f8c524e,
+            0x01ae3a4617c510ea,
+        ]),
+        c1: Fq::ZERO,
+    },
+    // u^((p^11 - 1) / 6)
+    Fq2 {
+        c0: Fq::from_raw_unchecked([
+            0xffca93e41f1603cf,
+            0x332941cfc8f883fa,
+            0x288ed7951a488e3b,
+            0x36a986deef71f15c,
+            0x3937b3ebd47e0a1b,
+            0x00e0c97ad7fbdab6,
+        ]),
+        c1: Fq::ZERO,
+    },
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    crate::field_testing_suite!(Fq12, "field_arithmetic");
+    crate::field_testing_suite!(Fq12, "ext_sqrt");
+    crate::field_testing_suite!(Fq12, "legendre");
+    // extension field-specific
+    crate::field_testing_suite!(Fq12, "quadratic_sparse_mul", Fq6, Fq2);
+    crate::field_testing_suite!(
+        Fq12,
+        "frobenius",
+        // Frobenius endomorphism power parameter for extension field
+        //  ϕ: E → E
+        //  (x, y) ↦ (x^p, y^p)
+        // p: modulus of base field (Here, Fq::MODULUS)
+        Fq::MODULUS_LIMBS
+    );
+    crate::field_testing_suite!(Fq12, "mul_by_root", Fq12::new(Fq6::ZERO, Fq6::ONE));
+    crate::field_testing_suite!(Fq12, "norm", 2, 6);
+}
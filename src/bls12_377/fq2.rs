@@ -0,0 +1,137 @@
+use super::fq::Fq;
+use crate::ff::{Field, FromUniformBytes, PrimeField, WithSmallOrderMulGroup};
+use crate::ff_ext::quadratic::{QuadExtField, QuadExtFieldArith, SQRT};
+use crate::ff_ext::ExtField;
+use core::convert::TryInto;
+use subtle::{Choice, CtOption};
+
+crate::impl_binops_additive!(Fq2, Fq2);
+crate::impl_binops_multiplicative!(Fq2, Fq2);
+crate::impl_binops_calls!(Fq2);
+crate::impl_sum_prod!(Fq2);
+// `12 + u` generates the multiplicative group of `Fq2`, of order `q^2 - 1 = 2^47 t`.
+crate::impl_tower2!(
+    Fq,
+    Fq2,
+    multiplicative_generator = Fq2::new(Fq::from_raw([12, 0, 0, 0, 0, 0]), Fq::ONE),
+    s = 47,
+    root_of_unity = Fq2::new(
+        Fq::ZERO,
+        Fq::from_raw([
+            0xd8cb062177414135,
+            0x9b5b6422f1d6d951,
+            0x57601c53ff9e1c96,
+            0xe4c86a26c2565be8,
+            0x398078c10d98b40b,
+            0x00f2c031dfc38d59,
+        ]),
+    ),
+    root_of_unity_inv = Fq2::new(
+        Fq::ZERO,
+        Fq::from_raw([
+            0x4a4925d8c142affc,
+            0x8e9d3e87c683a0c7,
+            0xc9d948507366044d,
+            0x70ee9bcf4e6c3c22,
+            0x3a73bf956f101f84,
+            0x0092605e58806ff8,
+        ]),
+    ),
+    delta = Fq2::new(
+        Fq::from_raw([
+            0xfedf750bb4021f39,
+            0xd2d27e7ee9475cc5,
+            0xee25ebd3ad89ab4e,
+            0xcf79a8b594be8186,
+            0x6b1c2f8433b02843,
+            0x016d14ca49a843a5,
+        ]),
+        Fq::from_raw([
+            0x2d48dc9a9806ae32,
+            0xe6aefbf4ee55b2f6,
+            0x49b0a09e2e39e550,
+            0x597f76c0cdf4407d,
+            0xcaef960ebf9a0859,
+            0x0106e6934571d6a3,
+        ]),
+    )
+);
+crate::impl_tower2_from_uniform_bytes!(Fq, Fq2, 128);
+
+/// -5 is a quadratic non-residue in Fq. Fq2 = Fq[X]/(X^2 + 5)
+/// We introduce the variable u such that u^2 = -5
+pub type Fq2 = QuadExtField<Fq>;
+impl QuadExtFieldArith for Fq2 {
+    type Base = Fq;
+    // u
+    const NON_SQUARE: Fq2 = Fq2::new(Fq::ZERO, Fq::ONE);
+    // q = 1 mod 4, the square roots are taken in Fq.
+    const SQRT: SQRT<Fq> = SQRT::Complex;
+}
+
+impl ExtField for Fq2 {
+    type BaseField = Fq;
+
+    // u, neither a square nor a cube in Fq2.
+    const NON_RESIDUE: Self = Fq2::new(Fq::ZERO, Fq::ONE);
+
+    fn mul_by_nonresidue(&self) -> Self {
+        // (c0 + c1 u) u = -5 c1 + c0 u
+        Self {
+            c0: self.c1.mul_by_nonresidue(),
+            c1: self.c0,
+        }
+    }
+
+    fn frobenius_map(&mut self, power: usize) {
+        if power % 2 != 0 {
+            self.conjugate();
+        }
+    }
+
+    #[inline(always)]
+    fn frobenius_map_const<const POWER: usize>(&mut self) {
+        if POWER % 2 != 0 {
+            self.conjugate();
+        }
+    }
+
+    fn norm(&self) -> Fq {
+        QuadExtField::norm(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    crate::field_testing_suite!(Fq2, "field_arithmetic");
+    crate::field_testing_suite!(Fq2, "ext_sqrt");
+    crate::field_testing_suite!(Fq2, "conversion");
+    crate::field_testing_suite!(Fq2, "serialization");
+    crate::field_testing_suite!(Fq2, "constants");
+    crate::field_testing_suite!(Fq2, "quadratic_residue");
+    crate::field_testing_suite!(Fq2, "sqrt");
+    crate::field_testing_suite!(Fq2, "zeta", Fq);
+    // extension field-specific
+    crate::field_testing_suite!(Fq2, "f2_tests", Fq);
+    crate::field_testing_suite!(
+        Fq2,
+        "frobenius",
+        // Frobenius endomorphism power parameter for extension field
+        //  ϕ: E → E
+        //  (x, y) ↦ (x^p, y^p)
+        // p: modulus of base field (Here, Fq::MODULUS)
+        Fq::MODULUS_LIMBS
+    );
+    crate::field_testing_suite!(Fq2, "mul_by_root", Fq2::new(Fq::ZERO, Fq::ONE));
+    crate::field_testing_suite!(Fq2, "norm", 2, 1);
+
+    #[test]
+    fn test_fq2_mul_nonresidue() {
+        let e = Fq2::random(rand_core::OsRng);
+        let a0 = e.mul_by_nonresidue();
+        let a1 = e * Fq2::NON_RESIDUE;
+        assert_eq!(a0, a1);
+    }
+}
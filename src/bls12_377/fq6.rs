@@ -0,0 +1,232 @@
+use super::fq::Fq;
+use super::fq2::Fq2;
+use crate::ff_ext::{
+    cubic::{CubicExtField, CubicExtFieldArith, CubicSparseMul, SQRT},
+    ExtField,
+};
+use ff::Field;
+
+// u is a cubic non-residue in Fq2. Fq6 = Fq2[X]/(X^3 - u)
+// We introduce the variable v such that v^3 = u
+// An element of Fq6, represented by c0 + c1 * v + c2 * v^2.
+crate::impl_binops_additive!(Fq6, Fq6);
+crate::impl_binops_multiplicative!(Fq6, Fq6);
+crate::impl_binops_calls!(Fq6);
+crate::impl_sum_prod!(Fq6);
+pub type Fq6 = CubicExtField<Fq2>;
+
+impl CubicExtFieldArith for Fq6 {
+    type Base = Fq2;
+    // v
+    const NON_SQUARE: Fq6 = Fq6::new(Fq2::ZERO, Fq2::ONE, Fq2::ZERO);
+    const SQRT: SQRT = SQRT::Norm {
+        p_minus_1_over_2: &[
+            0x4284600000000000,
+            0x0b85aea218000000,
+            0x8f79b117dd04a400,
+            0x8d116cf9807a89c7,
+            0x631d82e03650a49d,
+            0x00d71d230be28875,
+        ],
+    };
+}
+
+impl CubicSparseMul for Fq6 {
+    type Base = Fq2;
+}
+
+impl ExtField for Fq6 {
+    type BaseField = Fq2;
+
+    const NON_RESIDUE: Self = Fq6::new(Fq2::ZERO, Fq2::ONE, Fq2::ZERO);
+
+    fn frobenius_map(&mut self, power: usize) {
+        self.c0.frobenius_map(power);
+        self.c1.frobenius_map(power);
+        self.c2.frobenius_map(power);
+        self.c1 *= &FROBENIUS_COEFF_FQ6_C1[power % 6];
+        self.c2 *= &FROBENIUS_COEFF_FQ6_C2[power % 6];
+    }
+
+    #[inline(always)]
+    fn frobenius_map_const<const POWER: usize>(&mut self) {
+        if POWER % 6 != 0 {
+            self.c0.frobenius_map_const::<POWER>();
+            self.c1.frobenius_map_const::<POWER>();
+            self.c2.frobenius_map_const::<POWER>();
+            self.c1 *= &FROBENIUS_COEFF_FQ6_C1[POWER % 6];
+            self.c2 *= &FROBENIUS_COEFF_FQ6_C2[POWER % 6];
+        }
+    }
+
+    fn mul_by_nonresidue(self: &Fq6) -> Fq6 {
+        let c0 = self.c2.mul_by_nonresidue();
+        let c1 = self.c0;
+        let c2 = self.c1;
+        Self { c0, c1, c2 }
+    }
+
+    fn norm(&self) -> Fq2 {
+        CubicExtField::norm(self)
+    }
+}
+
+/// u^((p^i - 1) / 3) for i = 0,...,5
+pub const FROBENIUS_COEFF_FQ6_C1: [Fq2; 6] = [
+    // u^((p^0 - 1) / 3)
+    Fq2 {
+        c0: Fq::ONE,
+        c1: Fq::ZERO,
+    },
+    // u^((p^1 - 1) / 3)
+    Fq2 {
+        c0: Fq::from_raw_unchecked([
+            0x8508c00000000002,
+            0x452217cc90000000,
+            0xc5ed1347970dec00,
+            0x619aaf7d34594aab,
+            0x09b3af05dd14f6ec,
+            0x0000000000000000,
+        ]),
+        c1: Fq::ZERO,
+    },
+    // u^((p^2 - 1) / 3)
+    Fq2 {
+        c0: Fq::from_raw_unchecked([
+            0x8508c00000000001,
+            0x452217cc90000000,
+            0xc5ed1347970dec00,
+            0x619aaf7d34594aab,
+            0x09b3af05dd14f6ec,
+            0x0000000000000000,
+        ]),
+        c1: Fq::ZERO,
+    },
+    // u^((p^3 - 1) / 3)
+    Fq2 {
+        c0: Fq::from_raw_unchecked([
+            0x8508c00000000000,
+            0x170b5d4430000000,
+            0x1ef3622fba094800,
+            0x1a22d9f300f5138f,
+            0xc63b05c06ca1493b,
+            0x01ae3a4617c510ea,
+        ]),
+        c1: Fq::ZERO,
+    },
+    // u^((p^4 - 1) / 3)
+    Fq2 {
+        c0: Fq::from_raw_unchecked([
+            0xffffffffffffffff,
+            0xd1e945779fffffff,
+            0x59064ee822fb5bff,
+            0xb8882a75cc9bc8e3,
+            0xbc8756ba8f8c524e,
+            0x01ae3a4617c510ea,
+        ]),
+        c1: Fq::ZERO,
+    },
+    // u^((p^5 - 1) / 3)
+    Fq2 {
+        c0: Fq::from_raw_unchecked([
+            0x0000000000000000,
+            0xd1e94577a0000000,
+            0x59064ee822fb5bff,
+            0xb8882a75cc9bc8e3,
+            0xbc8756ba8f8c524e,
+            0x01ae3a4617c510ea,
+        ]),
+        c1: Fq::ZERO,
+    },
+];
+
+/// u^((2p^i - 2) / 3) for i = 0,...,5
+pub const FROBENIUS_COEFF_FQ6_C2: [Fq2; 6] = [
+    // u^((2p^0 - 2) / 3)
+    Fq2 {
+        c0: Fq::ONE,
+        c1: Fq::ZERO,
+    },
+    // u^((2p^1 - 2) / 3)
+    Fq2 {
+        c0: Fq::from_raw_unchecked([
+            0x8508c00000000001,
+            0x452217cc90000000,
+            0xc5ed1347970dec00,
+            0x619aaf7d34594aab,
+            0x09b3af05dd14f6ec,
+            0x0000000000000000,
+        ]),
+        c1: Fq::ZERO,
+    },
+    // u^((2p^2 - 2) / 3)
+    Fq2 {
+        c0: Fq::from_raw_unchecked([
+            0xffffffffffffffff,
+            0xd1e945779fffffff,
+            0x59064ee822fb5bff,
+            0xb8882a75cc9bc8e3,
+            0xbc8756ba8f8c524e,
+            0x01ae3a4617c510ea,
+        ]),
+        c1: Fq::ZERO,
+    },
+    // u^((2p^3 - 2) / 3)
+    Fq2 {
+        c0: Fq::ONE,
+        c1: Fq::ZERO,
+    },
+    // u^((2p^4 - 2) / 3)
+    Fq2 {
+        c0: Fq::from_raw_unchecked([
+            0x8508c00000000001,
+            0x452217cc90000000,
+            0xc5ed1347970dec00,
+            0x619aaf7d34594aab,
+            0x09b3af05dd14f6ec,
+            0x0000000000000000,
+        ]),
+        c1: Fq::ZERO,
+    },
+    // u^((2p^5 - 2) / 3)
+    Fq2 {
+        c0: Fq::from_raw_unchecked([
+            0xffffffffffffffff,
+            0xd1e945779fffffff,
+            0x59064ee822fb5bff,
+            0xb8882a75cc9bc8e3,
+            0xbc8756ba8f8c524e,
+            0x01ae3a4617c510ea,
+        ]),
+        c1: Fq::ZERO,
+    },
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    crate::field_testing_suite!(Fq6, "field_arithmetic");
+    crate::field_testing_suite!(Fq6, "ext_sqrt");
+    crate::field_testing_suite!(Fq6, "legendre");
+    // extension field-specific
+    crate::field_testing_suite!(Fq6, "cubic_sparse_mul", Fq2);
+    crate::field_testing_suite!(
+        Fq6,
+        "frobenius",
+        // Frobenius endomorphism power parameter for extension field
+        //  ϕ: E → E
+        //  (x, y) ↦ (x^p, y^p)
+        // p: modulus of base field (Here, Fq::MODULUS)
+        Fq::MODULUS_LIMBS
+    );
+    crate::field_testing_suite!(Fq6, "mul_by_root", Fq6::new(Fq2::ZERO, Fq2::ONE, Fq2::ZERO));
+    crate::field_testing_suite!(Fq6, "norm", 3, 2);
+
+    #[test]
+    fn test_fq6_mul_nonresidue() {
+        let e = Fq6::random(rand_core::OsRng);
+        let a0 = e.mul_by_nonresidue();
+        let a1 = e * Fq6::NON_RESIDUE;
+        assert_eq!(a0, a1);
+    }
+}
@@ -0,0 +1,43 @@
+//! # `BLS12-377`
+//!
+//! Implementation of the BLS12-377 curve of Zexe, with the parameters of
+//! Arkworks: its base field [`Fq`] has two-adicity 46, so that it is the scalar
+//! field of a curve with efficient FFTs, such as BW6-761, and its scalar field [`Fr`]
+//! has two-adicity 47.
+//!
+//! * [`G1`], `y^2 = x^3 + 1`, is defined over the 377 bit field [`Fq`].
+//! * [`G2`], `y^2 = x^3 + 1/u`, is its D-type sextic twist over [`Fq2`].
+//! * The [`Bls12377`] engine computes the optimal ate pairing into [`Fq12`], built
+//!   as `Fq2 = Fq[u]/(u^2 + 5)`, `Fq6 = Fq2[v]/(v^3 - u)` and `Fq12 = Fq6[w]/(w^2 - v)`.
+//! * [`eth`] has the encodings and the pairing check of the EIP-2539 precompiles.
+//!
+//! The curve is generated by `x = 0x8508c00000000001`, with `r = x^4 - x^2 + 1` and
+//! `p = (x - 1)^2 r / 3 + x`. Its embedded twisted Edwards curve, defined over
+//! [`Fr`], is in [`crate::ed_on_bls12_377`].
+//!
+//! Parameters derivation: `script/bls12_377.py`
+mod curve;
+mod engine;
+pub mod eth;
+mod fq;
+mod fq12;
+mod fq2;
+mod fq6;
+mod fr;
+
+pub use curve::*;
+pub use engine::*;
+pub use fq::*;
+pub use fq12::*;
+pub use fq2::*;
+pub use fq6::*;
+pub use fr::*;
+
+pub const BLS_X: u64 = 0x8508c00000000001;
+
+// BLS_X in NAF form, least significant digit first
+pub const BLS_X_NAF: [i8; 64] = [
+    1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -1, 0, 1, 0, 0, 1, 0, 0, 0, 0, 1, 0, 1, 0, 0, 0, 0,
+    1,
+];
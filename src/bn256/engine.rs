@@ -2,23 +2,34 @@ use crate::bn256::curve::*;
 use crate::bn256::fq::*;
 use crate::bn256::fq12::*;
 use crate::bn256::fq2::*;
-use crate::bn256::fq6::FROBENIUS_COEFF_FQ6_C1;
+use crate::bn256::fq6::{Fq6, FROBENIUS_COEFF_FQ6_C1};
 use crate::bn256::fr::*;
 use crate::ff::PrimeField;
 use crate::ff_ext::quadratic::QuadSparseMul;
 use crate::ff_ext::ExtField;
 use crate::group::cofactor::CofactorCurveAffine;
 use crate::group::Group;
+use alloc::vec::Vec;
 use core::borrow::Borrow;
 use core::iter::Sum;
+use core::ops::MulAssign;
 use core::ops::{Add, Mul, Neg, Sub};
 use pairing::{Engine, MillerLoopResult, MultiMillerLoop, PairingCurveAffine};
 use rand_core::RngCore;
-use std::ops::MulAssign;
-use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
-
-crate::impl_gt!(Gt, Fq12, Fr);
-crate::impl_miller_loop_components!(Bn256, G1, G1Affine, G2, G2Affine, Fq12, Gt, Fr);
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+crate::impl_gt!(Gt, Fq12, Fq6, Fq2, Fr);
+crate::impl_miller_loop_components!(
+    Bn256,
+    G1,
+    G1Affine,
+    G2,
+    G2Affine,
+    Fq12,
+    Gt,
+    Fr,
+    super::SIX_U_PLUS_2_NAF
+);
 
 impl MillerLoopResult for Fq12 {
     type Gt = Gt;
@@ -46,16 +57,16 @@ impl MillerLoopResult for Fq12 {
                 let mut r = f1;
                 r.mul_assign(&f2);
                 f2 = r;
-                r.frobenius_map(2);
+                r.frobenius_map_const::<2>();
                 r.mul_assign(&f2);
 
                 let mut fp = r;
-                fp.frobenius_map(1);
+                fp.frobenius_map_const::<1>();
 
                 let mut fp2 = r;
-                fp2.frobenius_map(2);
+                fp2.frobenius_map_const::<2>();
                 let mut fp3 = fp2;
-                fp3.frobenius_map(1);
+                fp3.frobenius_map_const::<1>();
 
                 let mut fu = r;
                 exp_by_x(&mut fu);
@@ -67,16 +78,16 @@ impl MillerLoopResult for Fq12 {
                 exp_by_x(&mut fu3);
 
                 let mut y3 = fu;
-                y3.frobenius_map(1);
+                y3.frobenius_map_const::<1>();
 
                 let mut fu2p = fu2;
-                fu2p.frobenius_map(1);
+                fu2p.frobenius_map_const::<1>();
 
                 let mut fu3p = fu3;
-                fu3p.frobenius_map(1);
+                fu3p.frobenius_map_const::<1>();
 
                 let mut y2 = fu2;
-                y2.frobenius_map(2);
+                y2.frobenius_map_const::<2>();
 
                 let mut y0 = fp;
                 y0.mul_assign(&fp2);
@@ -139,7 +150,10 @@ pub fn multi_miller_loop(terms: &[(&G1Affine, &G2Affine)]) -> Fq12 {
         .collect::<Vec<_>>();
 
     let mut f = Fq12::one();
-    let mut r = terms.iter().map(|(_, q)| q.to_curve()).collect::<Vec<_>>();
+    let mut r = terms
+        .iter()
+        .map(|(_, q)| crate::line::JacobianPoint::from(q))
+        .collect::<Vec<_>>();
 
     for (i, x) in super::SIX_U_PLUS_2_NAF.iter().rev().skip(1).enumerate() {
         (i != 0).then(|| f.square_assign());
@@ -162,6 +176,18 @@ pub fn multi_miller_loop(terms: &[(&G1Affine, &G2Affine)]) -> Fq12 {
         }
     }
 
+    for ((p, q), r) in terms.iter().zip(r.iter_mut()) {
+        for q in frobenius_points(q).iter() {
+            add(&mut f, r, q, p);
+        }
+    }
+
+    f
+}
+
+// The points `π(Q)` and `-π^2(Q)` added after the loop, `π` being the
+// untwist-Frobenius-twist endomorphism.
+fn frobenius_points(q: &G2Affine) -> [G2Affine; 2] {
     const XI_TO_Q_MINUS_1_OVER_2: Fq2 = Fq2 {
         c0: Fq([
             0xe4bbdd0c2936b629,
@@ -177,33 +203,27 @@ pub fn multi_miller_loop(terms: &[(&G1Affine, &G2Affine)]) -> Fq12 {
         ]),
     };
 
-    for ((p, q), r) in terms.iter().zip(r.iter_mut()) {
-        let mut q1: G2Affine = *q;
-        q1.x.conjugate();
-        q1.x.mul_assign(&FROBENIUS_COEFF_FQ6_C1[1]);
-        q1.y.conjugate();
-        q1.y.mul_assign(&XI_TO_Q_MINUS_1_OVER_2);
-        add(&mut f, r, &q1, p);
-    }
+    let mut q1: G2Affine = *q;
+    q1.x.conjugate();
+    q1.x.mul_assign(&FROBENIUS_COEFF_FQ6_C1[1]);
+    q1.y.conjugate();
+    q1.y.mul_assign(&XI_TO_Q_MINUS_1_OVER_2);
 
-    for ((p, q), r) in terms.iter().zip(r.iter_mut()) {
-        let mut minusq2: G2Affine = *q;
-        minusq2.x.mul_assign(&FROBENIUS_COEFF_FQ6_C1[2]);
-        add(&mut f, r, &minusq2, p);
-    }
+    let mut minusq2: G2Affine = *q;
+    minusq2.x.mul_assign(&FROBENIUS_COEFF_FQ6_C1[2]);
 
-    f
+    [q1, minusq2]
 }
 
 // Final steps of the line function on prepared coefficients
-fn ell(f: &mut Fq12, coeffs: &(Fq2, Fq2, Fq2), p: &G1Affine) {
-    let mut c0 = coeffs.0;
-    let mut c1 = coeffs.1;
+fn ell(f: &mut Fq12, coeffs: &crate::line::Line<Fq2>, p: &G1Affine) {
+    let mut c0 = coeffs.c0;
+    let mut c1 = coeffs.c1;
     c0.c0.mul_assign(&p.y);
     c0.c1.mul_assign(&p.y);
     c1.c0.mul_assign(&p.x);
     c1.c1.mul_assign(&p.x);
-    Fq12::mul_by_034(f, &c0, &c1, &coeffs.2);
+    Fq12::mul_by_line(f, &c0, &c1, &coeffs.c2);
 }
 
 #[cfg(test)]
@@ -215,4 +235,12 @@ mod test {
     use pairing::{Engine, MillerLoopResult, PairingCurveAffine};
     use rand_core::OsRng;
     crate::test_pairing!(Bn256, G1, G1Affine, G2, G2Affine, Fq12, Gt, Fr);
+    crate::algebraic_law_tests!(Bn256, "pairing"; OsRng);
+    // 64 doublings, 25 additions for the non-zero NAF digits and 2 Frobenius additions.
+    crate::test_pairing_op_count!(
+        G1Affine,
+        G2Affine,
+        miller_loop: { sparse_mul: 91, square: 63 },
+        final_exponentiation: { dense_mul: 99, cyclotomic_square: 196 }
+    );
 }
@@ -0,0 +1,250 @@
+//! The encodings and the precompiles of BN256 (alt_bn128) in Ethereum, from
+//! [EIP-196](https://eips.ethereum.org/EIPS/eip-196) and
+//! [EIP-197](https://eips.ethereum.org/EIPS/eip-197).
+//!
+//! Field elements are 32 byte big-endian integers, which must be smaller than the
+//! modulus. A point of `G1` is `x || y`, and a point of `G2` is
+//! `x.c1 || x.c0 || y.c1 || y.c0`, with the imaginary parts first. The identity is
+//! encoded with zero coordinates.
+//!
+//! The precompiles return `None` where the EVM call fails:
+//!
+//! * [`ecadd`] (`0x06`) and [`ecmul`] (`0x07`) read their input padded with zeros
+//!   or truncated to 128 and 96 bytes, and fail on an invalid point of `G1`;
+//! * [`ecpairing`] (`0x08`) fails if its input is not a sequence of 192 byte pairs
+//!   of a point of `G1` and a point of `G2`, or on an invalid point, including points
+//!   of the twist outside of `G2`.
+
+use crate::bn256::{Bn256, Fq, Fq2, Fr, G1Affine, G2Affine, G1, G2};
+use crate::ff::FromUniformBytes;
+use crate::group::cofactor::CofactorGroup;
+use crate::group::{Curve, Group};
+use crate::CurveAffine;
+use alloc::vec::Vec;
+use pairing::{MillerLoopResult, MultiMillerLoop};
+
+fn fq_from_bytes(bytes: &[u8]) -> Option<Fq> {
+    let mut le = [0u8; 32];
+    le.copy_from_slice(bytes);
+    le.reverse();
+    Fq::from_bytes(&le).into()
+}
+
+fn fq_to_bytes(x: &Fq, bytes: &mut [u8]) {
+    let mut le = x.to_bytes();
+    le.reverse();
+    bytes.copy_from_slice(&le);
+}
+
+// Pads `input` with zeros or truncates it to `N` bytes, as the EVM reads it.
+fn padded<const N: usize>(input: &[u8]) -> [u8; N] {
+    let mut res = [0; N];
+    let len = input.len().min(N);
+    res[..len].copy_from_slice(&input[..len]);
+    res
+}
+
+/// Decodes a point of `G1`, failing if a coordinate is not canonical or if the
+/// point is not on the curve.
+pub fn g1_from_bytes(bytes: &[u8; 64]) -> Option<G1Affine> {
+    let x = fq_from_bytes(&bytes[..32])?;
+    let y = fq_from_bytes(&bytes[32..])?;
+    let p = G1Affine { x, y };
+    bool::from(p.is_on_curve()).then_some(p)
+}
+
+/// Encodes a point of `G1`.
+pub fn g1_to_bytes(p: &G1Affine) -> [u8; 64] {
+    let mut res = [0; 64];
+    fq_to_bytes(&p.x, &mut res[..32]);
+    fq_to_bytes(&p.y, &mut res[32..]);
+    res
+}
+
+/// Decodes a point of `G2`, failing if a coordinate is not canonical or if the
+/// point is not in `G2`.
+pub fn g2_from_bytes(bytes: &[u8; 128]) -> Option<G2Affine> {
+    let x = Fq2::new(fq_from_bytes(&bytes[32..64])?, fq_from_bytes(&bytes[..32])?);
+    let y = Fq2::new(fq_from_bytes(&bytes[96..])?, fq_from_bytes(&bytes[64..96])?);
+    let p = G2Affine { x, y };
+    bool::from(p.is_on_curve() & G2::from(p).is_torsion_free()).then_some(p)
+}
+
+/// Encodes a point of `G2`.
+pub fn g2_to_bytes(p: &G2Affine) -> [u8; 128] {
+    let mut res = [0; 128];
+    fq_to_bytes(&p.x.c1, &mut res[..32]);
+    fq_to_bytes(&p.x.c0, &mut res[32..64]);
+    fq_to_bytes(&p.y.c1, &mut res[64..96]);
+    fq_to_bytes(&p.y.c0, &mut res[96..]);
+    res
+}
+
+/// The `ECADD` precompile: returns the encoding of `p + q` from `p || q`.
+pub fn ecadd(input: &[u8]) -> Option<[u8; 64]> {
+    let input = padded::<128>(input);
+    let p = g1_from_bytes(input[..64].try_into().unwrap())?;
+    let q = g1_from_bytes(input[64..].try_into().unwrap())?;
+    Some(g1_to_bytes(&(G1::from(p) + q).to_affine()))
+}
+
+/// The `ECMUL` precompile: returns the encoding of `[s] p` from `p || s`, where the
+/// scalar `s` is any 32 byte big-endian integer.
+pub fn ecmul(input: &[u8]) -> Option<[u8; 64]> {
+    let input = padded::<96>(input);
+    let p = g1_from_bytes(input[..64].try_into().unwrap())?;
+    // `G1` has prime order, `s` is taken modulo `r`.
+    let mut wide = [0u8; 64];
+    wide[..32].copy_from_slice(&input[64..]);
+    wide[..32].reverse();
+    let s = Fr::from_uniform_bytes(&wide);
+    Some(g1_to_bytes(&(p * s).to_affine()))
+}
+
+/// The `ECPAIRING` precompile: returns 1 as a 32 byte big-endian integer if the
+/// product of the pairings of the pairs of `input` is the identity, and 0
+/// otherwise. The product of no pairings is the identity.
+pub fn ecpairing(input: &[u8]) -> Option<[u8; 32]> {
+    if input.len() % 192 != 0 {
+        return None;
+    }
+    let terms = input
+        .chunks(192)
+        .map(|pair| {
+            let p = g1_from_bytes(pair[..64].try_into().unwrap())?;
+            let q = g2_from_bytes(pair[64..].try_into().unwrap())?;
+            Some((p, q))
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let terms = terms.iter().map(|(p, q)| (p, q)).collect::<Vec<_>>();
+    let res = Bn256::multi_miller_loop(&terms).final_exponentiation();
+
+    let mut out = [0; 32];
+    out[31] = bool::from(res.is_identity()) as u8;
+    Some(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ff::Field;
+    use crate::group::prime::PrimeCurveAffine;
+    use crate::CurveExt;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_encodings() {
+        let g1 = G1Affine::generator();
+        let mut bytes = [0; 64];
+        bytes[31] = 1;
+        bytes[63] = 2;
+        assert_eq!(g1_to_bytes(&g1), bytes);
+        assert_eq!(g1_from_bytes(&bytes).unwrap(), g1);
+
+        // The imaginary parts are first.
+        let g2 = G2Affine::generator();
+        assert_eq!(
+            hex::encode(g2_to_bytes(&g2)),
+            "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+             1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed\
+             090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b\
+             12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"
+        );
+        assert_eq!(g2_from_bytes(&g2_to_bytes(&g2)).unwrap(), g2);
+
+        // The identity.
+        assert_eq!(g1_to_bytes(&G1Affine::identity()), [0; 64]);
+        assert_eq!(g1_from_bytes(&[0; 64]).unwrap(), G1Affine::identity());
+        assert_eq!(g2_to_bytes(&G2Affine::identity()), [0; 128]);
+        assert_eq!(g2_from_bytes(&[0; 128]).unwrap(), G2Affine::identity());
+
+        for _ in 0..10 {
+            let p = G1::random(OsRng).to_affine();
+            let q = G2::random(OsRng).to_affine();
+            assert_eq!(g1_from_bytes(&g1_to_bytes(&p)).unwrap(), p);
+            assert_eq!(g2_from_bytes(&g2_to_bytes(&q)).unwrap(), q);
+        }
+
+        // Points off the curve and non-canonical coordinates.
+        bytes[63] = 3;
+        assert!(g1_from_bytes(&bytes).is_none());
+        let mut bytes = [0; 64];
+        fq_to_bytes(&-Fq::ONE, &mut bytes[32..]);
+        bytes[31] = 1;
+        assert!(g1_from_bytes(&bytes).is_none());
+        let p = hex::decode("30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47")
+            .unwrap();
+        bytes[32..].copy_from_slice(&p);
+        assert!(g1_from_bytes(&bytes).is_none());
+
+        // Points of the twist outside of `G2`.
+        let mut x = Fq2::ZERO;
+        let q = loop {
+            x += Fq2::ONE;
+            if let Some(y) = Option::from((x.square() * x + G2::b()).sqrt()) {
+                break G2Affine { x, y };
+            }
+        };
+        assert!(!bool::from(G2::from(q).is_torsion_free()));
+        assert!(g2_from_bytes(&g2_to_bytes(&q)).is_none());
+    }
+
+    #[test]
+    fn test_precompiles() {
+        let g = g1_to_bytes(&G1Affine::generator());
+        let double = hex::decode(
+            "030644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd3\
+             15ed738c0e0a7c92e7845f96b2ae9c0a68a6a449e3538fc7ff3ebf7a5a18a2c4",
+        )
+        .unwrap();
+
+        // ECADD
+        assert_eq!(ecadd(&[g, g].concat()).unwrap().to_vec(), double);
+        assert_eq!(ecadd(&g).unwrap(), g);
+        assert_eq!(ecadd(&[]).unwrap(), [0; 64]);
+        assert_eq!(ecadd(&[g, g, g].concat()).unwrap().to_vec(), double);
+        let neg = g1_to_bytes(&-G1Affine::generator());
+        assert_eq!(ecadd(&[g, neg].concat()).unwrap(), [0; 64]);
+        let mut invalid = g;
+        invalid[63] = 3;
+        assert!(ecadd(&[g, invalid].concat()).is_none());
+
+        // ECMUL
+        let mut two = [0; 32];
+        two[31] = 2;
+        assert_eq!(ecmul(&[&g[..], &two].concat()).unwrap().to_vec(), double);
+        assert_eq!(ecmul(&g).unwrap(), [0; 64]);
+        let r = hex::decode("30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001")
+            .unwrap();
+        assert_eq!(ecmul(&[&g[..], &r].concat()).unwrap(), [0; 64]);
+        // s = r + 2
+        let mut s = r.clone();
+        s[31] += 2;
+        assert_eq!(ecmul(&[&g[..], &s].concat()).unwrap().to_vec(), double);
+        assert_eq!(ecmul(&[[0xff; 32], [0; 32]].concat()), None);
+
+        // ECPAIRING
+        let one = {
+            let mut one = [0; 32];
+            one[31] = 1;
+            one
+        };
+        let a = Fr::random(OsRng);
+        let b = Fr::random(OsRng);
+        let p = |s: Fr| g1_to_bytes(&(G1Affine::generator() * s).to_affine());
+        let q = |s: Fr| g2_to_bytes(&(G2Affine::generator() * s).to_affine());
+        assert_eq!(ecpairing(&[]).unwrap(), one);
+        // e(a P, b Q) e(-ab P, Q) = 1
+        let input = [&p(a)[..], &q(b), &p(-(a * b)), &q(Fr::ONE)].concat();
+        assert_eq!(ecpairing(&input).unwrap(), one);
+        let input = [&p(a)[..], &q(b), &p(a * b), &q(Fr::ONE)].concat();
+        assert_eq!(ecpairing(&input).unwrap(), [0; 32]);
+        // Pairs with the identity.
+        let input = [&[0; 64][..], &q(b), &p(a), &[0; 128]].concat();
+        assert_eq!(ecpairing(&input).unwrap(), one);
+        assert!(ecpairing(&input[..191]).is_none());
+        let mut invalid = input.clone();
+        invalid[63] = 1;
+        assert!(ecpairing(&invalid).is_none());
+    }
+}
@@ -1,7 +1,7 @@
 use crate::ff_ext::ExtField;
 use core::convert::TryInto;
 use halo2derive::impl_field;
-use rand::RngCore;
+use rand_core::RngCore;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 impl_field!(
@@ -20,16 +20,24 @@ crate::impl_binops_additive!(Fq, Fq);
 crate::impl_binops_multiplicative!(Fq, Fq);
 crate::field_bits!(Fq);
 crate::serialize_deserialize_primefield!(Fq);
+crate::impl_canonical_encoding_field!(Fq, Fq::SIZE);
 crate::impl_from_u64!(Fq);
+crate::impl_random_seeded!(Fq, 64);
 
 use ff::Field;
 const NEGATIVE_ONE: Fq = Fq::ZERO.sub_const(&Fq::ONE);
 impl ExtField for Fq {
+    type BaseField = Fq;
+
     const NON_RESIDUE: Self = NEGATIVE_ONE;
     fn mul_by_nonresidue(&self) -> Self {
         self.neg()
     }
     fn frobenius_map(&mut self, _: usize) {}
+
+    fn norm(&self) -> Fq {
+        *self
+    }
 }
 
 #[cfg(test)]
@@ -42,9 +50,20 @@ mod test {
     crate::field_testing_suite!(Fq, "bits");
     crate::field_testing_suite!(Fq, "serialization_check");
     crate::field_testing_suite!(Fq, "constants");
+    crate::field_testing_suite!(Fq, "montgomery_constants");
+    crate::field_testing_suite!(Fq, "mul_u64");
+    crate::field_testing_suite!(Fq, "halve");
+    crate::field_testing_suite!(Fq, "invert");
+    crate::field_testing_suite!(Fq, "from_raw");
+    crate::field_testing_suite!(Fq, "from_wide", from_u512);
     crate::field_testing_suite!(Fq, "sqrt");
     crate::field_testing_suite!(Fq, "zeta");
     crate::field_testing_suite!(Fq, "from_uniform_bytes", 64, 48);
+    crate::field_testing_suite!(
+        Fq,
+        "random_seeded",
+        "1fc8fc198f4c5ae3ce6a56f4c4b7f0bc95cb9d425360318a7ca494d842b50923"
+    );
     #[test]
     fn test_fq_mul_nonresidue() {
         let e = Fq::random(rand_core::OsRng);
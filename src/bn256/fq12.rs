@@ -2,7 +2,7 @@ use super::fq::Fq;
 use super::fq2::Fq2;
 use super::fq6::Fq6;
 use crate::ff_ext::{
-    quadratic::{QuadExtField, QuadExtFieldArith, QuadSparseMul},
+    quadratic::{QuadExtField, QuadExtFieldArith, QuadSparseMul, TwistType, SQRT},
     ExtField,
 };
 
@@ -14,13 +14,21 @@ pub type Fq12 = QuadExtField<Fq6>;
 
 impl QuadExtFieldArith for Fq12 {
     type Base = Fq6;
+    // w
+    const NON_SQUARE: Fq12 = Fq12::new(Fq6::zero(), Fq6::one());
+    const SQRT: SQRT<Self::Base> = SQRT::Complex;
+    #[cfg(test)]
+    const COUNT_OPS: bool = true;
 }
 
 impl QuadSparseMul for Fq12 {
     type Base = Fq2;
+    const TWIST_TYPE: TwistType = TwistType::D;
 }
 
 impl ExtField for Fq12 {
+    type BaseField = Fq6;
+
     const NON_RESIDUE: Self = Fq12::zero(); // no needs
 
     fn frobenius_map(&mut self, power: usize) {
@@ -30,6 +38,21 @@ impl ExtField for Fq12 {
         self.c1.c1.mul_assign(&FROBENIUS_COEFF_FQ12_C1[power % 12]);
         self.c1.c2.mul_assign(&FROBENIUS_COEFF_FQ12_C1[power % 12]);
     }
+
+    #[inline(always)]
+    fn frobenius_map_const<const POWER: usize>(&mut self) {
+        if POWER % 12 != 0 {
+            self.c0.frobenius_map_const::<POWER>();
+            self.c1.frobenius_map_const::<POWER>();
+            self.c1.c0.mul_assign(&FROBENIUS_COEFF_FQ12_C1[POWER % 12]);
+            self.c1.c1.mul_assign(&FROBENIUS_COEFF_FQ12_C1[POWER % 12]);
+            self.c1.c2.mul_assign(&FROBENIUS_COEFF_FQ12_C1[POWER % 12]);
+        }
+    }
+
+    fn norm(&self) -> Fq6 {
+        QuadExtField::norm(self)
+    }
 }
 
 crate::impl_binops_additive!(Fq12, Fq12);
@@ -197,6 +220,8 @@ pub const FROBENIUS_COEFF_FQ12_C1: [Fq2; 12] = [
 mod test {
     use super::*;
     crate::field_testing_suite!(Fq12, "field_arithmetic");
+    crate::field_testing_suite!(Fq12, "ext_sqrt");
+    crate::field_testing_suite!(Fq12, "legendre");
     // extension field-specific
     crate::field_testing_suite!(Fq12, "quadratic_sparse_mul", Fq6, Fq2);
     crate::field_testing_suite!(
@@ -208,4 +233,6 @@ mod test {
         // p: modulus of base field (Here, Fq::MODULUS)
         Fq::MODULUS_LIMBS
     );
+    crate::field_testing_suite!(Fq12, "mul_by_root", Fq12::new(Fq6::zero(), Fq6::one()));
+    crate::field_testing_suite!(Fq12, "norm", 2, 6);
 }
@@ -1,21 +1,66 @@
 use super::fq::Fq;
 use crate::ff::{Field, FromUniformBytes, PrimeField, WithSmallOrderMulGroup};
 use crate::ff_ext::quadratic::{QuadExtField, QuadExtFieldArith, SQRT};
-use crate::ff_ext::{ExtField, Legendre};
+use crate::ff_ext::ExtField;
 use core::convert::TryInto;
-use std::cmp::Ordering;
 use subtle::{Choice, CtOption};
 
 crate::impl_binops_additive!(Fq2, Fq2);
 crate::impl_binops_multiplicative!(Fq2, Fq2);
 crate::impl_binops_calls!(Fq2);
 crate::impl_sum_prod!(Fq2);
-crate::impl_tower2!(Fq, Fq2);
+// `9 + u` generates the multiplicative group of `Fq2`, of order `q^2 - 1 = 2^4 t`.
+crate::impl_tower2!(
+    Fq,
+    Fq2,
+    multiplicative_generator = Fq2::new(Fq::from_raw([9, 0, 0, 0]), Fq::ONE),
+    s = 4,
+    root_of_unity = Fq2::new(
+        Fq::from_raw([
+            0x47cfbbedda71cf82,
+            0x5398a41a4e1dc5d3,
+            0x0dd3ecd4f3051527,
+            0x0b20dcb5704e326a,
+        ]),
+        Fq::from_raw([
+            0x911151aa341ac43b,
+            0xa7246e407f00771c,
+            0x95fdf38a582edb43,
+            0x2fb24e8308a94437,
+        ]),
+    ),
+    root_of_unity_inv = Fq2::new(
+        Fq::from_raw([
+            0xf450d028fe0b2dc5,
+            0x43e8c6771a5404b9,
+            0xaa7c58e18e7c4336,
+            0x254371bd70e36dbf,
+        ]),
+        Fq::from_raw([
+            0x911151aa341ac43b,
+            0xa7246e407f00771c,
+            0x95fdf38a582edb43,
+            0x2fb24e8308a94437,
+        ]),
+    ),
+    delta = Fq2::new(
+        Fq::from_raw([
+            0x3c1f1b3f69f53e47,
+            0x97816a916871ca8d,
+            0xb85045b68181585d,
+            0x30644e72e131a029,
+        ]),
+        Fq::from_raw([0x00071e2e13d8c000, 0, 0, 0]),
+    )
+);
 crate::impl_tower2_from_uniform_bytes!(Fq, Fq2, 96);
+crate::impl_tower2_from_uniform_bytes!(Fq, Fq2, 128);
 
 pub type Fq2 = QuadExtField<Fq>;
 impl QuadExtFieldArith for Fq2 {
     type Base = Fq;
+    // u + 2
+    const NON_SQUARE: Fq2 = Fq2::new(Fq::from_raw([2, 0, 0, 0]), Fq::ONE);
     const SQRT: SQRT<Fq> = SQRT::Algorithm9 {
         q_minus_3_over_4: &[
             0x4f082305b61f3f51,
@@ -41,7 +86,9 @@ impl QuadExtFieldArith for Fq2 {
 }
 
 impl ExtField for Fq2 {
-    const NON_RESIDUE: Self = Fq2::new(Fq::from_raw([9u64, 0, 0, 0]), Fq::ONE);
+    type BaseField = Fq;
+
+    const NON_RESIDUE: Self = Fq2::new(Fq::from_raw_unchecked([9u64, 0, 0, 0]), Fq::ONE);
 
     fn mul_by_nonresidue(&self) -> Self {
         // (xu+y)(u+9) = (9x+y)u+(9y-x)
@@ -62,6 +109,17 @@ impl ExtField for Fq2 {
             self.conjugate();
         }
     }
+
+    #[inline(always)]
+    fn frobenius_map_const<const POWER: usize>(&mut self) {
+        if POWER % 2 != 0 {
+            self.conjugate();
+        }
+    }
+
+    fn norm(&self) -> Fq {
+        QuadExtField::norm(self)
+    }
 }
 
 #[cfg(test)]
@@ -69,8 +127,10 @@ mod test {
 
     use super::*;
     crate::field_testing_suite!(Fq2, "field_arithmetic");
+    crate::field_testing_suite!(Fq2, "ext_sqrt");
     crate::field_testing_suite!(Fq2, "conversion");
     crate::field_testing_suite!(Fq2, "serialization");
+    crate::field_testing_suite!(Fq2, "constants");
     crate::field_testing_suite!(Fq2, "quadratic_residue");
     crate::field_testing_suite!(Fq2, "sqrt");
     crate::field_testing_suite!(Fq2, "zeta", Fq);
@@ -85,6 +145,23 @@ mod test {
         // p: modulus of base field (Here, Fq::MODULUS)
         Fq::MODULUS_LIMBS
     );
+    crate::field_testing_suite!(Fq2, "mul_by_root", Fq2::new(Fq::ZERO, Fq::ONE));
+    crate::field_testing_suite!(Fq2, "norm", 2, 1);
+
+    #[test]
+    fn test_fq2_from_uniform_bytes() {
+        use rand_core::RngCore;
+
+        let mut bytes = [0u8; 128];
+        for _ in 0..1000 {
+            rand_core::OsRng.fill_bytes(&mut bytes);
+            // Each coefficient is an independent 64-byte wide reduction.
+            let c1: [u8; 64] = bytes[..64].try_into().unwrap();
+            let c0: [u8; 64] = bytes[64..].try_into().unwrap();
+            let (c0, c1) = (Fq::from_uniform_bytes(&c0), Fq::from_uniform_bytes(&c1));
+            assert_eq!(Fq2::from_uniform_bytes(&bytes), Fq2::new(c0, c1));
+        }
+    }
 
     #[test]
     fn test_fq2_mul_nonresidue() {
@@ -93,4 +170,12 @@ mod test {
         let a1 = e * Fq2::NON_RESIDUE;
         assert_eq!(a0, a1);
     }
+
+    #[test]
+    fn test_mul_by_u_plus_k() {
+        let e = Fq2::random(rand_core::OsRng);
+        for k in [0, 1, 2, 3, 9, 57, u64::MAX] {
+            assert_eq!(e.mul_by_u_plus_k(k), e * Fq2::new(Fq::from(k), Fq::ONE));
+        }
+    }
 }
@@ -1,7 +1,7 @@
 use super::fq::Fq;
 use super::fq2::Fq2;
 use crate::ff_ext::{
-    cubic::{CubicExtField, CubicExtFieldArith, CubicSparseMul},
+    cubic::{CubicExtField, CubicExtFieldArith, CubicSparseMul, SQRT},
     ExtField,
 };
 use ff::Field;
@@ -18,6 +18,16 @@ pub type Fq6 = CubicExtField<Fq2>;
 
 impl CubicExtFieldArith for Fq6 {
     type Base = Fq2;
+    // v
+    const NON_SQUARE: Fq6 = Fq6::new(Fq2::ZERO, Fq2::ONE, Fq2::ZERO);
+    const SQRT: SQRT = SQRT::Norm {
+        p_minus_1_over_2: &[
+            0x9e10460b6c3e7ea3,
+            0xcbc0b548b438e546,
+            0xdc2822db40c0ac2e,
+            0x183227397098d014,
+        ],
+    };
 }
 
 impl CubicSparseMul for Fq6 {
@@ -25,6 +35,8 @@ impl CubicSparseMul for Fq6 {
 }
 
 impl ExtField for Fq6 {
+    type BaseField = Fq2;
+
     const NON_RESIDUE: Self = Fq6::new(Fq2::ZERO, Fq2::ONE, Fq2::ZERO);
 
     fn frobenius_map(&mut self, power: usize) {
@@ -35,12 +47,27 @@ impl ExtField for Fq6 {
         self.c2.mul_assign(&FROBENIUS_COEFF_FQ6_C2[power % 6]);
     }
 
+    #[inline(always)]
+    fn frobenius_map_const<const POWER: usize>(&mut self) {
+        if POWER % 6 != 0 {
+            self.c0.frobenius_map_const::<POWER>();
+            self.c1.frobenius_map_const::<POWER>();
+            self.c2.frobenius_map_const::<POWER>();
+            self.c1.mul_assign(&FROBENIUS_COEFF_FQ6_C1[POWER % 6]);
+            self.c2.mul_assign(&FROBENIUS_COEFF_FQ6_C2[POWER % 6]);
+        }
+    }
+
     fn mul_by_nonresidue(self: &Fq6) -> Fq6 {
         let c0 = self.c2.mul_by_nonresidue();
         let c1 = self.c0;
         let c2 = self.c1;
         Self { c0, c1, c2 }
     }
+
+    fn norm(&self) -> Fq2 {
+        CubicExtField::norm(self)
+    }
 }
 
 pub const FROBENIUS_COEFF_FQ6_C1: [Fq2; 6] = [
@@ -205,6 +232,8 @@ pub const FROBENIUS_COEFF_FQ6_C2: [Fq2; 6] = [
 mod test {
     use super::*;
     crate::field_testing_suite!(Fq6, "field_arithmetic");
+    crate::field_testing_suite!(Fq6, "ext_sqrt");
+    crate::field_testing_suite!(Fq6, "legendre");
     // extension field-specific
     crate::field_testing_suite!(Fq6, "cubic_sparse_mul", Fq2);
     crate::field_testing_suite!(
@@ -216,6 +245,8 @@ mod test {
         // p: modulus of base field (Here, Fq::MODULUS)
         Fq::MODULUS_LIMBS
     );
+    crate::field_testing_suite!(Fq6, "mul_by_root", Fq6::new(Fq2::ZERO, Fq2::ONE, Fq2::ZERO));
+    crate::field_testing_suite!(Fq6, "norm", 3, 2);
 
     #[test]
     fn test_fq6_mul_nonresidue() {
@@ -225,4 +256,27 @@ mod test {
         let a1 = e * Fq6::NON_RESIDUE;
         assert_eq!(a0, a1);
     }
+
+    #[test]
+    fn test_fq6_is_zero() {
+        use ff::Field;
+        assert!(bool::from(Fq6::ZERO.is_zero()));
+        assert!(!bool::from(
+            Fq6::new(Fq2::ZERO, Fq2::ZERO, Fq2::ONE).is_zero()
+        ));
+    }
+
+    #[test]
+    fn test_norm() {
+        use ff::Field;
+        for _ in 0..100 {
+            let a = Fq6::random(rand_core::OsRng);
+            // N(a) = a * a^(p^2) * a^(p^4)
+            let mut a_q = a;
+            a_q.frobenius_map_const::<2>();
+            let mut a_q2 = a;
+            a_q2.frobenius_map_const::<4>();
+            assert_eq!(a * a_q * a_q2, Fq6::new(a.norm(), Fq2::ZERO, Fq2::ZERO));
+        }
+    }
 }
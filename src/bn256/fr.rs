@@ -1,6 +1,6 @@
 use core::convert::TryInto;
 use halo2derive::impl_field;
-use rand::RngCore;
+use rand_core::RngCore;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 impl_field!(
@@ -19,11 +19,13 @@ crate::impl_binops_additive!(Fr, Fr);
 crate::impl_binops_multiplicative!(Fr, Fr);
 crate::field_bits!(Fr);
 crate::serialize_deserialize_primefield!(Fr);
+crate::impl_canonical_encoding_field!(Fr, Fr::SIZE);
 
 #[cfg(feature = "bn256-table")]
 pub use table::FR_TABLE;
 #[cfg(not(feature = "bn256-table"))]
 crate::impl_from_u64!(Fr);
+crate::impl_random_seeded!(Fr, 64);
 #[cfg(feature = "bn256-table")]
 impl From<u64> for Fr {
     fn from(val: u64) -> Fr {
@@ -53,7 +55,18 @@ mod test {
     crate::field_testing_suite!(Fr, "bits");
     crate::field_testing_suite!(Fr, "serialization_check");
     crate::field_testing_suite!(Fr, "constants");
+    crate::field_testing_suite!(Fr, "montgomery_constants");
+    crate::field_testing_suite!(Fr, "mul_u64");
+    crate::field_testing_suite!(Fr, "halve");
+    crate::field_testing_suite!(Fr, "invert");
+    crate::field_testing_suite!(Fr, "from_raw");
+    crate::field_testing_suite!(Fr, "from_wide", from_u512);
     crate::field_testing_suite!(Fr, "sqrt");
     crate::field_testing_suite!(Fr, "zeta");
     crate::field_testing_suite!(Fr, "from_uniform_bytes", 64);
+    crate::field_testing_suite!(
+        Fr,
+        "random_seeded",
+        "2491242a4b432c0e3797ae71a65c13193bd107cacabe25fba5f65e5479171892"
+    );
 }
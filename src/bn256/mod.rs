@@ -1,5 +1,6 @@
 mod curve;
 mod engine;
+pub mod eth;
 mod fq;
 mod fq12;
 mod fq2;
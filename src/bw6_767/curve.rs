@@ -0,0 +1,354 @@
+use super::{fq::Fq, fr::Fr};
+use crate::derive::curve::{IDENTITY_MASK, IDENTITY_SHIFT, SIGN_MASK, SIGN_SHIFT};
+use crate::ff::WithSmallOrderMulGroup;
+use crate::ff::{Field, PrimeField};
+use crate::group::{prime::PrimeCurveAffine, Curve, Group as _, GroupEncoding};
+use crate::{Coordinates, CurveAffine, CurveExt};
+use alloc::boxed::Box;
+use core::cmp;
+use core::fmt::Debug;
+use core::iter::Sum;
+use core::ops::{Add, Mul, Neg, Sub};
+use group::cofactor::CofactorGroup;
+use rand_core::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+#[cfg(feature = "derive_serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    impl_binops_additive, impl_binops_additive_specify_output, impl_binops_multiplicative,
+    impl_binops_multiplicative_mixed, new_curve_impl,
+};
+
+const G1_GENERATOR_X: Fq = Fq::from_raw_unchecked([
+    0x75a2b2a99e774012,
+    0x51abfbaecbf011da,
+    0x44f87b2886dbfdb6,
+    0x32d2dfe4485da5e4,
+    0xcd6b4ef2e6613c3c,
+    0x61c53c6ea4fa67af,
+    0x335a0a48ce788b3e,
+    0x6212c31cfd30b17d,
+    0x91e03693e3ed8ffb,
+    0x0a5de79876b516a0,
+    0x6f5a0dee58bc4a18,
+    0x150e067c2afccbac,
+]);
+const G1_GENERATOR_Y: Fq = Fq::from_raw_unchecked([
+    0xd220c004d71f2e74,
+    0x36913c481ac278e9,
+    0x21403fe88f0aa570,
+    0xbc5c3e3fc57f6f37,
+    0x457bec4bd8f0354e,
+    0x32d0e963d40e4287,
+    0xc3e1613d51cd0ab5,
+    0xb612bfdcd6f9e857,
+    0x2ce88663261f5ab0,
+    0x2f2964ed6c5e9d60,
+    0xe1f51b80b95b10da,
+    0x44865bfc43a69006,
+]);
+
+const G1_A: Fq = Fq::ZERO;
+const G1_B: Fq = Fq::ONE;
+
+const G2_GENERATOR_X: Fq = Fq::from_raw_unchecked([
+    0xcfff404bb69daace,
+    0x437bd02b1ef04ffd,
+    0x6b2cc86971ad33c2,
+    0x765b5d05a12cdf08,
+    0x1d02cb318337d1f5,
+    0x8ddbb43c37b7bd13,
+    0x39f6534595a6c8c6,
+    0xd9a65e6d17539fa9,
+    0x8ab179813b2646f8,
+    0xe6c917dd254fb1f9,
+    0x1ae9a7915113f900,
+    0x3d1c80bb58974fcf,
+]);
+const G2_GENERATOR_Y: Fq = Fq::from_raw_unchecked([
+    0x67e9258bec46d5fb,
+    0x2fede463cc95b2c1,
+    0x3fc995525852bd90,
+    0x228710a92ff3f52d,
+    0x0173884dea977ee5,
+    0xbdd421b7b3a63b5c,
+    0x69d56dd25bef2d3d,
+    0x097213159da7f0bd,
+    0x1132991c7d1dd6bc,
+    0x16e32cf8f5a3020e,
+    0x2a67a8a56403ff1b,
+    0x4b0ce0388f6863da,
+]);
+
+const G2_A: Fq = Fq::ZERO;
+
+// b ξ = 3
+const G2_B: Fq = Fq::from_raw_unchecked([3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+/// `(p + 1 - t) / r` as little-endian limbs, where `t` is the trace of [`G1`].
+const G1_COFACTOR: [u64; 7] = [
+    0x9fed0006fffaaabc,
+    0xfae29bffb34d7c0d,
+    0xc51e35fba8145036,
+    0x58c9927410ca3a62,
+    0x7772b64205a0bc67,
+    0x26212b5cf67cecaf,
+    0x0000000000000003,
+];
+
+/// `(p + 1 - t') / r` as little-endian limbs, where `t'` is the trace of [`G2`].
+const G2_COFACTOR: [u64; 7] = [
+    0x9fed0006fffaaab1,
+    0xfae29bffb34d7c0d,
+    0xc51e35fba8145036,
+    0x58c9927410ca3a62,
+    0x7772b64205a0bc67,
+    0x26212b5cf67cecaf,
+    0x0000000000000003,
+];
+
+// Multiplies `p` by the little-endian limbs `e`, in variable time with respect to
+// `e`, which is public.
+fn mul_by_limbs<C: group::Group>(p: &C, e: &[u64]) -> C {
+    let mut acc = C::identity();
+    for limb in e.iter().rev() {
+        for i in (0..64).rev() {
+            acc = acc.double();
+            if ((limb >> i) & 1) == 1 {
+                acc += p;
+            }
+        }
+    }
+    acc
+}
+
+impl CofactorGroup for G1 {
+    type Subgroup = G1;
+
+    fn clear_cofactor(&self) -> Self {
+        mul_by_limbs(self, &G1_COFACTOR)
+    }
+
+    fn into_subgroup(self) -> CtOption<Self::Subgroup> {
+        CtOption::new(self, self.is_torsion_free())
+    }
+
+    fn is_torsion_free(&self) -> Choice {
+        mul_by_limbs(self, &Fr::MODULUS_LIMBS).is_identity()
+    }
+}
+
+impl CofactorGroup for G2 {
+    type Subgroup = G2;
+
+    fn clear_cofactor(&self) -> Self {
+        mul_by_limbs(self, &G2_COFACTOR)
+    }
+
+    fn into_subgroup(self) -> CtOption<Self::Subgroup> {
+        CtOption::new(self, self.is_torsion_free())
+    }
+
+    fn is_torsion_free(&self) -> Choice {
+        mul_by_limbs(self, &Fr::MODULUS_LIMBS).is_identity()
+    }
+}
+
+new_curve_impl!(
+    (pub),
+    G1,
+    G1Affine,
+    Fq,
+    Fr,
+    (G1_GENERATOR_X,G1_GENERATOR_Y),
+    G1_A,
+    G1_B,
+    "bw6_767_g1",
+    |domain_prefix| hash_to_curve_g1(domain_prefix),
+);
+
+new_curve_impl!(
+    (pub),
+    G2,
+    G2Affine,
+    Fq,
+    Fr,
+    (G2_GENERATOR_X,G2_GENERATOR_Y),
+    G2_A,
+    G2_B,
+    "bw6_767_g2",
+    |domain_prefix| hash_to_curve_g2(domain_prefix),
+);
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn hash_to_curve_g1<'a>(domain_prefix: &'a str) -> Box<dyn Fn(&[u8]) -> G1 + 'a> {
+    let suite = G1::default_hash_to_curve_suite();
+    Box::new(move |message| {
+        let r0 = suite.hash_to_curve(domain_prefix, message);
+        r0.clear_cofactor()
+    })
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn hash_to_curve_g2<'a>(domain_prefix: &'a str) -> Box<dyn Fn(&[u8]) -> G2 + 'a> {
+    let suite = G2::default_hash_to_curve_suite();
+    Box::new(move |message| {
+        let r0 = suite.hash_to_curve(domain_prefix, message);
+        r0.clear_cofactor()
+    })
+}
+
+impl G1 {
+    // The smallest `Z` of RFC 9380 for the SvdW method.
+    const SVDW_Z: Fq = Fq::ONE;
+
+    fn default_hash_to_curve_suite(
+    ) -> crate::hash_to_curve::Suite<Self, crate::hash_to_curve::ExpandMsgXmd<sha2::Sha256>, 112>
+    {
+        crate::hash_to_curve::Suite::<G1, crate::hash_to_curve::ExpandMsgXmd<sha2::Sha256>, 112>::new(
+            b"BW6767G1_XMD:SHA-256_SVDW_RO_",
+            Self::SVDW_Z,
+            crate::hash_to_curve::Method::SVDW,
+        )
+    }
+}
+
+impl G2 {
+    // The smallest `Z` of RFC 9380 for the SvdW method.
+    const SVDW_Z: Fq = Fq::ONE;
+
+    fn default_hash_to_curve_suite(
+    ) -> crate::hash_to_curve::Suite<Self, crate::hash_to_curve::ExpandMsgXmd<sha2::Sha256>, 112>
+    {
+        crate::hash_to_curve::Suite::<G2, crate::hash_to_curve::ExpandMsgXmd<sha2::Sha256>, 112>::new(
+            b"BW6767G2_XMD:SHA-256_SVDW_RO_",
+            Self::SVDW_Z,
+            crate::hash_to_curve::Method::SVDW,
+        )
+    }
+}
+
+impl crate::hash_to_curve::BatchHashToCurve for G1 {
+    fn batch_hash_to_curve(domain_prefix: &str, messages: &[&[u8]]) -> alloc::vec::Vec<Self> {
+        let mut points =
+            Self::default_hash_to_curve_suite().batch_hash_to_curve(domain_prefix, messages);
+        clear_cofactors(&mut points);
+        points
+    }
+}
+
+impl crate::hash_to_curve::MapToCurve for G1 {
+    fn hash_to_field(domain_prefix: &str, message: &[u8]) -> [Self::Base; 2] {
+        Self::default_hash_to_curve_suite().hash_to_field(domain_prefix, message)
+    }
+
+    fn map_to_curve(u: &Self::Base) -> Self {
+        Self::default_hash_to_curve_suite().map_to_curve(u)
+    }
+}
+
+impl crate::hash_to_curve::BatchHashToCurve for G2 {
+    fn batch_hash_to_curve(domain_prefix: &str, messages: &[&[u8]]) -> alloc::vec::Vec<Self> {
+        let mut points =
+            Self::default_hash_to_curve_suite().batch_hash_to_curve(domain_prefix, messages);
+        clear_cofactors(&mut points);
+        points
+    }
+}
+
+impl crate::hash_to_curve::MapToCurve for G2 {
+    fn hash_to_field(domain_prefix: &str, message: &[u8]) -> [Self::Base; 2] {
+        Self::default_hash_to_curve_suite().hash_to_field(domain_prefix, message)
+    }
+
+    fn map_to_curve(u: &Self::Base) -> Self {
+        Self::default_hash_to_curve_suite().map_to_curve(u)
+    }
+}
+
+fn clear_cofactors<C: CofactorGroup<Subgroup = C> + Send>(points: &mut [C]) {
+    #[cfg(feature = "std")]
+    {
+        use rayon::prelude::*;
+        points
+            .par_iter_mut()
+            .for_each(|point| *point = point.clear_cofactor());
+    }
+
+    #[cfg(not(feature = "std"))]
+    points
+        .iter_mut()
+        .for_each(|point| *point = point.clear_cofactor());
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use group::UncompressedEncoding;
+
+    crate::curve_testing_suite!(G1, G2, "clear_cofactor");
+    crate::curve_testing_suite!(G1, "torsion_free");
+    crate::curve_testing_suite!(G1, G2, "batch_hash_to_curve");
+    crate::curve_testing_suite!(G1, G2, "map_to_curve");
+    crate::curve_testing_suite!(G1, G2);
+    crate::curve_testing_suite!(G1, "endo_consistency");
+    crate::curve_testing_suite!(
+        G1,
+        "constants",
+        Fq::MODULUS,
+        G1_A,
+        G1_B,
+        G1_GENERATOR_X,
+        G1_GENERATOR_Y,
+        Fr::MODULUS
+    );
+    crate::curve_testing_suite!(
+        G2,
+        "constants",
+        Fq::MODULUS,
+        G2_A,
+        G2_B,
+        G2_GENERATOR_X,
+        G2_GENERATOR_Y,
+        Fr::MODULUS
+    );
+
+    #[test]
+    fn test_bls12_381_base_field() {
+        use num_bigint::BigInt;
+
+        // The base field of BLS12-381, `(x - 1)^2 (x^4 - x^2 + 1) / 3 + x`.
+        let x = -BigInt::from(0xd201000000010000u64);
+        let one = BigInt::from(1);
+        let q = (&x - &one).pow(2) * (x.pow(4) - x.pow(2) + &one) / BigInt::from(3) + &x;
+        assert_eq!(q, BigInt::from(crate::tests::modulus::<Fr>()));
+    }
+
+    // Vectors computed with arkworks, `ark-bw6-767` 0.5.
+    #[test]
+    fn test_generator_multiples() {
+        crate::tests::vectors::check_generator_multiples::<G1Affine>(&crate::test_vectors!(
+            "bw6_767_g1_mul.csv"
+        ));
+        crate::tests::vectors::check_generator_multiples::<G2Affine>(&crate::test_vectors!(
+            "bw6_767_g2_mul.csv"
+        ));
+    }
+
+    #[test]
+    fn test_hash_to_curve() {
+        for i in 0..20u32 {
+            let p = G1::hash_to_curve("test")(&i.to_be_bytes());
+            assert!(bool::from(p.is_on_curve() & p.is_torsion_free()));
+            let q = G2::hash_to_curve("test")(&i.to_be_bytes());
+            assert!(bool::from(q.is_on_curve() & q.is_torsion_free()));
+        }
+    }
+
+    mod g2 {
+        use super::*;
+        crate::curve_testing_suite!(G2, "torsion_free");
+    }
+}
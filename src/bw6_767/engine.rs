@@ -0,0 +1,313 @@
+#![allow(clippy::suspicious_arithmetic_impl)]
+
+use crate::bw6_767::curve::*;
+use crate::bw6_767::fq::Fq;
+use crate::bw6_767::fq3::Fq3;
+use crate::bw6_767::fq6::*;
+use crate::bw6_767::fr::Fr;
+use crate::ff::PrimeField;
+use crate::ff_ext::quadratic::QuadSparseMul;
+use crate::ff_ext::ExtField;
+use crate::group::cofactor::CofactorCurveAffine;
+use crate::group::Group;
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::iter::Sum;
+use core::ops::{Add, Mul, Neg, Sub};
+use ff::Field;
+use pairing::{Engine, MillerLoopResult, MultiMillerLoop, PairingCurveAffine};
+use rand_core::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+/// `|x|`, for the parameter `x = -0xd201000000010000` of BLS12-381.
+const X: u64 = 0xd201000000010000;
+
+/// `|x - 1| / 3`.
+const X_MINUS_1_DIV_3: u64 = 0x460055555555aaab;
+
+/// The NAF digits of `x^2 - x - 1`, least significant first.
+const LOOP_2_NAF: [i8; 129] = [
+    -1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0,
+    -1, 0, -1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, -1, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 1, 0, -1, 0, -1, 0, 1, 0, 0, 1, 0, 0, 0, -1, 0,
+    -1, 0, -1, 0, 1,
+];
+
+/// The lifts `h_t = -4` and `h_y = -6` of the trace and of the square root of the
+/// discriminant of the curve define `d1 = (h_t - h_y) / 2` and
+/// `d2 = (h_t^2 + 3 h_y^2) / 4` of the hard part of the final exponentiation.
+const D1: u64 = 1;
+const D2: u64 = 31;
+
+crate::impl_gt!(Gt, Fq6, Fq3, Fq, Fr, 6);
+crate::impl_miller_loop_components!(Bw6767, G1, G1Affine, G2, G2Affine, Fq6, Gt, Fr);
+
+/// The optimal ate Miller loop, formulas (4.15) and (4.17) of
+/// https://yelhousni.github.io/phd.pdf: `f_{x, Q}(P) l_{[x]Q, Q}(P)` raised to
+/// `p`, times `f_{x^2 - x - 1, [x]Q}(P)`, whose loop reuses `f_{x, Q}(P)`.
+pub fn multi_miller_loop(terms: &[(&G1Affine, &G2Affine)]) -> Fq6 {
+    let terms = terms
+        .iter()
+        .filter_map(|&(p, q)| {
+            if bool::from(p.is_identity()) || bool::from(q.is_identity()) {
+                None
+            } else {
+                Some((*p, *q))
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // f_{|x|, Q}(P), with r = [|x|]Q.
+    let mut f_x = Fq6::one();
+    let mut r = terms
+        .iter()
+        .map(|(_, q)| crate::line::JacobianPoint::from(q))
+        .collect::<Vec<_>>();
+    for i in (0..63).rev() {
+        (i != 62).then(|| f_x.square_assign());
+
+        for ((p, _), r) in terms.iter().zip(r.iter_mut()) {
+            double(&mut f_x, r, p);
+        }
+
+        if (X >> i) & 1 == 1 {
+            for ((p, q), r) in terms.iter().zip(r.iter_mut()) {
+                add(&mut f_x, r, q, p);
+            }
+        }
+    }
+
+    // As x is negative, f_{x, Q} is the inverse of f_{|x|, Q}, which is its
+    // conjugate up to the final exponentiation, and [x]Q = -r.
+    let f_x_inv = f_x;
+    f_x.conjugate();
+    let xq = r
+        .iter()
+        .map(|r| {
+            let (x, y) = r.coordinates().unwrap();
+            G2Affine { x, y: -y }
+        })
+        .collect::<Vec<_>>();
+
+    // f_1 = f_{x, Q}(P) l_{[x]Q, Q}(P)
+    let mut f_1 = f_x;
+    for ((p, q), xq) in terms.iter().zip(xq.iter()) {
+        add(&mut f_1, &mut crate::line::JacobianPoint::from(xq), q, p);
+    }
+
+    // f_2 = f_{x^2 - x - 1, [x]Q}(P) f_{x, Q}(P)^(x^2 - x - 1)
+    let mut f_2 = f_x;
+    let mut r = xq
+        .iter()
+        .map(crate::line::JacobianPoint::from)
+        .collect::<Vec<_>>();
+    for x in LOOP_2_NAF.iter().rev().skip(1) {
+        f_2.square_assign();
+
+        for ((p, _), r) in terms.iter().zip(r.iter_mut()) {
+            double(&mut f_2, r, p);
+        }
+
+        match x {
+            1 => {
+                f_2 *= f_x;
+                for ((p, _), (r, xq)) in terms.iter().zip(r.iter_mut().zip(xq.iter())) {
+                    add(&mut f_2, r, xq, p);
+                }
+            }
+            -1 => {
+                f_2 *= f_x_inv;
+                for ((p, _), (r, xq)) in terms.iter().zip(r.iter_mut().zip(xq.iter())) {
+                    add(&mut f_2, r, &xq.neg(), p);
+                }
+            }
+            _ => continue,
+        }
+    }
+
+    f_1.frobenius_map_const::<1>();
+    f_1 * f_2
+}
+
+// f^e for `f` in the cyclotomic subgroup.
+fn cyclotomic_exp(f: &Fq6, e: u64) -> Fq6 {
+    let mut res = Fq6::one();
+    for i in (0..64 - e.leading_zeros()).rev() {
+        res.cyclotomic_square();
+        if ((e >> i) & 1) == 1 {
+            res *= f;
+        }
+    }
+    res
+}
+
+fn cyclotomic_square(f: &Fq6) -> Fq6 {
+    let mut f = *f;
+    f.cyclotomic_square();
+    f
+}
+
+fn cyclotomic_inverse(f: &Fq6) -> Fq6 {
+    let mut f = *f;
+    f.conjugate();
+    f
+}
+
+fn exp_by_x(f: &Fq6) -> Fq6 {
+    cyclotomic_inverse(&cyclotomic_exp(f, X))
+}
+
+fn exp_by_x_plus_1(f: &Fq6) -> Fq6 {
+    exp_by_x(f) * f
+}
+
+fn exp_by_x_minus_1(f: &Fq6) -> Fq6 {
+    exp_by_x(f) * cyclotomic_inverse(f)
+}
+
+fn exp_by_x_minus_1_div_3(f: &Fq6) -> Fq6 {
+    cyclotomic_inverse(&cyclotomic_exp(f, X_MINUS_1_DIV_3))
+}
+
+impl MillerLoopResult for Fq6 {
+    type Gt = Gt;
+
+    fn final_exponentiation(&self) -> Gt {
+        let mut f1 = *self;
+        f1.conjugate();
+
+        Gt(self
+            .invert()
+            .map(|f2| {
+                // Easy part, f^((p^3 - 1)(p + 1)).
+                let mut f = f1 * f2;
+                let mut fp = f;
+                fp.frobenius_map_const::<1>();
+                f *= fp;
+
+                // Hard part, f^((x + 1)(p^2 - p + 1) / r), with Algorithm 4.3 of
+                // https://yelhousni.github.io/phd.pdf.
+                let mut fp = f;
+                fp.frobenius_map_const::<1>();
+                let a = exp_by_x_minus_1(&exp_by_x_minus_1(&f));
+                let a = cyclotomic_inverse(&(f * a)) * fp;
+                let b = exp_by_x_plus_1(&a) * f;
+                let a = cyclotomic_inverse(&(cyclotomic_square(&a) * a));
+                let c = exp_by_x_minus_1_div_3(&b);
+                let d = exp_by_x_minus_1(&c);
+                let e = exp_by_x_minus_1(&exp_by_x_minus_1(&d)) * d;
+                let f = cyclotomic_inverse(&(exp_by_x_plus_1(&e) * c)) * d;
+                let g = cyclotomic_inverse(&exp_by_x_plus_1(&(f * d))) * c * b;
+                let h = cyclotomic_exp(&f, D1) * e;
+                let h = cyclotomic_square(&h) * h * b * cyclotomic_exp(&g, D2);
+                a * h
+            })
+            .unwrap())
+    }
+}
+
+// Final steps of the line function on prepared coefficients
+fn ell(f: &mut Fq6, coeffs: &crate::line::Line<Fq>, p: &G1Affine) {
+    let c0 = coeffs.c0 * p.y;
+    let c1 = coeffs.c1 * p.x;
+    Fq6::mul_by_line(f, &c0, &c1, &coeffs.c2);
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{Bw6767, Fr, G1, G2};
+    use super::{multi_miller_loop, Fq6, G1Affine, G2Affine, Gt};
+    use ff::Field;
+    use group::{prime::PrimeCurveAffine, Curve, Group};
+    use pairing::{Engine, MillerLoopResult, PairingCurveAffine};
+    use rand_core::OsRng;
+    crate::test_pairing!(Bw6767, G1, G1Affine, G2, G2Affine, Fq6, Gt, Fr, "pairing");
+    // 63 doublings and 5 additions for f_{x, Q}, 1 addition for f_1, and 128
+    // doublings and 23 additions for the non-zero NAF digits of f_2, each of which
+    // also multiplies by f_{x, Q} or its inverse before the final product.
+    crate::test_pairing_op_count!(
+        G1Affine,
+        G2Affine,
+        miller_loop: { sparse_mul: 220, dense_mul: 24, square: 190 },
+        final_exponentiation: { dense_mul: 107, cyclotomic_square: 583 }
+    );
+
+    #[test]
+    fn test_twist_constants() {
+        use crate::bw6_767::{Fq, Fq3};
+        use crate::ff_ext::ExtField;
+        use crate::CurveExt;
+
+        // u^3 = ξ in Fq3, and v^2 = u in Fq6.
+        let xi = Fq::NON_RESIDUE;
+        let u = Fq3::new(Fq::ZERO, Fq::ONE, Fq::ZERO);
+        assert_eq!(u.square() * u, Fq3::new(xi, Fq::ZERO, Fq::ZERO));
+        let v = Fq6::new(Fq3::ZERO, Fq3::ONE);
+        assert_eq!(v.square(), Fq6::new(u, Fq3::ZERO));
+
+        // G2 is the M-type twist y^2 = x^3 + b ξ.
+        assert_eq!(G2::b(), G1::b() * xi);
+    }
+
+    #[test]
+    fn test_final_exponentiation() {
+        use crate::bw6_767::Fq;
+
+        // (x + 1)(p^6 - 1) / r = -(|x| - 1)(p^6 - 1) / r
+        let p = crate::tests::modulus::<Fq>();
+        let r = crate::tests::modulus::<Fr>();
+        let e = (super::X - 1) * (p.pow(6) - 1u64) / r;
+        let f = Fq6::random(OsRng);
+        let mut expected = f.pow_vartime(e.to_u64_digits());
+        expected.conjugate();
+        assert_eq!(f.final_exponentiation().0, expected);
+    }
+
+    // Vectors computed with arkworks, `ark-bw6-767` 0.5.
+    #[test]
+    fn test_pairing_vectors() {
+        use crate::bw6_767::Fq;
+        use ff::PrimeField;
+
+        let vectors = crate::test_vectors!("bw6_767_pairing.json");
+        let fq = |v: &serde_json::Value| crate::tests::vectors::field::<Fq>(v.as_str().unwrap());
+        let gt = |v: &serde_json::Value| hex::decode(v.as_str().unwrap()).unwrap();
+
+        let mut terms = vec![];
+        for vector in vectors.cases("pairing") {
+            let scalar = |v: &serde_json::Value| Fr::from_str_vartime(v.as_str().unwrap()).unwrap();
+            let g1 = (G1::generator() * scalar(&vector["g1_scalar"])).to_affine();
+            let g2 = (G2::generator() * scalar(&vector["g2_scalar"])).to_affine();
+            assert_eq!(g1.x, fq(&vector["g1"]["x"]));
+            assert_eq!(g1.y, fq(&vector["g1"]["y"]));
+            assert_eq!(g2.x, fq(&vector["g2"]["x"]));
+            assert_eq!(g2.y, fq(&vector["g2"]["y"]));
+
+            assert_eq!(
+                Bw6767::pairing(&g1, &g2).to_bytes().as_slice(),
+                gt(&vector["gt"])
+            );
+            terms.push((g1, g2));
+        }
+
+        for vector in vectors.cases("multi_miller_loop") {
+            let terms = vector["terms"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|i| {
+                    let (g1, g2) = &terms[i.as_u64().unwrap() as usize];
+                    (g1, g2)
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                multi_miller_loop(&terms)
+                    .final_exponentiation()
+                    .to_bytes()
+                    .as_slice(),
+                gt(&vector["gt"])
+            );
+        }
+    }
+}
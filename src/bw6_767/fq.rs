@@ -0,0 +1,71 @@
+use crate::ff_ext::ExtField;
+use core::convert::TryInto;
+use halo2derive::impl_field;
+use rand_core::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+impl_field!(
+    bw6_767_fq,
+    Fq,
+    modulus = "51e2bcf25fa8992238259ea59a063294c36dc4098befce4230f8d18f41e3fc19665e4360b872007d3dd5a1b865cbe8dadc2ce0c034926d18fe0ef8c1c63df7d97cbc118805598e5c31732000974254c83a38b08e7179beb96896aaaec71538e7",
+    mul_gen = "3",
+    zeta = "771b41608b23c7794b115723886a2df808bed1f9c66dcdb90e7cc73e27e4b623e18d74f38e36d2caf9cec03fda2570c9e7eab35195b74e60d53e17e33528b590f1f38e4d008b27c130fbfe0a419624bdfdf611c4016f444849383e1d40b90cc",
+    from_uniform = [112, 192],
+    endian = "little",
+);
+
+crate::extend_field_legendre!(Fq);
+crate::impl_binops_calls!(Fq);
+crate::impl_binops_additive!(Fq, Fq);
+crate::impl_binops_multiplicative!(Fq, Fq);
+crate::field_bits!(Fq);
+crate::serialize_deserialize_primefield!(Fq);
+crate::impl_canonical_encoding_field!(Fq, Fq::SIZE);
+crate::impl_from_u64!(Fq);
+crate::impl_random_seeded!(Fq, 112);
+
+impl ExtField for Fq {
+    type BaseField = Fq;
+
+    // 3, neither a square nor a cube.
+    const NON_RESIDUE: Self = Fq::from_raw_unchecked([3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    fn mul_by_nonresidue(&self) -> Self {
+        self.double() + self
+    }
+    fn frobenius_map(&mut self, _: usize) {}
+
+    fn norm(&self) -> Fq {
+        *self
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    crate::field_testing_suite!(Fq, "field_arithmetic");
+    crate::field_testing_suite!(Fq, "conversion");
+    crate::field_testing_suite!(Fq, "serialization");
+    crate::field_testing_suite!(Fq, "quadratic_residue");
+    crate::field_testing_suite!(Fq, "bits");
+    crate::field_testing_suite!(Fq, "serialization_check");
+    crate::field_testing_suite!(Fq, "constants");
+    crate::field_testing_suite!(Fq, "montgomery_constants");
+    crate::field_testing_suite!(Fq, "mul_u64");
+    crate::field_testing_suite!(Fq, "halve");
+    crate::field_testing_suite!(Fq, "invert");
+    crate::field_testing_suite!(Fq, "from_raw");
+    crate::field_testing_suite!(Fq, "from_wide", from_u1536);
+    crate::field_testing_suite!(Fq, "sqrt");
+    crate::field_testing_suite!(Fq, "zeta");
+    crate::field_testing_suite!(Fq, "from_uniform_bytes", 112, 192);
+
+    #[test]
+    fn test_fq_mul_nonresidue() {
+        let e = Fq::random(rand_core::OsRng);
+        let a0 = e.mul_by_nonresidue();
+        let a1 = e * Fq::NON_RESIDUE;
+        assert_eq!(a0, a1);
+        assert_eq!(Fq::NON_RESIDUE, Fq::from(3));
+    }
+}
@@ -0,0 +1,184 @@
+use super::fq::Fq;
+use crate::ff_ext::{
+    cubic::{CubicExtField, CubicExtFieldArith, CubicSparseMul, SQRT},
+    ExtField,
+};
+use ff::Field;
+
+// ξ = 3 is a cubic non-residue in Fq. Fq3 = Fq[X]/(X^3 - ξ)
+// We introduce the variable u such that u^3 = ξ
+crate::impl_binops_additive!(Fq3, Fq3);
+crate::impl_binops_multiplicative!(Fq3, Fq3);
+crate::impl_binops_calls!(Fq3);
+crate::impl_sum_prod!(Fq3);
+pub type Fq3 = CubicExtField<Fq>;
+
+impl CubicExtFieldArith for Fq3 {
+    type Base = Fq;
+    // u, of norm ξ
+    const NON_SQUARE: Fq3 = Fq3::new(Fq::ZERO, Fq::ONE, Fq::ZERO);
+    const SQRT: SQRT = SQRT::PrimeNorm {
+        p_plus_1_over_2: &[
+            0xb44b5557638a9c74,
+            0x1d1c584738bcdf5c,
+            0x18b990004ba12a64,
+            0xbe5e08c402acc72e,
+            0x7f077c60e31efbec,
+            0x6e1670601a49368c,
+            0x9eead0dc32e5f46d,
+            0xb32f21b05c39003e,
+            0x187c68c7a0f1fe0c,
+            0x61b6e204c5f7e721,
+            0x1c12cf52cd03194a,
+            0x28f15e792fd44c91,
+        ],
+    };
+}
+
+impl CubicSparseMul for Fq3 {
+    type Base = Fq;
+}
+
+impl ExtField for Fq3 {
+    type BaseField = Fq;
+
+    const NON_RESIDUE: Self = Fq3::new(Fq::ZERO, Fq::ONE, Fq::ZERO);
+
+    fn frobenius_map(&mut self, power: usize) {
+        self.c1 *= &FROBENIUS_COEFF_FQ3_C1[power % 3];
+        self.c2 *= &FROBENIUS_COEFF_FQ3_C2[power % 3];
+    }
+
+    #[inline(always)]
+    fn frobenius_map_const<const POWER: usize>(&mut self) {
+        if POWER % 3 != 0 {
+            self.c1 *= &FROBENIUS_COEFF_FQ3_C1[POWER % 3];
+            self.c2 *= &FROBENIUS_COEFF_FQ3_C2[POWER % 3];
+        }
+    }
+
+    fn mul_by_nonresidue(self: &Fq3) -> Fq3 {
+        let c0 = self.c2.mul_by_nonresidue();
+        let c1 = self.c0;
+        let c2 = self.c1;
+        Self { c0, c1, c2 }
+    }
+
+    fn norm(&self) -> Fq {
+        CubicExtField::norm(self)
+    }
+}
+
+/// Fq coefficients for the efficient computation of Frobenius Endomorphism in Fq3.
+pub(crate) const FROBENIUS_COEFF_FQ3_C1: [Fq; 3] = [
+    // ξ^((p^0 - 1) / 3)
+    Fq::ONE,
+    // ξ^((p^1 - 1) / 3)
+    Fq::from_raw_unchecked([
+        0xe40326ccf309a81a,
+        0x5a594f723162ca74,
+        0x1e63601ff328f27c,
+        0x6d9cd8a33550dbe0,
+        0xf0bb174392eb6c80,
+        0x3dae358b1b36f832,
+        0x8e38b5b4682991ce,
+        0x28456c117f8e9350,
+        0xa011051b5f65b0b7,
+        0x42e1d6e9ef88f166,
+        0xa3748933617f8fb5,
+        0x4a7108dc56f65caa,
+    ]),
+    // ξ^((p^2 - 1) / 3)
+    Fq::from_raw_unchecked([
+        0x849383e1d40b90cc,
+        0xdfdf611c4016f444,
+        0x130fbfe0a419624b,
+        0x0f1f38e4d008b27c,
+        0x0d53e17e33528b59,
+        0x9e7eab35195b74e6,
+        0xaf9cec03fda2570c,
+        0x3e18d74f38e36d2c,
+        0x90e7cc73e27e4b62,
+        0x808bed1f9c66dcdb,
+        0x94b115723886a2df,
+        0x0771b41608b23c77,
+    ]),
+];
+
+/// Fq coefficients for the efficient computation of Frobenius Endomorphism in Fq3.
+pub(crate) const FROBENIUS_COEFF_FQ3_C2: [Fq; 3] = [
+    // ξ^(2(p^0 - 1) / 3)
+    Fq::ONE,
+    // ξ^(2(p^1 - 1) / 3)
+    Fq::from_raw_unchecked([
+        0x849383e1d40b90cc,
+        0xdfdf611c4016f444,
+        0x130fbfe0a419624b,
+        0x0f1f38e4d008b27c,
+        0x0d53e17e33528b59,
+        0x9e7eab35195b74e6,
+        0xaf9cec03fda2570c,
+        0x3e18d74f38e36d2c,
+        0x90e7cc73e27e4b62,
+        0x808bed1f9c66dcdb,
+        0x94b115723886a2df,
+        0x0771b41608b23c77,
+    ]),
+    // ξ^(2(p^2 - 1) / 3)
+    Fq::from_raw_unchecked([
+        0xe40326ccf309a81a,
+        0x5a594f723162ca74,
+        0x1e63601ff328f27c,
+        0x6d9cd8a33550dbe0,
+        0xf0bb174392eb6c80,
+        0x3dae358b1b36f832,
+        0x8e38b5b4682991ce,
+        0x28456c117f8e9350,
+        0xa011051b5f65b0b7,
+        0x42e1d6e9ef88f166,
+        0xa3748933617f8fb5,
+        0x4a7108dc56f65caa,
+    ]),
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    crate::field_testing_suite!(Fq3, "field_arithmetic");
+    crate::field_testing_suite!(Fq3, "ext_sqrt");
+    crate::field_testing_suite!(Fq3, "legendre");
+    // extension field-specific
+    crate::field_testing_suite!(Fq3, "cubic_sparse_mul", Fq);
+    crate::field_testing_suite!(
+        Fq3,
+        "frobenius",
+        // Frobenius endomorphism power parameter for extension field
+        //  ϕ: E → E
+        //  (x, y) ↦ (x^p, y^p)
+        // p: modulus of base field (Here, Fq::MODULUS)
+        Fq::MODULUS_LIMBS
+    );
+    crate::field_testing_suite!(Fq3, "mul_by_root", Fq3::new(Fq::ZERO, Fq::ONE, Fq::ZERO));
+    crate::field_testing_suite!(Fq3, "norm", 3, 1);
+
+    #[test]
+    fn test_fq3_mul_nonresidue() {
+        let e = Fq3::random(rand_core::OsRng);
+        let a0 = e.mul_by_nonresidue();
+        let a1 = e * Fq3::NON_RESIDUE;
+        assert_eq!(a0, a1);
+    }
+
+    #[test]
+    fn test_norm() {
+        for _ in 0..100 {
+            let a = Fq3::random(rand_core::OsRng);
+            // N(a) = a * a^p * a^(p^2)
+            let mut a_q = a;
+            a_q.frobenius_map_const::<1>();
+            let mut a_q2 = a;
+            a_q2.frobenius_map_const::<2>();
+            assert_eq!(a * a_q * a_q2, Fq3::new(a.norm(), Fq::ZERO, Fq::ZERO));
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use super::fq::Fq;
+use super::fq3::Fq3;
+use crate::ff_ext::{
+    quadratic::{QuadExtField, QuadExtFieldArith, QuadSparseMul, TwistType, SQRT},
+    ExtField,
+};
+use ff::Field;
+
+/// u is a quadratic non-residue in Fq3. Fq6 = Fq3[X]/(X^2 - u)
+/// We introduce the variable v such that v^2 = u, so that v^6 = ξ
+pub type Fq6 = QuadExtField<Fq3>;
+
+impl QuadExtFieldArith for Fq6 {
+    type Base = Fq3;
+    // w + 1
+    const NON_SQUARE: Fq6 = Fq6::new(Fq3::ONE, Fq3::ONE);
+    const SQRT: SQRT<Self::Base> = SQRT::Complex;
+    #[cfg(test)]
+    const COUNT_OPS: bool = true;
+}
+
+impl QuadSparseMul for Fq6 {
+    type Base = Fq;
+    const TWIST_TYPE: TwistType = TwistType::M;
+}
+
+impl ExtField for Fq6 {
+    type BaseField = Fq3;
+
+    const NON_RESIDUE: Self = Fq6::zero(); // no needs
+
+    fn frobenius_map(&mut self, power: usize) {
+        self.c0.frobenius_map(power);
+        self.c1.frobenius_map(power);
+        self.c1.c0 *= &FROBENIUS_COEFF_FQ6_C1[power % 6];
+        self.c1.c1 *= &FROBENIUS_COEFF_FQ6_C1[power % 6];
+        self.c1.c2 *= &FROBENIUS_COEFF_FQ6_C1[power % 6];
+    }
+
+    #[inline(always)]
+    fn frobenius_map_const<const POWER: usize>(&mut self) {
+        if POWER % 6 != 0 {
+            self.c0.frobenius_map_const::<POWER>();
+            self.c1.frobenius_map_const::<POWER>();
+            self.c1.c0 *= &FROBENIUS_COEFF_FQ6_C1[POWER % 6];
+            self.c1.c1 *= &FROBENIUS_COEFF_FQ6_C1[POWER % 6];
+            self.c1.c2 *= &FROBENIUS_COEFF_FQ6_C1[POWER % 6];
+        }
+    }
+
+    fn norm(&self) -> Fq3 {
+        QuadExtField::norm(self)
+    }
+}
+
+crate::impl_binops_additive!(Fq6, Fq6);
+crate::impl_binops_multiplicative!(Fq6, Fq6);
+crate::impl_binops_calls!(Fq6);
+crate::impl_sum_prod!(Fq6);
+crate::impl_cyclotomic_square!(Fq, Fq6);
+
+/// ξ^((p^i-1)/6) for i=0,...,5
+pub const FROBENIUS_COEFF_FQ6_C1: [Fq; 6] = [
+    // ξ^((p^0 - 1) / 6)
+    Fq::ONE,
+    // ξ^((p^1 - 1) / 6)
+    Fq::from_raw_unchecked([
+        0xe40326ccf309a81b,
+        0x5a594f723162ca74,
+        0x1e63601ff328f27c,
+        0x6d9cd8a33550dbe0,
+        0xf0bb174392eb6c80,
+        0x3dae358b1b36f832,
+        0x8e38b5b4682991ce,
+        0x28456c117f8e9350,
+        0xa011051b5f65b0b7,
+        0x42e1d6e9ef88f166,
+        0xa3748933617f8fb5,
+        0x4a7108dc56f65caa,
+    ]),
+    // ξ^((p^2 - 1) / 6)
+    Fq::from_raw_unchecked([
+        0xe40326ccf309a81a,
+        0x5a594f723162ca74,
+        0x1e63601ff328f27c,
+        0x6d9cd8a33550dbe0,
+        0xf0bb174392eb6c80,
+        0x3dae358b1b36f832,
+        0x8e38b5b4682991ce,
+        0x28456c117f8e9350,
+        0xa011051b5f65b0b7,
+        0x42e1d6e9ef88f166,
+        0xa3748933617f8fb5,
+        0x4a7108dc56f65caa,
+    ]),
+    // ξ^((p^3 - 1) / 6)
+    Fq::from_raw_unchecked([
+        0x6896aaaec71538e6,
+        0x3a38b08e7179beb9,
+        0x31732000974254c8,
+        0x7cbc118805598e5c,
+        0xfe0ef8c1c63df7d9,
+        0xdc2ce0c034926d18,
+        0x3dd5a1b865cbe8da,
+        0x665e4360b872007d,
+        0x30f8d18f41e3fc19,
+        0xc36dc4098befce42,
+        0x38259ea59a063294,
+        0x51e2bcf25fa89922,
+    ]),
+    // ξ^((p^4 - 1) / 6)
+    Fq::from_raw_unchecked([
+        0x849383e1d40b90cc,
+        0xdfdf611c4016f444,
+        0x130fbfe0a419624b,
+        0x0f1f38e4d008b27c,
+        0x0d53e17e33528b59,
+        0x9e7eab35195b74e6,
+        0xaf9cec03fda2570c,
+        0x3e18d74f38e36d2c,
+        0x90e7cc73e27e4b62,
+        0x808bed1f9c66dcdb,
+        0x94b115723886a2df,
+        0x0771b41608b23c77,
+    ]),
+    // ξ^((p^5 - 1) / 6)
+    Fq::from_raw_unchecked([
+        0x849383e1d40b90cd,
+        0xdfdf611c4016f444,
+        0x130fbfe0a419624b,
+        0x0f1f38e4d008b27c,
+        0x0d53e17e33528b59,
+        0x9e7eab35195b74e6,
+        0xaf9cec03fda2570c,
+        0x3e18d74f38e36d2c,
+        0x90e7cc73e27e4b62,
+        0x808bed1f9c66dcdb,
+        0x94b115723886a2df,
+        0x0771b41608b23c77,
+    ]),
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    crate::field_testing_suite!(Fq6, "field_arithmetic");
+    crate::field_testing_suite!(Fq6, "ext_sqrt");
+    crate::field_testing_suite!(Fq6, "legendre");
+    // extension field-specific
+    crate::field_testing_suite!(Fq6, "quadratic_sparse_mul", Fq3, Fq);
+    crate::field_testing_suite!(
+        Fq6,
+        "frobenius",
+        // Frobenius endomorphism power parameter for extension field
+        //  ϕ: E → E
+        //  (x, y) ↦ (x^p, y^p)
+        // p: modulus of base field (Here, Fq::MODULUS)
+        Fq::MODULUS_LIMBS
+    );
+    crate::field_testing_suite!(Fq6, "mul_by_root", Fq6::new(Fq3::ZERO, Fq3::ONE));
+    crate::field_testing_suite!(Fq6, "norm", 2, 3);
+}
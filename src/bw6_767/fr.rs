@@ -0,0 +1,47 @@
+use core::convert::TryInto;
+use halo2derive::impl_field;
+use rand_core::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+// The base field of BLS12-381.
+impl_field!(
+    bw6_767_fr,
+    Fr,
+    modulus = "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab",
+    mul_gen = "2",
+    zeta = "5f19672fdf76ce51ba69c6076a0f77eaddb3a93be6f89688de17d813620a00022e01fffffffefffe",
+    from_uniform = [64, 96],
+    endian = "little",
+);
+
+crate::extend_field_legendre!(Fr);
+crate::impl_binops_calls!(Fr);
+crate::impl_binops_additive!(Fr, Fr);
+crate::impl_binops_multiplicative!(Fr, Fr);
+crate::field_bits!(Fr);
+crate::serialize_deserialize_primefield!(Fr);
+crate::impl_canonical_encoding_field!(Fr, Fr::SIZE);
+crate::impl_from_u64!(Fr);
+crate::impl_random_seeded!(Fr, 64);
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    crate::field_testing_suite!(Fr, "field_arithmetic");
+    crate::field_testing_suite!(Fr, "conversion");
+    crate::field_testing_suite!(Fr, "serialization");
+    crate::field_testing_suite!(Fr, "quadratic_residue");
+    crate::field_testing_suite!(Fr, "bits");
+    crate::field_testing_suite!(Fr, "serialization_check");
+    crate::field_testing_suite!(Fr, "constants");
+    crate::field_testing_suite!(Fr, "montgomery_constants");
+    crate::field_testing_suite!(Fr, "mul_u64");
+    crate::field_testing_suite!(Fr, "halve");
+    crate::field_testing_suite!(Fr, "invert");
+    crate::field_testing_suite!(Fr, "from_raw");
+    crate::field_testing_suite!(Fr, "from_wide", from_u768);
+    crate::field_testing_suite!(Fr, "sqrt");
+    crate::field_testing_suite!(Fr, "zeta");
+    crate::field_testing_suite!(Fr, "from_uniform_bytes", 64, 96);
+}
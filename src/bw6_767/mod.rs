@@ -0,0 +1,37 @@
+//! # `BW6-767`
+//!
+//! Implementation of BW6-767, the BW6 curve of El Housni and Guillevic for one
+//! layer proof composition over BLS12-381, with the parameters of arkworks'
+//! `ark-bw6-767`. Its scalar field [`Fr`] is the base field of BLS12-381, so that
+//! the points and the pairings of BLS12-381 are native arithmetic in the circuits
+//! over [`Fr`].
+//!
+//! * [`G1`], `y^2 = x^3 + 1`, is defined over the 767 bit field [`Fq`].
+//! * [`G2`], `y^2 = x^3 + 3`, is its M-type sextic twist, also defined over [`Fq`].
+//! * The [`Bw6767`] engine computes the optimal ate pairing into the degree 6
+//!   extension [`Fq6`], built as `Fq3 = Fq[u]/(u^3 - 3)` and `Fq6 = Fq3[v]/(v^2 - u)`.
+//!
+//! The curve follows the Brezing-Weng method for embedding degree 6: with `ζ` a
+//! primitive cube root of unity modulo `r`, the trace is `t = ζ + 1 - 4r` and
+//! `p = (t^2 + 3y^2) / 4` for `y = (ζ - 1) / sqrt(-3) + 6r`, the smallest prime
+//! among the lifts of `t` and `y` by at most `20r`. BLS12-381 itself is not
+//! implemented by the crate.
+//!
+//! Both groups hash to the curve with the SvdW method of RFC 9380, under the
+//! suites `BW6767G1_XMD:SHA-256_SVDW_RO_` and `BW6767G2_XMD:SHA-256_SVDW_RO_`,
+//! which are not standardized.
+//!
+//! Parameters derivation: `script/bw6_767.py`
+mod curve;
+mod engine;
+mod fq;
+mod fq3;
+mod fq6;
+mod fr;
+
+pub use curve::*;
+pub use engine::*;
+pub use fq::*;
+pub use fq3::*;
+pub use fq6::*;
+pub use fr::*;
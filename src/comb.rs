@@ -0,0 +1,187 @@
+//! Fixed-base scalar multiplication with the comb method of Lim and Lee.
+//!
+//! A scalar of at most `width * depth` bits is laid out as a `width x depth`
+//! matrix, bit `i * depth + j` sitting at row `i` and column `j`. Column `j`
+//! reads as the comb digit `sum_i bit(i * depth + j) * 2^i`, and the table
+//! entry for a digit `x` is `T[x] = sum_i x_i * 2^(i * depth) * P`. The
+//! product `k * P` then takes `depth - 1` doublings and `depth` additions:
+//!
+//! ```text
+//! acc = O
+//! for j in (0..depth).rev():
+//!     acc = 2 * acc + T[digit_j]
+//! ```
+//!
+//! [`ToComb::to_comb`] and [`CombTable::points`] expose the exact digits and
+//! table used by [`CombTable::mul`], so that circuits implementing fixed-base
+//! multiplication can mirror the native computation.
+
+use alloc::vec::Vec;
+
+use crate::CurveAffine;
+use ff::PrimeField;
+use group::{Curve, Group};
+use subtle::ConstantTimeEq;
+
+/// Comb encoding of scalars.
+pub trait ToComb {
+    /// Returns the `depth` comb digits of `self`, digit `j` collecting the bits
+    /// `j, depth + j, ..., (width - 1) * depth + j` from the least significant
+    /// one. Each digit is smaller than `2^width`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width * depth` is smaller than the bit length of the field.
+    fn to_comb(&self, width: usize, depth: usize) -> Vec<usize>;
+}
+
+impl<F: PrimeField> ToComb for F {
+    fn to_comb(&self, width: usize, depth: usize) -> Vec<usize> {
+        assert!(width * depth >= F::NUM_BITS as usize);
+        let repr = self.to_repr();
+        let repr = repr.as_ref();
+        let bit = |k: usize| -> usize {
+            repr.get(k / 8)
+                .map_or(0, |byte| ((byte >> (k % 8)) & 1) as usize)
+        };
+        (0..depth)
+            .map(|j| (0..width).map(|i| bit(i * depth + j) << i).sum())
+            .collect()
+    }
+}
+
+/// Precomputed multiples of a fixed base for the comb method.
+#[derive(Clone, Debug)]
+pub struct CombTable<C: CurveAffine> {
+    width: usize,
+    depth: usize,
+    points: Vec<C>,
+}
+
+impl<C: CurveAffine> CombTable<C> {
+    /// Builds the `2^width` entries of the comb table of `base`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is zero or larger than 16, or if `width * depth` is
+    /// smaller than the bit length of the scalar field.
+    pub fn new(base: &C, width: usize, depth: usize) -> Self {
+        assert!(width > 0 && width <= 16);
+        assert!(width * depth >= C::ScalarExt::NUM_BITS as usize);
+
+        // Row generators 2^(i * depth) * base.
+        let mut rows = Vec::with_capacity(width);
+        let mut row = base.to_curve();
+        for _ in 0..width {
+            rows.push(row);
+            for _ in 0..depth {
+                row = row.double();
+            }
+        }
+
+        let mut points = Vec::with_capacity(1 << width);
+        points.push(C::Curve::identity());
+        for (i, row) in rows.iter().enumerate() {
+            // Entries with top bit `i` extend the ones already computed.
+            for x in 0..(1 << i) {
+                let entry = points[x] + row;
+                points.push(entry);
+            }
+        }
+
+        let mut affine = alloc::vec![C::identity(); points.len()];
+        C::Curve::batch_normalize(&points, &mut affine);
+        Self {
+            width,
+            depth,
+            points: affine,
+        }
+    }
+
+    /// Number of rows of the comb, the bit length of the digits.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Number of columns of the comb, the number of digits.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// The table entries, entry `x` being `sum_i x_i * 2^(i * depth) * base`.
+    pub fn points(&self) -> &[C] {
+        &self.points
+    }
+
+    /// Computes `scalar * base` in constant time.
+    pub fn mul(&self, scalar: &C::ScalarExt) -> C::Curve {
+        let digits = scalar.to_comb(self.width, self.depth);
+        let mut acc = C::Curve::identity();
+        for digit in digits.iter().rev() {
+            acc = acc.double();
+            acc += self.select(*digit as u64);
+        }
+        acc
+    }
+
+    // Constant time lookup of the table entry.
+    fn select(&self, digit: u64) -> C {
+        let mut p = C::identity();
+        for (j, entry) in self.points.iter().enumerate() {
+            p = C::conditional_select(&p, entry, (j as u64).ct_eq(&digit));
+        }
+        p
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CombTable, ToComb};
+    use crate::CurveAffine;
+    use ff::{Field, PrimeField};
+    use group::{Curve, Group};
+    use rand_core::OsRng;
+
+    fn run_comb_test<C: CurveAffine>() {
+        let base = C::Curve::random(OsRng).to_affine();
+        let num_bits = C::ScalarExt::NUM_BITS as usize;
+        for width in [1, 2, 4, 5, 8] {
+            let depth = (num_bits + width - 1) / width;
+            let table = CombTable::new(&base, width, depth);
+            assert_eq!(table.points().len(), 1 << width);
+            assert_eq!(table.points()[0], C::identity());
+
+            for scalar in [
+                C::ScalarExt::ZERO,
+                C::ScalarExt::ONE,
+                -C::ScalarExt::ONE,
+                C::ScalarExt::random(OsRng),
+            ] {
+                let digits = scalar.to_comb(width, depth);
+                assert_eq!(digits.len(), depth);
+
+                // The digits recompose to the scalar.
+                let two = C::ScalarExt::from(2);
+                let mut recomposed = C::ScalarExt::ZERO;
+                for (j, digit) in digits.iter().enumerate() {
+                    assert!(*digit < 1 << width);
+                    for i in 0..width {
+                        if (digit >> i) & 1 == 1 {
+                            recomposed += two.pow([(i * depth + j) as u64]);
+                        }
+                    }
+                }
+                assert_eq!(recomposed, scalar);
+
+                assert_eq!(table.mul(&scalar), base * scalar);
+            }
+        }
+    }
+
+    #[test]
+    fn test_comb() {
+        run_comb_test::<crate::bn256::G1Affine>();
+        run_comb_test::<crate::secp256k1::Secp256k1Affine>();
+        run_comb_test::<crate::pluto_eris::G1Affine>();
+    }
+}
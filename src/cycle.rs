@@ -0,0 +1,215 @@
+//! Cycles of elliptic curves.
+//!
+//! Two curves `C1` and `C2` form a cycle when the scalar field of each one is the
+//! base field of the other:
+//!
+//! ```text
+//! C1::Base = C2::Scalar
+//! C2::Base = C1::Scalar
+//! ```
+//!
+//! so that the group arithmetic of one curve is native field arithmetic in the
+//! proof system over the other, which is what recursive proof composition needs.
+//! The cycles of the crate are [`PlutoEris`](crate::pluto_eris::PlutoEris),
+//! [`Pasta`](crate::pasta::Pasta) and [`Bn256Grumpkin`](crate::grumpkin::Bn256Grumpkin).
+//!
+//! A recursive verifier constantly moves values from one field of the cycle to
+//! the other. The two moduli differ, so an element of the larger field may not
+//! fit in the smaller one. [`from_base_field_element`] returns `None` for these,
+//! and [`from_base_field_element_wrapping`] reduces them modulo the smaller
+//! modulus, which loses the injectivity of the map. Scalars that have to stay
+//! exact, such as the scalars of a scalar multiplication, go across as bits with
+//! [`to_base_field_bits`].
+//!
+//! The conversions read the canonical integers from the little-endian
+//! representations of the fields, as those of the cycles of the crate are.
+
+use alloc::vec::Vec;
+
+use crate::CurveExt;
+use ff::{Field, PrimeField};
+use subtle::{ConstantTimeEq, CtOption};
+
+/// A 2-cycle of curves, see the [module documentation](self).
+pub trait CurveCycle {
+    type C1: CurveExt<Base = <Self::C2 as CurveExt>::ScalarExt>;
+    type C2: CurveExt<Base = <Self::C1 as CurveExt>::ScalarExt>;
+
+    /// Maps an element of the base field of `C1` to the scalar field of `C1` by its
+    /// canonical integer. Returns `None` if it is not smaller than the modulus of the
+    /// scalar field.
+    fn c1_base_to_scalar(
+        x: &<Self::C1 as CurveExt>::Base,
+    ) -> CtOption<<Self::C1 as CurveExt>::ScalarExt> {
+        from_base_field_element::<Self::C1>(x)
+    }
+
+    /// Maps an element of the base field of `C2` to the scalar field of `C2` by its
+    /// canonical integer. Returns `None` if it is not smaller than the modulus of the
+    /// scalar field.
+    fn c2_base_to_scalar(
+        x: &<Self::C2 as CurveExt>::Base,
+    ) -> CtOption<<Self::C2 as CurveExt>::ScalarExt> {
+        from_base_field_element::<Self::C2>(x)
+    }
+
+    /// Maps an element of the base field of `C1` to the scalar field of `C1` by its
+    /// canonical integer, reduced modulo the order of the scalar field.
+    fn c1_base_to_scalar_wrapping(
+        x: &<Self::C1 as CurveExt>::Base,
+    ) -> <Self::C1 as CurveExt>::ScalarExt {
+        from_base_field_element_wrapping::<Self::C1>(x)
+    }
+
+    /// Maps an element of the base field of `C2` to the scalar field of `C2` by its
+    /// canonical integer, reduced modulo the order of the scalar field.
+    fn c2_base_to_scalar_wrapping(
+        x: &<Self::C2 as CurveExt>::Base,
+    ) -> <Self::C2 as CurveExt>::ScalarExt {
+        from_base_field_element_wrapping::<Self::C2>(x)
+    }
+}
+
+/// Maps an element of the base field of `C` to the scalar field of `C` by its
+/// canonical integer. Returns `None` if it is not smaller than the modulus of the
+/// scalar field.
+pub fn from_base_field_element<C: CurveExt>(x: &C::Base) -> CtOption<C::ScalarExt> {
+    cast(x)
+}
+
+/// Maps an element of the base field of `C` to the scalar field of `C` by its
+/// canonical integer, reduced modulo the order of the scalar field. This never
+/// fails, but when the base field is the larger one, the integers `x` and `x + r`
+/// below its modulus map to the same scalar.
+pub fn from_base_field_element_wrapping<C: CurveExt>(x: &C::Base) -> C::ScalarExt {
+    let shift = C::ScalarExt::from_u128(1 << 64);
+    // Horner's rule on the 64-bit limbs, from the most significant one.
+    x.to_repr()
+        .as_ref()
+        .rchunks(8)
+        .fold(C::ScalarExt::ZERO, |acc, limb| {
+            let mut bytes = [0u8; 8];
+            bytes[..limb.len()].copy_from_slice(limb);
+            acc * shift + C::ScalarExt::from(u64::from_le_bytes(bytes))
+        })
+}
+
+/// Returns the `NUM_BITS` bits of the canonical integer of the scalar `k` of `C`,
+/// least significant first, as zeros and ones of the base field of `C`. The bits
+/// of any scalar fit in the base field, whatever the moduli.
+pub fn to_base_field_bits<C: CurveExt>(k: &C::ScalarExt) -> Vec<C::Base> {
+    let repr = k.to_repr();
+    (0..C::ScalarExt::NUM_BITS as usize)
+        .map(|i| {
+            let bit = (repr.as_ref()[i / 8] >> (i % 8)) & 1;
+            C::Base::from(bit as u64)
+        })
+        .collect()
+}
+
+// Moves the canonical integer of `x` from `F` to `G`. Both fields must have
+// little-endian representations.
+fn cast<F: PrimeField, G: PrimeField>(x: &F) -> CtOption<G> {
+    let bytes = x.to_repr();
+    let bytes = bytes.as_ref();
+    let mut repr = G::Repr::default();
+    let len = repr.as_ref().len().min(bytes.len());
+    repr.as_mut()[..len].copy_from_slice(&bytes[..len]);
+
+    let overflow = bytes[len..].iter().fold(0u8, |acc, byte| acc | byte);
+    G::from_repr(repr).and_then(|y| CtOption::new(y, overflow.ct_eq(&0)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        from_base_field_element, from_base_field_element_wrapping, to_base_field_bits, CurveCycle,
+    };
+    use crate::tests::{big_to_fe, fe_to_big, modulus};
+    use crate::CurveExt;
+    use ff::{Field, PrimeField};
+    use rand_core::OsRng;
+
+    fn run_cycle_test<Cycle: CurveCycle>() {
+        type Base1<Cycle> = <<Cycle as CurveCycle>::C1 as CurveExt>::Base;
+        type Base2<Cycle> = <<Cycle as CurveCycle>::C2 as CurveExt>::Base;
+
+        for x in [
+            Base1::<Cycle>::ZERO,
+            Base1::<Cycle>::ONE,
+            Base1::<Cycle>::from(u64::MAX),
+        ] {
+            let y = Cycle::c1_base_to_scalar(&x).unwrap();
+            assert_eq!(Cycle::c2_base_to_scalar(&y).unwrap(), x);
+        }
+
+        for _ in 0..100 {
+            let x = Base1::<Cycle>::random(OsRng);
+            if let Some(y) = Option::<Base2<Cycle>>::from(Cycle::c1_base_to_scalar(&x)) {
+                assert_eq!(x.to_repr().as_ref(), y.to_repr().as_ref());
+                assert_eq!(Cycle::c2_base_to_scalar(&y).unwrap(), x);
+            }
+        }
+
+        // `-1` only fits in the field with the largest modulus.
+        let minus_one_1 = Cycle::c1_base_to_scalar(&-Base1::<Cycle>::ONE);
+        let minus_one_2 = Cycle::c2_base_to_scalar(&-Base2::<Cycle>::ONE);
+        assert!(bool::from(minus_one_1.is_none() ^ minus_one_2.is_none()));
+    }
+
+    fn run_conversion_test<C: CurveExt>() {
+        for x in [
+            C::Base::ZERO,
+            C::Base::ONE,
+            -C::Base::ONE,
+            C::Base::random(OsRng),
+            C::Base::random(OsRng),
+        ] {
+            // Wrapping reduces the integer, and agrees with the checked map when
+            // it fits.
+            let y = from_base_field_element_wrapping::<C>(&x);
+            assert_eq!(y, big_to_fe::<C::ScalarExt>(&fe_to_big(&x)));
+            if let Some(z) = Option::<C::ScalarExt>::from(from_base_field_element::<C>(&x)) {
+                assert_eq!(y, z);
+            }
+        }
+
+        for k in [
+            C::ScalarExt::ZERO,
+            -C::ScalarExt::ONE,
+            C::ScalarExt::random(OsRng),
+        ] {
+            // The bits recompose into the integer of the scalar.
+            let bits = to_base_field_bits::<C>(&k);
+            assert_eq!(bits.len(), C::ScalarExt::NUM_BITS as usize);
+            assert!(bits
+                .iter()
+                .all(|b| *b == C::Base::ZERO || *b == C::Base::ONE));
+            let x = bits
+                .iter()
+                .rev()
+                .fold(C::Base::ZERO, |acc, bit| acc.double() + bit);
+            assert_eq!(x, big_to_fe::<C::Base>(&fe_to_big(&k)));
+            if fe_to_big(&k) < modulus::<C::Base>() {
+                assert_eq!(from_base_field_element_wrapping::<C>(&x), k);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cycles() {
+        run_cycle_test::<crate::pluto_eris::PlutoEris>();
+        run_cycle_test::<crate::pasta::Pasta>();
+        run_cycle_test::<crate::grumpkin::Bn256Grumpkin>();
+    }
+
+    #[test]
+    fn test_conversions() {
+        run_conversion_test::<crate::pluto_eris::G1>();
+        run_conversion_test::<crate::pluto_eris::Eris>();
+        run_conversion_test::<crate::pasta::Ep>();
+        run_conversion_test::<crate::pasta::Eq>();
+        run_conversion_test::<crate::bn256::G1>();
+        run_conversion_test::<crate::grumpkin::G1>();
+    }
+}
@@ -27,11 +27,21 @@ macro_rules! endo {
                     borrow & 1 != 0
                 };
 
+                // Divides by 2^256, rounding to the closest integer.
+                let round = |c: [u64; 8]| {
+                    let mut res = [c[4], c[5], c[6], c[7]];
+                    let mut carry = c[3] >> 63;
+                    for limb in res.iter_mut() {
+                        let (sum, overflow) = limb.overflowing_add(carry);
+                        *limb = sum;
+                        carry = overflow as u64;
+                    }
+                    res
+                };
+
                 let input = to_limbs(&k);
-                let c1 = mul_512($params.gamma2, input);
-                let c2 = mul_512($params.gamma1, input);
-                let c1 = [c1[4], c1[5], c1[6], c1[7]];
-                let c2 = [c2[4], c2[5], c2[6], c2[7]];
+                let c1 = round(mul_512($params.gamma2, input));
+                let c2 = round(mul_512($params.gamma1, input));
                 let q1 = mul_512(c1, $params.b1);
                 let q2 = mul_512(c2, $params.b2);
                 let q1 = $field::from_raw([q1[0], q1[1], q1[2], q1[3]]);
@@ -49,10 +59,10 @@ macro_rules! endo {
     };
 }
 
-// Sign mask for 0, 1 and 2 spare bits.
+// Sign mask for any number of spare bits.
 pub(crate) const SIGN_MASK: u8 = 0b1000_0000;
 pub(crate) const SIGN_SHIFT: u8 = 7;
-// Identity mask for 0 and 2 spare bits (1 spare bit does not use it).
+// Identity mask for 0 and 2 or more spare bits (1 spare bit does not use it).
 pub(crate) const IDENTITY_MASK: u8 = 0b0100_0000;
 pub(crate) const IDENTITY_SHIFT: u8 = 6;
 
@@ -74,9 +84,13 @@ macro_rules! new_curve_impl {
         // **Compressed formats**:
         // The encoding of the x-coordinate can be Little Endian or Big Endian (inherited from the
         // field encoding).
-        // The bit flags appear in the MSB of the encoded x-coordinate in the 1 and 2 Spare bits
+        // The bit flags appear in the MSB of the encoded x-coordinate in the 1 and 2+ Spare bits
         // case, and in an extra byte after the encoded x-coordinate in the 0 Spare bits case.
         // `BS` is the base size: the number of bytes required to encode a coordinate.
+        // Over a quadratic extension, such as the G2 of BN256 or Triton, the x-coordinate is
+        // `c0` followed by `c1` and the flags are in the spare bits of `c1`. The sign is the
+        // `sgn0` of `y` (see `y_sign`). The sizes are the `SIZE` constants of the `Compressed`
+        // and `Uncompressed` encodings.
         //
         // According to the number of spare bits.
         // 1 Spare bit:
@@ -90,7 +104,7 @@ macro_rules! new_curve_impl {
         //     | Non-identity $P$ | $sgn0(P)$ | $P.x$        |
         //
         // ---
-        // 2 Spare bits:
+        // 2 or more Spare bits (the other spare bits are those of the canonical x-coordinate):
         //     |                  | sign      | ident    | x-coordinate |
         //     | Byte pos. (LE)   | BS-1                     ..       0 |
         //     | Byte pos. (BE)   | 0                     ..       BS-1 |
@@ -134,8 +148,14 @@ macro_rules! new_curve_impl {
                     [u8; [< $name _COMPRESSED_SIZE >]]
                 );
 
-                impl std::fmt::Debug for [< $name Compressed >] {
-                    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                impl [< $name Compressed >] {
+                    /// The size of the compressed encoding in bytes: that of the
+                    /// x-coordinate, plus one flag byte if it has no spare bits.
+                    pub const SIZE: usize = [< $name _COMPRESSED_SIZE >];
+                }
+
+                impl core::fmt::Debug for [< $name Compressed >] {
+                    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                         self.0[..].fmt(f)
                     }
                 }
@@ -183,7 +203,7 @@ macro_rules! new_curve_impl {
                 const [< $name _FLAG_BITS >]: u8 =
                 if $spare_bits == 1 {
                     0b1000_0000
-                } else if $spare_bits == 2 {
+                } else if $spare_bits >= 2 {
                     0b1100_0000
                 } else {
                     //$spare_bits == 0
@@ -195,11 +215,49 @@ macro_rules! new_curve_impl {
 
 
                     fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+                        Self::from_bytes_unchecked(bytes).and_then(|p| {
+                            let is_torsion_free =
+                                group::cofactor::CofactorGroup::is_torsion_free(&$name::from(p));
+                            CtOption::new(p, is_torsion_free)
+                        })
+                    }
+
+                    // In compressed form we cannot skip the curve check, only the
+                    // subgroup check.
+                    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+                        $name_affine::from_bytes_with_sqrt(bytes, |y2| y2.sqrt())
+                    }
+
+                    fn to_bytes(&self) -> Self::Repr {
+                        let mut res = [0; [< $name _COMPRESSED_SIZE >]];
+
+                        let x_bytes = $base::conditional_select(&self.x, &$base::zero(), self.is_identity()).to_bytes();
+                        res[..$base::SIZE].copy_from_slice(&x_bytes);
+
+                        // Set identity flag if necessary. With 1 spare bit the identity is
+                        // encoded by x = 0 alone.
+                        if $spare_bits != 1 {
+                            res[ [< $name _FLAG_BYTE_INDEX>]] |= u8::conditional_select(&0u8, &IDENTITY_MASK, self.is_identity());
+                        }
+
+                        // Set sign flag if point is not identity, and has negative sign.
+                        res[ [< $name _FLAG_BYTE_INDEX>]] |= u8::conditional_select(&0u8, &SIGN_MASK, !self.is_identity() & Self::y_sign(&self.y));
+                        [< $name Compressed >](res)
+                    }
+                }
+
+                impl $name_affine {
+                    // Decompresses `bytes` as `GroupEncoding::from_bytes_unchecked`, taking
+                    // the square root of `x^3 + a x + b` with `sqrt`.
+                    pub(crate) fn from_bytes_with_sqrt(
+                        bytes: &[< $name Compressed >],
+                        sqrt: impl Fn(&$base) -> CtOption<$base>,
+                    ) -> CtOption<Self> {
                         let mut tmp = bytes.0;
 
                         let flag_byte = tmp[[< $name _FLAG_BYTE_INDEX>]];
                         // Get identity and sign flags.
-                        let identity_flag = if $spare_bits == 0  || $spare_bits == 2 {
+                        let identity_flag = if $spare_bits == 0  || $spare_bits >= 2 {
                             Choice::from((flag_byte & IDENTITY_MASK) >> IDENTITY_SHIFT )
                         } else {
                             Choice::from(0u8)
@@ -229,7 +287,7 @@ macro_rules! new_curve_impl {
 
                             // Decide if the point is the identity and the validity of the encoding.
                             let (is_valid, is_identity) =
-                            if $spare_bits   == 0  || $spare_bits == 2 {
+                            if $spare_bits   == 0  || $spare_bits >= 2 {
                                 // Correct encoding follows one of the following:
                                 // 1. Identity:
                                 //  identity_flag = 1, sign = 0, x = 0, extra_bits = 0
@@ -250,49 +308,44 @@ macro_rules! new_curve_impl {
                             };
 
 
+                            // A set identity flag with a non-zero x or a sign is not valid.
                             CtOption::new(
                                 Self::identity(),
-                                is_identity)
+                                is_identity & is_valid)
                             .or_else(|| {
                                 // Computes corresponding y coordinate.
-                                $name_affine::y2(x).sqrt().and_then(|y| {
-                                    // Get sign of obtained solution. sign = y % 2.
-                                    let sign = Choice::from(y.to_bytes()[0] & 1);
-                                    // Adjust sign if necessary.
-                                    let y = $base::conditional_select(&y, &-y, sign_flag ^ sign);
-                                    CtOption::new(
-                                        $name_affine {
-                                            x,
-                                            y,
-                                        },
-                                        is_valid,
-                                    )
-                                })
+                                sqrt(&$name_affine::y2(x))
+                                    .map(|y| $base::conditional_select(&y, &-y, Self::y_sign(&y) ^ sign_flag))
+                                    .map(|y| $name_affine { x, y })
+                                    .and_then(|p| CtOption::new(p, is_valid))
                             })
                         })
                     }
+                }
 
-                    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
-                        // In compressed form we cannot skip the curve check.
-                        Self::from_bytes(bytes)
+                impl $crate::serde::ValidatedEncoding<[< $name Compressed >]> for $name_affine {
+                    fn from_bytes_with(
+                        bytes: &[< $name Compressed >],
+                        validation: $crate::serde::Validation,
+                    ) -> CtOption<Self> {
+                        match validation {
+                            $crate::serde::Validation::Subgroup => <Self as GroupEncoding>::from_bytes(bytes),
+                            // Decompressed points are always on the curve.
+                            _ => <Self as GroupEncoding>::from_bytes_unchecked(bytes),
+                        }
                     }
+                }
 
-                    fn to_bytes(&self) -> Self::Repr {
-                        let mut res = [0; [< $name _COMPRESSED_SIZE >]];
-
-                        let x_bytes = $base::conditional_select(&self.x, &$base::zero(), self.is_identity()).to_bytes();
-                        res[..$base::SIZE].copy_from_slice(&x_bytes);
-
-                        // Set identity flag if necessary.
-                        res[ [< $name _FLAG_BYTE_INDEX>]] |= u8::conditional_select(&0u8, &IDENTITY_MASK, self.is_identity());
-
-                        // Set sign flag if point is not identity, and has negative sign.
-                        res[ [< $name _FLAG_BYTE_INDEX>]] |= u8::conditional_select(&0u8, &SIGN_MASK, !self.is_identity() & Choice::from(self.y.to_bytes()[0] & 1));
-                        [< $name Compressed >](res)
+                impl $crate::serde::ValidatedEncoding<[< $name Compressed >]> for $name {
+                    fn from_bytes_with(
+                        bytes: &[< $name Compressed >],
+                        validation: $crate::serde::Validation,
+                    ) -> CtOption<Self> {
+                        <$name_affine as $crate::serde::ValidatedEncoding<_>>::from_bytes_with(bytes, validation)
+                            .map(Self::from)
                     }
                 }
 
-
                 }
             };
         }
@@ -317,7 +370,7 @@ macro_rules! new_curve_impl {
         //     | Non-identity $P$ | 0 | $P.x$        | 0 | $P.y$        |
         //
         // ----
-        // 2 Spare bits:
+        // 2 or more Spare bits:
         //
         //     |                  | 0 | 0 | x-coordinate | 0 | 0 | y-coordinate |
         //     | Byte pos. (LE)   | BS-1      ..       0 | 2*BS-1       ..   BS |
@@ -344,8 +397,15 @@ macro_rules! new_curve_impl {
 
                 #[derive(Copy, Clone)]
                 pub struct [< $name Uncompressed >]([u8; 2*$base::SIZE]);
-                    impl std::fmt::Debug for [< $name Uncompressed >] {
-                        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+
+                    impl [< $name Uncompressed >] {
+                        /// The size of the uncompressed encoding in bytes: that of the
+                        /// two coordinates.
+                        pub const SIZE: usize = 2 * $base::SIZE;
+                    }
+
+                    impl core::fmt::Debug for [< $name Uncompressed >] {
+                        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                             self.0[..].fmt(f)
                         }
                     }
@@ -387,7 +447,11 @@ macro_rules! new_curve_impl {
                         type Uncompressed = [< $name Uncompressed >];
 
                         fn from_uncompressed(bytes: &Self::Uncompressed) -> CtOption<Self> {
-                            Self::from_uncompressed_unchecked(bytes).and_then(|p| CtOption::new(p, p.is_on_curve()))
+                            Self::from_uncompressed_unchecked(bytes).and_then(|p| {
+                                let is_torsion_free =
+                                    group::cofactor::CofactorGroup::is_torsion_free(&$name::from(p));
+                                CtOption::new(p, p.is_on_curve() & is_torsion_free)
+                            })
                         }
 
                         fn from_uncompressed_unchecked(bytes: &Self::Uncompressed) -> CtOption<Self> {
@@ -400,18 +464,18 @@ macro_rules! new_curve_impl {
                             let mut any_flag_set = Choice::from(0u8);
 
                             // Get sign flag to check they are set to 0.
-                            if $spare_bits == 2 || $spare_bits == 1 {
+                            if $spare_bits >= 1 {
                                 any_flag_set |=  Choice::from( (bytes[ flag_idx_x ] & SIGN_MASK) >> SIGN_SHIFT  |
                              (bytes[ flag_idx_y ] & SIGN_MASK) >> SIGN_SHIFT )
                             }
 
                             // Get identity flag to check they are set to 0.
-                            if $spare_bits == 2 {
+                            if $spare_bits >= 2 {
                                 any_flag_set |= Choice::from( (( bytes[ flag_idx_x ] & IDENTITY_MASK) >> IDENTITY_SHIFT) | (( bytes[ flag_idx_y ] & IDENTITY_MASK) >> IDENTITY_SHIFT) );
                             }
 
                             // Clear spare bits.
-                            if $spare_bits == 2 || $spare_bits == 1 {
+                            if $spare_bits >= 1 {
                                 bytes[flag_idx_x] &= ![< $name _FLAG_BITS >];
                                 bytes[flag_idx_y] &= ![< $name _FLAG_BITS >];
                             }
@@ -472,6 +536,21 @@ macro_rules! new_curve_impl {
                             [< $name Uncompressed >](res)
                         }
                     }
+
+                    impl $crate::serde::ValidatedEncoding<[< $name Uncompressed >]> for $name_affine {
+                        fn from_bytes_with(
+                            bytes: &[< $name Uncompressed >],
+                            validation: $crate::serde::Validation,
+                        ) -> CtOption<Self> {
+                            use group::UncompressedEncoding;
+                            match validation {
+                                $crate::serde::Validation::Unchecked => Self::from_uncompressed_unchecked(bytes),
+                                $crate::serde::Validation::OnCurve => Self::from_uncompressed_unchecked(bytes)
+                                    .and_then(|p| CtOption::new(p, p.is_on_curve())),
+                                $crate::serde::Validation::Subgroup => Self::from_uncompressed(bytes),
+                            }
+                        }
+                    }
                 }
             };
         }
@@ -542,6 +621,7 @@ macro_rules! new_curve_impl {
         }
 
         #[derive(Copy, Clone, Debug)]
+        #[repr(C)]
         $($privacy)* struct $name {
             pub x: $base,
             pub y: $base,
@@ -549,11 +629,20 @@ macro_rules! new_curve_impl {
         }
 
         #[derive(Copy, Clone, PartialEq)]
+        #[repr(C)]
         $($privacy)* struct $name_affine {
             pub x: $base,
             pub y: $base,
         }
 
+        // The points are plain limbs, without padding.
+        const _: () = assert!(
+            core::mem::size_of::<$name>() == 3 * core::mem::size_of::<$base>()
+                && core::mem::size_of::<$name_affine>() == 2 * core::mem::size_of::<$base>()
+                && core::mem::size_of::<$base>() % 8 == 0
+                && core::mem::align_of::<$name_affine>() == core::mem::align_of::<u64>()
+        );
+
         #[cfg(feature = "derive_serde")]
         serialize_deserialize_to_from_bytes!();
 
@@ -565,6 +654,25 @@ macro_rules! new_curve_impl {
 
 
         impl $name {
+            /// Order of the prime order subgroup, the modulus of the scalar field,
+            /// as little-endian limbs.
+            pub const ORDER: &'static [u64] = &$scalar::MODULUS_LIMBS;
+
+            /// Views a slice of points as the Montgomery limbs of their
+            /// coordinates, in the order of the fields of the struct, without
+            /// copying them. The coefficients of extension field coordinates are
+            /// laid out from `c0` up.
+            pub fn slice_as_limbs(points: &[Self]) -> &[u64] {
+                // SAFETY: the struct is `#[repr(C)]` over its coordinates, which
+                // are limbs without padding, as asserted with the struct.
+                unsafe {
+                    core::slice::from_raw_parts(
+                        points.as_ptr() as *const u64,
+                        points.len() * core::mem::size_of::<Self>() / 8,
+                    )
+                }
+            }
+
             pub fn generator() -> Self {
                 let generator = $name_affine::generator();
                 Self {
@@ -576,10 +684,7 @@ macro_rules! new_curve_impl {
 
             #[inline]
             fn curve_constant_3b() -> $base {
-                lazy_static::lazy_static! {
-                    static ref CONST_3B: $base = $constant_b + $constant_b + $constant_b;
-                }
-                *CONST_3B
+                $constant_b + $constant_b + $constant_b
             }
 
             fn mul_by_3b(input: &$base) -> $base {
@@ -589,9 +694,129 @@ macro_rules! new_curve_impl {
                     input * $name::curve_constant_3b()
                 }
             }
+
+            /// Computes `scalar * G` where `G` is the generator of the group.
+            ///
+            /// When the `precompute` feature is enabled this uses a table of
+            /// multiples of the generator (built on first use) and only performs
+            /// additions. Otherwise it falls back to the generic scalar multiplication.
+            /// With the feature, `generator() * scalar` takes the same path.
+            pub fn mul_by_generator(scalar: &$scalar) -> Self {
+                #[cfg(feature = "precompute")]
+                {
+                    $name::mul_by_generator_table(scalar)
+                }
+                #[cfg(not(feature = "precompute"))]
+                {
+                    $name::generator() * scalar
+                }
+            }
+
+            /// Computes `scalar * self` with the width-5 NAF of `scalar`, skipping its
+            /// leading zeros. This is for public scalars, e.g. when verifying proofs or
+            /// signatures; the `*` operator runs in constant time, for secret scalars.
+            ///
+            /// **This operation is variable time with respect to the scalar.**
+            pub fn mul_vartime(&self, scalar: &$scalar) -> Self {
+                $crate::multi_mul::multi_mul_vartime([(*self, *scalar)])
+            }
+
+            /// Adds an affine point with the complete formulas of
+            /// [RCB15](https://eprint.iacr.org/2015/1060.pdf), without special-casing
+            /// any input.
+            ///
+            /// `self + rhs` uses the cheaper mixed formulas, which don't hold for the
+            /// identity `rhs` and select `self` in that case. Here `rhs` is lifted to
+            /// projective coordinates instead, `(0, 1, 0)` for the identity, and the
+            /// projective formulas are applied, which are exception-free for all the
+            /// points of odd order. The same field operations run for every input,
+            /// including the identity and `rhs == ±self`.
+            pub fn add_mixed_complete(&self, rhs: &$name_affine) -> Self {
+                let is_identity = rhs.is_identity();
+                let rhs = $name {
+                    x: rhs.x,
+                    y: $base::conditional_select(&rhs.y, &$base::one(), is_identity),
+                    z: $base::conditional_select(&$base::one(), &$base::zero(), is_identity),
+                };
+                self + rhs
+            }
+
+            // Scalar multiplication with signed 4-bit windows. The scalar is recoded
+            // into digits in `[-8, 8)`, so `table` only holds `[P, 2P, ..., 8P]` and
+            // negative digits negate the selected entry.
+            fn mul_signed_window(table: &[$name; 8], scalar: &$scalar) -> $name {
+                let digits = $crate::arithmetic::signed_radix16(scalar.to_repr().as_ref());
+                let mut acc = $name::identity();
+                for digit in digits.iter().rev() {
+                    acc = acc.double().double().double().double();
+                    acc += $crate::arithmetic::select_signed(table, $name::identity(), *digit);
+                }
+                acc
+            }
+
+            /// Returns the table of multiples of the generator used by
+            /// [`Self::mul_by_generator`], built on first use: row `i` holds
+            /// `j * 2^(4i) * G` for `j` in `1..=8`, for signed 4-bit windows.
+            #[cfg(feature = "precompute")]
+            pub fn generator_table() -> &'static [[$name_affine; 8]] {
+                lazy_static::lazy_static! {
+                    static ref GENERATOR_TABLE: alloc::vec::Vec<[$name_affine; 8]> = {
+                        // One more window than nibbles for the carry of the recoding.
+                        let windows = <$scalar as PrimeField>::Repr::default().as_ref().len() * 2 + 1;
+
+                        let mut points = alloc::vec::Vec::with_capacity(windows * 8);
+                        let mut base = $name::generator();
+                        for _ in 0..windows {
+                            let mut acc = base;
+                            for _ in 0..8 {
+                                points.push(acc);
+                                acc += base;
+                            }
+                            // The last entry is 8 * base.
+                            base = points[points.len() - 1].double();
+                        }
+
+                        let mut affine = alloc::vec![$name_affine::identity(); points.len()];
+                        <$name as $crate::group::Curve>::batch_normalize(&points, &mut affine);
+                        affine
+                            .chunks(8)
+                            .map(|row| row.try_into().unwrap())
+                            .collect()
+                    };
+                }
+                &GENERATOR_TABLE
+            }
+
+            #[cfg(feature = "precompute")]
+            fn mul_by_generator_table(scalar: &$scalar) -> Self {
+                let digits = $crate::arithmetic::signed_radix16(scalar.to_repr().as_ref());
+                let mut acc = $name::identity();
+                for (row, digit) in $name::generator_table().iter().zip(digits) {
+                    acc += $crate::arithmetic::select_signed(row, $name_affine::identity(), digit);
+                }
+                acc
+            }
         }
 
         impl $name_affine {
+            /// Order of the prime order subgroup, see [`$name::ORDER`].
+            pub const ORDER: &'static [u64] = $name::ORDER;
+
+            /// Views a slice of points as the Montgomery limbs of their
+            /// coordinates, in the order of the fields of the struct, without
+            /// copying them. The coefficients of extension field coordinates are
+            /// laid out from `c0` up.
+            pub fn slice_as_limbs(points: &[Self]) -> &[u64] {
+                // SAFETY: the struct is `#[repr(C)]` over its coordinates, which
+                // are limbs without padding, as asserted with the struct.
+                unsafe {
+                    core::slice::from_raw_parts(
+                        points.as_ptr() as *const u64,
+                        points.len() * core::mem::size_of::<Self>() / 8,
+                    )
+                }
+            }
+
             pub fn generator() -> Self {
                 Self {
                     x: $generator.0,
@@ -610,7 +835,116 @@ macro_rules! new_curve_impl {
                 }
             }
 
-            pub fn random(mut rng: impl RngCore) -> Self {
+            /// The sign of a `y` coordinate in the compressed encoding, its `sgn0`
+            /// in RFC 9380: the parity of `y` over a prime field and, over a quadratic
+            /// extension, the parity of `y.c0`, or of `y.c1` when `y.c0` is zero.
+            pub fn y_sign(y: &$base) -> Choice {
+                y.is_odd()
+            }
+
+            /// Returns the `y` coordinate of sign `sign`, see [`Self::y_sign`], of
+            /// the points with abscissa `x`, if there are any.
+            pub fn recover_y(x: &$base, sign: Choice) -> CtOption<$base> {
+                Self::y2(*x).sqrt().map(|y| {
+                    $base::conditional_select(&y, &-y, Self::y_sign(&y) ^ sign)
+                })
+            }
+
+            /// Returns the point with abscissa `x` and a `y` coordinate of sign
+            /// `sign`, if there is one. The point is on the curve but may lie
+            /// outside of the prime order subgroup.
+            pub fn from_x(x: $base, sign: Choice) -> CtOption<Self> {
+                Self::recover_y(&x, sign).map(|y| Self { x, y })
+            }
+
+            /// Returns the point with abscissa `x` and an even `y` coordinate, if
+            /// there is one, as the `lift_x` of x-only public keys in BIP-340.
+            pub fn lift_x(x: $base) -> CtOption<Self> {
+                Self::from_x(x, Choice::from(0))
+            }
+
+            /// Samples a random point of the prime order subgroup by sampling `x`
+            /// coordinates until one is on the curve. The discrete logarithm of the
+            /// result with respect to the generator is unknown.
+            pub fn random(rng: impl RngCore) -> Self {
+                let p = $name::random_from_x(rng);
+                // On prime order curves cofactor clearing is a no-op and the point
+                // is still normalized.
+                if bool::from(p.z.ct_eq(&$base::ONE)) {
+                    $name_affine { x: p.x, y: p.y }
+                } else {
+                    p.to_affine()
+                }
+            }
+
+            /// Checks whether each of `points` is on the curve, with the same result as
+            /// [`CurveAffine::is_on_curve`] for every point. The affine check already
+            /// avoids inversions, this only hoists the curve constants out of the loop.
+            pub fn batch_is_on_curve(points: &[Self]) -> alloc::vec::Vec<Choice> {
+                let a = $constant_a;
+                let b = $constant_b;
+                let a_is_zero = a == $base::ZERO;
+                points
+                    .iter()
+                    .map(|p| {
+                        let x2 = p.x.square();
+                        let rhs = if a_is_zero { x2 * p.x } else { (x2 + a) * p.x };
+                        (p.y.square() - rhs).ct_eq(&b) | p.is_identity()
+                    })
+                    .collect()
+            }
+
+            /// Decompresses each of `bytes`, with the same result as
+            /// [`GroupEncoding::from_bytes`] for every encoding. Decompression takes no
+            /// inversion to batch, the encodings are split among the threads instead.
+            ///
+            /// [`GroupEncoding::from_bytes`]: group::GroupEncoding::from_bytes
+            pub fn batch_from_compressed(
+                bytes: &[<Self as group::GroupEncoding>::Repr],
+            ) -> alloc::vec::Vec<CtOption<Self>> {
+                $crate::arithmetic::par_map_chunks(bytes, |bytes| {
+                    bytes
+                        .iter()
+                        .map(<Self as group::GroupEncoding>::from_bytes)
+                        .collect()
+                })
+            }
+
+            /// Samples a random point as a multiple of the generator by a random
+            /// scalar. Cheaper than [`Self::random`] on curves with a cofactor, but
+            /// the discrete logarithm of the result is known to the sampler.
+            pub fn random_from_scalar(rng: impl RngCore) -> Self {
+                $name::mul_by_generator(&$scalar::random(rng)).to_affine()
+            }
+
+            /// Samples `n` independent random points of the prime order subgroup,
+            /// uniformly distributed as those of [`Self::random`].
+            ///
+            /// This is a convenience over calling [`Self::random`] `n` times: the
+            /// square roots are still taken one point at a time, only the
+            /// normalization of the points after clearing the cofactor is batched,
+            /// with a single inversion. On prime order curves it costs as much as
+            /// the loop.
+            pub fn random_batch(mut rng: impl RngCore, n: usize) -> alloc::vec::Vec<Self> {
+                use $crate::group::cofactor::CofactorGroup;
+
+                let mut points = alloc::vec::Vec::with_capacity(n);
+                while points.len() < n {
+                    let x = $base::random(&mut rng);
+                    let sign = Choice::from((rng.next_u32() % 2) as u8);
+                    if let Some(y) = Option::<$base>::from(Self::recover_y(&x, sign)) {
+                        points.push(Self { x, y }.to_curve().clear_cofactor());
+                    }
+                }
+
+                let mut res = alloc::vec![Self::identity(); n];
+                $name::batch_normalize(&points, &mut res);
+                res
+            }
+        }
+
+        impl $name {
+            fn random_from_x(mut rng: impl RngCore) -> Self {
                 loop {
                     let x = $base::random(&mut rng);
                     let ysign = (rng.next_u32() % 2) as u8;
@@ -627,8 +961,7 @@ macro_rules! new_curve_impl {
 
 
                         use $crate::group::cofactor::CofactorGroup;
-                        let p = p.to_curve();
-                        return p.clear_cofactor().to_affine()
+                        return p.to_curve().clear_cofactor();
                     }
                 }
             }
@@ -636,7 +969,7 @@ macro_rules! new_curve_impl {
 
 
 
-        // Jacobian implementations
+        // Homogeneous projective implementations
 
         impl<'a> From<&'a $name_affine> for $name {
             fn from(p: &'a $name_affine) -> $name {
@@ -656,6 +989,9 @@ macro_rules! new_curve_impl {
             }
         }
 
+        #[cfg(feature = "zeroize")]
+        impl $crate::zeroize::DefaultIsZeroes for $name {}
+
         impl subtle::ConstantTimeEq for $name {
             fn ct_eq(&self, other: &Self) -> Choice {
                 // Is (x, y, z) equal to (x', y, z') when converted to affine?
@@ -721,7 +1057,7 @@ macro_rules! new_curve_impl {
 
 
             #[allow(clippy::redundant_closure_call)]
-            fn hash_to_curve<'a>(domain_prefix: &'a str) -> Box<dyn Fn(&[u8]) -> Self + 'a> {
+            fn hash_to_curve<'a>(domain_prefix: &'a str) -> alloc::boxed::Box<dyn Fn(&[u8]) -> Self + 'a> {
                 $hash_to_curve(domain_prefix)
             }
 
@@ -818,8 +1154,8 @@ macro_rules! new_curve_impl {
         impl group::Group for $name {
             type Scalar = $scalar;
 
-            fn random(mut rng: impl RngCore) -> Self {
-                $name_affine::random(&mut rng).to_curve()
+            fn random(rng: impl RngCore) -> Self {
+                $name::random_from_x(rng)
             }
 
             fn double(&self) -> Self {
@@ -931,21 +1267,31 @@ macro_rules! new_curve_impl {
                     bool::from(res.is_on_curve()).then(|| res)
                 })
             }
-            fn to_raw_bytes(&self) -> Vec<u8> {
-                let mut res = Vec::with_capacity(3 * $base::SIZE);
-                Self::write_raw(self, &mut res).unwrap();
+            fn to_raw_bytes(&self) -> alloc::vec::Vec<u8> {
+                let mut res = alloc::vec::Vec::with_capacity(3 * $base::SIZE);
+                for coord in [self.x, self.y, self.z] {
+                    res.extend_from_slice(&coord.to_raw_bytes());
+                }
                 res
             }
+            #[cfg(feature = "std")]
             fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
                 let [x, y, z] = [(); 3].map(|_| $base::read_raw_unchecked(reader));
                 Self { x, y, z }
             }
+            #[cfg(feature = "std")]
             fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
                 let x = $base::read_raw(reader)?;
                 let y = $base::read_raw(reader)?;
                 let z = $base::read_raw(reader)?;
-                Ok(Self { x, y, z })
+                let res = Self { x, y, z };
+                // Check that the point is on the curve, as `from_raw_bytes` does.
+                if !bool::from(res.is_on_curve()) {
+                    return Err($crate::Error::NotOnCurve.into());
+                }
+                Ok(res)
             }
+            #[cfg(feature = "std")]
             fn write_raw<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
                 self.x.write_raw(writer)?;
                 self.y.write_raw(writer)?;
@@ -965,8 +1311,8 @@ macro_rules! new_curve_impl {
 
         // Affine implementations
 
-        impl std::fmt::Debug for $name_affine {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        impl core::fmt::Debug for $name_affine {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
                 if self.is_identity().into() {
                     write!(f, "Infinity")
                 } else {
@@ -975,6 +1321,62 @@ macro_rules! new_curve_impl {
             }
         }
 
+        impl core::fmt::Display for $name_affine {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                if self.is_identity().into() {
+                    write!(f, "Infinity")
+                } else {
+                    write!(f, "({}, {})", self.x, self.y)
+                }
+            }
+        }
+
+        impl core::fmt::LowerHex for $name_affine {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                if self.is_identity().into() {
+                    write!(f, "Infinity")
+                } else {
+                    write!(f, "(")?;
+                    core::fmt::LowerHex::fmt(&self.x, f)?;
+                    write!(f, ", ")?;
+                    core::fmt::LowerHex::fmt(&self.y, f)?;
+                    write!(f, ")")
+                }
+            }
+        }
+
+        // The projective points hash, compare and print as their affine form.
+
+        impl core::hash::Hash for $name {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.to_affine().hash(state)
+            }
+        }
+
+        impl cmp::Ord for $name {
+            fn cmp(&self, other: &Self) -> cmp::Ordering {
+                self.to_affine().cmp(&other.to_affine())
+            }
+        }
+
+        impl cmp::PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(&self.to_affine(), f)
+            }
+        }
+
+        impl core::fmt::LowerHex for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::LowerHex::fmt(&self.to_affine(), f)
+            }
+        }
+
         impl<'a> From<&'a $name> for $name_affine {
             fn from(p: &'a $name) -> $name_affine {
                 p.to_affine()
@@ -993,6 +1395,9 @@ macro_rules! new_curve_impl {
             }
         }
 
+        #[cfg(feature = "zeroize")]
+        impl $crate::zeroize::DefaultIsZeroes for $name_affine {}
+
         impl subtle::ConstantTimeEq for $name_affine {
             fn ct_eq(&self, other: &Self) -> Choice {
                 let z1 = self.is_identity();
@@ -1013,6 +1418,36 @@ macro_rules! new_curve_impl {
 
         impl cmp::Eq for $name_affine {}
 
+        impl $name_affine {
+            /// Returns the compressed encoding of the point, the bytes of
+            /// `GroupEncoding::to_bytes`. `Ord` and `Hash` are defined on these bytes,
+            /// so ordered and hashed collections keyed by points behave the same on
+            /// every platform.
+            pub fn as_canonical_bytes(&self) -> <Self as group::GroupEncoding>::Repr {
+                group::GroupEncoding::to_bytes(self)
+            }
+        }
+
+        impl core::hash::Hash for $name_affine {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.as_canonical_bytes().as_ref().hash(state)
+            }
+        }
+
+        impl cmp::Ord for $name_affine {
+            fn cmp(&self, other: &Self) -> cmp::Ordering {
+                self.as_canonical_bytes()
+                    .as_ref()
+                    .cmp(other.as_canonical_bytes().as_ref())
+            }
+        }
+
+        impl cmp::PartialOrd for $name_affine {
+            fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
 
         impl $crate::serde::SerdeObject for $name_affine {
             fn from_raw_bytes_unchecked(bytes: &[u8]) -> Self {
@@ -1032,26 +1467,98 @@ macro_rules! new_curve_impl {
                     bool::from(res.is_on_curve()).then(|| res)
                 })
             }
-            fn to_raw_bytes(&self) -> Vec<u8> {
-                let mut res = Vec::with_capacity(2 * $base::SIZE);
-                Self::write_raw(self, &mut res).unwrap();
+            fn to_raw_bytes(&self) -> alloc::vec::Vec<u8> {
+                let mut res = alloc::vec::Vec::with_capacity(2 * $base::SIZE);
+                for coord in [self.x, self.y] {
+                    res.extend_from_slice(&coord.to_raw_bytes());
+                }
                 res
             }
+            #[cfg(feature = "std")]
             fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
                 let [x, y] = [(); 2].map(|_| $base::read_raw_unchecked(reader));
                 Self { x, y }
             }
+            #[cfg(feature = "std")]
             fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
                 let x = $base::read_raw(reader)?;
                 let y = $base::read_raw(reader)?;
-                Ok(Self { x, y })
+                let res = Self { x, y };
+                // Check that the point is on the curve, as `from_raw_bytes` does.
+                if !bool::from(res.is_on_curve()) {
+                    return Err($crate::Error::NotOnCurve.into());
+                }
+                Ok(res)
             }
+            #[cfg(feature = "std")]
             fn write_raw<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
                 self.x.write_raw(writer)?;
                 self.y.write_raw(writer)
             }
         }
 
+        paste::paste! {
+            impl $crate::serde::CanonicalEncoding for $name_affine {
+                fn to_payload(&self, compressed: bool) -> alloc::vec::Vec<u8> {
+                    if compressed {
+                        GroupEncoding::to_bytes(self).as_ref().to_vec()
+                    } else {
+                        group::UncompressedEncoding::to_uncompressed(self).as_ref().to_vec()
+                    }
+                }
+
+                fn from_payload(bytes: &[u8], compressed: bool) -> Option<Self> {
+                    if bytes.len() != Self::payload_size(compressed) {
+                        return None;
+                    }
+                    if compressed {
+                        let mut repr = [< $name Compressed >]::default();
+                        repr.as_mut().copy_from_slice(bytes);
+                        Option::from(<Self as GroupEncoding>::from_bytes(&repr))
+                    } else {
+                        let mut repr = [< $name Uncompressed >]::default();
+                        repr.as_mut().copy_from_slice(bytes);
+                        Option::from(<Self as group::UncompressedEncoding>::from_uncompressed(&repr))
+                    }
+                }
+
+                fn coordinate_size(compressed: bool) -> usize {
+                    // The compressed encoding is treated as a single coordinate, flags included.
+                    if compressed {
+                        [< $name _COMPRESSED_SIZE >]
+                    } else {
+                        $base::SIZE
+                    }
+                }
+
+                fn payload_size(compressed: bool) -> usize {
+                    if compressed {
+                        [< $name _COMPRESSED_SIZE >]
+                    } else {
+                        2 * $base::SIZE
+                    }
+                }
+            }
+        }
+
+        impl $crate::serde::CanonicalEncoding for $name {
+            fn to_payload(&self, compressed: bool) -> alloc::vec::Vec<u8> {
+                $name_affine::from(self).to_payload(compressed)
+            }
+
+            fn from_payload(bytes: &[u8], compressed: bool) -> Option<Self> {
+                $name_affine::from_payload(bytes, compressed).map(Self::from)
+            }
+
+            fn coordinate_size(compressed: bool) -> usize {
+                $name_affine::coordinate_size(compressed)
+            }
+
+            fn payload_size(compressed: bool) -> usize {
+                $name_affine::payload_size(compressed)
+            }
+        }
+
         impl group::prime::PrimeCurveAffine for $name_affine {
             type Curve = $name;
             type Scalar = $scalar;
@@ -1388,24 +1895,19 @@ macro_rules! new_curve_impl {
         impl<'a, 'b> Mul<&'b $scalar> for &'a $name {
             type Output = $name;
 
-            // This is a simple double-and-add implementation of point
-            // multiplication, moving from most significant to least
-            // significant bit of the scalar.
-
             fn mul(self, other: &'b $scalar) -> Self::Output {
-                let mut acc = $name::identity();
-                for bit in other
-                    .to_repr()
-                    .as_ref()
-                    .iter()
-                    .rev()
-                    .flat_map(|byte| (0..8).rev().map(move |i| Choice::from((byte >> i) & 1u8)))
-                {
-                    acc = acc.double();
-                    acc = $name::conditional_select(&acc, &(acc + self), bit);
+                // Multiples of the generator come from its table. Only whether the
+                // base is the generator, usually public, decides the branch.
+                #[cfg(feature = "precompute")]
+                if bool::from(self.ct_eq(&$name::generator())) {
+                    return $name::mul_by_generator_table(other);
                 }
 
-                acc
+                let mut table = [*self; 8];
+                for j in 1..8 {
+                    table[j] = table[j - 1] + self;
+                }
+                $name::mul_signed_window(&table, other)
             }
         }
 
@@ -1465,25 +1967,351 @@ macro_rules! new_curve_impl {
             type Output = $name;
 
             fn mul(self, other: &'b $scalar) -> Self::Output {
-                let mut acc = $name::identity();
+                #[cfg(feature = "precompute")]
+                if bool::from(self.ct_eq(&$name_affine::generator())) {
+                    return $name::mul_by_generator_table(other);
+                }
 
-                // This is a simple double-and-add implementation of point
-                // multiplication, moving from most significant to least
-                // significant bit of the scalar.
+                let mut table = [self.to_curve(); 8];
+                for j in 1..8 {
+                    table[j] = table[j - 1] + self;
+                }
+                $name::mul_signed_window(&table, other)
+            }
+        }
 
-                for bit in other
-                    .to_repr()
-                    .as_ref()
-                    .iter()
-                    .rev()
-                    .flat_map(|byte| (0..8).rev().map(move |i| Choice::from((byte >> i) & 1u8)))
-                {
-                    acc = acc.double();
-                    acc = $name::conditional_select(&acc, &(acc + self), bit);
+        // Jacobian coordinates
+        //
+        // `$name` uses homogeneous projective coordinates with the complete
+        // formulas of https://eprint.iacr.org/2015/1060.pdf, which don't branch
+        // on exceptional cases. The Jacobian formulas below are cheaper for
+        // doubling-heavy code but incomplete: additions select the exceptional
+        // results in constant time instead, which costs an extra doubling. They
+        // are provided alongside `$name` with conversions between the two, and
+        // the `curve` bench compares them.
+        paste::paste! {
+            #[doc = "A point of [`" $name "`] in Jacobian coordinates, representing the affine point `(x/z^2, y/z^3)`."]
+            #[derive(Copy, Clone, Debug)]
+            $($privacy)* struct [<$name Jacobian>] {
+                pub x: $base,
+                pub y: $base,
+                pub z: $base,
+            }
+
+            impl $name {
+                /// Converts the point to Jacobian coordinates.
+                pub fn into_jacobian(&self) -> [<$name Jacobian>] {
+                    let (x, y, z) = self.jacobian_coordinates();
+                    [<$name Jacobian>] {
+                        x,
+                        y: $base::conditional_select(&y, &$base::one(), z.is_zero()),
+                        z,
+                    }
+                }
+            }
+
+            impl [<$name Jacobian>] {
+                /// Returns the point at infinity, `(1, 1, 0)`.
+                pub fn identity() -> Self {
+                    Self {
+                        x: $base::one(),
+                        y: $base::one(),
+                        z: $base::zero(),
+                    }
                 }
 
-                acc
+                /// Returns whether the point is the point at infinity.
+                pub fn is_identity(&self) -> Choice {
+                    self.z.is_zero()
+                }
+
+                /// Returns whether the point is on the curve.
+                pub fn is_on_curve(&self) -> Choice {
+                    // Check y^2 = x^3 + a x z^4 + b z^6
+                    let z2 = self.z.square();
+                    let z4 = z2.square();
+                    let z6 = z4 * z2;
+                    (self.y.square() - (self.x.square() + $constant_a * z4) * self.x)
+                        .ct_eq(&(z6 * $constant_b))
+                        | self.z.is_zero()
+                }
+
+                /// Converts the point to homogeneous projective coordinates.
+                pub fn into_homogeneous(&self) -> $name {
+                    let z3 = self.z.square() * self.z;
+                    $name::conditional_select(
+                        &$name {
+                            x: self.x * self.z,
+                            y: self.y,
+                            z: z3,
+                        },
+                        &$name::identity(),
+                        self.is_identity(),
+                    )
+                }
+
+                /// Converts the point to affine coordinates.
+                pub fn to_affine(&self) -> $name_affine {
+                    let zinv = self.z.invert().unwrap_or($base::zero());
+                    let zinv2 = zinv.square();
+                    let tmp = $name_affine {
+                        x: self.x * zinv2,
+                        y: self.y * zinv2 * zinv,
+                    };
+                    $name_affine::conditional_select(&tmp, &$name_affine::identity(), zinv.is_zero())
+                }
+
+                /// Doubles the point.
+                pub fn double(&self) -> Self {
+                    if $constant_a == $base::ZERO {
+                        // http://hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-0.html#doubling-dbl-2009-l
+                        let a = self.x.square();
+                        let b = self.y.square();
+                        let c = b.square();
+                        let d = (self.x + b).square() - a - c;
+                        let d = d + d;
+                        let e = a + a + a;
+                        let f = e.square();
+                        let x3 = f - d - d;
+                        let c8 = c.double().double().double();
+                        let y3 = e * (d - x3) - c8;
+                        let z3 = self.y * self.z;
+                        let z3 = z3 + z3;
+
+                        Self {
+                            x: x3,
+                            y: y3,
+                            z: z3,
+                        }
+                    } else {
+                        // http://hyperelliptic.org/EFD/g1p/auto-shortw-jacobian.html#doubling-dbl-2007-bl
+                        let xx = self.x.square();
+                        let yy = self.y.square();
+                        let yyyy = yy.square();
+                        let zz = self.z.square();
+                        let s = (self.x + yy).square() - xx - yyyy;
+                        let s = s + s;
+                        let m = xx + xx + xx + $constant_a * zz.square();
+                        let x3 = m.square() - s - s;
+                        let yyyy8 = yyyy.double().double().double();
+                        let y3 = m * (s - x3) - yyyy8;
+                        let z3 = (self.y + self.z).square() - yy - zz;
+
+                        Self {
+                            x: x3,
+                            y: y3,
+                            z: z3,
+                        }
+                    }
+                }
+            }
+
+            impl<'a> From<&'a $name> for [<$name Jacobian>] {
+                fn from(p: &'a $name) -> [<$name Jacobian>] {
+                    p.into_jacobian()
+                }
+            }
+
+            impl From<$name> for [<$name Jacobian>] {
+                fn from(p: $name) -> [<$name Jacobian>] {
+                    p.into_jacobian()
+                }
+            }
+
+            impl<'a> From<&'a [<$name Jacobian>]> for $name {
+                fn from(p: &'a [<$name Jacobian>]) -> $name {
+                    p.into_homogeneous()
+                }
+            }
+
+            impl From<[<$name Jacobian>]> for $name {
+                fn from(p: [<$name Jacobian>]) -> $name {
+                    p.into_homogeneous()
+                }
+            }
+
+            impl From<$name_affine> for [<$name Jacobian>] {
+                fn from(p: $name_affine) -> [<$name Jacobian>] {
+                    [<$name Jacobian>]::conditional_select(
+                        &[<$name Jacobian>] {
+                            x: p.x,
+                            y: p.y,
+                            z: $base::one(),
+                        },
+                        &[<$name Jacobian>]::identity(),
+                        p.is_identity(),
+                    )
+                }
+            }
+
+            impl From<[<$name Jacobian>]> for $name_affine {
+                fn from(p: [<$name Jacobian>]) -> $name_affine {
+                    p.to_affine()
+                }
             }
+
+            impl Default for [<$name Jacobian>] {
+                fn default() -> [<$name Jacobian>] {
+                    [<$name Jacobian>]::identity()
+                }
+            }
+
+            #[cfg(feature = "zeroize")]
+            impl $crate::zeroize::DefaultIsZeroes for [<$name Jacobian>] {}
+
+            impl subtle::ConstantTimeEq for [<$name Jacobian>] {
+                fn ct_eq(&self, other: &Self) -> Choice {
+                    // (x/z^2, y/z^3) equal to (x'/z'^2, y'/z'^3)
+                    // => (x z'^2 == x' z^2) & (y z'^3 == y' z^3)
+                    let z1z1 = self.z.square();
+                    let z2z2 = other.z.square();
+
+                    let x1 = self.x * z2z2;
+                    let y1 = self.y * z2z2 * other.z;
+
+                    let x2 = other.x * z1z1;
+                    let y2 = other.y * z1z1 * self.z;
+
+                    let self_is_zero = self.is_identity();
+                    let other_is_zero = other.is_identity();
+
+                    (self_is_zero & other_is_zero)
+                        | ((!self_is_zero) & (!other_is_zero) & x1.ct_eq(&x2) & y1.ct_eq(&y2))
+                }
+            }
+
+            impl subtle::ConditionallySelectable for [<$name Jacobian>] {
+                fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                    [<$name Jacobian>] {
+                        x: $base::conditional_select(&a.x, &b.x, choice),
+                        y: $base::conditional_select(&a.y, &b.y, choice),
+                        z: $base::conditional_select(&a.z, &b.z, choice),
+                    }
+                }
+            }
+
+            impl PartialEq for [<$name Jacobian>] {
+                fn eq(&self, other: &Self) -> bool {
+                    self.ct_eq(other).into()
+                }
+            }
+
+            impl cmp::Eq for [<$name Jacobian>] {}
+
+            impl<'a> Neg for &'a [<$name Jacobian>] {
+                type Output = [<$name Jacobian>];
+
+                fn neg(self) -> [<$name Jacobian>] {
+                    [<$name Jacobian>] {
+                        x: self.x,
+                        y: -self.y,
+                        z: self.z,
+                    }
+                }
+            }
+
+            impl Neg for [<$name Jacobian>] {
+                type Output = [<$name Jacobian>];
+
+                fn neg(self) -> [<$name Jacobian>] {
+                    -&self
+                }
+            }
+
+            impl<'a, 'b> Add<&'a [<$name Jacobian>]> for &'b [<$name Jacobian>] {
+                type Output = [<$name Jacobian>];
+
+                fn add(self, rhs: &'a [<$name Jacobian>]) -> [<$name Jacobian>] {
+                    // http://hyperelliptic.org/EFD/g1p/auto-shortw-jacobian.html#addition-add-2007-bl
+                    let z1z1 = self.z.square();
+                    let z2z2 = rhs.z.square();
+                    let u1 = self.x * z2z2;
+                    let u2 = rhs.x * z1z1;
+                    let s1 = self.y * rhs.z * z2z2;
+                    let s2 = rhs.y * self.z * z1z1;
+                    let h = u2 - u1;
+                    let i = (h + h).square();
+                    let j = h * i;
+                    let r = s2 - s1;
+                    let r = r + r;
+                    let v = u1 * i;
+                    let x3 = r.square() - j - v - v;
+                    let s1j = s1 * j;
+                    let y3 = r * (v - x3) - s1j - s1j;
+                    let z3 = ((self.z + rhs.z).square() - z1z1 - z2z2) * h;
+
+                    // The formulas don't hold for the identity or when adding a
+                    // point to itself. When adding a point to its inverse `h` is
+                    // zero and so is `z3`.
+                    let tmp = [<$name Jacobian>] {
+                        x: x3,
+                        y: y3,
+                        z: z3,
+                    };
+                    let tmp = [<$name Jacobian>]::conditional_select(
+                        &tmp,
+                        &self.double(),
+                        h.is_zero() & r.is_zero(),
+                    );
+                    let tmp = [<$name Jacobian>]::conditional_select(&tmp, self, rhs.is_identity());
+                    [<$name Jacobian>]::conditional_select(&tmp, rhs, self.is_identity())
+                }
+            }
+
+            impl<'a, 'b> Add<&'a $name_affine> for &'b [<$name Jacobian>] {
+                type Output = [<$name Jacobian>];
+
+                // Mixed addition
+                fn add(self, rhs: &'a $name_affine) -> [<$name Jacobian>] {
+                    // http://hyperelliptic.org/EFD/g1p/auto-shortw-jacobian.html#addition-madd-2007-bl
+                    let z1z1 = self.z.square();
+                    let u2 = rhs.x * z1z1;
+                    let s2 = rhs.y * self.z * z1z1;
+                    let h = u2 - self.x;
+                    let hh = h.square();
+                    let i = hh.double().double();
+                    let j = h * i;
+                    let r = s2 - self.y;
+                    let r = r + r;
+                    let v = self.x * i;
+                    let x3 = r.square() - j - v - v;
+                    let y1j = self.y * j;
+                    let y3 = r * (v - x3) - y1j - y1j;
+                    let z3 = (self.z + h).square() - z1z1 - hh;
+
+                    let tmp = [<$name Jacobian>] {
+                        x: x3,
+                        y: y3,
+                        z: z3,
+                    };
+                    let tmp = [<$name Jacobian>]::conditional_select(
+                        &tmp,
+                        &self.double(),
+                        h.is_zero() & r.is_zero(),
+                    );
+                    let tmp = [<$name Jacobian>]::conditional_select(&tmp, self, rhs.is_identity());
+                    [<$name Jacobian>]::conditional_select(&tmp, &(*rhs).into(), self.is_identity())
+                }
+            }
+
+            impl<'a, 'b> Sub<&'a [<$name Jacobian>]> for &'b [<$name Jacobian>] {
+                type Output = [<$name Jacobian>];
+
+                fn sub(self, other: &'a [<$name Jacobian>]) -> [<$name Jacobian>] {
+                    self + (-other)
+                }
+            }
+
+            impl<'a, 'b> Sub<&'a $name_affine> for &'b [<$name Jacobian>] {
+                type Output = [<$name Jacobian>];
+
+                fn sub(self, other: &'a $name_affine) -> [<$name Jacobian>] {
+                    self + (-other)
+                }
+            }
+
+            $crate::impl_binops_additive!([<$name Jacobian>], [<$name Jacobian>]);
+            $crate::impl_binops_additive!([<$name Jacobian>], $name_affine);
         }
     };
 }
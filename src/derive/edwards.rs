@@ -0,0 +1,683 @@
+/// Implements a twisted Edwards curve `a x^2 + y^2 = 1 + d x^2 y^2` of order
+/// `2^k l`, with points in extended coordinates and its prime order subgroup as
+/// scalar field. The formulas are complete when `a` is a square and `d` is not.
+///
+/// The Montgomery constants are `A = 2 (a + d) / (a - d)` and `B = 4 / (a - d)`
+/// of the birationally equivalent curve `B v^2 = u^3 + A u^2 + u`.
+#[macro_export]
+macro_rules! new_edwards_curve_impl {
+    (($($privacy:tt)*),
+    $name:ident,
+    $name_affine:ident,
+    $base:ident,
+    $scalar:ident,
+    ($gen_x:expr, $gen_y:expr),
+    $edwards_a:expr,
+    $edwards_d:expr,
+    ($montgomery_a:expr, $montgomery_b:expr),
+    $cofactor_log2:expr,
+    ) => {
+        /// A point of the curve in affine coordinates.
+        #[derive(Copy, Clone, Debug)]
+        #[repr(C)]
+        $($privacy)* struct $name_affine {
+            pub x: $base,
+            pub y: $base,
+        }
+
+        /// A point of the curve in extended twisted Edwards coordinates
+        /// `(X : Y : T : Z)`, with `x = X / Z`, `y = Y / Z` and `x y = T / Z`.
+        #[derive(Copy, Clone, Debug)]
+        #[repr(C)]
+        $($privacy)* struct $name {
+            pub x: $base,
+            pub y: $base,
+            pub t: $base,
+            pub z: $base,
+        }
+
+        // The points are plain limbs, without padding.
+        const _: () = assert!(
+            core::mem::size_of::<$name>() == 4 * core::mem::size_of::<$base>()
+                && core::mem::size_of::<$name_affine>() == 2 * core::mem::size_of::<$base>()
+                && core::mem::size_of::<$base>() % 8 == 0
+                && core::mem::align_of::<$name_affine>() == core::mem::align_of::<u64>()
+        );
+
+        // Whether `x` is larger than `(p - 1) / 2`, the sign of the compressed encoding.
+        fn is_negative(x: &$base) -> Choice {
+            x.double().is_odd()
+        }
+
+        impl $name_affine {
+            /// Views a slice of points as the Montgomery limbs of their
+            /// coordinates, in the order of the fields of the struct, without
+            /// copying them.
+            pub fn slice_as_limbs(points: &[Self]) -> &[u64] {
+                // SAFETY: the struct is `#[repr(C)]` over its coordinates, which
+                // are limbs without padding, as asserted with the struct.
+                unsafe {
+                    core::slice::from_raw_parts(
+                        points.as_ptr() as *const u64,
+                        points.len() * core::mem::size_of::<Self>() / 8,
+                    )
+                }
+            }
+
+            /// Returns the identity, `(0, 1)`.
+            pub fn identity() -> Self {
+                Self {
+                    x: $base::ZERO,
+                    y: $base::ONE,
+                }
+            }
+
+            /// Returns the generator of the subgroup of order `l`.
+            pub fn generator() -> Self {
+                Self {
+                    x: $gen_x,
+                    y: $gen_y,
+                }
+            }
+
+            /// Returns whether the point is the identity.
+            pub fn is_identity(&self) -> Choice {
+                self.x.is_zero() & self.y.ct_eq(&$base::ONE)
+            }
+
+            /// Returns whether the point satisfies the curve equation.
+            pub fn is_on_curve(&self) -> Choice {
+                let x2 = self.x.square();
+                let y2 = self.y.square();
+                ($edwards_a * x2 + y2).ct_eq(&($base::ONE + $edwards_d * x2 * y2))
+            }
+
+            /// Maps the point to the birationally equivalent Montgomery curve
+            /// `B v^2 = u^3 + A u^2 + u` with `u = (1 + y) / (1 - y)` and `v = u / x`.
+            /// The point of order two `(0, -1)` is mapped to `(0, 0)`, and the identity,
+            /// which corresponds to the point at infinity, to `None`.
+            pub fn to_montgomery(&self) -> CtOption<($base, $base)> {
+                let u = ($base::ONE + self.y) * ($base::ONE - self.y).invert().unwrap_or($base::ZERO);
+                let v = u * self.x.invert().unwrap_or($base::ZERO);
+                CtOption::new((u, v), !self.is_identity())
+            }
+
+            /// Maps a point of the Montgomery curve `B v^2 = u^3 + A u^2 + u` back to
+            /// the Edwards curve, failing if it is not on the curve. See
+            /// [`Self::to_montgomery`].
+            pub fn from_montgomery(u: &$base, v: &$base) -> CtOption<Self> {
+                let u2 = u.square();
+                let is_on_curve =
+                    ($montgomery_b * v.square()).ct_eq(&(u2 * u + $montgomery_a * u2 + u));
+                // `v = 0` only for `u = 0`, and `u = -1` is not on the curve.
+                let x = u * v.invert().unwrap_or($base::ZERO);
+                let y = (u - $base::ONE) * (u + $base::ONE).invert().unwrap_or($base::ZERO);
+                CtOption::new(Self { x, y }, is_on_curve)
+            }
+
+            /// Encodes the point as the little-endian encoding of `y`, with the most
+            /// significant bit set if `x > (p - 1) / 2`.
+            pub fn to_bytes(&self) -> [u8; $base::SIZE] {
+                let mut res = self.y.to_bytes();
+                res[$base::SIZE - 1] |= u8::conditional_select(&0, &0x80, is_negative(&self.x));
+                res
+            }
+
+            /// Decodes a point encoded with [`Self::to_bytes`], failing if the encoding
+            /// is not canonical or not a point of the curve. The point may not be in the
+            /// subgroup of order `l`.
+            pub fn from_bytes_unchecked(bytes: &[u8; $base::SIZE]) -> CtOption<Self> {
+                let (y, sign) = Self::decode_y(bytes);
+                y.and_then(|y| Self::recover_x(y, sign, Self::x2_den(y).invert().unwrap()))
+            }
+
+            /// Decodes a point encoded with [`Self::to_bytes`], failing if it is not in
+            /// the subgroup of order `l`.
+            pub fn from_bytes(bytes: &[u8; $base::SIZE]) -> CtOption<Self> {
+                Self::from_bytes_unchecked(bytes)
+                    .and_then(|p| CtOption::new(p, $name::from(p).is_torsion_free()))
+            }
+
+            /// Decodes each of `bytes`, with the same result as [`Self::from_bytes`]
+            /// for every encoding. The encodings are split among the threads, and
+            /// each thread recovers its `x` coordinates with a single inversion.
+            pub fn batch_from_compressed(
+                bytes: &[[u8; $base::SIZE]],
+            ) -> alloc::vec::Vec<CtOption<Self>> {
+                $crate::arithmetic::par_map_chunks(bytes, |bytes| {
+                    let ys = bytes.iter().map(Self::decode_y).collect::<alloc::vec::Vec<_>>();
+                    let mut dens = ys
+                        .iter()
+                        .map(|(y, _)| Self::x2_den(y.unwrap_or($base::ZERO)))
+                        .collect::<alloc::vec::Vec<_>>();
+                    let mut scratch = alloc::vec![$base::ZERO; dens.len()];
+                    $crate::ff::BatchInverter::invert_with_external_scratch(&mut dens, &mut scratch);
+
+                    ys.into_iter()
+                        .zip(dens)
+                        .map(|((y, sign), den_inv)| {
+                            y.and_then(|y| Self::recover_x(y, sign, den_inv))
+                                .and_then(|p| CtOption::new(p, $name::from(p).is_torsion_free()))
+                        })
+                        .collect()
+                })
+            }
+
+            // Splits an encoding into `y`, if canonical, and the sign of `x`.
+            fn decode_y(bytes: &[u8; $base::SIZE]) -> (CtOption<$base>, Choice) {
+                let sign = Choice::from(bytes[$base::SIZE - 1] >> 7);
+                let mut bytes = *bytes;
+                bytes[$base::SIZE - 1] &= 0x7f;
+                ($base::from_bytes(&bytes), sign)
+            }
+
+            // x^2 = (1 - y^2) / (a - d y^2), the denominator is never zero since a / d
+            // is not a square.
+            fn x2_den(y: $base) -> $base {
+                $edwards_a - $edwards_d * y.square()
+            }
+
+            // Recovers the point from `y`, the sign of `x` and the inverse of `x2_den(y)`.
+            fn recover_x(y: $base, sign: Choice, den_inv: $base) -> CtOption<Self> {
+                let x2 = ($base::ONE - y.square()) * den_inv;
+                x2.sqrt().and_then(|x| {
+                    let x = $base::conditional_select(&x, &-x, is_negative(&x) ^ sign);
+                    // The sign of zero is positive.
+                    CtOption::new(Self { x, y }, !(x.is_zero() & sign))
+                })
+            }
+        }
+
+        impl $name {
+            /// Views a slice of points as the Montgomery limbs of their
+            /// coordinates, in the order of the fields of the struct, without
+            /// copying them.
+            pub fn slice_as_limbs(points: &[Self]) -> &[u64] {
+                // SAFETY: the struct is `#[repr(C)]` over its coordinates, which
+                // are limbs without padding, as asserted with the struct.
+                unsafe {
+                    core::slice::from_raw_parts(
+                        points.as_ptr() as *const u64,
+                        points.len() * core::mem::size_of::<Self>() / 8,
+                    )
+                }
+            }
+
+            /// Returns whether the point satisfies the curve equation.
+            pub fn is_on_curve(&self) -> Choice {
+                // a X^2 + Y^2 = Z^2 + d T^2, X Y = T Z.
+                let lhs = $edwards_a * self.x.square() + self.y.square();
+                let rhs = self.z.square() + $edwards_d * self.t.square();
+                lhs.ct_eq(&rhs) & (self.x * self.y).ct_eq(&(self.t * self.z)) & !self.z.is_zero()
+            }
+
+            // Multiplies by the order `l` of the subgroup, a public constant.
+            fn mul_by_order(&self) -> Self {
+                let mut acc = Self::identity();
+                for limb in $scalar::MODULUS_LIMBS.iter().rev() {
+                    for i in (0..64).rev() {
+                        acc = acc.double();
+                        if (limb >> i) & 1 == 1 {
+                            acc += self;
+                        }
+                    }
+                }
+                acc
+            }
+        }
+
+        impl Default for $name_affine {
+            fn default() -> Self {
+                Self::identity()
+            }
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                Self::identity()
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        impl $crate::zeroize::DefaultIsZeroes for $name_affine {}
+
+        #[cfg(feature = "zeroize")]
+        impl $crate::zeroize::DefaultIsZeroes for $name {}
+
+        impl ConstantTimeEq for $name_affine {
+            fn ct_eq(&self, other: &Self) -> Choice {
+                self.x.ct_eq(&other.x) & self.y.ct_eq(&other.y)
+            }
+        }
+
+        impl ConstantTimeEq for $name {
+            fn ct_eq(&self, other: &Self) -> Choice {
+                (self.x * other.z).ct_eq(&(other.x * self.z))
+                    & (self.y * other.z).ct_eq(&(other.y * self.z))
+            }
+        }
+
+        impl PartialEq for $name_affine {
+            fn eq(&self, other: &Self) -> bool {
+                self.ct_eq(other).into()
+            }
+        }
+
+        impl PartialEq for $name {
+            fn eq(&self, other: &Self) -> bool {
+                self.ct_eq(other).into()
+            }
+        }
+
+        impl Eq for $name_affine {}
+
+        impl Eq for $name {}
+
+        // Points hash and compare as their compressed encoding, as the points of
+        // the short Weierstrass curves.
+
+        impl core::hash::Hash for $name_affine {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.to_bytes().hash(state)
+            }
+        }
+
+        impl core::hash::Hash for $name {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.to_affine().hash(state)
+            }
+        }
+
+        impl Ord for $name_affine {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                self.to_bytes().cmp(&other.to_bytes())
+            }
+        }
+
+        impl PartialOrd for $name_affine {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $name {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                self.to_affine().cmp(&other.to_affine())
+            }
+        }
+
+        impl PartialOrd for $name {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl core::fmt::Display for $name_affine {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "({}, {})", self.x, self.y)
+            }
+        }
+
+        impl core::fmt::LowerHex for $name_affine {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "(")?;
+                core::fmt::LowerHex::fmt(&self.x, f)?;
+                write!(f, ", ")?;
+                core::fmt::LowerHex::fmt(&self.y, f)?;
+                write!(f, ")")
+            }
+        }
+
+        impl core::fmt::Display for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(&self.to_affine(), f)
+            }
+        }
+
+        impl core::fmt::LowerHex for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::LowerHex::fmt(&self.to_affine(), f)
+            }
+        }
+
+        impl ConditionallySelectable for $name_affine {
+            fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                Self {
+                    x: $base::conditional_select(&a.x, &b.x, choice),
+                    y: $base::conditional_select(&a.y, &b.y, choice),
+                }
+            }
+        }
+
+        impl ConditionallySelectable for $name {
+            fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                Self {
+                    x: $base::conditional_select(&a.x, &b.x, choice),
+                    y: $base::conditional_select(&a.y, &b.y, choice),
+                    t: $base::conditional_select(&a.t, &b.t, choice),
+                    z: $base::conditional_select(&a.z, &b.z, choice),
+                }
+            }
+        }
+
+        impl From<$name_affine> for $name {
+            fn from(p: $name_affine) -> Self {
+                Self {
+                    x: p.x,
+                    y: p.y,
+                    t: p.x * p.y,
+                    z: $base::ONE,
+                }
+            }
+        }
+
+        impl<'a> From<&'a $name_affine> for $name {
+            fn from(p: &'a $name_affine) -> Self {
+                (*p).into()
+            }
+        }
+
+        impl From<$name> for $name_affine {
+            fn from(p: $name) -> Self {
+                // Z is never zero.
+                let z_inv = p.z.invert().unwrap();
+                Self {
+                    x: p.x * z_inv,
+                    y: p.y * z_inv,
+                }
+            }
+        }
+
+        impl<'a> From<&'a $name> for $name_affine {
+            fn from(p: &'a $name) -> Self {
+                (*p).into()
+            }
+        }
+
+        impl<'a> Neg for &'a $name_affine {
+            type Output = $name_affine;
+
+            fn neg(self) -> $name_affine {
+                $name_affine {
+                    x: -self.x,
+                    y: self.y,
+                }
+            }
+        }
+
+        impl Neg for $name_affine {
+            type Output = $name_affine;
+
+            fn neg(self) -> $name_affine {
+                -&self
+            }
+        }
+
+        impl<'a> Neg for &'a $name {
+            type Output = $name;
+
+            fn neg(self) -> $name {
+                $name {
+                    x: -self.x,
+                    y: self.y,
+                    t: -self.t,
+                    z: self.z,
+                }
+            }
+        }
+
+        impl Neg for $name {
+            type Output = $name;
+
+            fn neg(self) -> $name {
+                -&self
+            }
+        }
+
+        impl<'a, 'b> Add<&'a $name> for &'b $name {
+            type Output = $name;
+
+            fn add(self, rhs: &'a $name) -> $name {
+                // add-2008-hwcd, https://eprint.iacr.org/2008/522.pdf
+                let a = self.x * rhs.x;
+                let b = self.y * rhs.y;
+                let c = $edwards_d * self.t * rhs.t;
+                let d = self.z * rhs.z;
+                let e = (self.x + self.y) * (rhs.x + rhs.y) - a - b;
+                let f = d - c;
+                let g = d + c;
+                let h = b - $edwards_a * a;
+                $name {
+                    x: e * f,
+                    y: g * h,
+                    t: e * h,
+                    z: f * g,
+                }
+            }
+        }
+
+        impl<'a, 'b> Sub<&'a $name> for &'b $name {
+            type Output = $name;
+
+            fn sub(self, rhs: &'a $name) -> $name {
+                self + -rhs
+            }
+        }
+
+        impl<'a, 'b> Add<&'a $name_affine> for &'b $name {
+            type Output = $name;
+
+            fn add(self, rhs: &'a $name_affine) -> $name {
+                self + $name::from(rhs)
+            }
+        }
+
+        impl<'a, 'b> Sub<&'a $name_affine> for &'b $name {
+            type Output = $name;
+
+            fn sub(self, rhs: &'a $name_affine) -> $name {
+                self + -rhs
+            }
+        }
+
+        impl<'a, 'b> Add<&'a $name> for &'b $name_affine {
+            type Output = $name;
+
+            fn add(self, rhs: &'a $name) -> $name {
+                rhs + self
+            }
+        }
+
+        impl<'a, 'b> Add<&'a $name_affine> for &'b $name_affine {
+            type Output = $name;
+
+            fn add(self, rhs: &'a $name_affine) -> $name {
+                $name::from(self) + rhs
+            }
+        }
+
+        impl<'a, 'b> Sub<&'a $name> for &'b $name_affine {
+            type Output = $name;
+
+            fn sub(self, rhs: &'a $name) -> $name {
+                self + -rhs
+            }
+        }
+
+        impl<'a, 'b> Sub<&'a $name_affine> for &'b $name_affine {
+            type Output = $name;
+
+            fn sub(self, rhs: &'a $name_affine) -> $name {
+                self + -rhs
+            }
+        }
+
+        impl<'a, 'b> Mul<&'b $scalar> for &'a $name {
+            type Output = $name;
+
+            fn mul(self, other: &'b $scalar) -> $name {
+                let mut acc = $name::identity();
+                for byte in other.to_repr().as_ref().iter().rev() {
+                    for i in (0..8).rev() {
+                        acc = acc.double();
+                        let sum = acc + self;
+                        acc = $name::conditional_select(&acc, &sum, Choice::from((byte >> i) & 1));
+                    }
+                }
+                acc
+            }
+        }
+
+        impl<'a, 'b> Mul<&'b $scalar> for &'a $name_affine {
+            type Output = $name;
+
+            fn mul(self, other: &'b $scalar) -> $name {
+                $name::from(self) * other
+            }
+        }
+
+        $crate::impl_binops_additive!($name, $name);
+        $crate::impl_binops_additive!($name, $name_affine);
+        $crate::impl_binops_additive_specify_output!($name_affine, $name_affine, $name);
+        $crate::impl_binops_additive_specify_output!($name_affine, $name, $name);
+        $crate::impl_binops_multiplicative!($name, $scalar);
+        $crate::impl_binops_multiplicative_mixed!($name_affine, $scalar, $name);
+
+        impl<T> Sum<T> for $name
+        where
+            T: core::borrow::Borrow<$name>,
+        {
+            fn sum<I>(iter: I) -> Self
+            where
+                I: Iterator<Item = T>,
+            {
+                iter.fold(Self::identity(), |acc, item| acc + item.borrow())
+            }
+        }
+
+        impl Group for $name {
+            type Scalar = $scalar;
+
+            fn random(rng: impl RngCore) -> Self {
+                Self::generator() * $scalar::random(rng)
+            }
+
+            fn identity() -> Self {
+                Self {
+                    x: $base::ZERO,
+                    y: $base::ONE,
+                    t: $base::ZERO,
+                    z: $base::ONE,
+                }
+            }
+
+            fn generator() -> Self {
+                $name_affine::generator().into()
+            }
+
+            fn is_identity(&self) -> Choice {
+                self.x.is_zero() & self.y.ct_eq(&self.z)
+            }
+
+            fn double(&self) -> Self {
+                // dbl-2008-hwcd, https://eprint.iacr.org/2008/522.pdf
+                let a = self.x.square();
+                let b = self.y.square();
+                let c = self.z.square().double();
+                let d = $edwards_a * a;
+                let e = (self.x + self.y).square() - a - b;
+                let g = d + b;
+                let f = g - c;
+                let h = d - b;
+                Self {
+                    x: e * f,
+                    y: g * h,
+                    t: e * h,
+                    z: f * g,
+                }
+            }
+        }
+
+        impl Curve for $name {
+            type AffineRepr = $name_affine;
+
+            fn batch_normalize(p: &[Self], q: &mut [Self::AffineRepr]) {
+                assert_eq!(p.len(), q.len());
+
+                let mut z_invs = p.iter().map(|p| p.z).collect::<Vec<_>>();
+                let mut scratch = z_invs.clone();
+                BatchInverter::invert_with_external_scratch(&mut z_invs, &mut scratch);
+                for ((p, z_inv), q) in p.iter().zip(z_invs).zip(q.iter_mut()) {
+                    *q = $name_affine {
+                        x: p.x * z_inv,
+                        y: p.y * z_inv,
+                    };
+                }
+            }
+
+            fn to_affine(&self) -> Self::AffineRepr {
+                self.into()
+            }
+        }
+
+        impl GroupEncoding for $name {
+            type Repr = [u8; $base::SIZE];
+
+            fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+                $name_affine::from_bytes(bytes).map(Self::from)
+            }
+
+            fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+                $name_affine::from_bytes_unchecked(bytes).map(Self::from)
+            }
+
+            fn to_bytes(&self) -> Self::Repr {
+                self.to_affine().to_bytes()
+            }
+        }
+
+        impl $crate::serde::ValidatedEncoding<[u8; $base::SIZE]> for $name_affine {
+            fn from_bytes_with(
+                bytes: &[u8; $base::SIZE],
+                validation: $crate::serde::Validation,
+            ) -> CtOption<Self> {
+                match validation {
+                    $crate::serde::Validation::Subgroup => Self::from_bytes(bytes),
+                    // Decompressed points are always on the curve.
+                    _ => Self::from_bytes_unchecked(bytes),
+                }
+            }
+        }
+
+        impl $crate::serde::ValidatedEncoding<[u8; $base::SIZE]> for $name {
+            fn from_bytes_with(
+                bytes: &[u8; $base::SIZE],
+                validation: $crate::serde::Validation,
+            ) -> CtOption<Self> {
+                <$name_affine as $crate::serde::ValidatedEncoding<_>>::from_bytes_with(bytes, validation)
+                    .map(Self::from)
+            }
+        }
+
+        // As `G2` of BN256, the type is meant for the subgroup of order `l`: points out
+        // of it are only obtained from unchecked decoding, the Montgomery form or the
+        // public coordinates, and can be checked with `is_torsion_free`.
+        impl PrimeGroup for $name {}
+
+        impl CofactorGroup for $name {
+            type Subgroup = $name;
+
+            fn clear_cofactor(&self) -> Self {
+                (0..$cofactor_log2).fold(*self, |acc, _| acc.double())
+            }
+
+            fn into_subgroup(self) -> CtOption<Self::Subgroup> {
+                CtOption::new(self, self.is_torsion_free())
+            }
+
+            fn is_torsion_free(&self) -> Choice {
+                self.mul_by_order().is_identity()
+            }
+        }
+    };
+}
@@ -31,7 +31,7 @@ macro_rules! field_bits {
 
                         limb
                     })
-                    .collect::<Vec<_>>();
+                    .collect::<alloc::vec::Vec<_>>();
 
                 ff::FieldBits::new(limbs.try_into().unwrap())
             }
@@ -53,10 +53,10 @@ macro_rules! impl_from_u64 {
     ($field:ident) => {
         impl From<u64> for $field {
             fn from(val: u64) -> $field {
-                let limbs = std::iter::once(val)
-                    .chain(std::iter::repeat(0))
+                let limbs = core::iter::once(val)
+                    .chain(core::iter::repeat(0))
                     .take(Self::NUM_LIMBS)
-                    .collect::<Vec<_>>()
+                    .collect::<alloc::vec::Vec<_>>()
                     .try_into()
                     .unwrap();
 
@@ -101,5 +101,44 @@ macro_rules! serialize_deserialize_primefield {
                 }
             }
         }
+        #[cfg(feature = "derive_serde")]
+        impl $crate::ff_ext::SerdeCoefficients for $field {
+            type Base = $field;
+            const DEGREE: usize = 1;
+
+            fn write_coefficients(&self, out: &mut alloc::vec::Vec<$field>) {
+                out.push(*self);
+            }
+
+            fn read_coefficients<I: Iterator<Item = $field>>(coeffs: &mut I) -> Option<$field> {
+                coeffs.next()
+            }
+        }
+    };
+}
+
+/// A macro to implement [`crate::serde::CanonicalEncoding`] for field elements using their
+/// little-endian `to_bytes`/`from_bytes` representation. `$coordinate_size` is the size of the
+/// base prime field.
+#[macro_export]
+macro_rules! impl_canonical_encoding_field {
+    ($field:ident, $coordinate_size:expr) => {
+        impl $crate::serde::CanonicalEncoding for $field {
+            fn to_payload(&self, _compressed: bool) -> alloc::vec::Vec<u8> {
+                self.to_bytes().to_vec()
+            }
+
+            fn from_payload(bytes: &[u8], _compressed: bool) -> Option<Self> {
+                Option::from(Self::from_bytes(bytes.try_into().ok()?))
+            }
+
+            fn coordinate_size(_compressed: bool) -> usize {
+                $coordinate_size
+            }
+
+            fn payload_size(_compressed: bool) -> usize {
+                Self::SIZE
+            }
+        }
     };
 }
@@ -2,30 +2,17 @@
 macro_rules! impl_tower2 {
     (
         $base:ident,
-        $field:ident
+        $field:ident,
+        multiplicative_generator = $generator:expr,
+        s = $s:expr,
+        root_of_unity = $root_of_unity:expr,
+        root_of_unity_inv = $root_of_unity_inv:expr,
+        delta = $delta:expr
     ) => {
         impl $field {
             pub const SIZE: usize = $base::SIZE * 2;
         }
 
-        impl Ord for $field {
-            #[inline(always)]
-            fn cmp(&self, other: &$field) -> Ordering {
-                match self.c1.cmp(&other.c1) {
-                    Ordering::Greater => Ordering::Greater,
-                    Ordering::Less => Ordering::Less,
-                    Ordering::Equal => self.c0.cmp(&other.c0),
-                }
-            }
-        }
-
-        impl PartialOrd for $field {
-            #[inline(always)]
-            fn partial_cmp(&self, other: &$field) -> Option<Ordering> {
-                Some(self.cmp(other))
-            }
-        }
-
         impl From<u64> for $field {
             fn from(val: u64) -> Self {
                 $field {
@@ -43,8 +30,8 @@ macro_rules! impl_tower2 {
                 let c1 = $base::from_bytes(bytes[$base::SIZE..$base::SIZE * 2].try_into().unwrap());
                 CtOption::new(
                     $field {
-                        c0: c0.unwrap(),
-                        c1: c1.unwrap(),
+                        c0: c0.unwrap_or($base::ZERO),
+                        c1: c1.unwrap_or($base::ZERO),
                     },
                     c0.is_some() & c1.is_some(),
                 )
@@ -63,6 +50,8 @@ macro_rules! impl_tower2 {
             }
         }
 
+        $crate::impl_canonical_encoding_field!($field, $base::SIZE);
+
         impl WithSmallOrderMulGroup<3> for $field {
             const ZETA: Self = $field {
                 c0: $base::ZETA.mul_const(&$base::ZETA),
@@ -70,28 +59,20 @@ macro_rules! impl_tower2 {
             };
         }
 
-        impl Legendre for $field {
-            fn legendre(&self) -> i64 {
-                self.norm().legendre()
-            }
-        }
-
         impl PrimeField for $field {
             type Repr = $crate::serde::Repr<{ $base::SIZE * 2 }>;
 
             const MODULUS: &'static str = <$base as PrimeField>::MODULUS;
-            const MULTIPLICATIVE_GENERATOR: Self = $field {
-                c0: $base::MULTIPLICATIVE_GENERATOR,
-                c1: $base::ZERO,
-            };
             const NUM_BITS: u32 = $base::NUM_BITS;
             const CAPACITY: u32 = $base::NUM_BITS;
-            const S: u32 = $base::S;
 
-            // TODO: Check that we can just 0 this and forget.
-            const ROOT_OF_UNITY: Self = $field::ZERO;
-            const ROOT_OF_UNITY_INV: Self = $field::ZERO;
-            const DELTA: Self = $field::ZERO;
+            // The constants of the multiplicative group of `$field`, of order
+            // `q^2 - 1 = 2^S t` with `t` odd, rather than of the one of `$base`.
+            const MULTIPLICATIVE_GENERATOR: Self = $generator;
+            const S: u32 = $s;
+            const ROOT_OF_UNITY: Self = $root_of_unity;
+            const ROOT_OF_UNITY_INV: Self = $root_of_unity_inv;
+            const DELTA: Self = $delta;
 
             const TWO_INV: Self = $field {
                 c0: $base::TWO_INV,
@@ -107,7 +88,10 @@ macro_rules! impl_tower2 {
                 let c1: <$base as PrimeField>::Repr = c1.into();
                 let c1 = $base::from_repr(c1);
 
-                CtOption::new($field::new(c0.unwrap(), c1.unwrap()), Choice::from(1))
+                CtOption::new(
+                    $field::new(c0.unwrap_or($base::ZERO), c1.unwrap_or($base::ZERO)),
+                    c0.is_some() & c1.is_some(),
+                )
             }
 
             fn to_repr(&self) -> Self::Repr {
@@ -119,8 +103,10 @@ macro_rules! impl_tower2 {
                 res
             }
 
+            /// The `sgn0` of RFC 9380: the parity of `c0`, or of `c1` when `c0` is
+            /// zero, so that `x` and `-x` have different signs unless `x` is zero.
             fn is_odd(&self) -> Choice {
-                Choice::from(self.to_repr().as_ref()[0] & 1)
+                self.c0.is_odd() | (self.c0.is_zero() & self.c1.is_odd())
             }
         }
 
@@ -139,22 +125,25 @@ macro_rules! impl_tower2 {
                     [0, $base::SIZE].map(|i| $base::from_raw_bytes(&bytes[i..i + $base::SIZE]));
                 c0.zip(c1).map(|(c0, c1)| Self { c0, c1 })
             }
-            fn to_raw_bytes(&self) -> Vec<u8> {
-                let mut res = Vec::with_capacity($base::SIZE * 2);
+            fn to_raw_bytes(&self) -> alloc::vec::Vec<u8> {
+                let mut res = alloc::vec::Vec::with_capacity($base::SIZE * 2);
                 for limb in self.c0.0.iter().chain(self.c1.0.iter()) {
                     res.extend_from_slice(&limb.to_le_bytes());
                 }
                 res
             }
+            #[cfg(feature = "std")]
             fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
                 let [c0, c1] = [(); 2].map(|_| $base::read_raw_unchecked(reader));
                 Self { c0, c1 }
             }
+            #[cfg(feature = "std")]
             fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
                 let c0 = $base::read_raw(reader)?;
                 let c1 = $base::read_raw(reader)?;
                 Ok(Self { c0, c1 })
             }
+            #[cfg(feature = "std")]
             fn write_raw<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
                 self.c0.write_raw(writer)?;
                 self.c1.write_raw(writer)
@@ -193,7 +182,12 @@ macro_rules! impl_cyclotomic_square {
     ) => {
         impl $tower12 {
             pub fn cyclotomic_square(&mut self) {
+                #[cfg(test)]
+                $crate::tests::op_count::record($crate::tests::op_count::Op::CyclotomicSquare);
+
                 fn fp4_square(c0: &mut $tower2, c1: &mut $tower2, a0: &$tower2, a1: &$tower2) {
+                    // Unused when `$tower2` is a prime field, with inherent methods.
+                    #[allow(unused_imports)]
                     use ff::Field;
                     let t0 = a0.square();
                     let t1 = a1.square();
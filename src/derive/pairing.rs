@@ -1,5 +1,79 @@
 #[macro_export]
 macro_rules! impl_miller_loop_components {
+    (
+    $engine:ident,
+    $g1:ident,
+    $g1affine:ident,
+    $g2:ident,
+    $g2affine:ident,
+    $base:ident,
+    $target:ident,
+    $scalar:ident,
+    $naf:expr
+    ) => {
+        $crate::impl_miller_loop_components!(
+            $engine, $g1, $g1affine, $g2, $g2affine, $base, $target, $scalar
+        );
+
+        impl $crate::line::LineEvaluator for $engine {
+            type Coeff = <$g2 as $crate::CurveExt>::Base;
+
+            const LOOP_NAF: &'static [i8] = &$naf;
+
+            fn double_step(
+                r: &mut $crate::line::JacobianPoint<Self::Coeff>,
+            ) -> $crate::line::Line<Self::Coeff> {
+                double_step(r)
+            }
+
+            fn add_step(
+                r: &mut $crate::line::JacobianPoint<Self::Coeff>,
+                q: &$g2affine,
+            ) -> $crate::line::Line<Self::Coeff> {
+                add_step(r, q)
+            }
+
+            fn frobenius_points(q: &$g2affine) -> alloc::vec::Vec<$g2affine> {
+                frobenius_points(q).to_vec()
+            }
+
+            fn evaluate(f: &mut $base, line: &$crate::line::Line<Self::Coeff>, p: &$g1affine) {
+                ell(f, line, p);
+            }
+
+            fn prepare(q: &$g2affine) -> alloc::vec::Vec<$crate::line::Line<Self::Coeff>> {
+                if bool::from(q.is_identity()) {
+                    return alloc::vec::Vec::new();
+                }
+                $crate::line::prepare(
+                    &$naf,
+                    q,
+                    $crate::line::JacobianPoint::from(q),
+                    frobenius_points(q),
+                    double_step,
+                    add_step,
+                )
+            }
+
+            fn miller_loop_prepared(
+                terms: &[(&$g1affine, &[$crate::line::Line<Self::Coeff>])],
+            ) -> $base {
+                let terms = terms
+                    .iter()
+                    .filter(|(p, lines)| !bool::from(p.is_identity()) && !lines.is_empty())
+                    .copied()
+                    .collect::<alloc::vec::Vec<_>>();
+                $crate::line::miller_loop_prepared(
+                    &$naf,
+                    $crate::line::num_frobenius(frobenius_points),
+                    &terms,
+                    |f, line, p| ell(f, line, p),
+                )
+            }
+        }
+    };
+    // Without the `LineEvaluator` steps, for the Miller loops that do not follow
+    // their schedule.
     (
     $engine:ident,
     $g1:ident,
@@ -35,6 +109,41 @@ macro_rules! impl_miller_loop_components {
             }
         }
 
+        impl $engine {
+            /// Returns the multi-Miller loops of the chunks of at most `chunk_size` terms
+            /// of `terms`. The loop is multiplicative in its terms, so the product of the
+            /// results of the chunks, computed by different threads or machines, is the
+            /// multi-Miller loop of all the terms.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `chunk_size` is 0.
+            pub fn multi_miller_loop_chunks<'a>(
+                terms: &'a [(&'a $g1affine, &'a $g2affine)],
+                chunk_size: usize,
+            ) -> impl Iterator<Item = $base> + 'a {
+                terms.chunks(chunk_size).map(multi_miller_loop)
+            }
+
+            /// Computes the multi-Miller loop of `terms` with a chunk of terms per thread
+            /// of the current rayon pool.
+            #[cfg(feature = "std")]
+            pub fn multi_miller_loop_par(terms: &[(&$g1affine, &$g2affine)]) -> $base {
+                use ff::Field;
+                use rayon::prelude::*;
+
+                let num_threads = rayon::current_num_threads();
+                if num_threads == 1 || terms.len() <= 1 {
+                    return multi_miller_loop(terms);
+                }
+                let chunk_size = (terms.len() + num_threads - 1) / num_threads;
+                terms
+                    .par_chunks(chunk_size)
+                    .map(multi_miller_loop)
+                    .reduce(|| $base::ONE, |acc, f| acc * f)
+            }
+        }
+
         impl PairingCurveAffine for $g1affine {
             type Pair = $g2affine;
             type PairingResult = $target;
@@ -53,7 +162,11 @@ macro_rules! impl_miller_loop_components {
             }
         }
 
-        fn double(f: &mut $base, r: &mut $g2, p: &$g1affine) {
+        fn double_step(
+            r: &mut $crate::line::JacobianPoint<<$g2 as $crate::CurveExt>::Base>,
+        ) -> $crate::line::Line<<$g2 as $crate::CurveExt>::Base> {
+            // Prime fields have inherent `square` methods, see `impl_field`.
+            #[allow(unused_imports)]
             use ff::Field;
             let t0 = r.x.square();
             let t1 = r.y.square();
@@ -81,10 +194,18 @@ macro_rules! impl_miller_loop_components {
             let t0 = r.z * zsquared;
             let t0 = t0 + t0;
 
-            ell(f, &(t0, t3, t6), p);
+            $crate::line::Line {
+                c0: t0,
+                c1: t3,
+                c2: t6,
+            }
         }
 
-        fn add(f: &mut $base, r: &mut $g2, q: &$g2affine, p: &$g1affine) {
+        fn add_step(
+            r: &mut $crate::line::JacobianPoint<<$g2 as $crate::CurveExt>::Base>,
+            q: &$g2affine,
+        ) -> $crate::line::Line<<$g2 as $crate::CurveExt>::Base> {
+            #[allow(unused_imports)]
             use ff::Field;
             let zsquared = r.z.square();
             let ysquared = q.y.square();
@@ -113,7 +234,40 @@ macro_rules! impl_miller_loop_components {
             let t6 = -t6;
             let t1 = t6 + t6;
 
-            ell(f, &(t10, t1, t9), p);
+            $crate::line::Line {
+                c0: t10,
+                c1: t1,
+                c2: t9,
+            }
+        }
+
+        fn double(
+            f: &mut $base,
+            r: &mut $crate::line::JacobianPoint<<$g2 as $crate::CurveExt>::Base>,
+            p: &$g1affine,
+        ) {
+            ell(f, &double_step(r), p);
+        }
+
+        fn add(
+            f: &mut $base,
+            r: &mut $crate::line::JacobianPoint<<$g2 as $crate::CurveExt>::Base>,
+            q: &$g2affine,
+            p: &$g1affine,
+        ) {
+            ell(f, &add_step(r, q), p);
+        }
+
+        impl From<&$g2affine> for $crate::line::JacobianPoint<<$g2 as $crate::CurveExt>::Base> {
+            fn from(q: &$g2affine) -> Self {
+                use ff::Field;
+                type Base = <$g2 as $crate::CurveExt>::Base;
+                Self {
+                    x: q.x,
+                    y: q.y,
+                    z: Base::conditional_select(&Base::ONE, &Base::ZERO, q.is_identity()),
+                }
+            }
         }
     };
 }
@@ -123,11 +277,56 @@ macro_rules! impl_gt {
     (
         $target:ident,
         $base:ident,
+        $tower6:ident,
+        $tower2:ident,
         $scalar:ident
+    ) => {
+        $crate::impl_gt!($target, $base, $tower6, $tower2, $scalar, 12);
+    };
+    (
+        $target:ident,
+        $base:ident,
+        $tower6:ident,
+        $tower2:ident,
+        $scalar:ident,
+        $embedding_degree:literal
     ) => {
         #[derive(Copy, Clone, Debug, Default)]
         pub struct $target(pub(crate) $base);
 
+        impl core::hash::Hash for $target {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.0.hash(state)
+            }
+        }
+
+        impl Ord for $target {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        impl PartialOrd for $target {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl core::fmt::Display for $target {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl core::fmt::LowerHex for $target {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                core::fmt::LowerHex::fmt(&self.0, f)
+            }
+        }
+
+        #[cfg(feature = "zeroize")]
+        impl $crate::zeroize::DefaultIsZeroes for $target {}
+
         impl ConstantTimeEq for $target {
             fn ct_eq(&self, other: &Self) -> Choice {
                 self.0.ct_eq(&other.0)
@@ -156,8 +355,163 @@ macro_rules! impl_gt {
 
             /// Doubles this group element.
             pub fn double(&self) -> $target {
+                let mut res = self.0;
+                res.cyclotomic_square();
+                $target(res)
+            }
+
+            /// Exponentiates `self` by `exp`, given as little-endian limbs, with
+            /// cyclotomic squarings.
+            ///
+            /// **This operation is variable time with respect to the exponent.**
+            pub fn pow_vartime<S: AsRef<[u64]>>(&self, exp: S) -> $target {
+                let mut res = $base::one();
+                for limb in exp.as_ref().iter().rev() {
+                    for i in (0..64).rev() {
+                        res.cyclotomic_square();
+                        if ((limb >> i) & 1) == 1 {
+                            res.mul_assign(&self.0);
+                        }
+                    }
+                }
+                $target(res)
+            }
+
+            /// Size of the compressed encoding, half the size of the underlying
+            /// extension element.
+            pub const COMPRESSED_SIZE: usize = $tower2::SIZE * 3;
+
+            /// Compresses the element to `g = (1 + c0) / c1` where `self = c0 + c1 w`.
+            ///
+            /// Elements of the cyclotomic subgroup satisfy `c0^2 - v c1^2 = 1`, so they
+            /// are recovered from `g` as `(g + w) / (g - w)`. The identity, the only
+            /// element with `c1 = 0`, is mapped to `g = 0`, which would otherwise
+            /// decompress to `-1` that is not in the group.
+            pub fn to_compressed(&self) -> [u8; $tower2::SIZE * 3] {
+                use ff::Field;
+                let g = (self.0.c0 + $tower6::ONE) * self.0.c1.invert().unwrap_or($tower6::ZERO);
+                let mut res = [0u8; $tower2::SIZE * 3];
+                for (chunk, c) in res.chunks_mut($tower2::SIZE).zip([g.c0, g.c1, g.c2]) {
+                    chunk.copy_from_slice(&c.to_bytes());
+                }
+                res
+            }
+
+            /// Decompresses an element encoded with [`Self::to_compressed`], failing
+            /// if the coordinates are not canonical or the result is not in the group.
+            pub fn from_compressed(bytes: &[u8; $tower2::SIZE * 3]) -> CtOption<$target> {
                 use ff::Field;
-                $target(self.0.square())
+                let coeff = |i: usize| {
+                    $tower2::from_bytes(
+                        bytes[i * $tower2::SIZE..(i + 1) * $tower2::SIZE]
+                            .try_into()
+                            .unwrap(),
+                    )
+                };
+                coeff(0).and_then(|c0| {
+                    coeff(1).and_then(|c1| {
+                        coeff(2).and_then(|c2| {
+                            let g = $tower6::new(c0, c1, c2);
+                            let v = <$tower6 as $crate::ff_ext::ExtField>::NON_RESIDUE;
+                            let g2 = g.square();
+                            // g^2 - v is never zero, v is not a square.
+                            let d = (g2 - v).invert().unwrap();
+                            let x = $base::new((g2 + v) * d, g.double() * d);
+                            let x = $base::conditional_select(&x, &$base::one(), g.is_zero());
+                            let x = $target(x);
+                            CtOption::new(x, x.is_torsion_free())
+                        })
+                    })
+                })
+            }
+
+            /// Size of the uncompressed encoding.
+            pub const SIZE: usize = $tower2::SIZE * 6;
+
+            /// Encodes the underlying extension element as the concatenation of its
+            /// six coefficients over `$tower2`, lowest first.
+            pub fn to_bytes(&self) -> [u8; $tower2::SIZE * 6] {
+                let mut res = [0u8; $tower2::SIZE * 6];
+                let coeffs = [
+                    self.0.c0.c0,
+                    self.0.c0.c1,
+                    self.0.c0.c2,
+                    self.0.c1.c0,
+                    self.0.c1.c1,
+                    self.0.c1.c2,
+                ];
+                for (chunk, c) in res.chunks_mut($tower2::SIZE).zip(coeffs) {
+                    chunk.copy_from_slice(&c.to_bytes());
+                }
+                res
+            }
+
+            /// Decodes an element encoded with [`Self::to_bytes`], failing if the
+            /// coefficients are not canonical or the element is not in the group.
+            pub fn from_bytes(bytes: &[u8; $tower2::SIZE * 6]) -> CtOption<$target> {
+                let mut coeffs = [$tower2::default(); 6];
+                let mut is_some = Choice::from(1u8);
+                for (c, chunk) in coeffs.iter_mut().zip(bytes.chunks($tower2::SIZE)) {
+                    let decoded = $tower2::from_bytes(chunk.try_into().unwrap());
+                    is_some &= decoded.is_some();
+                    *c = decoded.unwrap_or($tower2::default());
+                }
+                let x = $target($base::new(
+                    $tower6::new(coeffs[0], coeffs[1], coeffs[2]),
+                    $tower6::new(coeffs[3], coeffs[4], coeffs[5]),
+                ));
+                // Skip the subgroup check for non-canonical encodings.
+                if bool::from(is_some) {
+                    CtOption::new(x, x.is_torsion_free())
+                } else {
+                    CtOption::new(x, is_some)
+                }
+            }
+
+            /// Computes `sum_i scalars[i] * bases[i]`, sharing the squarings
+            /// between all the terms.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `bases` and `scalars` have different lengths.
+            pub fn multi_exp(bases: &[$target], scalars: &[$scalar]) -> $target {
+                assert_eq!(bases.len(), scalars.len());
+                let reprs = scalars
+                    .iter()
+                    .map(|s| s.to_repr())
+                    .collect::<alloc::vec::Vec<_>>();
+                let num_bytes = reprs.first().map_or(0, |r| r.as_ref().len());
+
+                let mut acc = $target::identity();
+                for byte in (0..num_bytes).rev() {
+                    for i in (0..8).rev() {
+                        acc = acc.double();
+                        for (base, repr) in bases.iter().zip(reprs.iter()) {
+                            let bit = Choice::from((repr.as_ref()[byte] >> i) & 1u8);
+                            acc = $target::conditional_select(&acc, &(acc + base), bit);
+                        }
+                    }
+                }
+                acc
+            }
+
+            // Checks that an element of norm 1 is in the subgroup of order `r`. The
+            // cyclotomic squarings of `pow_vartime` are only valid for elements of
+            // order dividing `Φ_k(p) = p^(2m) - p^m + 1`, with `m = k / 6` for the
+            // embedding degree `k`, which is checked first.
+            fn is_torsion_free(&self) -> Choice {
+                let mut x_pm = self.0;
+                x_pm.frobenius_map_const::<{ $embedding_degree / 6 }>();
+                let mut x_p2m = x_pm;
+                x_p2m.frobenius_map_const::<{ $embedding_degree / 6 }>();
+                let cyclotomic = (x_p2m * self.0).ct_eq(&x_pm);
+                // Skip the exponentiation for elements out of the cyclotomic subgroup.
+                if bool::from(cyclotomic) {
+                    self.pow_vartime($scalar::MODULUS_LIMBS)
+                        .ct_eq(&$target::identity())
+                } else {
+                    cyclotomic
+                }
             }
         }
 
@@ -242,6 +596,8 @@ macro_rules! impl_gt {
         impl Group for $target {
             type Scalar = $scalar;
 
+            /// Maps a random element of the underlying extension to the group with
+            /// the final exponentiation.
             fn random(rng: impl RngCore) -> Self {
                 use ff::Field;
                 $base::random(rng).final_exponentiation()
@@ -0,0 +1,102 @@
+use crate::ed_on_bls12_377::{Fq, Fr};
+use crate::ff::{BatchInverter, Field, PrimeField};
+use crate::group::cofactor::CofactorGroup;
+use crate::group::prime::PrimeGroup;
+use crate::group::{Curve, Group, GroupEncoding};
+use alloc::vec::Vec;
+use core::iter::Sum;
+use core::ops::{Add, Mul, Neg, Sub};
+use rand_core::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+const EDWARDS_A: Fq = Fq::ZERO.sub_const(&Fq::ONE);
+const EDWARDS_D: Fq = Fq::from_raw([3021, 0, 0, 0]);
+// 2 (a + d) / (a - d)
+const MONTGOMERY_A: Fq = Fq::from_raw([
+    0xedb748da37e8f552,
+    0x93952ad84d4753dd,
+    0x26949031ece3971b,
+    0x08d26e3fada9010a,
+]);
+// 4 / (a - d)
+const MONTGOMERY_B: Fq = Fq::from_raw([
+    0x1c5a3725c8170aad,
+    0xc6154c2682b8ac23,
+    0x3a1fbcec6f5418e5,
+    0x09d8f71eec83a44c,
+]);
+
+// The generator of the subgroup of order `l` of Arkworks.
+const GENERATOR_X: Fq = Fq::from_raw([
+    0x894e2328f3ebca05,
+    0x6068dd2835790980,
+    0x6fed91c9ae9ebfa0,
+    0x09f1b5a5baf6acf0,
+]);
+const GENERATOR_Y: Fq = Fq::from_raw([
+    0xb50a67bf1a806781,
+    0x4453c177aaf3131b,
+    0xd906b256080ba845,
+    0x09a20df36571ac3c,
+]);
+
+crate::new_edwards_curve_impl!(
+    (pub),
+    EdOnBls12377,
+    EdOnBls12377Affine,
+    Fq,
+    Fr,
+    (GENERATOR_X, GENERATOR_Y),
+    EDWARDS_A,
+    EDWARDS_D,
+    (MONTGOMERY_A, MONTGOMERY_B),
+    2,
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    crate::edwards_testing_suite!(EdOnBls12377, EdOnBls12377Affine, Fq, Fr);
+
+    #[test]
+    fn test_generator() {
+        let g = EdOnBls12377Affine::generator();
+        assert!(bool::from(g.is_on_curve()));
+        assert!(bool::from(EdOnBls12377::generator().is_on_curve()));
+        assert!(bool::from(EdOnBls12377::generator().is_torsion_free()));
+        assert!(!bool::from(EdOnBls12377::generator().is_identity()));
+
+        // The coordinates of Arkworks, in decimal.
+        let x = Fq::from_str_vartime(
+            "4497879464030519973909970603271755437257548612157028181994697785683032656389",
+        );
+        let y = Fq::from_str_vartime(
+            "4357141146396347889246900916607623952598927460421559113092863576544024487809",
+        );
+        assert_eq!((g.x, g.y), (x.unwrap(), y.unwrap()));
+    }
+
+    #[test]
+    fn test_parameters() {
+        // a = -1 is a square and d is not, the Montgomery constants match a and d.
+        assert!(bool::from(EDWARDS_A.sqrt().is_some()));
+        assert!(bool::from(EDWARDS_D.sqrt().is_none()));
+        let a_minus_d_inv = (EDWARDS_A - EDWARDS_D).invert().unwrap();
+        assert_eq!(
+            MONTGOMERY_A,
+            (EDWARDS_A + EDWARDS_D).double() * a_minus_d_inv
+        );
+        assert_eq!(MONTGOMERY_B, Fq::from(4) * a_minus_d_inv);
+
+        // The order of the curve is `4 l`, with points of order 4.
+        let order_four = EdOnBls12377Affine {
+            x: EDWARDS_A.sqrt().unwrap().invert().unwrap(),
+            y: Fq::ZERO,
+        };
+        assert!(bool::from(order_four.is_on_curve()));
+        let order_four = EdOnBls12377::from(order_four);
+        assert!(!bool::from(order_four.double().is_identity()));
+        assert!(bool::from(order_four.clear_cofactor().is_identity()));
+    }
+}
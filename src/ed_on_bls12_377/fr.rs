@@ -0,0 +1,44 @@
+use core::convert::TryInto;
+use halo2derive::impl_field;
+use rand_core::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+// `l = 2 mod 3`, there is no cube root of unity.
+impl_field!(
+    ed_on_bls12_377_scalar,
+    Fr,
+    modulus = "04aad957a68b2955982d1347970dec005293a3afc43c8afeb95aee9ac33fd9ff",
+    mul_gen = "5",
+    from_uniform = [64],
+    endian = "little",
+);
+
+crate::extend_field_legendre!(Fr);
+crate::impl_binops_calls!(Fr);
+crate::impl_binops_additive!(Fr, Fr);
+crate::impl_binops_multiplicative!(Fr, Fr);
+crate::field_bits!(Fr);
+crate::serialize_deserialize_primefield!(Fr);
+crate::impl_canonical_encoding_field!(Fr, Fr::SIZE);
+crate::impl_from_u64!(Fr);
+crate::impl_random_seeded!(Fr, 64);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    crate::field_testing_suite!(Fr, "field_arithmetic");
+    crate::field_testing_suite!(Fr, "conversion");
+    crate::field_testing_suite!(Fr, "serialization");
+    crate::field_testing_suite!(Fr, "quadratic_residue");
+    crate::field_testing_suite!(Fr, "bits");
+    crate::field_testing_suite!(Fr, "serialization_check");
+    crate::field_testing_suite!(Fr, "constants");
+    crate::field_testing_suite!(Fr, "montgomery_constants");
+    crate::field_testing_suite!(Fr, "mul_u64");
+    crate::field_testing_suite!(Fr, "halve");
+    crate::field_testing_suite!(Fr, "invert");
+    crate::field_testing_suite!(Fr, "from_raw");
+    crate::field_testing_suite!(Fr, "from_wide", from_u512);
+    crate::field_testing_suite!(Fr, "sqrt");
+    crate::field_testing_suite!(Fr, "from_uniform_bytes", 64);
+}
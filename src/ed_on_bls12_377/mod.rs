@@ -0,0 +1,24 @@
+//! # `ed_on_bls12_377`
+//!
+//! The twisted Edwards curve [`EdOnBls12377`], `-x^2 + y^2 = 1 + 3021 x^2 y^2` over
+//! [`Fq`], the scalar field of BLS12-377, so that its arithmetic is native in
+//! circuits over BLS12-377. It is the embedded curve of Zexe, with the parameters
+//! of Arkworks.
+//!
+//! The curve has order `4 l`, and [`Fr`] is the scalar field of the subgroup of
+//! prime order `l`, which is generated by [`EdOnBls12377::generator`]. Since `-1`
+//! is a square and `3021` is not, the addition formulas are complete.
+//!
+//! The curve is birationally equivalent to the Montgomery curve
+//! `B v^2 = u^3 + A u^2 + u` with `A = 2 (a + d) / (a - d)` and `B = 4 / (a - d)`,
+//! see [`EdOnBls12377Affine::to_montgomery`].
+//!
+//! As for [`crate::babyjubjub`], the points implement the traits of the `group`
+//! crate only.
+
+mod curve;
+mod fr;
+
+pub use crate::bls12_377::Fr as Fq;
+pub use curve::*;
+pub use fr::*;
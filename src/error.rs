@@ -0,0 +1,50 @@
+//! The error of the decodings of the crate.
+//!
+//! The decodings of fixed-size encodings, e.g. [`group::GroupEncoding`] or
+//! [`ff::PrimeField::from_repr`], return a [`subtle::CtOption`] so that they run in
+//! constant time. The readers of the `serde`, `srs` and `mmap` modules return an
+//! `io::Error` of kind `InvalidData` wrapping an [`Error`], which tells what was
+//! wrong with the input, e.g. with `err.get_ref().and_then(|e| e.downcast_ref())`.
+
+use core::fmt;
+
+/// Why an input could not be decoded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// The header, flags or metadata of the encoding are invalid or unsupported.
+    InvalidHeader,
+    /// The input is shorter or longer than the encoding, or a length it declares is
+    /// invalid.
+    InvalidLength,
+    /// A field element is not smaller than the modulus.
+    NotCanonical,
+    /// The coordinates are not those of a point of the curve.
+    NotOnCurve,
+    /// A point is not a valid encoding of a point of the prime order subgroup.
+    InvalidPoint,
+    /// The input is not a valid encoding, for another reason.
+    InvalidEncoding,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Error::InvalidHeader => "invalid header",
+            Error::InvalidLength => "invalid length",
+            Error::NotCanonical => "field element not smaller than the modulus",
+            Error::NotOnCurve => "point not on the curve",
+            Error::InvalidPoint => "invalid point",
+            Error::InvalidEncoding => "invalid encoding",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+#[cfg(feature = "std")]
+impl From<Error> for std::io::Error {
+    fn from(err: Error) -> Self {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, err)
+    }
+}
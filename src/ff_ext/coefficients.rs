@@ -0,0 +1,273 @@
+//! Serde support for extension fields.
+//!
+//! Human-readable formats encode an extension field element as the flat array of
+//! its coefficients over the prime field, lowest first, each one a hex string:
+//! an `Fq2` element is `[c0, c1]`, an `Fq6` element `[c0.c0, c0.c1, c1.c0, ...]`
+//! and an `Fq12` element an array of 12 strings. The nested struct form
+//! `{"c0": ..., "c1": ...}` is also accepted on deserialization.
+//!
+//! Binary formats keep the nested struct encoding.
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::value::MapAccessDeserializer;
+use serde::de::{DeserializeOwned, Error, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::cubic::CubicExtField;
+use super::quadratic::QuadExtField;
+
+/// Flattening of field elements into their coefficients over the prime field.
+pub trait SerdeCoefficients: Sized {
+    /// The prime field.
+    type Base: Copy + Serialize + DeserializeOwned;
+
+    /// Number of coefficients.
+    const DEGREE: usize;
+
+    /// Appends the coefficients of `self` to `out`, lowest first.
+    fn write_coefficients(&self, out: &mut Vec<Self::Base>);
+
+    /// Reads an element from the next `DEGREE` coefficients of `coeffs`.
+    fn read_coefficients<I: Iterator<Item = Self::Base>>(coeffs: &mut I) -> Option<Self>;
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "QuadExtField")]
+struct QuadRepr<F> {
+    c0: F,
+    c1: F,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "CubicExtField")]
+struct CubicRepr<F> {
+    c0: F,
+    c1: F,
+    c2: F,
+}
+
+impl<F: ff::Field> From<QuadRepr<F>> for QuadExtField<F> {
+    fn from(repr: QuadRepr<F>) -> Self {
+        QuadExtField::new(repr.c0, repr.c1)
+    }
+}
+
+impl<F: ff::Field> From<CubicRepr<F>> for CubicExtField<F> {
+    fn from(repr: CubicRepr<F>) -> Self {
+        CubicExtField::new(repr.c0, repr.c1, repr.c2)
+    }
+}
+
+impl<F: ff::Field + SerdeCoefficients> SerdeCoefficients for QuadExtField<F> {
+    type Base = F::Base;
+    const DEGREE: usize = 2 * F::DEGREE;
+
+    fn write_coefficients(&self, out: &mut Vec<Self::Base>) {
+        self.c0.write_coefficients(out);
+        self.c1.write_coefficients(out);
+    }
+
+    fn read_coefficients<I: Iterator<Item = Self::Base>>(coeffs: &mut I) -> Option<Self> {
+        let c0 = F::read_coefficients(coeffs)?;
+        let c1 = F::read_coefficients(coeffs)?;
+        Some(QuadExtField::new(c0, c1))
+    }
+}
+
+impl<F: ff::Field + SerdeCoefficients> SerdeCoefficients for CubicExtField<F> {
+    type Base = F::Base;
+    const DEGREE: usize = 3 * F::DEGREE;
+
+    fn write_coefficients(&self, out: &mut Vec<Self::Base>) {
+        self.c0.write_coefficients(out);
+        self.c1.write_coefficients(out);
+        self.c2.write_coefficients(out);
+    }
+
+    fn read_coefficients<I: Iterator<Item = Self::Base>>(coeffs: &mut I) -> Option<Self> {
+        let c0 = F::read_coefficients(coeffs)?;
+        let c1 = F::read_coefficients(coeffs)?;
+        let c2 = F::read_coefficients(coeffs)?;
+        Some(CubicExtField::new(c0, c1, c2))
+    }
+}
+
+fn serialize_coefficients<T: SerdeCoefficients, S: Serializer>(
+    el: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let mut coeffs = Vec::with_capacity(T::DEGREE);
+    el.write_coefficients(&mut coeffs);
+    serializer.collect_seq(coeffs)
+}
+
+// Accepts both the flat array of coefficients and the struct form `R`.
+struct HumanReadableVisitor<T, R>(PhantomData<(T, R)>);
+
+impl<'de, T, R> Visitor<'de> for HumanReadableVisitor<T, R>
+where
+    T: SerdeCoefficients,
+    R: Deserialize<'de> + Into<T>,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "an array of {} prime field elements", T::DEGREE)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<T, A::Error> {
+        let mut coeffs = Vec::with_capacity(T::DEGREE);
+        for i in 0..T::DEGREE {
+            coeffs.push(
+                seq.next_element::<T::Base>()?
+                    .ok_or_else(|| A::Error::invalid_length(i, &self))?,
+            );
+        }
+        if seq.next_element::<IgnoredAny>()?.is_some() {
+            return Err(A::Error::invalid_length(T::DEGREE + 1, &self));
+        }
+        // All the coefficients are there, so the read cannot fail.
+        Ok(T::read_coefficients(&mut coeffs.into_iter()).unwrap())
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<T, A::Error> {
+        R::deserialize(MapAccessDeserializer::new(map)).map(Into::into)
+    }
+}
+
+impl<F: ff::Field + SerdeCoefficients + Serialize> Serialize for QuadExtField<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serialize_coefficients(self, serializer)
+        } else {
+            QuadRepr {
+                c0: self.c0,
+                c1: self.c1,
+            }
+            .serialize(serializer)
+        }
+    }
+}
+
+impl<'de, F: ff::Field + SerdeCoefficients + Deserialize<'de>> Deserialize<'de>
+    for QuadExtField<F>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(HumanReadableVisitor::<Self, QuadRepr<F>>(PhantomData))
+        } else {
+            QuadRepr::deserialize(deserializer).map(Into::into)
+        }
+    }
+}
+
+impl<F: ff::Field + SerdeCoefficients + Serialize> Serialize for CubicExtField<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serialize_coefficients(self, serializer)
+        } else {
+            CubicRepr {
+                c0: self.c0,
+                c1: self.c1,
+                c2: self.c2,
+            }
+            .serialize(serializer)
+        }
+    }
+}
+
+impl<'de, F: ff::Field + SerdeCoefficients + Deserialize<'de>> Deserialize<'de>
+    for CubicExtField<F>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(HumanReadableVisitor::<Self, CubicRepr<F>>(PhantomData))
+        } else {
+            CubicRepr::deserialize(deserializer).map(Into::into)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CubicRepr, QuadRepr, SerdeCoefficients};
+    use ff::Field;
+    use rand_core::OsRng;
+    use serde::de::DeserializeOwned;
+    use serde::Serialize;
+
+    fn run_serde_test<F>()
+    where
+        F: Field + SerdeCoefficients + Serialize + DeserializeOwned,
+    {
+        for _ in 0..20 {
+            let a = F::random(OsRng);
+
+            let bytes = bincode::serialize(&a).unwrap();
+            assert_eq!(bincode::deserialize::<F>(&bytes).unwrap(), a);
+
+            // A flat array of hex strings, one per prime field coefficient.
+            let json = serde_json::to_string(&a).unwrap();
+            let mut coeffs = Vec::new();
+            a.write_coefficients(&mut coeffs);
+            assert_eq!(coeffs.len(), F::DEGREE);
+            assert_eq!(json, serde_json::to_string(&coeffs).unwrap());
+            assert_eq!(serde_json::from_str::<F>(&json).unwrap(), a);
+
+            // Wrong number of coefficients.
+            let short = serde_json::to_string(&coeffs[1..]).unwrap();
+            assert!(serde_json::from_str::<F>(&short).is_err());
+            coeffs.push(coeffs[0]);
+            let long = serde_json::to_string(&coeffs).unwrap();
+            assert!(serde_json::from_str::<F>(&long).is_err());
+        }
+    }
+
+    #[test]
+    fn test_serde_coefficients() {
+        use crate::bn256::{Fq12, Fq2, Fq6};
+        use crate::pluto_eris::{Fp12, Fp2, Fp6};
+
+        run_serde_test::<Fq2>();
+        run_serde_test::<Fq6>();
+        run_serde_test::<Fq12>();
+        run_serde_test::<Fp2>();
+        run_serde_test::<Fp6>();
+        run_serde_test::<Fp12>();
+    }
+
+    #[test]
+    fn test_serde_struct_form() {
+        use crate::bn256::{Fq12, Fq6};
+
+        // Nested struct form, with the inner elements in either form.
+        let a = Fq12::random(OsRng);
+        let structs = |f: &Fq6| CubicRepr {
+            c0: QuadRepr {
+                c0: f.c0.c0,
+                c1: f.c0.c1,
+            },
+            c1: QuadRepr {
+                c0: f.c1.c0,
+                c1: f.c1.c1,
+            },
+            c2: QuadRepr {
+                c0: f.c2.c0,
+                c1: f.c2.c1,
+            },
+        };
+        let nested = QuadRepr {
+            c0: structs(&a.c0),
+            c1: structs(&a.c1),
+        };
+        let json = serde_json::to_string(&nested).unwrap();
+        assert_eq!(serde_json::from_str::<Fq12>(&json).unwrap(), a);
+
+        let mixed = QuadRepr { c0: a.c0, c1: a.c1 };
+        let json = serde_json::to_string(&mixed).unwrap();
+        assert_eq!(serde_json::from_str::<Fq12>(&json).unwrap(), a);
+    }
+}
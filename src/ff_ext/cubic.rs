@@ -1,15 +1,98 @@
-use super::ExtField;
+use core::fmt;
 
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+use super::{ExtField, Legendre, MulByRoot};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
 pub struct CubicExtField<F: ff::Field> {
     pub(crate) c0: F,
     pub(crate) c1: F,
     pub(crate) c2: F,
 }
 
+#[cfg(feature = "zeroize")]
+impl<F: ff::Field + crate::zeroize::DefaultIsZeroes> crate::zeroize::DefaultIsZeroes
+    for CubicExtField<F>
+{
+}
+
+/// Compares the coefficients from the highest one down.
+impl<F: ff::Field + Ord> Ord for CubicExtField<F> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.c2
+            .cmp(&other.c2)
+            .then_with(|| self.c1.cmp(&other.c1))
+            .then_with(|| self.c0.cmp(&other.c0))
+    }
+}
+
+impl<F: ff::Field + Ord> PartialOrd for CubicExtField<F> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The tuple of the coefficients, from `c0` up.
+impl<F: ff::Field + fmt::Display> fmt::Display for CubicExtField<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        fmt::Display::fmt(&self.c0, f)?;
+        write!(f, ", ")?;
+        fmt::Display::fmt(&self.c1, f)?;
+        write!(f, ", ")?;
+        fmt::Display::fmt(&self.c2, f)?;
+        write!(f, ")")
+    }
+}
+
+/// The tuple of the coefficients, from `c0` up, in hexadecimal.
+impl<F: ff::Field + fmt::LowerHex> fmt::LowerHex for CubicExtField<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        fmt::LowerHex::fmt(&self.c0, f)?;
+        write!(f, ", ")?;
+        fmt::LowerHex::fmt(&self.c1, f)?;
+        write!(f, ", ")?;
+        fmt::LowerHex::fmt(&self.c2, f)?;
+        write!(f, ")")
+    }
+}
+
+/// Multiplication by sparse elements of `Base[v] / (v^3 - ξ)`. The suffix lists the
+/// non-zero coefficients of the right hand side.
 pub trait CubicSparseMul {
     type Base: ExtField;
 
+    fn mul_by_0(lhs: &CubicExtField<Self::Base>, c0: &Self::Base) -> CubicExtField<Self::Base> {
+        CubicExtField {
+            c0: lhs.c0 * c0,
+            c1: lhs.c1 * c0,
+            c2: lhs.c2 * c0,
+        }
+    }
+
+    fn mul_by_2(lhs: &CubicExtField<Self::Base>, c2: &Self::Base) -> CubicExtField<Self::Base> {
+        CubicExtField {
+            c0: (lhs.c1 * c2).mul_by_nonresidue(),
+            c1: (lhs.c2 * c2).mul_by_nonresidue(),
+            c2: lhs.c0 * c2,
+        }
+    }
+
+    fn mul_by_12(
+        lhs: &CubicExtField<Self::Base>,
+        c1: &Self::Base,
+        c2: &Self::Base,
+    ) -> CubicExtField<Self::Base> {
+        // (c1 v + c2 v^2) = v (c1 + c2 v)
+        let t = Self::mul_by_01(lhs, c1, c2);
+        CubicExtField {
+            c0: t.c2.mul_by_nonresidue(),
+            c1: t.c0,
+            c2: t.c1,
+        }
+    }
+
     fn mul_by_1(lhs: &CubicExtField<Self::Base>, c1: &Self::Base) -> CubicExtField<Self::Base> {
         let b_b = lhs.c1 * c1;
 
@@ -45,8 +128,83 @@ pub trait CubicSparseMul {
     }
 }
 
+// Square roots in `F_{q^3}` for a quadratic base `F_q`, `q = p^2`, from the square root of
+// the norm: with `r = 1 + q + q^2` and `b = a^((r - 1) / 2)`, `N(a) = a^r = a b^2`, so that
+// `sqrt(a) = sqrt(N(a)) / b`. Since `(r - 1) / 2 = q (1 + p^2) / 2` and
+// `(1 + p^2) / 2 = (p + 1) (p - 1) / 2 + 1`, `b` only takes an exponentiation by `(p - 1) / 2`
+// and Frobenius maps.
+pub fn sqrt_norm<F: ExtField, S: AsRef<[u64]>>(
+    el: &CubicExtField<F>,
+    p_minus_1_over_2: S,
+) -> subtle::CtOption<CubicExtField<F>>
+where
+    CubicExtField<F>: CubicExtFieldArith<Base = F> + ExtField,
+{
+    use ff::Field;
+
+    if el.is_zero().into() {
+        return subtle::CtOption::new(CubicExtField::ZERO, subtle::Choice::from(1));
+    }
+
+    // c = a^((p - 1) / 2), b = (c^(p + 1) a)^q
+    let c = el.pow_vartime(p_minus_1_over_2);
+    let mut b = c;
+    b.frobenius_map_const::<1>();
+    b = b * c * el;
+    b.frobenius_map_const::<2>();
+
+    let b_inv = b.invert().unwrap();
+    el.norm().sqrt().map(|s| CubicExtField {
+        c0: b_inv.c0 * s,
+        c1: b_inv.c1 * s,
+        c2: b_inv.c2 * s,
+    })
+}
+
+// Square roots in `F_{p^3}` for a prime base `F_p`, as in [`sqrt_norm`]: here
+// `(r - 1) / 2 = p (p + 1) / 2`, so that `b = (a^((p + 1) / 2))^p`.
+pub fn sqrt_norm_prime<F: ExtField, S: AsRef<[u64]>>(
+    el: &CubicExtField<F>,
+    p_plus_1_over_2: S,
+) -> subtle::CtOption<CubicExtField<F>>
+where
+    CubicExtField<F>: CubicExtFieldArith<Base = F> + ExtField,
+{
+    use ff::Field;
+
+    if el.is_zero().into() {
+        return subtle::CtOption::new(CubicExtField::ZERO, subtle::Choice::from(1));
+    }
+
+    let mut b = el.pow_vartime(p_plus_1_over_2);
+    b.frobenius_map_const::<1>();
+
+    let b_inv = b.invert().unwrap();
+    el.norm().sqrt().map(|s| CubicExtField {
+        c0: b_inv.c0 * s,
+        c1: b_inv.c1 * s,
+        c2: b_inv.c2 * s,
+    })
+}
+
+pub enum SQRT {
+    /// See [`sqrt_norm`].
+    Norm {
+        p_minus_1_over_2: &'static [u64],
+    },
+    /// See [`sqrt_norm_prime`].
+    PrimeNorm {
+        p_plus_1_over_2: &'static [u64],
+    },
+    Unimplemented,
+}
+
 pub trait CubicExtFieldArith {
     type Base: ExtField;
+    const SQRT: SQRT = SQRT::Unimplemented;
+    /// A non-square of the field, of the form `v + i` for the smallest `i`.
+    /// `sqrt_ratio` multiplies the ratios that aren't squares by it.
+    const NON_SQUARE: CubicExtField<Self::Base>;
 
     fn mul_assign(lhs: &mut CubicExtField<Self::Base>, rhs: &CubicExtField<Self::Base>) {
         let a_a = lhs.c0 * rhs.c0;
@@ -168,6 +326,42 @@ where
     }
 }
 
+impl<F: ExtField> CubicExtField<F>
+where
+    Self: CubicExtFieldArith<Base = F>,
+{
+    /// Norm of the element over the base field, `N(a) = a * a^q * a^(q^2)`.
+    pub fn norm(&self) -> F {
+        // N(c0 + c1 v + c2 v^2) = c0^3 + ξ c1^3 + ξ^2 c2^3 - 3 ξ c0 c1 c2, where v^3 = ξ
+        let c0_3 = self.c0.square() * self.c0;
+        let c1_3 = self.c1.square() * self.c1;
+        let c2_3 = self.c2.square() * self.c2;
+        let c012 = self.c0 * self.c1 * self.c2;
+        let t = c1_3 - c012.double() - c012 + c2_3.mul_by_nonresidue();
+        c0_3 + t.mul_by_nonresidue()
+    }
+}
+
+impl<F: ExtField> MulByRoot for CubicExtField<F> {
+    // (c0 + c1 v + c2 v^2) v = ξ c2 + c0 v + c1 v^2
+    fn mul_by_root(&self) -> Self {
+        Self {
+            c0: self.c2.mul_by_nonresidue(),
+            c1: self.c0,
+            c2: self.c1,
+        }
+    }
+}
+
+impl<F: ExtField + Legendre> Legendre for CubicExtField<F>
+where
+    Self: CubicExtFieldArith<Base = F>,
+{
+    fn legendre(&self) -> i64 {
+        self.norm().legendre()
+    }
+}
+
 impl<F: ExtField> ff::Field for CubicExtField<F>
 where
     CubicExtField<F>: CubicExtFieldArith<Base = F> + ExtField, // kind of cyclic being `ExtField: Field` but it seems alright
@@ -184,7 +378,7 @@ where
     }
 
     fn is_zero(&self) -> subtle::Choice {
-        self.c0.is_zero() & self.c1.is_zero()
+        self.c0.is_zero() & self.c1.is_zero() & self.c2.is_zero()
     }
 
     fn square(&self) -> Self {
@@ -196,11 +390,15 @@ where
     }
 
     fn sqrt(&self) -> subtle::CtOption<Self> {
-        unimplemented!()
+        match Self::SQRT {
+            SQRT::Norm { p_minus_1_over_2 } => sqrt_norm(self, p_minus_1_over_2),
+            SQRT::PrimeNorm { p_plus_1_over_2 } => sqrt_norm_prime(self, p_plus_1_over_2),
+            SQRT::Unimplemented => unimplemented!(),
+        }
     }
 
-    fn sqrt_ratio(_: &Self, _: &Self) -> (subtle::Choice, Self) {
-        unimplemented!()
+    fn sqrt_ratio(num: &Self, div: &Self) -> (subtle::Choice, Self) {
+        super::sqrt_ratio(num, div, &Self::NON_SQUARE)
     }
 
     fn invert(&self) -> subtle::CtOption<Self> {
@@ -1,5 +1,6 @@
 use core::cmp::PartialEq;
-use std::ops::{Add, Mul, Neg, Sub};
+use core::ops::{Add, Mul, Neg, Sub};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
 /// Big signed (B * L)-bit integer type, whose variables store
 /// numbers in the two's complement code as arrays of B-bit chunks.
@@ -29,9 +30,7 @@ impl<const B: usize, const L: usize> CInt<B, L> {
     /// arithmetical shift to the current number
     pub fn shift(&self) -> Self {
         let mut data = [0; L];
-        if self.is_negative() {
-            data[L - 1] = Self::MASK;
-        }
+        data[L - 1] = Self::MASK & (self.is_negative() as u64).wrapping_neg();
         data[..L - 1].copy_from_slice(&self.0[1..]);
         Self(data)
     }
@@ -45,6 +44,15 @@ impl<const B: usize, const L: usize> CInt<B, L> {
     pub fn is_negative(&self) -> bool {
         self.0[L - 1] > (Self::MASK >> 1)
     }
+
+    /// Returns "a" if "choice" is 0 and "b" if it is 1, in constant time
+    pub fn select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut data = [0; L];
+        for (i, d) in data.iter_mut().enumerate() {
+            *d = u64::conditional_select(&a.0[i], &b.0[i], choice);
+        }
+        Self(data)
+    }
 }
 
 impl<const B: usize, const L: usize> PartialEq for CInt<B, L> {
@@ -184,11 +192,11 @@ impl<const B: usize, const L: usize> Mul<i64> for &CInt<B, L> {
         // for y and turns into the modified standard multiplication algorithm,
         // where the carry flag is initialized with the additively negated short
         // multiplicand and the chunks of the long multiplicand are bitwise inverted
-        let (other, mut carry, mask) = if other < 0 {
-            (-other, -other as u64, CInt::<B, L>::MASK)
-        } else {
-            (other, 0, 0)
-        };
+        // The sign of the short multiplicand is turned into masks rather than
+        // branched on, since the constant time inversion multiplies by secret values
+        let sign = (other >> 63) as u64;
+        let other = (other as u64 ^ sign).wrapping_sub(sign);
+        let (mut carry, mask) = (other & sign, CInt::<B, L>::MASK & sign);
         for (i, d) in data.iter_mut().enumerate().take(L) {
             let sum = (carry as u128) + ((self.0[i] ^ mask) as u128) * (other as u128);
             *d = sum as u64 & CInt::<B, L>::MASK;
@@ -251,6 +259,9 @@ pub struct BYInverter<const L: usize> {
 
     /// Multiplicative inverse of the modulus modulo 2^62
     inverse: i64,
+
+    /// Number of jumps of 62 steps that the constant time inversion performs
+    jumps: usize,
 }
 
 /// Type of the Bernstein-Yang transition matrix multiplied by 2^62
@@ -290,6 +301,42 @@ impl<const L: usize> BYInverter<L> {
         (delta, t)
     }
 
+    /// Returns the Bernstein-Yang transition matrix multiplied by 2^62 and the new value
+    /// of the delta variable for 62 divsteps, like "jump", but in constant time: every
+    /// divstep runs the same operations, with the branches replaced by masks. The
+    /// divstep is the one of the Bernstein-Yang paper, i.e. if delta > 0 and g is odd,
+    /// (delta, f, g) becomes (1 - delta, g, (g - f) / 2), and otherwise it becomes
+    /// (1 + delta, f, (g + (g mod 2) * f) / 2)
+    fn jump_ct(f: &CInt<62, L>, g: &CInt<62, L>, mut delta: i64) -> (i64, Matrix) {
+        // The 62 lowest bits of f and g determine the matrix
+        let (mut f, mut g) = (f.lowest(), g.lowest());
+        let (mut u, mut v, mut q, mut r) = (1i64, 0i64, 0i64, 1i64);
+
+        for _ in 0..62 {
+            // All ones if g is odd, and if the divstep swaps f and g, respectively
+            let odd = (g & 1).wrapping_neg();
+            let swap = (delta.wrapping_neg() >> 63) as u64 & odd;
+            let (odd_i, swap_i) = (odd as i64, swap as i64);
+
+            delta = ((delta ^ swap_i) - swap_i) + 1;
+            let (x, xu, xv) = (
+                (f ^ swap).wrapping_sub(swap),
+                (u ^ swap_i) - swap_i,
+                (v ^ swap_i) - swap_i,
+            );
+            f ^= (f ^ g) & swap;
+            u ^= (u ^ q) & swap_i;
+            v ^= (v ^ r) & swap_i;
+            g = g.wrapping_add(x & odd) >> 1;
+            q += xu & odd_i;
+            r += xv & odd_i;
+            u <<= 1;
+            v <<= 1;
+        }
+
+        (delta, [[u, v], [q, r]])
+    }
+
     /// Returns the updated values of the variables f and g for specified initial ones and Bernstein-Yang transition
     /// matrix multiplied by 2^62. The returned vector is "matrix * (f, g)' / 2^62", where "'" is the transpose operator
     fn fg(f: CInt<62, L>, g: CInt<62, L>, t: Matrix) -> (CInt<62, L>, CInt<62, L>) {
@@ -345,6 +392,22 @@ impl<const L: usize> BYInverter<L> {
         value
     }
 
+    /// Same as "norm", in constant time
+    fn norm_ct(&self, value: CInt<62, L>, negate: Choice) -> CInt<62, L> {
+        let zero = CInt::ZERO;
+        let value = CInt::select(
+            &zero,
+            &self.modulus,
+            Choice::from(value.is_negative() as u8),
+        ) + value;
+        let value = CInt::select(&value, &-&value, negate);
+        CInt::select(
+            &zero,
+            &self.modulus,
+            Choice::from(value.is_negative() as u8),
+        ) + value
+    }
+
     /// Returns a big unsigned integer as an array of O-bit chunks, which is equal modulo
     /// 2 ^ (O * S) to the input big unsigned integer stored as an array of I-bit chunks.
     /// The ordering of the chunks in these arrays is little-endian
@@ -391,12 +454,31 @@ impl<const L: usize> BYInverter<L> {
         (x.wrapping_mul(y.wrapping_add(1)) & CInt::<62, L>::MASK) as i64
     }
 
+    /// Returns the number of jumps of 62 divsteps after which g is zero for any modulus
+    /// of the bit length of "modulus" and any smaller non-negative integer, following
+    /// the bound (49 * d + 57) / 17 on the number of divsteps of theorem 11.2 of the
+    /// Bernstein-Yang paper for d >= 46 bits, which also holds for fewer bits
+    const fn jumps(modulus: &[u64]) -> usize {
+        let mut bits = modulus.len() * 64;
+        let mut i = modulus.len();
+        while i > 0 && modulus[i - 1] == 0 {
+            bits -= 64;
+            i -= 1;
+        }
+        if i > 0 {
+            bits -= modulus[i - 1].leading_zeros() as usize;
+        }
+        let bits = if bits < 46 { 46 } else { bits };
+        ((49 * bits + 57) / 17 + 61) / 62
+    }
+
     /// Creates the inverter for specified modulus and adjusting parameter
     pub const fn new(modulus: &[u64], adjuster: &[u64]) -> Self {
         Self {
             modulus: CInt::<62, L>(Self::convert::<64, 62, L>(modulus)),
             adjuster: CInt::<62, L>(Self::convert::<64, 62, L>(adjuster)),
             inverse: Self::inv(modulus[0]),
+            jumps: Self::jumps(modulus),
         }
     }
 
@@ -421,4 +503,29 @@ impl<const L: usize> BYInverter<L> {
         }
         Some(Self::convert::<62, 64, S>(&self.norm(d, antiunit).0))
     }
+
+    /// Returns the adjusted modular multiplicative inverse for the argument, like
+    /// "invert", but in constant time: a fixed number of jumps of constant time
+    /// divsteps is performed, enough for g to reach zero for any argument smaller
+    /// than the modulus, and the result is selected without branches. The argument
+    /// must be smaller than the modulus. Returns the inverse and whether the argument
+    /// is invertible, the inverse being meaningless otherwise
+    pub fn invert_ct<const S: usize>(&self, value: &[u64]) -> ([u64; S], Choice) {
+        let (mut d, mut e) = (CInt::ZERO, self.adjuster.clone());
+        let mut g = CInt::<62, L>(Self::convert::<64, 62, L>(value));
+        let (mut delta, mut f) = (1, self.modulus.clone());
+        let mut matrix;
+        for _ in 0..self.jumps {
+            (delta, matrix) = Self::jump_ct(&f, &g, delta);
+            (f, g) = Self::fg(f, g, matrix);
+            (d, e) = self.de(d, e, matrix);
+        }
+        // As in "invert", "f" is now the greatest common divisor up to its sign
+        let unit = f.0.ct_eq(&CInt::<62, L>::ONE.0);
+        let antiunit = f.0.ct_eq(&CInt::<62, L>::MINUS_ONE.0);
+        (
+            Self::convert::<62, 64, S>(&self.norm_ct(d, antiunit).0),
+            unit | antiunit,
+        )
+    }
 }
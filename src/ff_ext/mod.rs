@@ -1,12 +1,35 @@
+#[cfg(feature = "derive_serde")]
+mod coefficients;
 pub mod cubic;
 pub mod inverse;
 pub mod jacobi;
 pub mod quadratic;
+#[cfg(feature = "precompute")]
+pub mod sqrt_table;
 use subtle::{Choice, ConstantTimeEq};
 
+#[cfg(feature = "derive_serde")]
+pub use coefficients::SerdeCoefficients;
+
+/// Quadratic residuosity of field elements.
+///
+/// For a prime field `F_p` the Legendre symbol of `a` is `a^((p-1)/2)`.
+///
+/// For an extension `F_{q^k}` of `F_q` an element `a` is a square if and only if
+/// its norm `N(a) = a^((q^k-1)/(q-1))` is a square in `F_q`, since
+/// `a^((q^k-1)/2) = N(a)^((q-1)/2)`. The symbol of extension field elements is
+/// therefore computed as the symbol of their norm in the base field.
 pub trait Legendre {
+    /// Returns `1` if the element is a non-zero square, `-1` if it is not a square
+    /// and `0` if it is zero.
     fn legendre(&self) -> i64;
 
+    /// Returns `Choice(1)` if the element is a square. Zero is considered a square.
+    #[inline(always)]
+    fn is_quadratic_residue(&self) -> Choice {
+        self.ct_quadratic_residue()
+    }
+
     #[inline(always)]
     fn ct_quadratic_non_residue(&self) -> Choice {
         self.legendre().ct_eq(&-1)
@@ -33,10 +56,172 @@ macro_rules! extend_field_legendre {
     };
 }
 
+/// A field of a tower of extensions `Self = BaseField[X]/(X^k - β)`, `β` being
+/// `BaseField::NON_RESIDUE`. The prime fields are the bottom of the towers, their
+/// own base field of degree 1.
 pub trait ExtField: ff::Field {
+    /// The field below in the tower, `Self` for prime fields.
+    type BaseField: ExtField;
+
+    /// The non-residue defining the extension above in the tower.
     const NON_RESIDUE: Self;
     fn mul_by_nonresidue(&self) -> Self {
         Self::NON_RESIDUE * self
     }
     fn frobenius_map(&mut self, power: usize);
+
+    /// The norm of the element over [`ExtField::BaseField`], the product of its
+    /// conjugates `a * a^q * ... * a^(q^(k-1))`, with `q` the order of the base.
+    fn norm(&self) -> Self::BaseField;
+
+    /// [`ExtField::frobenius_map`] for a power known at compile time. The coefficients
+    /// are selected without reducing the power at runtime, and the powers that are
+    /// multiples of the degree are skipped.
+    #[inline(always)]
+    fn frobenius_map_const<const POWER: usize>(&mut self) {
+        self.frobenius_map(POWER);
+    }
+}
+
+/// Exponentiation by secret exponents, for every field of the crate and their
+/// extensions. Exponentiation by public exponents is `ff::Field::pow_vartime`.
+pub trait Pow: ff::Field {
+    /// Returns `self^exp`, the exponent given by its `u64` limbs in little endian.
+    ///
+    /// The exponent is processed in fixed 4-bit windows: each window costs four
+    /// squarings and one multiplication by an entry of the table `self^0, ...,
+    /// self^15`, selected by scanning the whole table. This runs in constant time
+    /// with respect to the value of the exponent, but not its number of limbs.
+    fn pow_ct(&self, exp: &[u64]) -> Self {
+        let mut table = [Self::ONE; 16];
+        for i in 1..16 {
+            table[i] = table[i - 1] * self;
+        }
+
+        let mut acc = Self::ONE;
+        for limb in exp.iter().rev() {
+            for i in (0..16).rev() {
+                acc = acc.square().square().square().square();
+                let window = (limb >> (4 * i)) & 0xf;
+                let mut entry = Self::ONE;
+                for (j, t) in table.iter().enumerate() {
+                    entry.conditional_assign(t, (j as u64).ct_eq(&window));
+                }
+                acc *= entry;
+            }
+        }
+        acc
+    }
+
+    /// Returns `self^exp` in constant time, see [`Pow::pow_ct`].
+    fn pow_u64(&self, exp: u64) -> Self {
+        self.pow_ct(&[exp])
+    }
+}
+
+impl<F: ff::Field> Pow for F {}
+
+/// Multiplication by the root `X` of the defining polynomial of an extension
+/// `F[X]/(X^k - β)`, `β` being `F::NON_RESIDUE`. It only rotates the coefficients and
+/// multiplies one of them by `β`. In the `Fq2 = Fq[u]`, `Fq6 = Fq2[v]`, `Fq12 = Fq6[w]`
+/// towers these are the multiplications by `u`, `v` and `w`. Along with
+/// [`ExtField::mul_by_nonresidue`] they let external implementations of the tower check
+/// each step against native results.
+pub trait MulByRoot {
+    fn mul_by_root(&self) -> Self;
+}
+
+// `Field::sqrt_ratio` of the extensions from their square roots, `non_square` being the
+// non-square by which the ratios that aren't squares are multiplied.
+pub(crate) fn sqrt_ratio<F: ff::Field>(num: &F, div: &F, non_square: &F) -> (Choice, F) {
+    if bool::from(num.is_zero()) {
+        return (Choice::from(1), F::ZERO);
+    }
+    let ratio = match Option::<F>::from(div.invert()) {
+        Some(div_inv) => *num * div_inv,
+        None => return (Choice::from(0), F::ZERO),
+    };
+    match Option::<F>::from(ratio.sqrt()) {
+        Some(root) => (Choice::from(1), root),
+        None => (Choice::from(0), (ratio * non_square).sqrt().unwrap()),
+    }
+}
+
+/// Deterministic sampling of field elements, for reproducible test vectors and
+/// protocol nonces.
+///
+/// `F::random_seeded(domain, counter)` is the element `hash_to_field(msg, 1)` of
+/// [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380#section-5.2) with
+/// `expand_message_xmd` and SHA-256, the domain separation tag `domain` and the
+/// message `msg` the 8 big-endian bytes of `counter`. The expanded message of
+/// [`RandomSeeded::UNIFORM_BYTES`] bytes is read as a big-endian integer and
+/// reduced modulo the characteristic, so other implementations of the RFC derive
+/// the same elements.
+pub trait RandomSeeded: ff::Field {
+    /// The length `L` of the expanded message reduced into an element, at least
+    /// `ceil((ceil(log2 p) + 128) / 8)` bytes for the 128-bit security of the RFC.
+    /// Fields of up to 256 bits round it up to the 64 bytes of their
+    /// [`ff::FromUniformBytes`] reduction.
+    const UNIFORM_BYTES: usize;
+
+    /// Returns the element of index `counter` of the stream of `domain`.
+    /// Domains longer than 255 bytes are hashed as in the RFC.
+    fn random_seeded(domain: &[u8], counter: u64) -> Self {
+        Self::hash_to_field(domain, &counter.to_be_bytes(), 1)[0]
+    }
+
+    /// Returns the `count` elements `hash_to_field(msg, count)` of the RFC,
+    /// under the domain separation tag `domain`. See [`hash_to_field`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count * UNIFORM_BYTES` is larger than 65535, the longest
+    /// expanded message.
+    fn hash_to_field(domain: &[u8], msg: &[u8], count: usize) -> alloc::vec::Vec<Self>;
+}
+
+/// Derives `count` field elements from `msg` with the `hash_to_field` function
+/// of [RFC 9380](https://www.rfc-editor.org/rfc/rfc9380#section-5.2), using
+/// `expand_message_xmd` with SHA-256 and the domain separation tag `domain`.
+///
+/// The elements are uniform and independent as long as SHA-256 is modeled as a
+/// random oracle, which makes them suitable for Fiat-Shamir challenges and
+/// deterministic nonces, unlike the reduction of a single hash output.
+///
+/// ```
+/// use halo2curves::bn256::Fr;
+/// use halo2curves::ff_ext::hash_to_field;
+///
+/// let challenges = hash_to_field::<Fr>(b"my-protocol-v1", b"transcript", 2);
+/// assert_ne!(challenges[0], challenges[1]);
+/// ```
+pub fn hash_to_field<F: RandomSeeded>(
+    domain: &[u8],
+    msg: &[u8],
+    count: usize,
+) -> alloc::vec::Vec<F> {
+    F::hash_to_field(domain, msg, count)
+}
+
+pub(crate) fn hash_to_field_xmd<F: ff::FromUniformBytes<L>, const L: usize>(
+    domain: &[u8],
+    msg: &[u8],
+    count: usize,
+) -> alloc::vec::Vec<F> {
+    use crate::hash_to_curve::{expand_to_field, ExpandMsgXmd};
+
+    expand_to_field::<F, ExpandMsgXmd<sha2::Sha256>, L>(&[], domain, msg, count)
+}
+
+#[macro_export]
+macro_rules! impl_random_seeded {
+    ($field:ident, $len:expr) => {
+        impl $crate::ff_ext::RandomSeeded for $field {
+            const UNIFORM_BYTES: usize = $len;
+
+            fn hash_to_field(domain: &[u8], msg: &[u8], count: usize) -> alloc::vec::Vec<Self> {
+                $crate::ff_ext::hash_to_field_xmd::<Self, $len>(domain, msg, count)
+            }
+        }
+    };
 }
@@ -1,14 +1,52 @@
+use core::fmt;
+
 use ff::Field;
-use subtle::{Choice, CtOption};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 use super::{
     cubic::{CubicExtField, CubicSparseMul},
-    ExtField,
+    ExtField, Legendre, MulByRoot,
 };
 
+/// The type of a sextic twist `E'` of a curve `E: y^2 = x^3 + b` over the
+/// quadratic extension, for the non-residue `ξ` of the extension tower.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TwistType {
+    /// Divisive twist, `E': y^2 = x^3 + b/ξ`.
+    D,
+    /// Multiplicative twist, `E': y^2 = x^3 + b ξ`.
+    M,
+}
+
+/// Multiplication of degree 12 extensions by line evaluations. The suffix lists the
+/// non-zero coefficients of the right hand side over the degree 2 base, `(c0, c1, c2)` of
+/// the first cubic half followed by `(c3, c4, c5)` of the second one. Lines on M-type
+/// twists have the `014` shape, lines on D-type twists the `034` shape.
 pub trait QuadSparseMul {
     type Base: ExtField;
 
+    /// The type of the twist of the `G2` whose lines are multiplied, which selects
+    /// the shape of [`QuadSparseMul::mul_by_line`].
+    const TWIST_TYPE: TwistType;
+
+    /// Multiplies `lhs` by a line evaluated at `(x, y)`, given as its coefficients
+    /// `c_y` and `c_x` already scaled by `y` and `x`, and its constant coefficient
+    /// `c`: [`QuadSparseMul::mul_by_034`] by `(c_y, c_x, c)` on a D-type twist,
+    /// [`QuadSparseMul::mul_by_014`] by `(c, c_x, c_y)` on an M-type twist.
+    fn mul_by_line(
+        lhs: &mut QuadExtField<CubicExtField<Self::Base>>,
+        c_y: &Self::Base,
+        c_x: &Self::Base,
+        c: &Self::Base,
+    ) where
+        CubicExtField<Self::Base>: CubicSparseMul<Base = Self::Base> + ExtField,
+    {
+        match Self::TWIST_TYPE {
+            TwistType::D => Self::mul_by_034(lhs, c_y, c_x, c),
+            TwistType::M => Self::mul_by_014(lhs, c, c_x, c_y),
+        }
+    }
+
     fn mul_by_014(
         lhs: &mut QuadExtField<CubicExtField<Self::Base>>,
         c0: &Self::Base,
@@ -17,6 +55,9 @@ pub trait QuadSparseMul {
     ) where
         CubicExtField<Self::Base>: CubicSparseMul<Base = Self::Base> + ExtField,
     {
+        #[cfg(test)]
+        crate::tests::op_count::record(crate::tests::op_count::Op::SparseMul);
+
         let aa = CubicExtField::mul_by_01(&lhs.c0, c0, c1);
         let bb = CubicExtField::mul_by_1(&lhs.c1, c4);
         let t0 = &(lhs.c1 + lhs.c0);
@@ -33,11 +74,10 @@ pub trait QuadSparseMul {
     ) where
         CubicExtField<Self::Base>: CubicSparseMul<Base = Self::Base> + ExtField,
     {
-        let t0 = CubicExtField {
-            c0: lhs.c0.c0 * c0,
-            c1: lhs.c0.c1 * c0,
-            c2: lhs.c0.c2 * c0,
-        };
+        #[cfg(test)]
+        crate::tests::op_count::record(crate::tests::op_count::Op::SparseMul);
+
+        let t0 = CubicExtField::mul_by_0(&lhs.c0, c0);
         let t1 = CubicExtField::mul_by_01(&lhs.c1, c3, c4);
         let t2 = lhs.c0 + lhs.c1;
         let t3 = *c0 + c3;
@@ -46,7 +86,10 @@ pub trait QuadSparseMul {
     }
 }
 
-// Algorithm 9 of https://eprint.iacr.org/2012/685.pdf
+// Algorithm 9 of https://eprint.iacr.org/2012/685.pdf, for q ≡ 3 mod 4.
+//
+// Constant time: both branches of the algorithm are computed and the result is selected,
+// the exponents are public and zero goes through the same operations, with `a0 = 0`.
 pub fn sqrt_algo9<F: ExtField, S: AsRef<[u64]>>(
     e: &QuadExtField<F>,
     q_minus_3_over_4: S,
@@ -55,41 +98,27 @@ pub fn sqrt_algo9<F: ExtField, S: AsRef<[u64]>>(
 where
     QuadExtField<F>: QuadExtFieldArith<Base = F> + ExtField,
 {
-    if e.is_zero().into() {
-        subtle::CtOption::new(QuadExtField::ZERO, subtle::Choice::from(1))
-    } else {
-        let mut a1 = e.pow(q_minus_3_over_4);
+    let a1 = e.pow(q_minus_3_over_4);
 
-        let alpha = a1.square();
-        let alpha = alpha * e;
+    let alpha = a1.square() * e;
 
-        let mut a0 = alpha;
-        a0.frobenius_map(1);
-        let a0 = a0 * alpha;
+    let mut a0 = alpha;
+    a0.frobenius_map_const::<1>();
+    let a0 = a0 * alpha;
 
-        let neg1 = QuadExtField::<F> {
-            c0: F::ZERO - F::ONE,
-            c1: F::ZERO,
-        };
+    let neg1 = QuadExtField::<F> {
+        c0: F::ZERO - F::ONE,
+        c1: F::ZERO,
+    };
 
-        if a0 == neg1 {
-            subtle::CtOption::new(a0, subtle::Choice::from(0))
-        } else {
-            a1.mul_assign(e);
-
-            if alpha == neg1 {
-                a1.mul_assign(&QuadExtField::<F> {
-                    c0: F::ZERO,
-                    c1: F::ONE,
-                });
-            } else {
-                let alpha = alpha + QuadExtField::<F>::ONE;
-                let alpha = alpha.pow(q_minus_1_over_2);
-                a1.mul_assign(&alpha);
-            }
-            subtle::CtOption::new(a1, subtle::Choice::from(1))
-        }
-    }
+    let x0 = a1 * e;
+    // alpha = -1: x = u x0, with u^2 = -1
+    let x_neg1 = x0.mul_by_root();
+    // otherwise: x = (1 + alpha)^((q - 1) / 2) x0
+    let x = (alpha + QuadExtField::<F>::ONE).pow(q_minus_1_over_2) * x0;
+    let x = QuadExtField::conditional_select(&x, &x_neg1, alpha.ct_eq(&neg1));
+
+    CtOption::new(x, !a0.ct_eq(&neg1))
 }
 
 // Algorithm 10 of https://eprint.iacr.org/2012/685.pdf
@@ -106,7 +135,7 @@ where
 
     let b_2 = b.square();
     let mut b_2_q = b_2;
-    b_2_q.frobenius_map(1);
+    b_2_q.frobenius_map_const::<1>();
 
     let a0 = b_2_q * b_2;
     let neg1 = QuadExtField::<F> {
@@ -118,20 +147,60 @@ where
         CtOption::new(a0, Choice::from(0))
     } else {
         let mut x = b;
-        x.frobenius_map(1);
+        x.frobenius_map_const::<1>();
         if x * b == QuadExtField::ONE {
             let x0 = (b_2 * el).c0.sqrt().unwrap();
             x.c0.mul_assign(x0);
             x.c1.mul_assign(x0);
             CtOption::new(x, Choice::from(1))
         } else {
-            let x0 = (b_2 * precompute_f * el).sqrt().unwrap();
-            x *= x0 * precompute_e;
+            // b^2 f a is in the base field, as in the branch above.
+            let x0 = (b_2 * precompute_f * el).c0.sqrt().unwrap();
+            x *= precompute_e;
+            x.c0.mul_assign(x0);
+            x.c1.mul_assign(x0);
             CtOption::new(x, Choice::from(1))
         }
     }
 }
 
+// The complex method: for `a = a0 + a1 u` with `u^2 = β` and `s = sqrt(N(a))` in the base,
+// `x0^2 = (a0 ± s) / 2` and `x1 = a1 / (2 x0)`. It only takes square roots in the base, so
+// it applies to any extension, e.g. `F_{p^12}` over `F_{p^6}` where `q ≡ 1 mod 4`.
+pub fn sqrt_complex<F: ExtField>(el: &QuadExtField<F>) -> subtle::CtOption<QuadExtField<F>>
+where
+    QuadExtField<F>: QuadExtFieldArith<Base = F> + ExtField,
+{
+    let two_inv = F::ONE.double().invert().unwrap();
+
+    if el.c1.is_zero().into() {
+        // a0 is either a square, or β times a square.
+        return match Option::<F>::from(el.c0.sqrt()) {
+            Some(x0) => CtOption::new(QuadExtField::new(x0, F::ZERO), Choice::from(1)),
+            None => {
+                let beta_inv = F::ONE.mul_by_nonresidue().invert().unwrap();
+                (el.c0 * beta_inv)
+                    .sqrt()
+                    .map(|x1| QuadExtField::new(F::ZERO, x1))
+            }
+        };
+    }
+
+    el.norm().sqrt().and_then(|s| {
+        let x0 = ((el.c0 + s) * two_inv).sqrt();
+        let x0 = if x0.is_some().into() {
+            x0
+        } else {
+            ((el.c0 - s) * two_inv).sqrt()
+        };
+        // `map` also runs on the placeholder of `None`.
+        x0.map(|x0| {
+            let x1 = el.c1 * x0.double().invert().unwrap_or(F::ZERO);
+            QuadExtField::new(x0, x1)
+        })
+    })
+}
+
 pub enum SQRT<F: Field> {
     Algorithm9 {
         q_minus_3_over_4: &'static [u64],
@@ -142,14 +211,27 @@ pub enum SQRT<F: Field> {
         precompute_f: QuadExtField<F>,
         q_minus_1_over_4: &'static [u64],
     },
+    /// See [`sqrt_complex`].
+    Complex,
     Unimplemented,
 }
 
 pub trait QuadExtFieldArith {
     type Base: ExtField;
     const SQRT: SQRT<Self::Base> = SQRT::Unimplemented;
+    /// A non-square of the field, of the form `u + i` for the smallest `i`.
+    /// `sqrt_ratio` multiplies the ratios that aren't squares by it.
+    const NON_SQUARE: QuadExtField<Self::Base>;
+    /// Whether the operations on this field are recorded by the test-only
+    /// pairing op counter.
+    #[cfg(test)]
+    const COUNT_OPS: bool = false;
 
     fn mul_assign(lhs: &mut QuadExtField<Self::Base>, rhs: &QuadExtField<Self::Base>) {
+        #[cfg(test)]
+        if Self::COUNT_OPS {
+            crate::tests::op_count::record(crate::tests::op_count::Op::DenseMul);
+        }
         let v0 = lhs.c0 * rhs.c0;
         let v1 = lhs.c1 * rhs.c1;
         lhs.c1 = (lhs.c0 + lhs.c1) * (rhs.c0 + rhs.c1) - (v0 + v1);
@@ -157,6 +239,10 @@ pub trait QuadExtFieldArith {
     }
 
     fn square_assign(el: &mut QuadExtField<Self::Base>) {
+        #[cfg(test)]
+        if Self::COUNT_OPS {
+            crate::tests::op_count::record(crate::tests::op_count::Op::Square);
+        }
         let ab = el.c0 * el.c1;
         let c0c1 = el.c0 + el.c1;
         let c0 = (el.c1.mul_by_nonresidue() + el.c0) * c0c1 - ab;
@@ -165,16 +251,54 @@ pub trait QuadExtFieldArith {
     }
 }
 
-#[cfg(feature = "derive_serde")]
-use serde::{Deserialize, Serialize};
-
-#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
-#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[repr(C)]
 pub struct QuadExtField<F: ff::Field> {
     pub(crate) c0: F,
     pub(crate) c1: F,
 }
 
+#[cfg(feature = "zeroize")]
+impl<F: ff::Field + crate::zeroize::DefaultIsZeroes> crate::zeroize::DefaultIsZeroes
+    for QuadExtField<F>
+{
+}
+
+/// Compares the coefficients from the highest one down.
+impl<F: ff::Field + Ord> Ord for QuadExtField<F> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.c1.cmp(&other.c1).then_with(|| self.c0.cmp(&other.c0))
+    }
+}
+
+impl<F: ff::Field + Ord> PartialOrd for QuadExtField<F> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The tuple of the coefficients, from `c0` up.
+impl<F: ff::Field + fmt::Display> fmt::Display for QuadExtField<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        fmt::Display::fmt(&self.c0, f)?;
+        write!(f, ", ")?;
+        fmt::Display::fmt(&self.c1, f)?;
+        write!(f, ")")
+    }
+}
+
+/// The tuple of the coefficients, from `c0` up, in hexadecimal.
+impl<F: ff::Field + fmt::LowerHex> fmt::LowerHex for QuadExtField<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        fmt::LowerHex::fmt(&self.c0, f)?;
+        write!(f, ", ")?;
+        fmt::LowerHex::fmt(&self.c1, f)?;
+        write!(f, ")")
+    }
+}
+
 impl<F: ff::Field> QuadExtField<F> {
     #[inline]
     pub const fn new(c0: F, c1: F) -> Self {
@@ -262,6 +386,48 @@ where
     pub fn norm(&self) -> F {
         self.c0.square() - self.c1.square().mul_by_nonresidue()
     }
+
+    /// Multiplication by `u + k` for a small `k`, such as the non-residue `u + 9` of
+    /// BN256 or the coefficient `b = u + 3` of Triton. The multiplications by `k` are
+    /// doublings and additions, so the time only depends on `k`.
+    pub fn mul_by_u_plus_k(&self, k: u64) -> Self {
+        // k x, from the most significant bit of k
+        let mul_k = |x: &F| {
+            (0..64 - k.leading_zeros()).rev().fold(F::ZERO, |acc, i| {
+                let acc = acc.double();
+                if (k >> i) & 1 == 1 {
+                    acc + x
+                } else {
+                    acc
+                }
+            })
+        };
+        // (c0 + c1 u)(u + k) = (k c0 + β c1) + (c0 + k c1) u
+        Self {
+            c0: mul_k(&self.c0) + self.c1.mul_by_nonresidue(),
+            c1: self.c0 + mul_k(&self.c1),
+        }
+    }
+}
+
+impl<F: ExtField> MulByRoot for QuadExtField<F> {
+    // (c0 + c1 u) u = β c1 + c0 u
+    fn mul_by_root(&self) -> Self {
+        Self {
+            c0: self.c1.mul_by_nonresidue(),
+            c1: self.c0,
+        }
+    }
+}
+
+impl<F: ExtField + Legendre> Legendre for QuadExtField<F>
+where
+    Self: QuadExtFieldArith<Base = F>,
+{
+    // `a` is a square in `F[u]/(u^2 - β)` iff `N(a) = a * a^q = c0^2 - β c1^2` is a square in `F`.
+    fn legendre(&self) -> i64 {
+        self.norm().legendre()
+    }
 }
 
 impl<F: ExtField> Field for QuadExtField<F>
@@ -298,12 +464,13 @@ where
                 precompute_f,
                 q_minus_1_over_4,
             } => sqrt_algo10(self, &precompute_e, &precompute_f, q_minus_1_over_4),
+            SQRT::Complex => sqrt_complex(self),
             SQRT::Unimplemented => unimplemented!(),
         }
     }
 
-    fn sqrt_ratio(_: &Self, _: &Self) -> (subtle::Choice, Self) {
-        unimplemented!()
+    fn sqrt_ratio(num: &Self, div: &Self) -> (subtle::Choice, Self) {
+        super::sqrt_ratio(num, div, &Self::NON_SQUARE)
     }
 
     fn invert(&self) -> subtle::CtOption<Self> {
@@ -0,0 +1,194 @@
+//! Square roots in prime fields with `p - 1 = t 2^32`, `t` odd, from precomputed tables.
+//!
+//! Tonelli–Shanks takes up to `S^2 / 2` multiplications after the exponentiation by
+//! `(t - 1) / 2`, which doubles the cost of the square roots of the 446-bit Pluto and
+//! Eris fields, with `S = 32`. [Sarkar's algorithm](https://eprint.iacr.org/2020/1407)
+//! instead finds the discrete logarithm of `u^t` in the subgroup of order `2^32` by
+//! 8-bit windows, from tables of the powers of its generator `ROOT_OF_UNITY`, as in
+//! `pasta_curves`. The tables take 57 KB for the 446-bit fields.
+//!
+//! The lookups are indexed by values derived from the input and the exponentiation
+//! runs in variable time, so the tables only serve public inputs. With the
+//! `precompute` feature, the derived fields with `S = 32` expose them as
+//! `sqrt_vartime`, from a lazily built [`SqrtTables`], while [`ff::Field::sqrt`]
+//! remains the constant time Tonelli–Shanks.
+
+use alloc::vec::Vec;
+
+use ff::PrimeField;
+use subtle::CtOption;
+
+/// Tables of the powers of `F::ROOT_OF_UNITY` for the square roots of [`SqrtTables::sqrt_vartime`].
+#[derive(Debug)]
+pub struct SqrtTables<F> {
+    t_minus_1_over_2: Vec<u64>,
+    // `g[i][j] = ROOT_OF_UNITY^(j 2^(8 i))`
+    g: [Vec<F>; 4],
+    // The discrete logarithms `256 - j` of the elements `g[3][j]` of order 256, sorted by
+    // the first 8 bytes of their representation.
+    inv: Vec<(u64, u8)>,
+}
+
+fn key<F: PrimeField>(x: &F) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&x.to_repr().as_ref()[..8]);
+    u64::from_le_bytes(bytes)
+}
+
+impl<F: PrimeField> SqrtTables<F> {
+    /// Builds the tables of `F`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `F::S` is not 32.
+    pub fn new() -> Self {
+        assert_eq!(F::S, 32);
+
+        // (t - 1) / 2 = (p - 1) >> 33
+        let modulus = F::MODULUS.trim_start_matches("0x");
+        let digits: Vec<u64> = modulus
+            .chars()
+            .rev()
+            .map(|c| c.to_digit(16).unwrap() as u64)
+            .collect();
+        let mut limbs: Vec<u64> = digits
+            .chunks(16)
+            .map(|chunk| chunk.iter().rev().fold(0, |acc, d| (acc << 4) | d))
+            .collect();
+        limbs[0] ^= 1;
+        let shift = F::S as usize + 1;
+        let t_minus_1_over_2 = (0..limbs.len())
+            .map(|i| {
+                let lo = limbs.get(i + shift / 64).map_or(0, |l| l >> (shift % 64));
+                let hi = limbs
+                    .get(i + shift / 64 + 1)
+                    .map_or(0, |l| l << (64 - shift % 64));
+                lo | hi
+            })
+            .collect();
+
+        let mut gi = F::ROOT_OF_UNITY;
+        let g = [0; 4].map(|_| {
+            let gtab: Vec<F> = (0..256)
+                .scan(F::ONE, |acc, _| {
+                    let res = *acc;
+                    *acc *= gi;
+                    Some(res)
+                })
+                .collect();
+            gi *= gtab[255];
+            gtab
+        });
+
+        let mut inv: Vec<(u64, u8)> = g[3]
+            .iter()
+            .enumerate()
+            .map(|(j, x)| (key(x), ((256 - j) & 0xff) as u8))
+            .collect();
+        inv.sort_unstable();
+        assert!(inv.windows(2).all(|w| w[0].0 != w[1].0));
+
+        Self {
+            t_minus_1_over_2,
+            g,
+            inv,
+        }
+    }
+
+    // The discrete logarithm `-j mod 256` of `x = ROOT_OF_UNITY^(j 2^24)`.
+    fn inv(&self, x: &F) -> usize {
+        let key = key(x);
+        self.inv
+            .binary_search_by_key(&key, |(k, _)| *k)
+            .map_or(0, |i| self.inv[i].1 as usize)
+    }
+
+    /// Returns the square root of `u`, if it exists, in variable time.
+    pub fn sqrt_vartime(&self, u: &F) -> CtOption<F> {
+        let sqr = |x: F, i: u32| (0..i).fold(x, |x, _| x.square());
+
+        // v = u^((t - 1) / 2), uv = u^((t + 1) / 2) and x3 = u^t, of order dividing 2^32.
+        let v = u.pow_vartime(&self.t_minus_1_over_2);
+        let uv = *u * v;
+        let x3 = uv * v;
+        let x2 = sqr(x3, 8);
+        let x1 = sqr(x2, 8);
+        let x0 = sqr(x1, 8);
+
+        // Finds the bits of t_ with x3 = ROOT_OF_UNITY^(-t_), 8 at a time.
+        let g = &self.g;
+        let mut t_ = self.inv(&x0);
+        let alpha = x1 * g[2][t_];
+        t_ += self.inv(&alpha) << 8;
+        let alpha = x2 * g[1][t_ & 0xff] * g[2][t_ >> 8];
+        t_ += self.inv(&alpha) << 16;
+        let alpha = x3 * g[0][t_ & 0xff] * g[1][(t_ >> 8) & 0xff] * g[2][t_ >> 16];
+        t_ += self.inv(&alpha) << 24;
+
+        // u is a square iff t_ is even, and then uv ROOT_OF_UNITY^(t_ / 2) is its root.
+        let t_ = ((t_ as u64 + 1) >> 1) as usize;
+        let res = uv
+            * g[0][t_ & 0xff]
+            * g[1][(t_ >> 8) & 0xff]
+            * g[2][(t_ >> 16) & 0xff]
+            * g[3][(t_ >> 24) & 0xff];
+        CtOption::new(res, res.square().ct_eq(u))
+    }
+}
+
+impl<F: PrimeField> Default for SqrtTables<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SqrtTables;
+    use crate::ff_ext::Legendre;
+    use crate::pluto_eris::{Fp, Fq};
+    use ff::{Field, PrimeField};
+    use rand_core::OsRng;
+
+    fn run_sqrt_tables_test<F: PrimeField + Legendre>() {
+        let tables = SqrtTables::<F>::new();
+
+        // x^t is in the subgroup of order 2^32.
+        let x = F::random(OsRng);
+        let x_t = x.pow_vartime(&tables.t_minus_1_over_2).square() * x;
+        assert_eq!((0..32).fold(x_t, |x, _| x.square()), F::ONE);
+
+        assert_eq!(tables.sqrt_vartime(&F::ZERO).unwrap(), F::ZERO);
+        assert_eq!(
+            tables
+                .sqrt_vartime(&F::ROOT_OF_UNITY.square())
+                .unwrap()
+                .square(),
+            F::ROOT_OF_UNITY.square()
+        );
+        assert!(bool::from(tables.sqrt_vartime(&F::ROOT_OF_UNITY).is_none()));
+        for _ in 0..1000 {
+            let a = F::random(OsRng);
+            let root = tables.sqrt_vartime(&a.square()).unwrap();
+            assert!(root == a || root == -a);
+
+            let b = F::random(OsRng);
+            assert_eq!(
+                bool::from(tables.sqrt_vartime(&b).is_some()),
+                b.legendre() == 1
+            );
+        }
+    }
+
+    #[test]
+    fn test_sqrt_tables() {
+        run_sqrt_tables_test::<Fp>();
+        run_sqrt_tables_test::<Fq>();
+
+        // `sqrt_vartime` agrees with the constant time `sqrt`.
+        let a = Fp::random(OsRng).square();
+        assert_eq!(a.sqrt_vartime().unwrap().square(), a);
+        let b = Fq::random(OsRng).square();
+        assert_eq!(b.sqrt_vartime().unwrap().square(), b);
+    }
+}
@@ -26,8 +26,16 @@ where
 /// $\omega^{-1}$ in place of $\omega$ and dividing each resulting field element
 /// by $n$.
 ///
-/// This will use multithreading if beneficial.
+/// This will use multithreading if beneficial, or the backend registered with
+/// [`register_ntt_backend`](crate::backend::register_ntt_backend).
 pub fn best_fft<Scalar: Field, G: FftGroup<Scalar>>(a: &mut [G], omega: Scalar, log_n: u32) {
+    match crate::backend::ntt_backend::<Scalar, G>() {
+        Some(backend) => backend.fft(a, omega, log_n),
+        None => cpu_fft(a, omega, log_n),
+    }
+}
+
+pub(crate) fn cpu_fft<Scalar: Field, G: FftGroup<Scalar>>(a: &mut [G], omega: Scalar, log_n: u32) {
     fn bitreverse(mut n: usize, l: usize) -> usize {
         let mut r = 0;
         for _ in 0..l {
@@ -0,0 +1,137 @@
+//! Fixed-base scalar multiplication with precomputed window tables.
+//!
+//! The scalar is split in `w`-bit windows `k = sum_i k_i * 2^(w * i)`, and row `i`
+//! of the table holds `j * 2^(w * i) * P` for every digit `j` in `0..2^w`. The
+//! product `k * P` is then the sum of one entry per row, without any doubling.
+//! Compared to the [`comb`](crate::comb) method the table is larger, `2^w` points
+//! per window instead of `2^w` points in total, but the multiplication needs half
+//! as many group operations.
+
+use alloc::vec::Vec;
+
+use crate::CurveAffine;
+use ff::PrimeField;
+use group::{Curve, Group};
+use subtle::ConstantTimeEq;
+
+/// Precomputed window tables of a fixed base.
+#[derive(Clone, Debug)]
+pub struct FixedBase<C: CurveAffine> {
+    window: usize,
+    rows: Vec<Vec<C>>,
+}
+
+impl<C: CurveAffine> FixedBase<C> {
+    /// Builds the tables of `base` for windows of `window` bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero or larger than 16.
+    pub fn new(base: &C, window: usize) -> Self {
+        assert!(window > 0 && window <= 16);
+        let num_windows = (C::ScalarExt::NUM_BITS as usize + window - 1) / window;
+
+        let mut points = Vec::with_capacity(num_windows << window);
+        let mut row_base = base.to_curve();
+        for _ in 0..num_windows {
+            let mut acc = C::Curve::identity();
+            for _ in 0..(1 << window) {
+                points.push(acc);
+                acc += row_base;
+            }
+            // acc = 2^window * row_base.
+            row_base = acc;
+        }
+
+        let mut affine = alloc::vec![C::identity(); points.len()];
+        C::Curve::batch_normalize(&points, &mut affine);
+        Self {
+            window,
+            rows: affine.chunks(1 << window).map(|row| row.to_vec()).collect(),
+        }
+    }
+
+    /// Width of the windows in bits.
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Computes `scalar * base` in constant time.
+    pub fn mul(&self, scalar: &C::ScalarExt) -> C::Curve {
+        let repr = scalar.to_repr();
+        let repr = repr.as_ref();
+        let bit = |k: usize| -> u64 {
+            repr.get(k / 8)
+                .map_or(0, |byte| ((byte >> (k % 8)) & 1) as u64)
+        };
+
+        let mut acc = C::Curve::identity();
+        for (i, row) in self.rows.iter().enumerate() {
+            let digit = (0..self.window)
+                .map(|j| bit(i * self.window + j) << j)
+                .sum();
+            acc += Self::select(row, digit);
+        }
+        acc
+    }
+
+    /// Computes `scalar * base` for every scalar, sharing the tables and the
+    /// normalization of the results.
+    pub fn mul_batch(&self, scalars: &[C::ScalarExt]) -> Vec<C> {
+        let points = scalars.iter().map(|s| self.mul(s)).collect::<Vec<_>>();
+        let mut affine = alloc::vec![C::identity(); points.len()];
+        C::Curve::batch_normalize(&points, &mut affine);
+        affine
+    }
+
+    // Constant time lookup of a row entry.
+    fn select(row: &[C], digit: u64) -> C {
+        let mut p = C::identity();
+        for (j, entry) in row.iter().enumerate() {
+            p = C::conditional_select(&p, entry, (j as u64).ct_eq(&digit));
+        }
+        p
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::FixedBase;
+    use crate::CurveAffine;
+    use ff::Field;
+    use group::{Curve, Group};
+    use rand_core::OsRng;
+
+    fn run_fixed_base_test<C: CurveAffine>() {
+        let base = C::Curve::random(OsRng).to_affine();
+        for window in [1, 3, 4, 7] {
+            let table = FixedBase::new(&base, window);
+            assert_eq!(table.window(), window);
+
+            let scalars = [
+                C::ScalarExt::ZERO,
+                C::ScalarExt::ONE,
+                -C::ScalarExt::ONE,
+                C::ScalarExt::random(OsRng),
+                C::ScalarExt::random(OsRng),
+            ];
+            for scalar in scalars.iter() {
+                assert_eq!(table.mul(scalar), base * scalar);
+            }
+
+            let batch = table.mul_batch(&scalars);
+            assert_eq!(batch.len(), scalars.len());
+            for (point, scalar) in batch.iter().zip(scalars.iter()) {
+                assert_eq!(*point, (base * scalar).to_affine());
+            }
+        }
+    }
+
+    #[test]
+    fn test_fixed_base() {
+        run_fixed_base_test::<crate::bn256::G1Affine>();
+        run_fixed_base_test::<crate::bn256::G2Affine>();
+        run_fixed_base_test::<crate::secp256k1::Secp256k1Affine>();
+        run_fixed_base_test::<crate::pluto_eris::G1Affine>();
+    }
+}
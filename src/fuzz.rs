@@ -0,0 +1,308 @@
+//! Differential checks on arbitrary bytes, with the `fuzzing` feature, for the
+//! `cargo fuzz` targets of `fuzz/`.
+//!
+//! The checks panic when an optimized path disagrees with a reference:
+//!
+//! * prime field arithmetic against the integers of `num_bigint`, which catches
+//!   the carries of the Montgomery multiplication, of the 7-limb Pluto-Eris fields
+//!   in particular, and of the `asm` and `limb32` backends when they are enabled;
+//! * the Karatsuba multiplication and squaring of quadratic extensions against the
+//!   schoolbook formulas;
+//! * the windowed and GLV scalar multiplications against double-and-add;
+//! * the decoders against the encoders: a decoded field element or point must be
+//!   valid and encode back to the same bytes.
+//!
+//! The `*_target` functions pick the field or curve with the first byte.
+
+use ff::{PrimeField, WithSmallOrderMulGroup};
+use group::{Group, GroupEncoding};
+use num_bigint::BigUint;
+
+use crate::ff_ext::quadratic::{QuadExtField, QuadExtFieldArith};
+use crate::ff_ext::ExtField;
+use crate::{CurveAffine, CurveEndo, CurveExt};
+
+fn modulus<F: PrimeField>() -> BigUint {
+    BigUint::parse_bytes(F::MODULUS.trim_start_matches("0x").as_bytes(), 16).unwrap()
+}
+
+fn to_biguint<F: PrimeField>(x: &F) -> BigUint {
+    BigUint::from_bytes_le(x.to_repr().as_ref())
+}
+
+// Converts an integer smaller than the modulus.
+fn from_biguint<F: PrimeField>(x: &BigUint) -> F {
+    let mut repr = F::Repr::default();
+    let bytes = x.to_bytes_le();
+    repr.as_mut()[..bytes.len()].copy_from_slice(&bytes);
+    F::from_repr(repr).unwrap()
+}
+
+// Splits `data` into `N` integers modulo `p`, or `None` if it is too short.
+fn integers<F: PrimeField, const N: usize>(data: &[u8]) -> Option<[BigUint; N]> {
+    let size = F::Repr::default().as_ref().len();
+    if data.len() < N * size {
+        return None;
+    }
+    let p = modulus::<F>();
+    Some(core::array::from_fn(|i| {
+        BigUint::from_bytes_le(&data[i * size..(i + 1) * size]) % &p
+    }))
+}
+
+/// Checks the arithmetic of `F` on two elements read from `data` against the
+/// integers modulo `p`.
+pub fn field_arith<F: PrimeField>(data: &[u8]) {
+    let [a, b] = match integers::<F, 2>(data) {
+        Some(ints) => ints,
+        None => return,
+    };
+    let p = modulus::<F>();
+    let (x, y) = (from_biguint::<F>(&a), from_biguint::<F>(&b));
+    assert_eq!(to_biguint(&x), a);
+
+    assert_eq!(to_biguint(&(x * y)), &a * &b % &p);
+    assert_eq!(to_biguint(&x.square()), &a * &a % &p);
+    assert_eq!(to_biguint(&(x + y)), (&a + &b) % &p);
+    assert_eq!(to_biguint(&(x - y)), (&a + &p - &b) % &p);
+    assert_eq!(to_biguint(&x.double()), (&a + &a) % &p);
+    assert_eq!(to_biguint(&-x), (&p - &a) % &p);
+
+    let p_minus_2 = &p - 2u32;
+    match Option::<F>::from(x.invert()) {
+        Some(inv) => assert_eq!(to_biguint(&inv), a.modpow(&p_minus_2, &p)),
+        None => assert_eq!(a, BigUint::default()),
+    }
+
+    // `sqrt` fails exactly on the non-squares, for which `a^((p - 1) / 2) = -1`.
+    match Option::<F>::from(x.sqrt()) {
+        Some(root) => assert_eq!(root.square(), x),
+        None => assert_eq!(a.modpow(&((&p - 1u32) >> 1), &p), &p - 1u32),
+    }
+}
+
+/// Checks the Karatsuba multiplication and squaring of a quadratic extension of
+/// the prime field `F` against the schoolbook formulas.
+pub fn quadratic_arith<F>(data: &[u8])
+where
+    F: PrimeField + ExtField,
+    QuadExtField<F>: QuadExtFieldArith<Base = F>,
+{
+    let [a0, a1, b0, b1] = match integers::<F, 4>(data) {
+        Some(ints) => ints.map(|x| from_biguint::<F>(&x)),
+        None => return,
+    };
+    let (a, b) = (QuadExtField::new(a0, a1), QuadExtField::new(b0, b1));
+
+    // (a0 + a1 u)(b0 + b1 u) = (a0 b0 + β a1 b1) + (a0 b1 + a1 b0) u
+    let expected = QuadExtField::new(a0 * b0 + (a1 * b1).mul_by_nonresidue(), a0 * b1 + a1 * b0);
+    assert_eq!(a.mul(&b), expected);
+    assert_eq!(
+        QuadExtField::square(&a),
+        QuadExtField::new(
+            a0.square() + a1.square().mul_by_nonresidue(),
+            (a0 * a1).double()
+        )
+    );
+}
+
+// `k * p` with the double-and-add of the binary expansion of `k`.
+fn reference_mul<C: Group>(p: &C, k: &C::Scalar) -> C {
+    let repr = k.to_repr();
+    repr.as_ref().iter().rev().fold(C::identity(), |acc, byte| {
+        (0..8).rev().fold(acc, |acc, i| {
+            let acc = acc.double();
+            if (byte >> i) & 1 == 1 {
+                acc + p
+            } else {
+                acc
+            }
+        })
+    })
+}
+
+// A point and a scalar read from `data`.
+fn point_and_scalar<C: CurveExt>(data: &[u8]) -> Option<(C, C::ScalarExt)> {
+    let [s, k] = integers::<C::ScalarExt, 2>(data)?;
+    let p = reference_mul(&C::generator(), &from_biguint(&s));
+    Some((p, from_biguint(&k)))
+}
+
+/// Checks the scalar multiplications of `C` against double-and-add.
+pub fn scalar_mul<C: CurveExt>(data: &[u8]) {
+    let (p, k) = match point_and_scalar::<C>(data) {
+        Some(input) => input,
+        None => return,
+    };
+    let expected = reference_mul(&p, &k);
+    assert_eq!(p * k, expected);
+    assert_eq!(p.to_affine() * k, expected);
+    assert_eq!(crate::multi_mul::multi_mul_vartime([(p, k)]), expected);
+}
+
+/// Checks the GLV decomposition and multiplication of `C` against double-and-add.
+pub fn glv_mul<C: CurveEndo>(data: &[u8]) {
+    let (p, k) = match point_and_scalar::<C>(data) {
+        Some(input) => input,
+        None => return,
+    };
+    assert_eq!(p.mul_glv(&k), reference_mul(&p, &k));
+
+    // k = ±k1 ∓ ζ k2
+    let (k1, k1_neg, k2, k2_neg) = C::decompose_scalar(&k);
+    let k1 = C::ScalarExt::from_u128(k1);
+    let k2 = C::ScalarExt::from_u128(k2) * C::ScalarExt::ZETA;
+    let k1 = if k1_neg { -k1 } else { k1 };
+    let k2 = if k2_neg { k2 } else { -k2 };
+    assert_eq!(k1 + k2, k);
+}
+
+/// Checks that `F::from_repr` accepts exactly the integers below the modulus, and
+/// that they encode back to the same bytes.
+pub fn field_decoding<F: PrimeField>(data: &[u8]) {
+    let mut repr = F::Repr::default();
+    let len = repr.as_ref().len().min(data.len());
+    repr.as_mut()[..len].copy_from_slice(&data[..len]);
+
+    let x = Option::<F>::from(F::from_repr(repr));
+    let canonical = BigUint::from_bytes_le(repr.as_ref()) < modulus::<F>();
+    assert_eq!(x.is_some(), canonical);
+    if let Some(x) = x {
+        assert_eq!(x.to_repr().as_ref(), repr.as_ref());
+    }
+}
+
+/// Checks that the compressed points decoded from `data` are on the curve, agree
+/// between the affine and projective decoders, and encode back to the same bytes.
+pub fn point_decoding<C: CurveExt>(data: &[u8]) {
+    let mut repr = <C::AffineExt as GroupEncoding>::Repr::default();
+    let len = repr.as_ref().len().min(data.len());
+    repr.as_mut()[..len].copy_from_slice(&data[..len]);
+
+    let affine = Option::<C::AffineExt>::from(C::AffineExt::from_bytes(&repr));
+    let mut projective_repr = C::Repr::default();
+    projective_repr.as_mut().copy_from_slice(repr.as_ref());
+    let projective = Option::<C>::from(C::from_bytes(&projective_repr));
+    assert_eq!(affine.map(C::from), projective);
+    if let Some(p) = affine {
+        assert!(bool::from(p.is_on_curve()));
+        assert_eq!(p.to_bytes().as_ref(), repr.as_ref());
+    }
+    // The unchecked decoder must not panic either.
+    let _ = C::AffineExt::from_bytes_unchecked(&repr);
+}
+
+/// Runs [`field_arith`] and [`quadratic_arith`] on the field picked by the first
+/// byte of `data`.
+pub fn field_target(data: &[u8]) {
+    use crate::{bls12_377, bn256, bw6_767, pasta, pluto_eris, secp256k1, secp256r1};
+
+    let (selector, data) = match data.split_first() {
+        Some(split) => split,
+        None => return,
+    };
+    match selector % 16 {
+        0 => field_arith::<bn256::Fq>(data),
+        1 => field_arith::<bn256::Fr>(data),
+        2 => field_arith::<pluto_eris::Fp>(data),
+        3 => field_arith::<pluto_eris::Fq>(data),
+        4 => field_arith::<secp256k1::Fp>(data),
+        5 => field_arith::<secp256k1::Fq>(data),
+        6 => field_arith::<secp256r1::Fp>(data),
+        7 => field_arith::<secp256r1::Fq>(data),
+        8 => field_arith::<bls12_377::Fq>(data),
+        9 => field_arith::<bls12_377::Fr>(data),
+        10 => field_arith::<bw6_767::Fq>(data),
+        11 => field_arith::<pasta::Fp>(data),
+        12 => field_arith::<pasta::Fq>(data),
+        13 => quadratic_arith::<bn256::Fq>(data),
+        14 => quadratic_arith::<pluto_eris::Fp>(data),
+        _ => quadratic_arith::<bls12_377::Fq>(data),
+    }
+}
+
+/// Runs [`scalar_mul`] and [`glv_mul`] on the curve picked by the first byte of
+/// `data`.
+pub fn curve_target(data: &[u8]) {
+    use crate::{bls12_377, bn256, grumpkin, pasta, pluto_eris, secp256k1, secp256r1};
+
+    let (selector, data) = match data.split_first() {
+        Some(split) => split,
+        None => return,
+    };
+    match selector % 14 {
+        0 => scalar_mul::<bn256::G1>(data),
+        1 => scalar_mul::<bn256::G2>(data),
+        2 => scalar_mul::<pluto_eris::G1>(data),
+        3 => scalar_mul::<pluto_eris::Eris>(data),
+        4 => scalar_mul::<pluto_eris::G2>(data),
+        5 => scalar_mul::<secp256k1::Secp256k1>(data),
+        6 => scalar_mul::<secp256r1::Secp256r1>(data),
+        7 => scalar_mul::<bls12_377::G1>(data),
+        8 => scalar_mul::<bls12_377::G2>(data),
+        9 => glv_mul::<bn256::G1>(data),
+        10 => glv_mul::<grumpkin::G1>(data),
+        11 => glv_mul::<secp256k1::Secp256k1>(data),
+        12 => glv_mul::<bls12_377::G1>(data),
+        _ => glv_mul::<pasta::Ep>(data),
+    }
+}
+
+/// Runs [`field_decoding`] and [`point_decoding`] on the field or curve picked by
+/// the first byte of `data`.
+pub fn decoding_target(data: &[u8]) {
+    use crate::{bls12_377, bn256, grumpkin, pluto_eris, secp256k1, secp256r1, secq256k1};
+
+    let (selector, data) = match data.split_first() {
+        Some(split) => split,
+        None => return,
+    };
+    match selector % 14 {
+        0 => field_decoding::<bn256::Fq>(data),
+        1 => field_decoding::<pluto_eris::Fp>(data),
+        2 => field_decoding::<pluto_eris::Fq>(data),
+        3 => field_decoding::<bls12_377::Fq>(data),
+        4 => point_decoding::<bn256::G1>(data),
+        5 => point_decoding::<bn256::G2>(data),
+        6 => point_decoding::<grumpkin::G1>(data),
+        7 => point_decoding::<pluto_eris::G1>(data),
+        8 => point_decoding::<pluto_eris::Eris>(data),
+        9 => point_decoding::<pluto_eris::G2>(data),
+        10 => point_decoding::<secp256k1::Secp256k1>(data),
+        11 => point_decoding::<secp256r1::Secp256r1>(data),
+        12 => point_decoding::<secq256k1::Secq256k1>(data),
+        _ => point_decoding::<bls12_377::G1>(data),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{curve_target, decoding_target, field_target};
+    use rand_core::{OsRng, RngCore};
+
+    // Random inputs for every selector, and the edge cases of all-zero and
+    // all-one bytes, which decode to zero and to values above the modulus.
+    fn run_target(target: fn(&[u8]), selectors: u8, iterations: usize) {
+        for selector in 0..selectors {
+            for fill in [0x00, 0xff] {
+                let mut data = vec![fill; 1 + 4 * 128];
+                data[0] = selector;
+                target(&data);
+            }
+            for _ in 0..iterations {
+                let mut data = vec![0; 1 + 4 * 128];
+                OsRng.fill_bytes(&mut data);
+                data[0] = selector;
+                target(&data);
+                target(&data[..(OsRng.next_u32() % 200) as usize]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fuzz_targets() {
+        run_target(field_target, 16, 20);
+        run_target(curve_target, 14, 2);
+        run_target(decoding_target, 14, 50);
+    }
+}
@@ -18,7 +18,7 @@ use core::cmp;
 use core::fmt::Debug;
 use core::iter::Sum;
 use core::ops::{Add, Mul, Neg, Sub};
-use rand::RngCore;
+use rand_core::RngCore;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 #[cfg(feature = "derive_serde")]
@@ -86,8 +86,10 @@ impl group::cofactor::CofactorGroup for G1 {
 impl G1 {
     const SVDW_Z: Fq = Fq::ONE;
 
-    fn default_hash_to_curve_suite() -> crate::hash_to_curve::Suite<Self, sha2::Sha256, 48> {
-        crate::hash_to_curve::Suite::<G1, sha2::Sha256, 48>::new(
+    fn default_hash_to_curve_suite(
+    ) -> crate::hash_to_curve::Suite<Self, crate::hash_to_curve::ExpandMsgXmd<sha2::Sha256>, 48>
+    {
+        crate::hash_to_curve::Suite::<G1, crate::hash_to_curve::ExpandMsgXmd<sha2::Sha256>, 48>::new(
             b"GRUMPKIN_XMD:SHA-256_SVDW_RO_",
             Self::SVDW_Z,
             crate::hash_to_curve::Method::SVDW,
@@ -95,13 +97,60 @@ impl G1 {
     }
 }
 
+impl crate::hash_to_curve::BatchHashToCurve for G1 {
+    fn batch_hash_to_curve(domain_prefix: &str, messages: &[&[u8]]) -> alloc::vec::Vec<Self> {
+        Self::default_hash_to_curve_suite().batch_hash_to_curve(domain_prefix, messages)
+    }
+}
+
+impl crate::hash_to_curve::MapToCurve for G1 {
+    fn hash_to_field(domain_prefix: &str, message: &[u8]) -> [Self::Base; 2] {
+        Self::default_hash_to_curve_suite().hash_to_field(domain_prefix, message)
+    }
+
+    fn map_to_curve(u: &Self::Base) -> Self {
+        Self::default_hash_to_curve_suite().map_to_curve(u)
+    }
+}
+
+/// The BN256 / Grumpkin cycle: `G1` of BN256 and Grumpkin.
+#[derive(Clone, Copy, Debug)]
+pub struct Bn256Grumpkin;
+
+impl crate::cycle::CurveCycle for Bn256Grumpkin {
+    type C1 = crate::bn256::G1;
+    type C2 = G1;
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use group::UncompressedEncoding;
     crate::curve_testing_suite!(G1);
     crate::curve_testing_suite!(G1, "endo_consistency");
+    crate::curve_testing_suite!(G1, "batch_hash_to_curve");
+    crate::curve_testing_suite!(G1, "map_to_curve");
     crate::curve_testing_suite!(G1, "endo");
+
+    #[test]
+    fn test_cycle() {
+        use crate::bn256;
+
+        fn same_type<T>(_: T, _: T) {}
+
+        // The scalar field of each curve is the base field of the other.
+        same_type(
+            <G1 as CurveExt>::ScalarExt::ONE,
+            <bn256::G1 as CurveExt>::Base::ONE,
+        );
+        same_type(
+            <bn256::G1 as CurveExt>::ScalarExt::ONE,
+            <G1 as CurveExt>::Base::ONE,
+        );
+        assert_eq!(G1::ORDER, &bn256::Fq::MODULUS_LIMBS);
+        assert_eq!(bn256::G1::ORDER, &bn256::Fr::MODULUS_LIMBS);
+        assert_eq!(G1_B, -Fq::from(17));
+    }
     crate::curve_testing_suite!(
         G1,
         "constants",
@@ -1,3 +1,13 @@
+//! # `Grumpkin`
+//!
+//! Grumpkin, [`G1`], is the embedded curve of BN254: it is defined over [`Fq`], the
+//! scalar field of [`bn256::G1`](crate::bn256::G1), and has [`Fr`], the base field
+//! of BN254, as scalar field. Together they form the [`Bn256Grumpkin`] cycle used by
+//! Aztec's Barretenberg.
+//!
+//! The curve is `y^2 = x^3 - 17`, with the endomorphism `(x, y) -> (ζx, y)` and an
+//! SVDW hash to curve.
+
 mod curve;
 
 pub use crate::bn256::{Fq as Fr, Fr as Fq};
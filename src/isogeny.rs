@@ -0,0 +1,62 @@
+//! Isogenies given by rational maps with constant coefficients, which send the
+//! curve `E'` of the simplified SWU map to a curve with `A = 0`.
+//!
+//! An isogeny of degree `n` is `(x, y) -> (x_num(x) / x_den(x), y * y_num(x) / y_den(x))`,
+//! where the polynomials have degree at most `n` and are given by their
+//! coefficients, from the constant term up. See
+//! <https://www.rfc-editor.org/rfc/rfc9380.html#name-simplified-swu-for-ab-0>.
+
+use ff::Field;
+
+/// The rational maps of an isogeny, with the coefficients of the polynomials from
+/// the constant term up.
+#[derive(Clone, Copy, Debug)]
+pub struct IsogenyMap<F: 'static> {
+    pub x_num: &'static [F],
+    pub x_den: &'static [F],
+    pub y_num: &'static [F],
+    pub y_den: &'static [F],
+}
+
+impl<F: Field> IsogenyMap<F> {
+    /// The degree of the polynomials of the map.
+    pub fn degree(&self) -> usize {
+        [self.x_num, self.x_den, self.y_num, self.y_den]
+            .iter()
+            .map(|p| p.len() - 1)
+            .max()
+            .unwrap()
+    }
+
+    /// Maps the point with homogeneous coordinates `(x : y : z)` to the homogeneous
+    /// coordinates of its image, without inversions. The points of the kernel map
+    /// to the identity `(0 : 1 : 0)`.
+    pub fn evaluate(&self, x: F, y: F, z: F) -> (F, F, F) {
+        let d = self.degree();
+        // z^0, ..., z^d
+        let zs = core::iter::successors(Some(F::ONE), |zi| Some(*zi * z))
+            .take(d + 1)
+            .collect::<alloc::vec::Vec<_>>();
+
+        // The polynomial homogenized to degree `d` with Horner's method.
+        let eval = |p: &[F]| {
+            p.iter()
+                .enumerate()
+                .rev()
+                .fold(F::ZERO, |acc, (i, c)| acc * x + *c * zs[d - i])
+        };
+
+        let x_num = eval(self.x_num);
+        let x_den = eval(self.x_den);
+        let y_num = eval(self.y_num) * y;
+        let y_den = eval(self.y_den) * z;
+
+        let z = x_den * y_den;
+        let is_identity = z.ct_eq(&F::ZERO);
+        (
+            F::conditional_select(&(x_num * y_den), &F::ZERO, is_identity),
+            F::conditional_select(&(y_num * x_den), &F::ONE, is_identity),
+            z,
+        )
+    }
+}
@@ -1,11 +1,54 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "alloc"))]
+compile_error!("halo2curves requires at least the `alloc` feature");
+
+extern crate alloc;
+
 mod arithmetic;
+#[cfg(feature = "std")]
+pub mod backend;
+pub mod comb;
+pub mod cycle;
+pub mod error;
 pub mod ff_ext;
+#[cfg(feature = "std")]
 pub mod fft;
+pub mod fixed_base;
+#[cfg(any(test, feature = "fuzzing"))]
+pub mod fuzz;
 pub mod hash_to_curve;
+pub mod isogeny;
+#[cfg(feature = "line-functions")]
+pub mod line;
+// The engines build their Miller loops on the line functions.
+#[cfg(not(feature = "line-functions"))]
+pub(crate) mod line;
+#[cfg(feature = "mmap")]
+pub mod mmap;
+#[cfg(feature = "std")]
 pub mod msm;
+pub mod multi_mul;
+#[cfg(feature = "std")]
+pub mod multi_pairing;
+#[cfg(feature = "std")]
+pub mod parallel;
 pub mod serde;
+pub mod sig;
+pub mod small_scalar;
+#[cfg(feature = "std")]
+pub mod srs;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod test_utils;
+pub mod wnaf;
+#[cfg(feature = "zeroize")]
+pub mod zeroize;
 
+pub mod babyjubjub;
+pub mod bls12_377;
 pub mod bn256;
+pub mod bw6_767;
+pub mod ed_on_bls12_377;
 pub mod grumpkin;
 pub mod pasta;
 pub mod pluto_eris;
@@ -17,6 +60,8 @@ pub mod secq256k1;
 mod derive;
 
 // Re-export to simplify down stream dependencies
+pub use arithmetic::CurveEndo;
+pub use error::Error;
 pub use ff;
 pub use group;
 pub use pairing;
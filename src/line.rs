@@ -0,0 +1,177 @@
+//! Line functions of the Miller loops of the pairing engines.
+//!
+//! The Miller loop of `e(P, Q)` doubles and adds a point `R` of `G2` along the NAF
+//! digits of the loop parameter, and multiplies its accumulator by the lines of each
+//! step evaluated at `P`. [`LineEvaluator`] exposes these steps for the pairing
+//! engines of the crate, so that the lines can be computed, stored or folded apart
+//! from their accumulation, e.g. by the witness generation of an in-circuit pairing
+//! verifier, which must follow the native loop step by step:
+//!
+//! * [`LineEvaluator::double_step`] and [`LineEvaluator::add_step`] update `R`, in
+//!   the Jacobian coordinates of [`JacobianPoint`], and return the coefficients of
+//!   the line of the step, which only depend on `Q`;
+//! * [`LineEvaluator::evaluate`] multiplies the accumulator by a line at `P`;
+//! * [`LineEvaluator::prepare`] collects the lines of `Q` in the order of the loop,
+//!   and [`LineEvaluator::miller_loop_prepared`] accumulates them, so that
+//!   `miller_loop_prepared(&[(p, &prepare(q))])` is `multi_miller_loop(&[(p, q)])`;
+//! * [`LineEvaluator::LOOP_NAF`] and [`LineEvaluator::frobenius_points`] give the
+//!   schedule of the steps. From `R = Q` and `f = 1`, for each digit below the most
+//!   significant one, `f` is squared (except for the first digit), multiplied by the
+//!   line of a doubling, and by the line of the addition of `±Q` if the digit is
+//!   nonzero. The loop ends with the additions of the Frobenius points.
+//!
+//! BW6-767 does not implement [`LineEvaluator`]: its optimal ate loop is made of
+//! two loops, the second one over `[x]Q`, which do not follow this schedule.
+//!
+//! The module is public with the `line-functions` feature. BLS12-381 is not
+//! implemented by the crate.
+
+use alloc::vec::Vec;
+use core::ops::Neg;
+
+use ff::Field;
+use pairing::MultiMillerLoop;
+use subtle::CtOption;
+
+/// Coefficients of a line of the Miller loop over the quadratic extension `Fq2`.
+///
+/// In the tower `Fq12 = Fq6[w]`, `Fq6 = Fq2[v]`, the line evaluated at a point
+/// `P = (x, y)` of `G1` is the sparse element `c0 y + (c1 x + c2 v) w`, up to a
+/// factor of `Fq2` that the final exponentiation removes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Line<F> {
+    pub c0: F,
+    pub c1: F,
+    pub c2: F,
+}
+
+/// A point `(x / z^2, y / z^3)` of `G2` in Jacobian coordinates, the point `R` of
+/// the steps of the Miller loop. The identity has `z = 0`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct JacobianPoint<F> {
+    pub x: F,
+    pub y: F,
+    pub z: F,
+}
+
+impl<F: Field> JacobianPoint<F> {
+    /// Returns the affine coordinates of `self`, or `None` for the identity.
+    pub fn coordinates(&self) -> CtOption<(F, F)> {
+        self.z.invert().map(|z_inv| {
+            let z_inv2 = z_inv.square();
+            (self.x * z_inv2, self.y * z_inv2 * z_inv)
+        })
+    }
+}
+
+/// Steps of the Miller loop of a pairing engine.
+pub trait LineEvaluator: MultiMillerLoop {
+    /// The quadratic extension of the coefficients of the lines.
+    type Coeff;
+
+    /// The NAF digits of the loop parameter, least significant first.
+    const LOOP_NAF: &'static [i8];
+
+    /// Doubles `r` and returns the tangent line at `r`.
+    fn double_step(r: &mut JacobianPoint<Self::Coeff>) -> Line<Self::Coeff>;
+
+    /// Adds `q` to `r` and returns the line through `r` and `q`.
+    fn add_step(r: &mut JacobianPoint<Self::Coeff>, q: &Self::G2Affine) -> Line<Self::Coeff>;
+
+    /// Returns the points of `G2` added to `R` after the loop of `q`.
+    fn frobenius_points(q: &Self::G2Affine) -> Vec<Self::G2Affine>;
+
+    /// Multiplies `f` by `line` evaluated at `p`.
+    fn evaluate(f: &mut Self::Result, line: &Line<Self::Coeff>, p: &Self::G1Affine);
+
+    /// Returns the lines of the Miller loop of `q`, in the order of the loop. There
+    /// are none for the identity.
+    fn prepare(q: &Self::G2Affine) -> Vec<Line<Self::Coeff>>;
+
+    /// Computes the multi-Miller loop of the points of `G1` and the lines of
+    /// [`LineEvaluator::prepare`] of the points of `G2`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lines of a term are neither empty nor as many as those of
+    /// [`LineEvaluator::prepare`].
+    #[allow(clippy::type_complexity)]
+    fn miller_loop_prepared(terms: &[(&Self::G1Affine, &[Line<Self::Coeff>])]) -> Self::Result;
+}
+
+// Number of lines of a Miller loop over the digits `naf` of the loop parameter, least
+// significant first, followed by `num_frobenius` Frobenius additions.
+fn num_lines(naf: &[i8], num_frobenius: usize) -> usize {
+    let steps = &naf[..naf.len() - 1];
+    steps.len() + steps.iter().filter(|x| **x != 0).count() + num_frobenius
+}
+
+// Number of Frobenius additions of an engine, from its `frobenius_points`.
+pub(crate) fn num_frobenius<Q, const N: usize>(_: impl Fn(&Q) -> [Q; N]) -> usize {
+    N
+}
+
+// Lines of the Miller loop of `q` from `r = q`, with the additions of `frobenius` after
+// the loop.
+pub(crate) fn prepare<R, Q, L, const N: usize>(
+    naf: &[i8],
+    q: &Q,
+    mut r: R,
+    frobenius: [Q; N],
+    double_step: impl Fn(&mut R) -> L,
+    add_step: impl Fn(&mut R, &Q) -> L,
+) -> Vec<L>
+where
+    Q: Copy + Neg<Output = Q>,
+{
+    let mut lines = Vec::with_capacity(num_lines(naf, N));
+    for x in naf.iter().rev().skip(1) {
+        lines.push(double_step(&mut r));
+        match x {
+            1 => lines.push(add_step(&mut r, q)),
+            -1 => lines.push(add_step(&mut r, &q.neg())),
+            _ => {}
+        }
+    }
+    for q in frobenius.iter() {
+        lines.push(add_step(&mut r, q));
+    }
+    lines
+}
+
+// Accumulates the lines of `terms`, following the schedule of `prepare`.
+pub(crate) fn miller_loop_prepared<F: Field, P, L>(
+    naf: &[i8],
+    num_frobenius: usize,
+    terms: &[(P, &[L])],
+    evaluate: impl Fn(&mut F, &L, &P),
+) -> F {
+    for (_, lines) in terms {
+        assert_eq!(
+            lines.len(),
+            num_lines(naf, num_frobenius),
+            "unexpected number of lines"
+        );
+    }
+    let mut lines = terms.iter().map(|(_, l)| l.iter()).collect::<Vec<_>>();
+    let mut step = |f: &mut F| {
+        for ((p, _), lines) in terms.iter().zip(lines.iter_mut()) {
+            evaluate(f, lines.next().unwrap(), p);
+        }
+    };
+
+    let mut f = F::ONE;
+    for (i, x) in naf.iter().rev().skip(1).enumerate() {
+        if i != 0 {
+            f = f.square();
+        }
+        step(&mut f);
+        if *x != 0 {
+            step(&mut f);
+        }
+    }
+    for _ in 0..num_frobenius {
+        step(&mut f);
+    }
+    f
+}
@@ -0,0 +1,302 @@
+//! Memory-mapped, read-only slices of points.
+//!
+//! Loading a reference string with [`read_points`](crate::srs::read_points)
+//! decompresses and validates every point, which dominates the load time of
+//! parameter files of several gigabytes. [`PointsMmap`] instead maps a file with
+//! the in-memory representation of the points and exposes it as a `&[C]`
+//! without any copy. The format is
+//!
+//! ```text
+//! | magic (8 bytes) | endianness (4 bytes) | point size (4 bytes) |
+//! | n (8 bytes, little-endian) | curve id (16 bytes, zero padded) | n points |
+//! ```
+//!
+//! The points are in Montgomery form with the limbs in native endianness, so
+//! files are only portable between machines of the same endianness, which is
+//! checked when loading them. [`PointsMmap::open`] checks that every point has
+//! canonical coordinates and is on the curve before exposing it.
+//! [`PointsMmap::open_unchecked`] only validates the header: the points can then
+//! be checked on demand with [`PointsMmap::spot_check`] or
+//! [`PointsMmap::check_all`].
+
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+
+use crate::CurveExt;
+use crate::Error;
+use crate::{Coordinates, CurveAffine};
+use ff::PrimeField;
+use memmap2::Mmap;
+use rand_core::RngCore;
+use rayon::prelude::*;
+
+const MAGIC: [u8; 8] = *b"h2cpoint";
+const ENDIANNESS: u32 = 0x01020304;
+const CURVE_ID_SIZE: usize = 16;
+const HEADER_SIZE: usize = 8 + 4 + 4 + 8 + CURVE_ID_SIZE;
+
+mod sealed {
+    /// Points whose in-memory representation is plain data: affine points with
+    /// `#[repr(C)]` coordinates of `#[repr(transparent)]` limbs, without padding.
+    ///
+    /// # Safety
+    ///
+    /// Any bit pattern of `size_of::<Self>()` bytes must be a value of `Self`,
+    /// although not necessarily a valid point, and `Self` must have no padding.
+    pub unsafe trait RawPoint: crate::CurveAffine {}
+}
+
+use sealed::RawPoint;
+
+macro_rules! impl_raw_point {
+    ($($affine:ty),*) => {
+        $(
+            unsafe impl RawPoint for $affine {}
+        )*
+    };
+}
+
+impl_raw_point!(
+    crate::bn256::G1Affine,
+    crate::grumpkin::G1Affine,
+    crate::pluto_eris::G1Affine,
+    crate::pluto_eris::ErisAffine,
+    crate::secp256k1::Secp256k1Affine,
+    crate::secp256r1::Secp256r1Affine,
+    crate::secq256k1::Secq256k1Affine
+);
+
+fn header<C: CurveAffine>(len: usize) -> [u8; HEADER_SIZE] {
+    let mut header = [0u8; HEADER_SIZE];
+    header[..8].copy_from_slice(&MAGIC);
+    header[8..12].copy_from_slice(&ENDIANNESS.to_ne_bytes());
+    header[12..16].copy_from_slice(&(core::mem::size_of::<C>() as u32).to_le_bytes());
+    header[16..24].copy_from_slice(&(len as u64).to_le_bytes());
+    let id = C::CurveExt::CURVE_ID.as_bytes();
+    let id_len = id.len().min(CURVE_ID_SIZE);
+    header[24..24 + id_len].copy_from_slice(&id[..id_len]);
+    header
+}
+
+// Checks that the coordinates of `point` are canonical and that it is on the curve.
+fn check_point<C: CurveAffine>(point: &C) -> Result<(), Error> {
+    let canonical = |x: &C::Base| Option::from(C::Base::from_repr(x.to_repr())) == Some(*x);
+    let canonical = bool::from(point.is_identity())
+        || Option::from(point.coordinates()).map_or(false, |c: Coordinates<C>| {
+            canonical(c.x()) && canonical(c.y())
+        });
+    if !canonical {
+        return Err(Error::NotCanonical);
+    }
+    if !bool::from(point.is_on_curve()) {
+        return Err(Error::NotOnCurve);
+    }
+    Ok(())
+}
+
+/// Writes `points` in the format described in the [module documentation](self).
+pub fn write_points_raw<C, W>(points: &[C], writer: &mut W) -> io::Result<()>
+where
+    C: RawPoint,
+    W: Write,
+{
+    // Safety: `C` is plain data without padding.
+    let bytes = unsafe {
+        core::slice::from_raw_parts(points.as_ptr().cast::<u8>(), core::mem::size_of_val(points))
+    };
+    writer.write_all(&header::<C>(points.len()))?;
+    writer.write_all(bytes)
+}
+
+/// A read-only, memory-mapped slice of points written with [`write_points_raw`].
+///
+/// It is available for the affine points of BN256 G1, Grumpkin, Pluto, Eris,
+/// secp256k1, secp256r1 and secq256k1, whose in-memory representation is plain
+/// data.
+pub struct PointsMmap<C> {
+    mmap: Mmap,
+    _marker: core::marker::PhantomData<C>,
+}
+
+impl<C: RawPoint> PointsMmap<C> {
+    /// Maps the file at `path`, failing if its header does not describe points
+    /// of `C` written on a machine of the same endianness, if it is truncated,
+    /// or if any point has non-canonical coordinates or is not on the curve.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let res = Self::open_unchecked(path)?;
+        res.points()
+            .par_iter()
+            .try_for_each(check_point)
+            .map_err(io::Error::from)?;
+        Ok(res)
+    }
+
+    /// Maps the file at `path` like [`open`](Self::open), only validating the
+    /// header. The points may then have non-canonical coordinates or be off the
+    /// curve until they are checked with [`spot_check`](Self::spot_check) or
+    /// [`check_all`](Self::check_all).
+    pub fn open_unchecked<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = File::open(path)?;
+        // Safety: the mapping is read-only. As for any memory-mapped file, the
+        // caller must ensure that it is not modified while mapped.
+        let mmap = unsafe { Mmap::map(&file)? };
+
+        if mmap.len() < HEADER_SIZE {
+            return Err(Error::InvalidLength.into());
+        }
+        let len = u64::from_le_bytes(mmap[16..24].try_into().unwrap());
+        let len = usize::try_from(len).map_err(|_| Error::InvalidLength)?;
+        let expected = header::<C>(len);
+        if mmap[..8] != expected[..8] {
+            return Err(Error::InvalidHeader.into());
+        }
+        if mmap[8..12] != expected[8..12] {
+            return Err(Error::InvalidHeader.into());
+        }
+        if mmap[12..16] != expected[12..16] {
+            return Err(Error::InvalidHeader.into());
+        }
+        if mmap[24..HEADER_SIZE] != expected[24..] {
+            return Err(Error::InvalidHeader.into());
+        }
+        let size = len
+            .checked_mul(core::mem::size_of::<C>())
+            .and_then(|size| size.checked_add(HEADER_SIZE))
+            .ok_or(Error::InvalidLength)?;
+        if mmap.len() != size {
+            return Err(Error::InvalidLength.into());
+        }
+        // Checks the alignment once, `points` can then cast the mapping.
+        if mmap[HEADER_SIZE..].as_ptr() as usize % core::mem::align_of::<C>() != 0 {
+            return Err(Error::InvalidEncoding.into());
+        }
+
+        Ok(Self {
+            mmap,
+            _marker: core::marker::PhantomData,
+        })
+    }
+
+    /// Returns the mapped points.
+    pub fn points(&self) -> &[C] {
+        let bytes = &self.mmap[HEADER_SIZE..];
+        // Safety: `C` is plain data, and `open_unchecked` checked that the bytes
+        // are aligned and hold a whole number of points.
+        unsafe {
+            core::slice::from_raw_parts(
+                bytes.as_ptr().cast::<C>(),
+                bytes.len() / core::mem::size_of::<C>(),
+            )
+        }
+    }
+
+    /// Returns the number of points.
+    pub fn len(&self) -> usize {
+        self.points().len()
+    }
+
+    /// Returns whether there are no points.
+    pub fn is_empty(&self) -> bool {
+        self.points().is_empty()
+    }
+
+    /// Checks that the first, the last and `samples` random points have
+    /// canonical coordinates and are on the curve. This detects corrupted files
+    /// and mismatched representations without reading the whole mapping.
+    pub fn spot_check(&self, samples: usize, mut rng: impl RngCore) -> bool {
+        let points = self.points();
+        if points.is_empty() {
+            return true;
+        }
+        let ends = [0, points.len() - 1];
+        let random = (0..samples).map(|_| rng.next_u64() as usize % points.len());
+        ends.into_iter()
+            .chain(random)
+            .all(|i| check_point(&points[i]).is_ok())
+    }
+
+    /// Checks that all the points have canonical coordinates and are on the
+    /// curve.
+    pub fn check_all(&self) -> bool {
+        self.points().par_iter().all(|p| check_point(p).is_ok())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{write_points_raw, PointsMmap, RawPoint, HEADER_SIZE};
+    use crate::srs::powers_of_tau;
+    use crate::tests::assert_decoding_error;
+    use crate::Error;
+    use ff::Field;
+    use rand_core::OsRng;
+    use std::io::Write;
+
+    fn run_mmap_test<C: RawPoint>(name: &str) {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("halo2curves-mmap-{}-{name}", std::process::id()));
+        let points = powers_of_tau::<C>(C::Scalar::random(OsRng), 100);
+
+        let mut buf = Vec::new();
+        write_points_raw(&points, &mut buf).unwrap();
+        std::fs::write(&path, &buf).unwrap();
+        let mmap = PointsMmap::<C>::open(&path).unwrap();
+        assert_eq!(mmap.points(), &points[..]);
+        assert_eq!(mmap.len(), 100);
+        assert!(mmap.spot_check(10, OsRng));
+        assert!(mmap.check_all());
+
+        // Truncated or extended files are rejected.
+        std::fs::write(&path, &buf[..buf.len() - 1]).unwrap();
+        assert_decoding_error(PointsMmap::<C>::open(&path), Error::InvalidLength);
+        let mut extended = buf.clone();
+        extended.push(0);
+        std::fs::write(&path, &extended).unwrap();
+        assert!(PointsMmap::<C>::open(&path).is_err());
+
+        // So are headers of another format.
+        let mut invalid = buf.clone();
+        invalid[0] ^= 1;
+        std::fs::write(&path, &invalid).unwrap();
+        assert_decoding_error(PointsMmap::<C>::open(&path), Error::InvalidHeader);
+
+        // Corrupted points are loaded, but caught by the checks.
+        let mut corrupted = buf.clone();
+        corrupted[HEADER_SIZE] ^= 1;
+        let last = corrupted.len() - 1;
+        corrupted[last] ^= 1;
+        std::fs::File::create(&path)
+            .unwrap()
+            .write_all(&corrupted)
+            .unwrap();
+        assert!(PointsMmap::<C>::open(&path).is_err());
+        let mmap = PointsMmap::<C>::open_unchecked(&path).unwrap();
+        assert!(!mmap.spot_check(0, OsRng));
+        assert!(!mmap.check_all());
+
+        // As are non-canonical coordinates, e.g. limbs all set.
+        let mut non_canonical = buf.clone();
+        non_canonical[HEADER_SIZE..HEADER_SIZE + core::mem::size_of::<C>()].fill(0xff);
+        std::fs::write(&path, &non_canonical).unwrap();
+        assert_decoding_error(PointsMmap::<C>::open(&path), Error::NotCanonical);
+        let mmap = PointsMmap::<C>::open_unchecked(&path).unwrap();
+        assert!(!mmap.spot_check(0, OsRng));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_mmap() {
+        run_mmap_test::<crate::bn256::G1Affine>("bn256");
+        run_mmap_test::<crate::pluto_eris::G1Affine>("pluto");
+
+        // Points of another curve are rejected.
+        let path = std::env::temp_dir().join(format!("halo2curves-mmap-{}", std::process::id()));
+        let mut buf = Vec::new();
+        write_points_raw(&[crate::grumpkin::G1Affine::generator()], &mut buf).unwrap();
+        std::fs::write(&path, &buf).unwrap();
+        assert!(PointsMmap::<crate::bn256::G1Affine>::open(&path).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -1,12 +1,16 @@
 use std::ops::Neg;
 
+use crate::small_scalar::SmallScalar;
+use crate::wnaf::bits_used;
 use crate::CurveAffine;
 use ff::Field;
 use ff::PrimeField;
-use group::Group;
+use group::{Curve, Group};
+use pasta_curves::arithmetic::Coordinates;
 use rayon::iter::{
     IndexedParallelIterator, IntoParallelRefIterator, IntoParallelRefMutIterator, ParallelIterator,
 };
+use rayon::slice::ParallelSlice;
 
 const BATCH_SIZE: usize = 64;
 
@@ -54,6 +58,18 @@ fn get_booth_index(window_index: usize, window_size: usize, el: &[u8]) -> i32 {
     }
 }
 
+// The window size of the bucket method for `n` points.
+// TODO: consider adjusting it with emprical data?
+fn window_size(n: usize) -> usize {
+    if n < 4 {
+        1
+    } else if n < 32 {
+        3
+    } else {
+        (f64::from(n as u32)).ln().ceil() as usize
+    }
+}
+
 fn batch_add<C: CurveAffine>(
     size: usize,
     buckets: &mut [BucketAffine<C>],
@@ -112,6 +128,79 @@ fn batch_add<C: CurveAffine>(
     }
 }
 
+/// Adds pairs of affine points in place with a single shared inversion: for
+/// every `(dst, src)` in `pairs`, `points[dst]` becomes `points[dst] + points[src]`.
+///
+/// This is the inner loop of the affine bucket method for multi-scalar
+/// multiplications, where `points` holds the buckets and the points to
+/// accumulate. Each pair costs a few multiplications instead of an inversion.
+///
+/// The destinations must be distinct and no destination may be the source of
+/// another pair, so that the additions are independent. Sums the affine formulas
+/// don't cover, an operand at infinity or `points[dst] == ±points[src]`, are
+/// computed in projective coordinates instead. This function is not constant time.
+///
+/// # Panics
+///
+/// Panics if an index is out of bounds.
+pub fn batch_add_affine<C: CurveAffine>(points: &mut [C], pairs: &[(u32, u32)]) {
+    debug_assert!(
+        {
+            let mut dsts = pairs.iter().map(|&(dst, _)| dst).collect::<Vec<_>>();
+            dsts.sort_unstable();
+            dsts.dedup();
+            dsts.len() == pairs.len()
+                && pairs
+                    .iter()
+                    .all(|&(_, src)| dsts.binary_search(&src).is_err())
+        },
+        "batch_add_affine: the additions are not independent"
+    );
+
+    // Coordinates of the operands, `None` for the exceptional pairs, and the
+    // products of the previous denominators `x_src - x_dst`.
+    let mut operands = Vec::with_capacity(pairs.len());
+    let mut prefix = Vec::with_capacity(pairs.len());
+    let mut acc = C::Base::ONE;
+    for &(dst, src) in pairs {
+        let (p, q) = (points[dst as usize], points[src as usize]);
+        let operand = if bool::from(p.is_identity() | q.is_identity()) {
+            None
+        } else {
+            let p: Option<Coordinates<C>> = p.coordinates().into();
+            let q: Option<Coordinates<C>> = q.coordinates().into();
+            p.zip(q)
+                .map(|(p, q)| (*p.x(), *p.y(), *q.x(), *q.y()))
+                .filter(|(x1, _, x2, _)| x1 != x2)
+        };
+        prefix.push(acc);
+        if let Some((x1, _, x2, _)) = operand {
+            acc *= x2 - x1;
+        }
+        operands.push(operand);
+    }
+
+    acc = acc.invert().unwrap();
+
+    for ((&(dst, src), prefix), operand) in pairs.iter().zip(prefix).zip(operands).rev() {
+        let (dst, src) = (dst as usize, src as usize);
+        points[dst] = match operand {
+            Some((x1, y1, x2, y2)) => {
+                // `acc` is the inverse of the product of the denominators up to
+                // this pair, so `acc * prefix` is the inverse of its denominator.
+                let inv = acc * prefix;
+                acc *= x2 - x1;
+
+                let lambda = (y2 - y1) * inv;
+                let x3 = lambda.square() - x1 - x2;
+                let y3 = lambda * (x1 - x3) - y1;
+                C::from_xy(x3, y3).unwrap()
+            }
+            None => (points[dst].to_curve() + points[src]).to_affine(),
+        };
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 struct Affine<C: CurveAffine> {
     x: C::Base,
@@ -288,16 +377,20 @@ impl<C: CurveAffine> Schedule<C> {
 
 pub fn multiexp_serial<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C], acc: &mut C::Curve) {
     let coeffs: Vec<_> = coeffs.iter().map(|a| a.to_repr()).collect();
+    multiexp_serial_bytes(&coeffs, bases, acc);
+}
 
-    let c = if bases.len() < 4 {
-        1
-    } else if bases.len() < 32 {
-        3
-    } else {
-        (f64::from(bases.len() as u32)).ln().ceil() as usize
-    };
+// `multiexp_serial` over the little-endian bytes of the scalars.
+fn multiexp_serial_bytes<C: CurveAffine, B: AsRef<[u8]>>(
+    coeffs: &[B],
+    bases: &[C],
+    acc: &mut C::Curve,
+) {
+    let c = window_size(bases.len());
 
-    let number_of_windows = C::Scalar::NUM_BITS as usize / c + 1;
+    // The windows above the longest scalar are empty.
+    let max_bits = coeffs.iter().map(|a| bits_used(a.as_ref())).max();
+    let number_of_windows = max_bits.unwrap_or(0) as usize / c + 1;
 
     for current_window in (0..number_of_windows).rev() {
         for _ in 0..c {
@@ -363,10 +456,64 @@ pub fn multiexp_serial<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C], acc: &
 ///
 /// This function will panic if coeffs and bases have a different length.
 ///
-/// This will use multithreading if beneficial.
+/// This will use multithreading if beneficial, or the backend registered with
+/// [`register_msm_backend`](crate::backend::register_msm_backend).
 pub fn best_multiexp<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C]) -> C::Curve {
     assert_eq!(coeffs.len(), bases.len());
 
+    match crate::backend::msm_backend::<C>() {
+        Some(backend) => backend.msm(coeffs, bases),
+        None => cpu_multiexp(coeffs, bases),
+    }
+}
+
+/// Performs a multi-exponentiation operation with 128-bit scalars, which takes
+/// half the windows of [`best_multiexp`] on the same number of points.
+///
+/// This function will panic if coeffs and bases have a different length.
+///
+/// This will use multithreading if beneficial. The scalars are embedded into the
+/// scalar field for the backend registered with
+/// [`register_msm_backend`](crate::backend::register_msm_backend), if any.
+pub fn best_multiexp_small<C: CurveAffine>(coeffs: &[SmallScalar], bases: &[C]) -> C::Curve {
+    assert_eq!(coeffs.len(), bases.len());
+
+    match crate::backend::msm_backend::<C>() {
+        Some(backend) => {
+            let coeffs: Vec<C::Scalar> = coeffs.iter().map(|a| a.to_field()).collect();
+            backend.msm(&coeffs, bases)
+        }
+        None => {
+            let coeffs: Vec<_> = coeffs.iter().map(|a| a.to_le_bytes()).collect();
+            cpu_multiexp_bytes(&coeffs, bases)
+        }
+    }
+}
+
+/// Performs a multi-exponentiation operation over both full width and 128-bit
+/// scalars, `sum coeffs[i] * bases[i] + sum small_coeffs[i] * small_bases[i]`.
+/// The 128-bit scalars don't pay for the windows of full width ones.
+///
+/// This function will panic if the scalars and bases of either part have a
+/// different length.
+pub fn best_multiexp_mixed<C: CurveAffine>(
+    coeffs: &[C::Scalar],
+    bases: &[C],
+    small_coeffs: &[SmallScalar],
+    small_bases: &[C],
+) -> C::Curve {
+    best_multiexp(coeffs, bases) + best_multiexp_small(small_coeffs, small_bases)
+}
+
+pub(crate) fn cpu_multiexp<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C]) -> C::Curve {
+    let coeffs: Vec<_> = coeffs.par_iter().map(|a| a.to_repr()).collect();
+    cpu_multiexp_bytes(&coeffs, bases)
+}
+
+fn cpu_multiexp_bytes<C: CurveAffine, B: AsRef<[u8]> + Sync>(
+    coeffs: &[B],
+    bases: &[C],
+) -> C::Curve {
     let num_threads = rayon::current_num_threads();
     if coeffs.len() > num_threads {
         let chunk = coeffs.len() / num_threads;
@@ -381,35 +528,97 @@ pub fn best_multiexp<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C]) -> C::Cu
                 .zip(results.iter_mut())
             {
                 scope.spawn(move |_| {
-                    multiexp_serial(coeffs, bases, acc);
+                    multiexp_serial_bytes(coeffs, bases, acc);
                 });
             }
         });
         results.iter().fold(C::Curve::identity(), |a, b| a + b)
     } else {
         let mut acc = C::Curve::identity();
-        multiexp_serial(coeffs, bases, &mut acc);
+        multiexp_serial_bytes(coeffs, bases, &mut acc);
         acc
     }
 }
+
+/// Performs a multi-exponentiation operation in a group without affine
+/// coordinates, such as the target group `Gt` of a pairing, where additions are
+/// multiplications in `Fq12` and doublings are cyclotomic squarings. This is the
+/// multi-exponentiation of inner pairing product arguments and of aggregated KZG
+/// openings. Points of `G1` and `G2` are faster with [`best_multiexp`].
 ///
 /// This function will panic if coeffs and bases have a different length.
 ///
 /// This will use multithreading if beneficial.
+pub fn best_multiexp_group<G: Group>(coeffs: &[G::Scalar], bases: &[G]) -> G {
+    assert_eq!(coeffs.len(), bases.len());
+
+    let coeffs: Vec<_> = coeffs.par_iter().map(|a| a.to_repr()).collect();
+    let num_threads = rayon::current_num_threads();
+    let chunk = ((coeffs.len() + num_threads - 1) / num_threads).max(1);
+    coeffs
+        .par_chunks(chunk)
+        .zip(bases.par_chunks(chunk))
+        .map(|(coeffs, bases)| multiexp_group_serial(coeffs, bases))
+        .reduce(G::identity, |a, b| a + b)
+}
+
+// The bucket method of `multiexp_serial_bytes` on group elements, with empty
+// buckets kept apart to skip the additions of the identity.
+fn multiexp_group_serial<G: Group, B: AsRef<[u8]>>(coeffs: &[B], bases: &[G]) -> G {
+    let c = window_size(bases.len());
+
+    let max_bits = coeffs.iter().map(|a| bits_used(a.as_ref())).max();
+    let number_of_windows = max_bits.unwrap_or(0) as usize / c + 1;
+
+    let mut acc = G::identity();
+    for current_window in (0..number_of_windows).rev() {
+        for _ in 0..c {
+            acc = acc.double();
+        }
+
+        let mut buckets: Vec<Option<G>> = vec![None; 1 << (c - 1)];
+        for (coeff, base) in coeffs.iter().zip(bases.iter()) {
+            let coeff = get_booth_index(current_window, c, coeff.as_ref());
+            let base = match coeff.signum() {
+                1 => *base,
+                -1 => -*base,
+                _ => continue,
+            };
+            let bucket = &mut buckets[coeff.unsigned_abs() as usize - 1];
+            *bucket = Some(bucket.map_or(base, |b| b + base));
+        }
+
+        // Summation by parts
+        let mut running_sum = G::identity();
+        for bucket in buckets.into_iter().rev() {
+            if let Some(bucket) = bucket {
+                running_sum += bucket;
+            }
+            acc += running_sum;
+        }
+    }
+    acc
+}
+
+/// Performs a multi-exponentiation operation with the affine bucket method, which
+/// shares a single inversion of the base field among a batch of point additions,
+/// e.g. of `Fq2` for the points of `G2`.
+///
+/// This function will panic if coeffs and bases have a different length.
+///
+/// This will use multithreading if beneficial, or the backend registered with
+/// [`register_msm_backend`](crate::backend::register_msm_backend).
 pub fn best_multiexp_independent_points<C: CurveAffine>(
     coeffs: &[C::Scalar],
     bases: &[C],
 ) -> C::Curve {
     assert_eq!(coeffs.len(), bases.len());
 
-    // TODO: consider adjusting it with emprical data?
-    let c = if bases.len() < 4 {
-        1
-    } else if bases.len() < 32 {
-        3
-    } else {
-        (f64::from(bases.len() as u32)).ln().ceil() as usize
-    };
+    if let Some(backend) = crate::backend::msm_backend::<C>() {
+        return backend.msm(coeffs, bases);
+    }
+
+    let c = window_size(bases.len());
 
     if c < 10 {
         return best_multiexp(coeffs, bases);
@@ -420,8 +629,9 @@ pub fn best_multiexp_independent_points<C: CurveAffine>(
     // copy bases into `Affine` to skip in on curve check for every access
     let bases_local: Vec<_> = bases.par_iter().map(Affine::from).collect();
 
-    // number of windows
-    let number_of_windows = C::Scalar::NUM_BITS as usize / c + 1;
+    // number of windows, up to the longest scalar
+    let max_bits = coeffs.par_iter().map(|a| bits_used(a.as_ref())).max();
+    let number_of_windows = max_bits.unwrap_or(0) as usize / c + 1;
     // accumumator for each window
     let mut acc = vec![C::Curve::identity(); number_of_windows];
     acc.par_iter_mut().enumerate().rev().for_each(|(w, acc)| {
@@ -476,12 +686,14 @@ mod test {
 
     use std::ops::Neg;
 
-    use crate::bn256::{Fr, G1Affine, G1};
+    use crate::bn256::{Fr, G1Affine, G2Affine, Gt, G1};
+    use crate::small_scalar::SmallScalar;
     use ark_std::{end_timer, start_timer};
-    use ff::{Field, PrimeField};
+    use ff::{Field, FromUniformBytes, PrimeField};
+    use group::prime::PrimeCurveAffine;
     use group::{Curve, Group};
     use pasta_curves::arithmetic::CurveAffine;
-    use rand_core::OsRng;
+    use rand_core::{OsRng, RngCore};
 
     #[test]
     fn test_booth_encoding() {
@@ -556,8 +768,114 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_batch_add_affine() {
+        let n = 100;
+        let mut points = (0..2 * n)
+            .map(|_| G1Affine::random(OsRng))
+            .collect::<Vec<_>>();
+        // Exceptional pairs: doubling, inverse and identity operands.
+        points[n] = points[0];
+        points[n + 1] = -points[1];
+        points[2] = G1Affine::identity();
+        points[n + 3] = G1Affine::identity();
+        points[4] = G1Affine::identity();
+        points[n + 4] = G1Affine::identity();
+
+        let pairs = (0..n as u32)
+            .map(|i| (i, i + n as u32))
+            .rev()
+            .collect::<Vec<_>>();
+        let expected = (0..n)
+            .map(|i| (points[i].to_curve() + points[i + n]).to_affine())
+            .collect::<Vec<_>>();
+
+        super::batch_add_affine(&mut points, &pairs);
+        assert_eq!(&points[..n], &expected[..]);
+        assert_eq!(points[0], (G1::from(points[n]).double()).to_affine());
+        assert_eq!(points[1], G1Affine::identity());
+
+        // No pairs.
+        let before = points.clone();
+        super::batch_add_affine(&mut points, &[]);
+        assert_eq!(points, before);
+    }
+
     #[test]
     fn test_msm_cross() {
         run_msm_cross::<G1Affine>(14, 18);
+        // The affine buckets of `G2` batch the inversions of `Fq2`.
+        run_msm_cross::<G2Affine>(13, 14);
+        run_msm_cross::<crate::bls12_377::G2Affine>(13, 13);
+    }
+
+    #[test]
+    fn test_msm_group() {
+        for n in [0, 1, 10, 40] {
+            let bases = (0..n).map(|_| Gt::random(OsRng)).collect::<Vec<_>>();
+            let scalars = (0..n).map(|_| Fr::random(OsRng)).collect::<Vec<_>>();
+            let expected = bases
+                .iter()
+                .zip(scalars.iter())
+                .fold(Gt::identity(), |acc, (p, s)| acc + p * s);
+            assert_eq!(super::best_multiexp_group(&scalars, &bases), expected);
+        }
+
+        // The group multi-exponentiation agrees with the one of `G1`.
+        let points = (0..100).map(|_| G1::random(OsRng)).collect::<Vec<_>>();
+        let mut affine_points = vec![G1Affine::identity(); 100];
+        G1::batch_normalize(&points, &mut affine_points);
+        let scalars = (0..100).map(|_| Fr::random(OsRng)).collect::<Vec<_>>();
+        assert_eq!(
+            super::best_multiexp_group(&scalars, &points),
+            super::best_multiexp(&scalars, &affine_points)
+        );
+    }
+
+    #[test]
+    fn test_msm_small() {
+        for n in [0, 1, 10, 100, 1 << 10] {
+            let points = (0..2 * n)
+                .map(|_| G1Affine::random(OsRng))
+                .collect::<Vec<_>>();
+            let small = (0..n)
+                .map(|_| SmallScalar(((OsRng.next_u64() as u128) << 64) | OsRng.next_u64() as u128))
+                .collect::<Vec<_>>();
+            let full = (0..n).map(|_| Fr::random(OsRng)).collect::<Vec<_>>();
+
+            let embedded = small.iter().map(|k| k.to_field()).collect::<Vec<Fr>>();
+            let expected = super::best_multiexp(&embedded, &points[..n]);
+            assert_eq!(super::best_multiexp_small(&small, &points[..n]), expected);
+            assert_eq!(
+                super::best_multiexp_mixed(&full, &points[n..], &small, &points[..n]),
+                expected + super::best_multiexp(&full, &points[n..])
+            );
+        }
+    }
+
+    #[test]
+    fn test_msm_short_scalars() {
+        // 128-bit and zero scalars skip the leading windows.
+        for n in [1, 10, 100, 1 << 13] {
+            let points = (0..n).map(|_| G1Affine::random(OsRng)).collect::<Vec<_>>();
+            for bits in [0, 1, 64, 128] {
+                let scalars = (0..n)
+                    .map(|_| {
+                        let mut bytes = [0u8; 64];
+                        OsRng.fill_bytes(&mut bytes[..(bits + 7) / 8]);
+                        Fr::from_uniform_bytes(&bytes)
+                    })
+                    .collect::<Vec<_>>();
+                let expected = points
+                    .iter()
+                    .zip(scalars.iter())
+                    .fold(G1::identity(), |acc, (p, s)| acc + p * s);
+                assert_eq!(super::best_multiexp(&scalars, &points), expected);
+                assert_eq!(
+                    super::best_multiexp_independent_points(&scalars, &points),
+                    expected
+                );
+            }
+        }
     }
 }
@@ -0,0 +1,121 @@
+//! Simultaneous multiplication of a few points, `a P + b Q + ...`, as in the
+//! verification of Schnorr and ECDSA signatures.
+//!
+//! The terms are interleaved in the method of Strauss and Shamir: every point has
+//! its own table of multiples, and a single chain of doublings is shared by all the
+//! scalars. `N` terms cost about as many doublings as one multiplication, instead
+//! of `N` times as many. The functions are meant for two to four terms; larger sums
+//! are faster with the bucket method of [`msm`](crate::msm).
+
+use core::ops::Neg;
+
+use ff::PrimeField;
+
+use crate::arithmetic::{select_signed, signed_radix16};
+use crate::wnaf::ToWnaf;
+use crate::CurveExt;
+
+/// Returns `sum_i k_i P_i` for the terms `(P_i, k_i)`, with interleaved signed
+/// 4-bit windows. This runs in constant time, for secret scalars.
+pub fn multi_mul<C: CurveExt, const N: usize>(terms: [(C, C::ScalarExt); N]) -> C
+where
+    for<'a> &'a C: Neg<Output = C>,
+{
+    let tables = terms.map(|(p, k)| {
+        // [P, 2P, ..., 8P]
+        let mut table = [p; 8];
+        for i in 1..8 {
+            table[i] = table[i - 1] + p;
+        }
+        (table, signed_radix16(k.to_repr().as_ref()))
+    });
+
+    let windows = tables.iter().map(|(_, digits)| digits.len()).max();
+    let mut acc = C::identity();
+    for i in (0..windows.unwrap_or(0)).rev() {
+        acc = acc.double().double().double().double();
+        for (table, digits) in tables.iter() {
+            acc += select_signed(table, C::identity(), digits[i]);
+        }
+    }
+    acc
+}
+
+/// Returns `sum_i k_i P_i` for the terms `(P_i, k_i)`, with interleaved width-5
+/// NAFs, skipping the leading zeros of the scalars. This runs in variable time.
+pub fn multi_mul_vartime<C: CurveExt, const N: usize>(terms: [(C, C::ScalarExt); N]) -> C {
+    let tables = terms.map(|(p, k)| {
+        // [P, 3P, 5P, ..., 15P]
+        let double = p.double();
+        let mut table = [p; 8];
+        for i in 1..8 {
+            table[i] = table[i - 1] + double;
+        }
+        (table, k.to_wnaf::<5>())
+    });
+
+    let len = tables.iter().map(|(_, digits)| digits.len()).max();
+    let mut acc = C::identity();
+    for i in (0..len.unwrap_or(0)).rev() {
+        acc = acc.double();
+        for (table, digits) in tables.iter() {
+            match digits.get(i).map_or(0, |digit| digit.signum()) {
+                1 => acc += table[digits[i] as usize / 2],
+                -1 => acc -= table[digits[i].unsigned_abs() as usize / 2],
+                _ => {}
+            }
+        }
+    }
+    acc
+}
+
+#[cfg(test)]
+mod test {
+    use super::{multi_mul, multi_mul_vartime};
+    use crate::bn256::G1;
+    use crate::pluto_eris::G1 as PlutoG1;
+    use crate::CurveExt;
+    use core::ops::Neg;
+    use ff::Field;
+    use rand_core::OsRng;
+
+    fn check<C: CurveExt, const N: usize>(terms: [(C, C::ScalarExt); N])
+    where
+        for<'a> &'a C: Neg<Output = C>,
+    {
+        let expected = terms.iter().fold(C::identity(), |acc, (p, k)| acc + *p * k);
+        assert_eq!(multi_mul(terms), expected);
+        assert_eq!(multi_mul_vartime(terms), expected);
+    }
+
+    fn run_multi_mul_test<C: CurveExt>()
+    where
+        for<'a> &'a C: Neg<Output = C>,
+    {
+        let p = || C::random(OsRng);
+        let k = || C::ScalarExt::random(OsRng);
+        for _ in 0..10 {
+            check::<C, 1>([(p(), k())]);
+            check([(p(), k()), (p(), k())]);
+            check([(p(), k()), (p(), k()), (p(), k())]);
+            check([(p(), k()), (p(), k()), (p(), k()), (p(), k())]);
+        }
+
+        // Short, zero and maximal scalars, the identity and repeated points.
+        let (p, q) = (p(), p());
+        let small = C::ScalarExt::from(5);
+        let max = -C::ScalarExt::ONE;
+        check([(p, small), (q, max)]);
+        check([(p, C::ScalarExt::ZERO), (q, small)]);
+        check([(C::identity(), max), (q, k())]);
+        check([(p, max), (p, C::ScalarExt::ONE)]);
+        check([(p, k()), (-p, k())]);
+        check::<C, 0>([]);
+    }
+
+    #[test]
+    fn test_multi_mul() {
+        run_multi_mul_test::<G1>();
+        run_multi_mul_test::<PlutoG1>();
+    }
+}
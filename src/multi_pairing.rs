@@ -0,0 +1,122 @@
+//! Products of pairings with scalar factors, `e(a_1 P_1, Q_1) ... e(a_n P_n, Q_n)`,
+//! over any pairing engine of the crate.
+//!
+//! The scalars are folded into the `G1` inputs before the Miller loop: the terms
+//! that share a point `Q` of `G2` become a single term, whose `G1` input is the MSM
+//! of their points and scalars. The Miller loop then runs once per distinct point
+//! of `G2`. A batched KZG verification, for instance, pairs all its terms with
+//! either `G2` or `tau G2`, so it needs two Miller loops whatever the number of
+//! openings.
+//!
+//! The MSMs run in variable time, and the grouping reveals which points of `G2`
+//! are equal: the inputs are meant to be public, as in a verifier.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use group::prime::PrimeCurveAffine;
+use group::Curve;
+use pairing::{MillerLoopResult, MultiMillerLoop};
+
+use crate::msm::best_multiexp_group;
+
+/// Returns the product of the pairings `e(a_i P_i, Q_i)` of the terms
+/// `(P_i, Q_i, a_i)`, written additively in `Gt` as `sum_i a_i e(P_i, Q_i)`. The
+/// product of no terms is the identity.
+pub fn multi_pairing<E: MultiMillerLoop>(terms: &[(E::G1Affine, E::G2Affine, E::Fr)]) -> E::Gt {
+    multi_miller_loop::<E>(terms).final_exponentiation()
+}
+
+/// Returns the multi-Miller loop of [`multi_pairing`], before the final
+/// exponentiation, e.g. to multiply it with other Miller loops first.
+pub fn multi_miller_loop<E: MultiMillerLoop>(
+    terms: &[(E::G1Affine, E::G2Affine, E::Fr)],
+) -> E::Result {
+    // The distinct points of `G2`, with the points and scalars of their terms.
+    #[allow(clippy::type_complexity)]
+    let mut groups: Vec<(E::G2Affine, Vec<E::G1>, Vec<E::Fr>)> = vec![];
+    for (p, q, a) in terms {
+        match groups.iter_mut().find(|(other, _, _)| other == q) {
+            Some((_, points, scalars)) => {
+                points.push(p.to_curve());
+                scalars.push(*a);
+            }
+            None => groups.push((*q, vec![p.to_curve()], vec![*a])),
+        }
+    }
+
+    let sums = groups
+        .iter()
+        .map(|(_, points, scalars)| best_multiexp_group(scalars, points))
+        .collect::<Vec<_>>();
+    let mut g1 = vec![E::G1Affine::identity(); sums.len()];
+    E::G1::batch_normalize(&sums, &mut g1);
+    let g2 = groups
+        .iter()
+        .map(|(q, _, _)| E::G2Prepared::from(*q))
+        .collect::<Vec<_>>();
+
+    let terms = g1.iter().zip(g2.iter()).collect::<Vec<_>>();
+    E::multi_miller_loop(&terms)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{multi_miller_loop, multi_pairing};
+    use ff::Field;
+    use group::prime::PrimeCurveAffine;
+    use group::{Curve, Group};
+    use pairing::{MillerLoopResult, MultiMillerLoop};
+    use rand_core::OsRng;
+
+    fn run_multi_pairing_test<E: MultiMillerLoop>() {
+        let g1 = || E::G1::random(OsRng).to_affine();
+        let g2 = || E::G2::random(OsRng).to_affine();
+        let a = || E::Fr::random(OsRng);
+        let expected = |terms: &[(E::G1Affine, E::G2Affine, E::Fr)]| {
+            terms.iter().fold(E::Gt::identity(), |acc, (p, q, a)| {
+                acc + E::pairing(&(*p * a).to_affine(), q)
+            })
+        };
+
+        // Distinct and shared points of `G2`, in any order.
+        let (q1, q2) = (g2(), g2());
+        for terms in [
+            vec![(g1(), q1, a())],
+            vec![(g1(), q1, a()), (g1(), q2, a())],
+            vec![
+                (g1(), q1, a()),
+                (g1(), q2, a()),
+                (g1(), q1, a()),
+                (g1(), q1, a()),
+            ],
+            (0..10).map(|_| (g1(), g2(), a())).collect(),
+        ] {
+            assert_eq!(multi_pairing::<E>(&terms), expected(&terms));
+            assert_eq!(
+                multi_miller_loop::<E>(&terms).final_exponentiation(),
+                expected(&terms)
+            );
+        }
+
+        // Identities and zero scalars.
+        let terms = [
+            (E::G1Affine::identity(), q1, a()),
+            (g1(), E::G2Affine::identity(), a()),
+            (g1(), q2, E::Fr::ZERO),
+        ];
+        assert!(bool::from(multi_pairing::<E>(&terms).is_identity()));
+        assert!(bool::from(multi_pairing::<E>(&[]).is_identity()));
+
+        // A pairing check with terms that cancel out, as in a KZG verification.
+        let (p, s) = (g1(), a());
+        let terms = [(p, q1, s), (g1(), q2, a()), (p, q1, -s)];
+        assert_eq!(multi_pairing::<E>(&terms), expected(&terms[1..2]));
+    }
+
+    #[test]
+    fn test_multi_pairing() {
+        run_multi_pairing_test::<crate::bn256::Bn256>();
+        run_multi_pairing_test::<crate::bls12_377::Bls12377>();
+    }
+}
@@ -0,0 +1,80 @@
+//! Control of the threads of the parallel algorithms.
+//!
+//! The multi-scalar multiplications, FFTs, batch normalizations and batch hashes
+//! of the crate run on the current rayon thread pool, the global one by default,
+//! and split their work by [`rayon::current_num_threads`]. Provers that manage
+//! their own pools can run them in a given pool with [`with_thread_pool`], or
+//! bound their threads with [`with_num_threads`], so that they don't
+//! oversubscribe the machine.
+
+use rayon::{ThreadPool, ThreadPoolBuildError, ThreadPoolBuilder};
+
+/// The environment variable read by [`thread_pool_from_env`].
+pub const NUM_THREADS_ENV: &str = "HALO2CURVES_NUM_THREADS";
+
+/// Runs `f` in `pool`: the parallel algorithms called by `f` use the threads of
+/// `pool` instead of the global pool.
+pub fn with_thread_pool<R: Send>(pool: &ThreadPool, f: impl FnOnce() -> R + Send) -> R {
+    pool.install(f)
+}
+
+/// Runs `f` in a new pool of `num_threads` threads, see [`with_thread_pool`].
+pub fn with_num_threads<R: Send>(
+    num_threads: usize,
+    f: impl FnOnce() -> R + Send,
+) -> Result<R, ThreadPoolBuildError> {
+    let pool = ThreadPoolBuilder::new().num_threads(num_threads).build()?;
+    Ok(with_thread_pool(&pool, f))
+}
+
+/// Builds a thread pool with the number of threads in [`NUM_THREADS_ENV`].
+///
+/// If the variable is unset, not a number or zero, the number of threads is
+/// chosen by rayon, which reads `RAYON_NUM_THREADS` and defaults to the number
+/// of CPUs.
+pub fn thread_pool_from_env() -> Result<ThreadPool, ThreadPoolBuildError> {
+    let num_threads = parse_num_threads(std::env::var(NUM_THREADS_ENV).ok().as_deref());
+    ThreadPoolBuilder::new().num_threads(num_threads).build()
+}
+
+// Parses the value of `NUM_THREADS_ENV`, `0` letting rayon choose.
+fn parse_num_threads(value: Option<&str>) -> usize {
+    value.and_then(|n| n.parse::<usize>().ok()).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bn256::{Fr, G1Affine, G1};
+    use crate::msm::best_multiexp;
+    use ff::Field;
+    use group::{Curve, Group};
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_thread_pool() {
+        let coeffs = (0..1000).map(|_| Fr::random(OsRng)).collect::<Vec<_>>();
+        let bases = (0..1000)
+            .map(|_| G1::random(OsRng).to_affine())
+            .collect::<Vec<G1Affine>>();
+        let expected = best_multiexp(&coeffs, &bases);
+
+        for num_threads in [1, 3] {
+            let (threads, res) = with_num_threads(num_threads, || {
+                (rayon::current_num_threads(), best_multiexp(&coeffs, &bases))
+            })
+            .unwrap();
+            assert_eq!(threads, num_threads);
+            assert_eq!(res, expected);
+        }
+    }
+
+    #[test]
+    fn test_parse_num_threads() {
+        assert_eq!(parse_num_threads(Some("2")), 2);
+        assert_eq!(parse_num_threads(Some("0")), 0);
+        assert_eq!(parse_num_threads(Some("invalid")), 0);
+        assert_eq!(parse_num_threads(Some("-1")), 0);
+        assert_eq!(parse_num_threads(None), 0);
+    }
+}
@@ -4,10 +4,10 @@ use crate::{
     arithmetic::{CurveEndo, EndoParameters},
     endo,
 };
+use core::convert::TryInto;
 use ff::PrimeField;
 use ff::WithSmallOrderMulGroup;
 pub use pasta_curves::{pallas, vesta, Ep, EpAffine, Eq, EqAffine, Fp, Fq};
-use std::convert::TryInto;
 
 // Generated using https://github.com/ConsenSys/gnark-crypto/blob/master/ecc/utils.go
 // with `pasta_curves::Fp::ZETA`
@@ -38,6 +38,30 @@ const ENDO_PARAMS_EP: EndoParameters = EndoParameters {
 endo!(Eq, Fp, ENDO_PARAMS_EQ);
 endo!(Ep, Fq, ENDO_PARAMS_EP);
 
+crate::impl_random_seeded!(Fp, 64);
+crate::impl_random_seeded!(Fq, 64);
+
+/// The Pasta cycle: Pallas (`Ep`) and Vesta (`Eq`).
+#[derive(Clone, Copy, Debug)]
+pub struct Pasta;
+
+impl crate::cycle::CurveCycle for Pasta {
+    type C1 = Ep;
+    type C2 = Eq;
+}
+
+impl crate::hash_to_curve::BatchHashToCurve for Eq {
+    fn batch_hash_to_curve(domain_prefix: &str, messages: &[&[u8]]) -> alloc::vec::Vec<Self> {
+        crate::hash_to_curve::par_hash_to_curve(domain_prefix, messages)
+    }
+}
+
+impl crate::hash_to_curve::BatchHashToCurve for Ep {
+    fn batch_hash_to_curve(domain_prefix: &str, messages: &[&[u8]]) -> alloc::vec::Vec<Self> {
+        crate::hash_to_curve::par_hash_to_curve(domain_prefix, messages)
+    }
+}
+
 #[test]
 fn test_endo() {
     use ff::Field;
@@ -71,3 +95,11 @@ fn test_endo() {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use pasta_curves::arithmetic::CurveExt;
+
+    crate::curve_testing_suite!(Ep, Eq, "batch_hash_to_curve");
+}
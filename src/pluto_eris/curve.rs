@@ -1,4 +1,4 @@
-use super::{fp::Fp, fp2::Fp2, fq::Fq};
+use super::{fp::Fp, fp2::Fp2, fp6::FROBENIUS_COEFF_FP6_C1, fq::Fq};
 use crate::derive::curve::{IDENTITY_MASK, IDENTITY_SHIFT, SIGN_MASK, SIGN_SHIFT};
 use crate::ff::WithSmallOrderMulGroup;
 use crate::ff::{Field, PrimeField};
@@ -9,7 +9,7 @@ use core::fmt::Debug;
 use core::iter::Sum;
 use core::ops::{Add, Mul, Neg, Sub};
 use group::cofactor::CofactorGroup;
-use rand::RngCore;
+use rand_core::RngCore;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 #[cfg(feature = "derive_serde")]
@@ -20,7 +20,7 @@ use crate::{
     impl_binops_multiplicative_mixed, new_curve_impl,
 };
 
-const G1_GENERATOR_X: Fp = Fp::from_raw([
+const G1_GENERATOR_X: Fp = Fp::from_raw_unchecked([
     0x9ffffcd2ffffffff,
     0xa2a7e8c30006b945,
     0xe4a7a5fe8fadffd6,
@@ -29,12 +29,155 @@ const G1_GENERATOR_X: Fp = Fp::from_raw([
     0x0130e0000d7f70e4,
     0x2400000000002400,
 ]);
-const G1_GENERATOR_Y: Fp = Fp::from_raw([0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+const G1_GENERATOR_Y: Fp = Fp::from_raw_unchecked([0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
 
 const PLUTO_A: Fp = Fp::ZERO;
-const PLUTO_B: Fp = Fp::from_raw([0x39, 0, 0, 0, 0, 0, 0]);
+const PLUTO_B: Fp = Fp::from_raw_unchecked([0x39, 0, 0, 0, 0, 0, 0]);
+
+// E': y^2 = x^3 + A' * x + B', 3-isogenous to Pluto, for the simplified SWU map. The
+// isogeny and Z are derived in `script/pluto_isogeny.py`.
+const PLUTO_ISO_A: Fp = Fp::from_raw_unchecked([
+    0xefba62e8011b1c59,
+    0x6e0b921cd2b5d98a,
+    0x42f2c8b9154bb59f,
+    0x71ddd9aa2fca7151,
+    0x787be10d549504df,
+    0xfa26236c5be3fd60,
+    0x02d9893f6ef09df0,
+]);
+const PLUTO_ISO_B: Fp = Fp::from_raw_unchecked([0x3855, 0, 0, 0, 0, 0, 0]);
+
+const PLUTO_ISO_MAP: crate::isogeny::IsogenyMap<Fp> = crate::isogeny::IsogenyMap {
+    x_num: &[
+        Fp::from_raw_unchecked([
+            0xfff708f8000001e2,
+            0x1b78d6e7897fbe2a,
+            0x0935ce4a8196e34f,
+            0xc2bbb1af0e706134,
+            0x700286947022bdc7,
+            0x00cb400008784098,
+            0x1800000000001800,
+        ]),
+        Fp::from_raw_unchecked([
+            0xa00cace6c431ebd5,
+            0xc435ecffc977994f,
+            0x291f97be35a6cb38,
+            0x3850abe2f82072ca,
+            0xb82f7a65dc4f7599,
+            0x76e7da501a12cd6d,
+            0x080f526c2b34f69c,
+        ]),
+        Fp::from_raw_unchecked([
+            0x35072c9897a9d750,
+            0xe6699dd8ab9f92da,
+            0x83c88b32edef0438,
+            0xecafc456c3c91fa8,
+            0xeda2f4c564191ba1,
+            0x0bf689c1e847dfc3,
+            0x1556625fd8220355,
+        ]),
+        Fp::from_raw_unchecked([
+            0x1aaaa75080000001,
+            0xcf9e6a18cab13420,
+            0x5f8b5ffb8c220fa0,
+            0xde716b447061a318,
+            0x40035ea29a32cca1,
+            0x010f00000bff78cb,
+            0x2000000000002000,
+        ]),
+    ],
+    x_den: &[
+        Fp::from_raw_unchecked([
+            0x94e1ac451944aab3,
+            0x2493a5a6da6918ae,
+            0x86813f93faec495e,
+            0x81a9e33a1cbfd93d,
+            0x3fde3f80a8d38787,
+            0xb606f22c56fb1997,
+            0x1f8b8a0691eb2437,
+        ]),
+        Fp::from_raw_unchecked([
+            0xf815e4c0d6b3b08c,
+            0x15364106fa0a4c34,
+            0x79a4a8e28be36f7f,
+            0x59596aaf35a71916,
+            0x74f1bc61e07c4a79,
+            0x27185dba3576c9f5,
+            0x16ec27f31bacc695,
+        ]),
+        Fp::ONE,
+    ],
+    y_num: &[
+        Fp::from_raw_unchecked([
+            0xf55554fb0000018d,
+            0xbcbd52c055561495,
+            0x1967f5ffd7135550,
+            0x95ce2d988a0f6163,
+            0x68006bd45406599a,
+            0x0021e000017ff019,
+            0x0400000000000400,
+        ]),
+        Fp::from_raw_unchecked([
+            0x44ab9d356b2f9054,
+            0x0a550e98ef00efce,
+            0x61806b7c0fa0615b,
+            0x97256e1e1e8e6e51,
+            0x9fbbf604d8b84d8b,
+            0xa2c38e1ff30b403f,
+            0x1a61fd0ee9061ce8,
+        ]),
+        Fp::from_raw_unchecked([
+            0x0ea97077f4127632,
+            0x812eaed79ccc8402,
+            0x52b68a2b062a79a9,
+            0x625e3c2c5bb8df09,
+            0x47bd0d0ef74856ea,
+            0x298da575b3b1cae8,
+            0x1eb9fdc7d3cbdc14,
+        ]),
+        Fp::from_raw_unchecked([
+            0xa71c71a900000000,
+            0x3ee9c6401c7206dc,
+            0xb322a75547b11c70,
+            0x8744b9dd835a75cb,
+            0x780023f171577333,
+            0xaab5f55555d55008,
+            0x01555555555556aa,
+        ]),
+    ],
+    y_den: &[
+        Fp::from_raw_unchecked([
+            0x9ffffcd2ffffe7f5,
+            0xa2a7e8c30006b945,
+            0xe4a7a5fe8fadffd6,
+            0x443f9a5cda8a6c7b,
+            0xa803ca76f439266f,
+            0x0130e0000d7f70e4,
+            0x2400000000002400,
+        ]),
+        Fp::from_raw_unchecked([
+            0x7ea50b294bce0017,
+            0x286b1f6e8f2dd780,
+            0xca3472bed168dc6d,
+            0xfc7e74f4a12ab2c0,
+            0x6f932994120849b7,
+            0x1fb31684e9f26afc,
+            0x16a29e13b5c124a7,
+        ]),
+        Fp::from_raw_unchecked([
+            0x7420d721420d88d2,
+            0x9fd1618a770f724f,
+            0xb676fd53d1d5273e,
+            0x06062006d07aa5a1,
+            0x2f6a9a92d0ba6fb6,
+            0xbaa48c9750322ef0,
+            0x22623beca98329df,
+        ]),
+        Fp::ONE,
+    ],
+};
 
-const ERIS_GENERATOR_X: Fq = Fq::from_raw([
+const ERIS_GENERATOR_X: Fq = Fq::from_raw_unchecked([
     0x1ffffcd2ffffffff,
     0x9ca7e85d60050af4,
     0xe4a775fe8e177fd6,
@@ -43,14 +186,14 @@ const ERIS_GENERATOR_X: Fq = Fq::from_raw([
     0x0130e0000d7f70e4,
     0x2400000000002400,
 ]);
-const ERIS_GENERATOR_Y: Fq = Fq::from_raw([0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
+const ERIS_GENERATOR_Y: Fq = Fq::from_raw_unchecked([0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
 
 const ERIS_A: Fq = Fq::ZERO;
-const ERIS_B: Fq = Fq::from_raw([0x39, 0, 0, 0, 0, 0, 0]);
+const ERIS_B: Fq = Fq::from_raw_unchecked([0x39, 0, 0, 0, 0, 0, 0]);
 
 const G2_GENERATOR_X: Fp2 = Fp2 {
     // 0x13576c81faf3a13fd815d0e9bd54b845ee935948b84498b27ca972bfb93722e223c9e276a4ebe7559cfc86dd865f07d64f2b5fe6556f9066
-    c0: Fp::from_raw([
+    c0: Fp::from_raw_unchecked([
         0x4f2b5fe6556f9066,
         0x9cfc86dd865f07d6,
         0x23c9e276a4ebe755,
@@ -61,7 +204,7 @@ const G2_GENERATOR_X: Fp2 = Fp2 {
     ]),
 
     //0x142164cb875db0465e5092f9380f44f555243d011699b7393029f2d201554727aeb383298fdf5847b9b3dff01bbe8d63fe7c781a8fd7bf21
-    c1: Fp::from_raw([
+    c1: Fp::from_raw_unchecked([
         0xfe7c781a8fd7bf21,
         0xb9b3dff01bbe8d63,
         0xaeb383298fdf5847,
@@ -73,7 +216,7 @@ const G2_GENERATOR_X: Fp2 = Fp2 {
 };
 const G2_GENERATOR_Y: Fp2 = Fp2 {
     //0x2239f7408ead478c58e88d4df1e7418c42fdbb92e64ba85aa4dc17d7dace3f32eb471c004db774bfe78574aca67b3898cd1b78ad106ab9fe
-    c0: Fp::from_raw([
+    c0: Fp::from_raw_unchecked([
         0xcd1b78ad106ab9fe,
         0xe78574aca67b3898,
         0xeb471c004db774bf,
@@ -84,7 +227,7 @@ const G2_GENERATOR_Y: Fp2 = Fp2 {
     ]),
 
     // 0x1260b04d51136590dbb53dfd7caf450aeca714555bbe4f079ca65d97eb28fc9fc697b4e10bbcd9e0539ef82a731fb88ed49e3c080e6d945d
-    c1: Fp::from_raw([
+    c1: Fp::from_raw_unchecked([
         0xd49e3c080e6d945d,
         0x539ef82a731fb88e,
         0xc697b4e10bbcd9e0,
@@ -99,7 +242,7 @@ const TRITON_A: Fp2 = Fp2::ZERO;
 
 // u + 3
 const TRITON_B: Fp2 = Fp2 {
-    c0: Fp::from_raw([0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+    c0: Fp::from_raw_unchecked([0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
     c1: Fp::ONE,
 };
 
@@ -153,13 +296,55 @@ impl G1 {
     /// Computed using https://www.ietf.org/archive/id/draft-irtf-cfrg-hash-to-curve-10.html#svdw-z-code
     const SVDW_Z: Fp = Fp::ONE;
 
-    fn default_hash_to_curve_suite() -> crate::hash_to_curve::Suite<Self, sha2::Sha256, 72> {
-        crate::hash_to_curve::Suite::<Self, sha2::Sha256, 72>::new(
+    fn default_hash_to_curve_suite(
+    ) -> crate::hash_to_curve::Suite<Self, crate::hash_to_curve::ExpandMsgXmd<sha2::Sha256>, 72>
+    {
+        crate::hash_to_curve::Suite::<Self, crate::hash_to_curve::ExpandMsgXmd<sha2::Sha256>, 72>::new(
             b"pluto_XMD:SHA-256_SVDW_RO_",
             Self::SVDW_Z,
             crate::hash_to_curve::Method::SVDW,
         )
     }
+
+    /// Constant Z for the simplified SWU map on the 3-isogenous curve.
+    const SSWU_Z: Fp = Fp::from_raw_unchecked([5, 0, 0, 0, 0, 0, 0]);
+
+    /// The `pluto_XMD:SHA-256_SSWU_RO_` suite, which maps to a 3-isogenous curve with
+    /// the simplified SWU map instead of the SVDW map of the default suite, and is
+    /// faster. The two suites hash to different points.
+    pub fn sswu_hash_to_curve_suite(
+    ) -> crate::hash_to_curve::Suite<Self, crate::hash_to_curve::ExpandMsgXmd<sha2::Sha256>, 72>
+    {
+        let iso = crate::hash_to_curve::Iso {
+            a: PLUTO_ISO_A,
+            b: PLUTO_ISO_B,
+            map: alloc::boxed::Box::new(|x, y, z| {
+                let (x, y, z) = PLUTO_ISO_MAP.evaluate(x, y, z);
+                G1 { x, y, z }
+            }),
+        };
+        crate::hash_to_curve::Suite::new(
+            b"pluto_XMD:SHA-256_SSWU_RO_",
+            Self::SSWU_Z,
+            crate::hash_to_curve::Method::SSWU(iso),
+        )
+    }
+}
+
+impl crate::hash_to_curve::BatchHashToCurve for G1 {
+    fn batch_hash_to_curve(domain_prefix: &str, messages: &[&[u8]]) -> alloc::vec::Vec<Self> {
+        Self::default_hash_to_curve_suite().batch_hash_to_curve(domain_prefix, messages)
+    }
+}
+
+impl crate::hash_to_curve::MapToCurve for G1 {
+    fn hash_to_field(domain_prefix: &str, message: &[u8]) -> [Self::Base; 2] {
+        Self::default_hash_to_curve_suite().hash_to_field(domain_prefix, message)
+    }
+
+    fn map_to_curve(u: &Self::Base) -> Self {
+        Self::default_hash_to_curve_suite().map_to_curve(u)
+    }
 }
 
 new_curve_impl!(
@@ -175,56 +360,171 @@ new_curve_impl!(
     |domain_prefix| crate::hash_to_curve::hash_to_curve(domain_prefix, Eris::default_hash_to_curve_suite()),
 );
 
-impl CofactorGroup for G2 {
-    type Subgroup = G2;
+#[cfg(feature = "precompute")]
+impl G1Affine {
+    /// Decompresses each of `bytes`, with the same result as
+    /// [`Self::batch_from_compressed`], but takes the square roots with the
+    /// table-based [`Fp::sqrt_vartime`]. It runs in variable time, so it is only
+    /// meant for public points, e.g. those of a proof or of an SRS.
+    pub fn batch_from_compressed_vartime(
+        bytes: &[<Self as GroupEncoding>::Repr],
+    ) -> alloc::vec::Vec<CtOption<Self>> {
+        // Pluto has prime order, every point on the curve is in the subgroup.
+        crate::arithmetic::par_map_chunks(bytes, |bytes| {
+            bytes
+                .iter()
+                .map(|bytes| Self::from_bytes_with_sqrt(bytes, Fp::sqrt_vartime))
+                .collect()
+        })
+    }
+}
 
-    fn clear_cofactor(&self) -> Self {
-        // cofactor = 2*p - q
-        //0x24000000000024000130e0000d7f70e4a803ca76f439266f443f9a5d3a8a6c7be4a7d5fe91447fd6a8a7e928a00867971ffffcd300000001
-        let e: [u8; 56] = [
-            0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0x24, 0x00, 0x01, 0x30, 0xe0, 0x00, 0x0d, 0x7f,
-            0x70, 0xe4, 0xa8, 0x03, 0xca, 0x76, 0xf4, 0x39, 0x26, 0x6f, 0x44, 0x3f, 0x9a, 0x5d,
-            0x3a, 0x8a, 0x6c, 0x7b, 0xe4, 0xa7, 0xd5, 0xfe, 0x91, 0x44, 0x7f, 0xd6, 0xa8, 0xa7,
-            0xe9, 0x28, 0xa0, 0x08, 0x67, 0x97, 0x1f, 0xff, 0xfc, 0xd3, 0x00, 0x00, 0x00, 0x01,
-        ];
-
-        // self * TRITON_COFACTOR
+#[cfg(feature = "precompute")]
+impl ErisAffine {
+    /// Decompresses each of `bytes` like [`G1Affine::batch_from_compressed_vartime`],
+    /// with the table-based [`Fq::sqrt_vartime`]. Only meant for public points.
+    pub fn batch_from_compressed_vartime(
+        bytes: &[<Self as GroupEncoding>::Repr],
+    ) -> alloc::vec::Vec<CtOption<Self>> {
+        // Eris has prime order, every point on the curve is in the subgroup.
+        crate::arithmetic::par_map_chunks(bytes, |bytes| {
+            bytes
+                .iter()
+                .map(|bytes| Self::from_bytes_with_sqrt(bytes, Fq::sqrt_vartime))
+                .collect()
+        })
+    }
+}
+
+/// Value of (57/(u + 3))^((p - 1)/2) where u^2 + 5 = 0 in Fp2, the factor of the
+/// `y` coordinate in the untwist-Frobenius-twist endomorphism of [`G2`], see
+/// [`XI`](super::XI).
+pub const XI_TO_P_MINUS_1_OVER_2: Fp2 = Fp2 {
+    c0: Fp::from_raw_unchecked([
+        0x54cf5ad1c0926216,
+        0x186c1f3ce4a46d4e,
+        0x9c23800ce9c9452f,
+        0x50e0d09ff6d6c08b,
+        0x7cf421e4d46f6666,
+        0x678664ba4b6d8343,
+        0x21cc26d5de0f80f4,
+    ]),
+
+    c1: Fp::from_raw_unchecked([
+        0xc0505f4c260e91f4,
+        0xe7bbd15f10723657,
+        0xb4b3e0c35358097e,
+        0x87c56f42a558750d,
+        0x4b7211d23f34f0ae,
+        0xf6839d29e2f0d250,
+        0x16ebe8b2e12a1106,
+    ]),
+};
+
+/// `6 * u^2` as little-endian limbs, the eigenvalue of `psi` on G2.
+const SIX_U_SQUARED: [u64; 4] = [
+    0x8000000000000000,
+    0x06000065a001ae51,
+    0x0000300001968000,
+    0x0000000060000000,
+];
+
+impl G2 {
+    // Multiplication by the curve parameter `u`.
+    fn mul_by_u(&self) -> G2 {
         let mut acc = G2::identity();
-        for bit in e
-            .iter()
-            .flat_map(|byte| (0..8).rev().map(move |i| Choice::from((byte >> i) & 1u8)))
-            .skip(1)
-        {
+        for i in (0..128 - super::engine::NEG_PLUTO_U.leading_zeros()).rev() {
             acc = acc.double();
-            acc = G2::conditional_select(&acc, &(acc + self), bit);
+            if (super::engine::NEG_PLUTO_U >> i) & 1 == 1 {
+                acc += self;
+            }
+        }
+        -acc
+    }
+
+    /// The untwist-Frobenius-twist endomorphism of the twist,
+    /// `psi: (x, y) -> (x^p * ξ^((p - 1)/3), y^p * ξ^((p - 1)/2))`. It acts on G2 as
+    /// the multiplication by `p`, that is, by `6 * u^2` modulo the group order.
+    pub fn psi(&self) -> G2 {
+        let mut g2 = *self;
+        g2.x.conjugate();
+        g2.y.conjugate();
+        g2.z.conjugate();
+
+        g2.x *= FROBENIUS_COEFF_FP6_C1[1];
+        g2.y *= XI_TO_P_MINUS_1_OVER_2;
+
+        g2
+    }
+
+    /// `psi^2: (x, y) -> (w * x, -y)`, with `w` a primitive cube root of unity of
+    /// Fp. The Frobenius of Fp2 is an involution, so it needs no conjugation.
+    pub fn psi2(&self) -> G2 {
+        G2 {
+            x: self.x * FROBENIUS_COEFF_FP6_C1[2],
+            y: -self.y,
+            z: self.z,
+        }
+    }
+}
+
+impl G2Affine {
+    /// [`G2::psi`] on affine coordinates.
+    pub fn psi(&self) -> G2Affine {
+        let mut g2 = *self;
+        g2.x.conjugate();
+        g2.y.conjugate();
+
+        g2.x *= FROBENIUS_COEFF_FP6_C1[1];
+        g2.y *= XI_TO_P_MINUS_1_OVER_2;
+
+        g2
+    }
+
+    /// [`G2::psi2`] on affine coordinates.
+    pub fn psi2(&self) -> G2Affine {
+        G2Affine {
+            x: self.x * FROBENIUS_COEFF_FP6_C1[2],
+            y: -self.y,
         }
-        acc
+    }
+}
+
+impl CofactorGroup for G2 {
+    type Subgroup = G2;
+
+    fn clear_cofactor(&self) -> Self {
+        // The multiplication by the 446 bit cofactor `2p - q` is replaced by the
+        // polynomial `u + 3u psi + u psi^2 + psi^3` of the endomorphism, which maps
+        // the points of the twist to G2 as well, as the multiplication by a multiple
+        // of the cofactor (Fuentes-Castañeda, Knapp and Rodríguez-Henríquez, "Faster
+        // hashing to G2", https://eprint.iacr.org/2011/297, section 6.1). It only
+        // takes one multiplication by the 111 bit `u`.
+        let p1 = self.mul_by_u();
+        let p3 = p1.double() + p1;
+        p1 + p3.psi() + p1.psi().psi() + self.psi().psi().psi()
     }
 
     fn into_subgroup(self) -> CtOption<Self::Subgroup> {
-        // TODO: Handle the case where the point is already in the subgroup.
-        CtOption::new(self.clear_cofactor(), 1.into())
+        CtOption::new(self, self.is_torsion_free())
     }
 
     fn is_torsion_free(&self) -> Choice {
-        // group order = q
-        let e: [u8; 56] = [
-            0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0x24, 0x00, 0x01, 0x30, 0xe0, 0x00, 0x0d, 0x7f,
-            0x70, 0xe4, 0xa8, 0x03, 0xca, 0x76, 0xf4, 0x39, 0x26, 0x6f, 0x44, 0x3f, 0x9a, 0x5c,
-            0x7a, 0x8a, 0x6c, 0x7b, 0xe4, 0xa7, 0x75, 0xfe, 0x8e, 0x17, 0x7f, 0xd6, 0x9c, 0xa7,
-            0xe8, 0x5d, 0x60, 0x05, 0x0a, 0xf4, 0x1f, 0xff, 0xfc, 0xd3, 0x00, 0x00, 0x00, 0x01,
-        ];
-        // self * GROUP_ORDER;
+        // `psi` acts on G2 as the multiplication by `p = t - 1 = 6u^2` modulo the
+        // group order, and points with `psi(P) = [6u^2] P` are exactly the points of
+        // G2 (El Housni, Guillevic and Piellard, https://eprint.iacr.org/2022/352).
+        // The multiplication by the 223 bit `6u^2` replaces the one by the 446 bit
+        // group order.
         let mut acc = G2::identity();
-        for bit in e
-            .iter()
-            .flat_map(|byte| (0..8).rev().map(move |i| Choice::from((byte >> i) & 1u8)))
-            .skip(1)
-        {
-            acc = acc.double();
-            acc = G2::conditional_select(&acc, &(acc + self), bit);
+        for limb in SIX_U_SQUARED.iter().rev() {
+            for i in (0..64).rev() {
+                acc = acc.double();
+                if ((limb >> i) & 1) == 1 {
+                    acc += self;
+                }
+            }
         }
-        acc.is_identity()
+        acc.ct_eq(&self.psi())
     }
 }
 
@@ -233,8 +533,10 @@ impl Eris {
     /// Computed using https://www.ietf.org/archive/id/draft-irtf-cfrg-hash-to-curve-10.html#svdw-z-code
     const SVDW_Z: Fq = Fq::ONE;
 
-    fn default_hash_to_curve_suite() -> crate::hash_to_curve::Suite<Self, sha2::Sha256, 72> {
-        crate::hash_to_curve::Suite::<Eris, sha2::Sha256, 72>::new(
+    fn default_hash_to_curve_suite(
+    ) -> crate::hash_to_curve::Suite<Self, crate::hash_to_curve::ExpandMsgXmd<sha2::Sha256>, 72>
+    {
+        crate::hash_to_curve::Suite::<Eris, crate::hash_to_curve::ExpandMsgXmd<sha2::Sha256>, 72>::new(
             b"eris_XMD:SHA-256_SVDW_RO_",
             Self::SVDW_Z,
             crate::hash_to_curve::Method::SVDW,
@@ -242,6 +544,31 @@ impl Eris {
     }
 }
 
+/// The Pluto / Eris cycle: `G1` is Pluto and `Eris` its partner.
+#[derive(Clone, Copy, Debug)]
+pub struct PlutoEris;
+
+impl crate::cycle::CurveCycle for PlutoEris {
+    type C1 = G1;
+    type C2 = Eris;
+}
+
+impl crate::hash_to_curve::BatchHashToCurve for Eris {
+    fn batch_hash_to_curve(domain_prefix: &str, messages: &[&[u8]]) -> alloc::vec::Vec<Self> {
+        Self::default_hash_to_curve_suite().batch_hash_to_curve(domain_prefix, messages)
+    }
+}
+
+impl crate::hash_to_curve::MapToCurve for Eris {
+    fn hash_to_field(domain_prefix: &str, message: &[u8]) -> [Self::Base; 2] {
+        Self::default_hash_to_curve_suite().hash_to_field(domain_prefix, message)
+    }
+
+    fn map_to_curve(u: &Self::Base) -> Self {
+        Self::default_hash_to_curve_suite().map_to_curve(u)
+    }
+}
+
 new_curve_impl!(
     (pub),
     G2,
@@ -261,9 +588,180 @@ mod test {
     use group::UncompressedEncoding;
 
     crate::curve_testing_suite!(G2, "clear_cofactor");
+    crate::curve_testing_suite!(G2, "torsion_free");
+    crate::curve_testing_suite!(
+        G2,
+        "psi",
+        Fq::from(6) * Fq::from_u128(super::super::engine::NEG_PLUTO_U).square()
+    );
     crate::curve_testing_suite!(G1, Eris, G2);
+    crate::curve_testing_suite!(G2, "compressed_flags");
     crate::curve_testing_suite!(G1, Eris, "hash_to_curve");
+    crate::curve_testing_suite!(G1, Eris, "batch_hash_to_curve");
+    crate::curve_testing_suite!(G1, Eris, "map_to_curve");
     crate::curve_testing_suite!(G1, Eris, "endo_consistency");
+
+    #[cfg(feature = "precompute")]
+    #[test]
+    fn test_batch_from_compressed_vartime() {
+        use rand_core::OsRng;
+
+        let mut bytes: Vec<_> = (0..32)
+            .map(|_| G1::random(OsRng).to_affine().to_bytes())
+            .collect();
+        bytes.push(G1Affine::identity().to_bytes());
+        // Abscissas of no point, and invalid encodings.
+        bytes.extend((0..8).map(|i| {
+            let mut b = G1Affine::generator().to_bytes();
+            b.as_mut()[0] ^= 1 << i;
+            b
+        }));
+        bytes.push(G1Compressed([0xff; G1Compressed::SIZE]));
+        let expected = G1Affine::batch_from_compressed(&bytes);
+        let res = G1Affine::batch_from_compressed_vartime(&bytes);
+        for (a, b) in res.iter().zip(expected.iter()) {
+            assert_eq!(Option::<G1Affine>::from(*a), Option::from(*b));
+        }
+        assert!(expected.iter().any(|p| bool::from(p.is_none())));
+
+        let bytes: Vec<_> = (0..32)
+            .map(|_| Eris::random(OsRng).to_affine().to_bytes())
+            .chain([ErisAffine::identity().to_bytes()])
+            .collect();
+        let res = ErisAffine::batch_from_compressed_vartime(&bytes);
+        assert_eq!(
+            res.iter().map(|p| p.unwrap()).collect::<Vec<_>>(),
+            ErisAffine::batch_from_compressed(&bytes)
+                .iter()
+                .map(|p| p.unwrap())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_cycle() {
+        fn same_type<T>(_: T, _: T) {}
+
+        // The scalar field of each curve is the base field of the other.
+        same_type(
+            <G1 as CurveExt>::ScalarExt::ONE,
+            <Eris as CurveExt>::Base::ONE,
+        );
+        same_type(
+            <Eris as CurveExt>::ScalarExt::ONE,
+            <G1 as CurveExt>::Base::ONE,
+        );
+        assert_eq!(G1::ORDER, &Fq::MODULUS_LIMBS);
+        assert_eq!(Eris::ORDER, &Fp::MODULUS_LIMBS);
+    }
+    #[test]
+    fn test_g2_clear_cofactor() {
+        use rand_core::OsRng;
+
+        // On G2, `psi` is the multiplication by `lambda = 6u^2` and the clearing is
+        // the multiplication by `u + 3u lambda + u lambda^2 + lambda^3`.
+        let u = -Fq::from_u128(super::super::engine::NEG_PLUTO_U);
+        let lambda = Fq::from(6) * u.square();
+        let h = u * (Fq::ONE + lambda * Fq::from(3) + lambda.square()) + lambda.cube();
+        assert!(!bool::from(h.is_zero()));
+        for _ in 0..10 {
+            let p = G2::random(OsRng);
+            assert_eq!(p.clear_cofactor(), p * h);
+        }
+
+        // Points of the twist outside of G2.
+        for _ in 0..10 {
+            let p = loop {
+                let x = Fp2::random(OsRng);
+                if let Some(y) = Option::<Fp2>::from((x.square() * x + G2::b()).sqrt()) {
+                    break G2Affine { x, y };
+                }
+            };
+            let p = G2::from(p);
+            assert!(!bool::from(p.is_torsion_free()));
+            let q = p.clear_cofactor();
+            assert!(bool::from(q.is_torsion_free()));
+            assert!(!bool::from(q.is_identity()));
+        }
+    }
+
+    #[test]
+    fn test_iso_map() {
+        use rand_core::OsRng;
+
+        // Points of E' and their sum, in affine coordinates.
+        let point = || loop {
+            let x = Fp::random(OsRng);
+            let y = (x.square() * x + PLUTO_ISO_A * x + PLUTO_ISO_B).sqrt();
+            if let Some(y) = Option::<Fp>::from(y) {
+                break (x, y);
+            }
+        };
+        let add = |(x1, y1): (Fp, Fp), (x2, y2): (Fp, Fp)| {
+            let m = (y2 - y1) * (x2 - x1).invert().unwrap();
+            let x = m.square() - x1 - x2;
+            (x, m * (x1 - x) - y1)
+        };
+        let iso_map = |(x, y): (Fp, Fp)| {
+            let (x, y, z) = PLUTO_ISO_MAP.evaluate(x, y, Fp::ONE);
+            G1 { x, y, z }
+        };
+
+        assert_eq!(PLUTO_ISO_MAP.degree(), 3);
+        for _ in 0..10 {
+            let (p, q) = (point(), point());
+            assert!(bool::from(iso_map(p).is_on_curve()));
+            assert_eq!(iso_map(add(p, q)), iso_map(p) + iso_map(q));
+
+            // Homogeneous inputs.
+            let z = Fp::random(OsRng);
+            let (x, y, z) = PLUTO_ISO_MAP.evaluate(p.0 * z, p.1 * z, z);
+            assert_eq!(G1 { x, y, z }, iso_map(p));
+        }
+
+        // The kernel, at the root of `x_den = (x - x1)^2`.
+        let x1 = -PLUTO_ISO_MAP.x_den[1] * Fp::TWO_INV;
+        assert_eq!(PLUTO_ISO_MAP.x_den[0], x1.square());
+        let (x, y, z) = PLUTO_ISO_MAP.evaluate(x1, Fp::random(OsRng), Fp::ONE);
+        assert!(bool::from(G1 { x, y, z }.is_identity()));
+    }
+
+    #[test]
+    fn test_sswu_hash_to_curve() {
+        // Test vectors from `script/pluto_isogeny.py`.
+        let suite = G1::sswu_hash_to_curve_suite();
+        [
+            (
+                &b""[..],
+                "1e4e2e468ecdf98b2a7d9a0b40eec4ed67ba57a8a2773959b3c1ccb1704c98987fad476a71d954647cc714be9dae88e01ba9998efe755380",
+                "127fa3eaefc4c326104c797dadc384d55302b7323e53e808b7212d3bbb4e69fe9627103becaae57d8233f74ea8b36c808f7ad5ee415f344b",
+            ),
+            (
+                b"abc",
+                "068c58f94a3cc76a1d3eaba99a6612df67333ac2c55c3bb66406eb1ceb2e40c02b0b4b8c5d762249ff2fe604bb388de3c8f7a9dedd1ff6ae",
+                "149f1e9af3c1bcfb70ce45c7438bbdf1589013ff4246622b6357bf6a1ad37313fb41a1f5705a77d33a6760053affd80ecae4ba165929af91",
+            ),
+            (
+                b"abcdef0123456789",
+                "15e03f332105c29914543962ca4482c75806e0a09e537030423b89cbba8a3234c9b217cc60a820092d42e4d8f80646a2a5302936e5b12773",
+                "028f4b36302ab776bb71ba800d5e15fa852cdde6c3a3e1880537f9bfa3175f914763fd43d2f4f76c16c2e0fcdc5248ffc90295b6be9db981",
+            ),
+        ]
+        .iter()
+        .for_each(|(msg, x, y)| {
+            let expect: G1Affine = crate::tests::point_from_hex(x, y);
+            let p = suite.hash_to_curve("QUUX-V01-CS02-with-", msg);
+            assert_eq!(p.to_affine(), expect);
+        });
+
+        // The batched map agrees.
+        let messages = [&b""[..], b"abc", b"abcdef0123456789"];
+        let batch = suite.batch_hash_to_curve("QUUX-V01-CS02-with-", &messages);
+        for (p, msg) in batch.iter().zip(messages) {
+            assert_eq!(*p, suite.hash_to_curve("QUUX-V01-CS02-with-", msg));
+        }
+    }
+
     crate::curve_testing_suite!(
         G1,
         "constants",
@@ -6,23 +6,23 @@ use crate::ff_ext::ExtField;
 use crate::group::cofactor::CofactorCurveAffine;
 use crate::group::Group;
 use crate::pluto_eris::curve::*;
-use crate::pluto_eris::fp::*;
 use crate::pluto_eris::fp12::*;
 use crate::pluto_eris::fp2::*;
-use crate::pluto_eris::fp6::FROBENIUS_COEFF_FP6_C1;
+use crate::pluto_eris::fp6::{Fp6, FROBENIUS_COEFF_FP6_C1};
 use crate::pluto_eris::fq::Fq;
+use alloc::vec::Vec;
 use core::borrow::Borrow;
 use core::iter::Sum;
+use core::ops::MulAssign;
 use core::ops::{Add, Mul, Neg, Sub};
 use ff::Field;
 use pairing::{Engine, MillerLoopResult, MultiMillerLoop, PairingCurveAffine};
 use rand_core::RngCore;
-use std::ops::MulAssign;
-use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 /// Adaptation of Algorithm 1, https://eprint.iacr.org/2013/722.pdf
 /// the parameter for the curve Pluto: u = -0x4000000000001000008780000000
-const NEG_PLUTO_U: u128 = 0x4000000000001000008780000000;
+pub(crate) const NEG_PLUTO_U: u128 = 0x4000000000001000008780000000;
 
 const NEG_SIX_U_PLUS_2_NAF: [i8; 114] = [
     0, -1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
@@ -31,8 +31,35 @@ const NEG_SIX_U_PLUS_2_NAF: [i8; 114] = [
     0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -1, 0, 1,
 ];
 
-crate::impl_gt!(Gt, Fp12, Fq);
-crate::impl_miller_loop_components!(Pluto, G1, G1Affine, G2, G2Affine, Fp12, Gt, Fq);
+pub use crate::ff_ext::quadratic::TwistType;
+
+/// Twist type of Triton, [`G2`]: `y^2 = x^3 + 57/ξ = x^3 + u + 3`.
+pub const TWIST_TYPE: TwistType = <Fp12 as QuadSparseMul>::TWIST_TYPE;
+
+/// The non-residue `ξ = 57/(u + 3)` of [`Fp2`], neither a square nor a cube.
+///
+/// It defines the tower `Fp6 = Fp2[v]/(v^3 - ξ)`, `Fp12 = Fp6[w]/(w^2 - v)`, so
+/// that `w^6 = ξ`, and the twist: a point `(x, y)` of [`G2`] is mapped to
+/// `(x w^2, y w^3)` on Pluto over `Fp12`. Multiplying by `ξ` is
+/// [`ExtField::mul_by_nonresidue`].
+pub const XI: Fp2 = <Fp2 as ExtField>::NON_RESIDUE;
+
+/// Value of `ξ^((p - 1)/3)`, the factor of the `x` coordinate in the
+/// untwist-Frobenius-twist endomorphism of [`G2`], see [`XI_TO_P_MINUS_1_OVER_2`].
+pub const XI_TO_P_MINUS_1_OVER_3: Fp2 = FROBENIUS_COEFF_FP6_C1[1];
+
+crate::impl_gt!(Gt, Fp12, Fp6, Fp2, Fq);
+crate::impl_miller_loop_components!(
+    Pluto,
+    G1,
+    G1Affine,
+    G2,
+    G2Affine,
+    Fp12,
+    Gt,
+    Fq,
+    NEG_SIX_U_PLUS_2_NAF
+);
 
 pub fn multi_miller_loop(terms: &[(&G1Affine, &G2Affine)]) -> Fp12 {
     let terms = terms
@@ -47,7 +74,10 @@ pub fn multi_miller_loop(terms: &[(&G1Affine, &G2Affine)]) -> Fp12 {
         .collect::<Vec<_>>();
 
     let mut f = Fp12::one();
-    let mut r = terms.iter().map(|(_, q)| q.to_curve()).collect::<Vec<_>>();
+    let mut r = terms
+        .iter()
+        .map(|(_, q)| crate::line::JacobianPoint::from(q))
+        .collect::<Vec<_>>();
 
     for (i, x) in NEG_SIX_U_PLUS_2_NAF.iter().rev().skip(1).enumerate() {
         (i != 0).then(|| f.square_assign());
@@ -70,42 +100,10 @@ pub fn multi_miller_loop(terms: &[(&G1Affine, &G2Affine)]) -> Fp12 {
         }
     }
 
-    /// Value of (57/(u + 3))^((p - 1)/2) where u^2 + 5 = 0 in Fp2.
-    const XI_TO_P_MINUS_1_OVER_2: Fp2 = Fp2 {
-        c0: Fp::from_raw([
-            0x54cf5ad1c0926216,
-            0x186c1f3ce4a46d4e,
-            0x9c23800ce9c9452f,
-            0x50e0d09ff6d6c08b,
-            0x7cf421e4d46f6666,
-            0x678664ba4b6d8343,
-            0x21cc26d5de0f80f4,
-        ]),
-
-        c1: Fp::from_raw([
-            0xc0505f4c260e91f4,
-            0xe7bbd15f10723657,
-            0xb4b3e0c35358097e,
-            0x87c56f42a558750d,
-            0x4b7211d23f34f0ae,
-            0xf6839d29e2f0d250,
-            0x16ebe8b2e12a1106,
-        ]),
-    };
-
-    for ((p, q), r) in terms.iter().zip(r.iter_mut()) {
-        let mut q1: G2Affine = *q;
-        q1.x.conjugate();
-        q1.x.mul_assign(&FROBENIUS_COEFF_FP6_C1[1]);
-        q1.y.conjugate();
-        q1.y.mul_assign(&XI_TO_P_MINUS_1_OVER_2);
-        add(&mut f, r, &q1.neg(), p);
-    }
-
     for ((p, q), r) in terms.iter().zip(r.iter_mut()) {
-        let mut minusq2: G2Affine = *q;
-        minusq2.x.mul_assign(&FROBENIUS_COEFF_FP6_C1[2]);
-        add(&mut f, r, &minusq2.neg(), p);
+        for q in frobenius_points(q).iter() {
+            add(&mut f, r, q, p);
+        }
     }
 
     f
@@ -137,16 +135,16 @@ impl MillerLoopResult for Fp12 {
                 let mut r = f1;
                 r.mul_assign(&f2);
                 f2 = r;
-                r.frobenius_map(2);
+                r.frobenius_map_const::<2>();
                 r.mul_assign(&f2);
 
                 let mut fp = r;
-                fp.frobenius_map(1);
+                fp.frobenius_map_const::<1>();
 
                 let mut fp2 = r;
-                fp2.frobenius_map(2);
+                fp2.frobenius_map_const::<2>();
                 let mut fp3 = fp2;
-                fp3.frobenius_map(1);
+                fp3.frobenius_map_const::<1>();
 
                 let mut fu = r;
                 exp_by_x(&mut fu);
@@ -158,16 +156,16 @@ impl MillerLoopResult for Fp12 {
                 exp_by_x(&mut fu3);
 
                 let mut y3 = fu;
-                y3.frobenius_map(1);
+                y3.frobenius_map_const::<1>();
 
                 let mut fu2p = fu2;
-                fu2p.frobenius_map(1);
+                fu2p.frobenius_map_const::<1>();
 
                 let mut fu3p = fu3;
-                fu3p.frobenius_map(1);
+                fu3p.frobenius_map_const::<1>();
 
                 let mut y2 = fu2;
-                y2.frobenius_map(2);
+                y2.frobenius_map_const::<2>();
 
                 let mut y0 = fp;
                 y0.mul_assign(&fp2);
@@ -217,15 +215,30 @@ impl MillerLoopResult for Fp12 {
     }
 }
 
+// The points `-π(Q)` and `π^2(Q)` added after the loop of the negative parameter,
+// `π` being the untwist-Frobenius-twist endomorphism.
+fn frobenius_points(q: &G2Affine) -> [G2Affine; 2] {
+    let mut q1: G2Affine = *q;
+    q1.x.conjugate();
+    q1.x.mul_assign(&FROBENIUS_COEFF_FP6_C1[1]);
+    q1.y.conjugate();
+    q1.y.mul_assign(&XI_TO_P_MINUS_1_OVER_2);
+
+    let mut minusq2: G2Affine = *q;
+    minusq2.x.mul_assign(&FROBENIUS_COEFF_FP6_C1[2]);
+
+    [q1.neg(), minusq2.neg()]
+}
+
 // Final steps of the line function on prepared coefficients
-fn ell(f: &mut Fp12, coeffs: &(Fp2, Fp2, Fp2), p: &G1Affine) {
-    let mut c0 = coeffs.0;
-    let mut c1 = coeffs.1;
+fn ell(f: &mut Fp12, coeffs: &crate::line::Line<Fp2>, p: &G1Affine) {
+    let mut c0 = coeffs.c0;
+    let mut c1 = coeffs.c1;
     c0.c0.mul_assign(&p.y);
     c0.c1.mul_assign(&p.y);
     c1.c0.mul_assign(&p.x);
     c1.c1.mul_assign(&p.x);
-    Fp12::mul_by_034(f, &c0, &c1, &coeffs.2);
+    Fp12::mul_by_line(f, &c0, &c1, &coeffs.c2);
 }
 
 #[cfg(test)]
@@ -237,4 +250,114 @@ mod test {
     use pairing::{Engine, MillerLoopResult, PairingCurveAffine};
     use rand_core::OsRng;
     crate::test_pairing!(Pluto, G1, G1Affine, G2, G2Affine, Fp12, Gt, Fq);
+    // 113 doublings, 10 additions for the non-zero NAF digits and 2 Frobenius additions.
+    crate::test_pairing_op_count!(
+        G1Affine,
+        G2Affine,
+        miller_loop: { sparse_mul: 125, square: 112 },
+        final_exponentiation: { dense_mul: 36, cyclotomic_square: 337 }
+    );
+
+    #[test]
+    fn test_twist_constants() {
+        use super::{TwistType, TWIST_TYPE, XI, XI_TO_P_MINUS_1_OVER_2, XI_TO_P_MINUS_1_OVER_3};
+        use crate::ff_ext::ExtField;
+        use crate::pluto_eris::{Fp, Fp2, Fp6};
+        use crate::CurveExt;
+
+        for _ in 0..100 {
+            let e = Fp2::random(OsRng);
+            assert_eq!(e.mul_by_nonresidue(), e * XI);
+        }
+        // ξ (u + 3) = 57
+        assert_eq!(XI * Fp2::new(Fp::from(3), Fp::ONE), Fp2::from(57));
+
+        // v^3 = ξ in Fp6, and w^2 = v in Fp12.
+        let v = Fp6::new(Fp2::ZERO, Fp2::ONE, Fp2::ZERO);
+        assert_eq!(v.square() * v, Fp6::new(XI, Fp2::ZERO, Fp2::ZERO));
+        let w = Fp12::new(Fp6::ZERO, Fp6::ONE);
+        assert_eq!(w.square(), Fp12::new(v, Fp6::ZERO));
+
+        assert_eq!(TWIST_TYPE, TwistType::D);
+        assert_eq!(G2::b() * XI, Fp2::from(57));
+        assert_eq!(G1::b(), Fp::from(57));
+
+        // Divides the little-endian limbs of p - 1 by `d`.
+        let p_minus_1_over = |d: u64| {
+            let mut limbs = Fp::MODULUS_LIMBS;
+            limbs[0] -= 1;
+            let mut rem = 0u128;
+            for limb in limbs.iter_mut().rev() {
+                let cur = (rem << 64) | *limb as u128;
+                *limb = (cur / d as u128) as u64;
+                rem = cur % d as u128;
+            }
+            assert_eq!(rem, 0);
+            limbs
+        };
+        assert_eq!(XI.pow_vartime(p_minus_1_over(2)), XI_TO_P_MINUS_1_OVER_2);
+        assert_eq!(XI.pow_vartime(p_minus_1_over(3)), XI_TO_P_MINUS_1_OVER_3);
+
+        // ξ^((p^2 - 1)/k) = c^(p + 1) = conj(c) c for c = ξ^((p - 1)/k), and is
+        // not one as ξ is neither a square nor a cube.
+        for c in [XI_TO_P_MINUS_1_OVER_2, XI_TO_P_MINUS_1_OVER_3] {
+            let mut conj = c;
+            conj.conjugate();
+            assert_ne!(conj * c, Fp2::ONE);
+        }
+    }
+
+    // Vectors generated by `script/pluto_pairing_vectors.py`, an implementation of
+    // the pairing that shares no code nor field representation with the crate.
+    #[test]
+    fn test_pairing_vectors() {
+        use crate::pluto_eris::{Fp, Fp2};
+        use ff::PrimeField;
+
+        let vectors = crate::test_vectors!("pluto_pairing.json");
+        let bytes = |v: &serde_json::Value| hex::decode(v.as_str().unwrap()).unwrap();
+        let fp = |b: &[u8]| Fp::from_bytes(b.try_into().unwrap()).unwrap();
+        let fp2 = |b: &[u8]| Fp2::new(fp(&b[..Fp::SIZE]), fp(&b[Fp::SIZE..]));
+
+        let mut terms = vec![];
+        for vector in vectors.cases("pairing") {
+            let scalar = |v: &serde_json::Value| Fq::from_str_vartime(v.as_str().unwrap()).unwrap();
+            let g1 = (G1::generator() * scalar(&vector["g1_scalar"])).to_affine();
+            let g2 = (G2::generator() * scalar(&vector["g2_scalar"])).to_affine();
+            assert_eq!(g1.x, fp(&bytes(&vector["g1"]["x"])));
+            assert_eq!(g1.y, fp(&bytes(&vector["g1"]["y"])));
+            assert_eq!(g2.x, fp2(&bytes(&vector["g2"]["x"])));
+            assert_eq!(g2.y, fp2(&bytes(&vector["g2"]["y"])));
+
+            let gt = bytes(&vector["gt"]);
+            assert_eq!(Pluto::pairing(&g1, &g2).to_bytes().as_slice(), gt);
+            assert_eq!(
+                multi_miller_loop(&[(&g1, &g2)])
+                    .final_exponentiation()
+                    .to_bytes()
+                    .as_slice(),
+                gt
+            );
+            terms.push((g1, g2));
+        }
+
+        for vector in vectors.cases("multi_miller_loop") {
+            let terms = vector["terms"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|i| {
+                    let (g1, g2) = &terms[i.as_u64().unwrap() as usize];
+                    (g1, g2)
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(
+                multi_miller_loop(&terms)
+                    .final_exponentiation()
+                    .to_bytes()
+                    .as_slice(),
+                bytes(&vector["gt"])
+            );
+        }
+    }
 }
@@ -1,7 +1,7 @@
 use crate::ff_ext::ExtField;
 use core::convert::TryInto;
 use halo2derive::impl_field;
-use rand::RngCore;
+use rand_core::RngCore;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 impl_field!(
@@ -20,10 +20,23 @@ crate::impl_binops_additive!(Fp, Fp);
 crate::impl_binops_multiplicative!(Fp, Fp);
 crate::field_bits!(Fp);
 crate::serialize_deserialize_primefield!(Fp);
+crate::impl_canonical_encoding_field!(Fp, Fp::SIZE);
 crate::impl_from_u64!(Fp);
+crate::impl_random_seeded!(Fp, 72);
+
+impl Fp {
+    /// Multiplies by `-5`, the quadratic non-residue of `Fp`, with two
+    /// doublings, an addition and a negation instead of a full multiplication.
+    #[inline]
+    pub fn mul_by_neg5(&self) -> Self {
+        (self.double().double() + self).neg()
+    }
+}
 
 impl ExtField for Fp {
-    const NON_RESIDUE: Self = Fp::from_raw([
+    type BaseField = Fp;
+
+    const NON_RESIDUE: Self = Fp::from_raw_unchecked([
         0x9ffffcd2fffffffc,
         0xa2a7e8c30006b945,
         0xe4a7a5fe8fadffd6,
@@ -33,9 +46,13 @@ impl ExtField for Fp {
         0x2400000000002400,
     ]);
     fn mul_by_nonresidue(&self) -> Self {
-        (self.double().double() + self).neg()
+        self.mul_by_neg5()
     }
     fn frobenius_map(&mut self, _: usize) {}
+
+    fn norm(&self) -> Fp {
+        *self
+    }
 }
 
 #[cfg(test)]
@@ -49,9 +66,45 @@ mod test {
     crate::field_testing_suite!(Fp, "bits");
     crate::field_testing_suite!(Fp, "serialization_check");
     crate::field_testing_suite!(Fp, "constants");
+    crate::field_testing_suite!(Fp, "montgomery_constants");
+    crate::field_testing_suite!(Fp, "mul_u64");
+    crate::field_testing_suite!(Fp, "halve");
+    crate::field_testing_suite!(Fp, "invert");
+    crate::field_testing_suite!(Fp, "from_raw");
+    crate::field_testing_suite!(Fp, "from_wide", from_u896);
     crate::field_testing_suite!(Fp, "sqrt");
     crate::field_testing_suite!(Fp, "zeta");
     crate::field_testing_suite!(Fp, "from_uniform_bytes", 64, 72, 112);
+    crate::field_testing_suite!(
+        Fp,
+        "random_seeded",
+        "122b53634caa73f6b6beaee0f6f458f3cbe73c17e7a9ad63fb09d1035fc5d04b47f2730649f3b919304a287f64fdcac2e1eabd830a1e6d46"
+    );
+    crate::field_testing_suite!(Fp, "limb32", LIMB32_VECTORS);
+
+    /// `(a, b, a * b)`, shared by the 64-bit and 32-bit limb backends.
+    const LIMB32_VECTORS: [(&str, &str, &str); 4] = [
+        (
+            "24000000000024000130e0000d7f70e4a803ca76f439266f443f9a5cda8a6c7be4a7a5fe8fadffd6a2a7e8c30006b9459ffffcd300000000",
+            "24000000000024000130e0000d7f70e4a803ca76f439266f443f9a5cda8a6c7be4a7a5fe8fadffd6a2a7e8c30006b9459ffffcd300000000",
+            "0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001",
+        ),
+        (
+            "2000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+            "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000ffffffffffffffff",
+            "02f100001400a443c12d8d5cee17e4135834fde57092c9c7e5c43ad92d088d35aebbc8968f8341118dffeb9ba4b663ef6449c3ef71c80001",
+        ),
+        (
+            "0055134e85389146c8868803e0724fb45fa40b6d1c4a02c1db00c98943e74c1e66e1c1257427a58aba5dd68477cec09fb3d161b5c4a0985b",
+            "21b10f6815106c0ef9e398bf35b9ad3a25de3b1321293ed565586173162476153fc521e9e112671a96d0d1e79164b92a5a0c252f8c8c13f0",
+            "05bcf1ef13e3f90aa5124531575a21d13c6787b2bb1ab6b8c1fe01d2fc740666d9fba3accb1ff4947f9994a568bce6d3ce2f3a23c14e338e",
+        ),
+        (
+            "0355a3e01ad8d0968daf33c0956227d199da86d4abb596da041606c1c978861802386f7ac13f8471a7dd73d494564eaa345c56c6750ba51d",
+            "0367e1f2871d138b5602216985049edde9648c83afe852897be07c0c389716a3eb284405d2e28c34462f960214fb0e5121533fd58c2d7389",
+            "184bc60ed6769d4c145d43a0defdb4102bd406a092f0de08d611f839bd2ed48629feb843e16046d1cd8da72eae669389bc1e31f18aa20dc7",
+        ),
+    ];
 
     #[test]
     fn test_fq_mul_nonresidue() {
@@ -60,4 +113,10 @@ mod test {
         let a1 = e * Fp::NON_RESIDUE;
         assert_eq!(a0, a1);
     }
+
+    #[test]
+    fn test_mul_by_neg5() {
+        let e = Fp::random(rand_core::OsRng);
+        assert_eq!(e.mul_by_neg5(), e * -Fp::from(5));
+    }
 }
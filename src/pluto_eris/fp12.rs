@@ -2,7 +2,7 @@ use super::fp::Fp;
 use super::fp2::Fp2;
 use super::fp6::Fp6;
 use crate::ff_ext::{
-    quadratic::{QuadExtField, QuadExtFieldArith, QuadSparseMul},
+    quadratic::{QuadExtField, QuadExtFieldArith, QuadSparseMul, TwistType, SQRT},
     ExtField,
 };
 use ff::Field;
@@ -14,13 +14,21 @@ pub type Fp12 = QuadExtField<Fp6>;
 
 impl QuadExtFieldArith for Fp12 {
     type Base = Fp6;
+    // w
+    const NON_SQUARE: Fp12 = Fp12::new(Fp6::ZERO, Fp6::ONE);
+    const SQRT: SQRT<Self::Base> = SQRT::Complex;
+    #[cfg(test)]
+    const COUNT_OPS: bool = true;
 }
 
 impl QuadSparseMul for Fp12 {
     type Base = Fp2;
+    const TWIST_TYPE: TwistType = TwistType::D;
 }
 
 impl ExtField for Fp12 {
+    type BaseField = Fp6;
+
     const NON_RESIDUE: Self = Fp12::zero(); // no needs
 
     fn frobenius_map(&mut self, power: usize) {
@@ -30,6 +38,21 @@ impl ExtField for Fp12 {
         self.c1.c1.mul_assign(&FROBENIUS_COEFF_FP12_C1[power % 12]);
         self.c1.c2.mul_assign(&FROBENIUS_COEFF_FP12_C1[power % 12]);
     }
+
+    #[inline(always)]
+    fn frobenius_map_const<const POWER: usize>(&mut self) {
+        if POWER % 12 != 0 {
+            self.c0.frobenius_map_const::<POWER>();
+            self.c1.frobenius_map_const::<POWER>();
+            self.c1.c0.mul_assign(&FROBENIUS_COEFF_FP12_C1[POWER % 12]);
+            self.c1.c1.mul_assign(&FROBENIUS_COEFF_FP12_C1[POWER % 12]);
+            self.c1.c2.mul_assign(&FROBENIUS_COEFF_FP12_C1[POWER % 12]);
+        }
+    }
+
+    fn norm(&self) -> Fp6 {
+        QuadExtField::norm(self)
+    }
 }
 
 crate::impl_binops_additive!(Fp12, Fp12);
@@ -45,7 +68,7 @@ pub const FROBENIUS_COEFF_FP12_C1: [Fp2; 12] = [
     // Fp2(v)**(((p^1) - 1) / 6)
     Fp2 {
         // 0x3c3ad3da8b99cb1df0709dc343113ccd9892dedd51f30695d89c647b90de8f41df055384b9e6cfd4e70648622c750f32ee965dfef2303d3
-        c0: Fp::from_raw([
+        c0: Fp::from_raw_unchecked([
             0x2ee965dfef2303d3,
             0x4e70648622c750f3,
             0x1df055384b9e6cfd,
@@ -55,7 +78,7 @@ pub const FROBENIUS_COEFF_FP12_C1: [Fp2; 12] = [
             0x03c3ad3da8b99cb1,
         ]),
         // 0x149fd9ed2c7affe7aaa3b912182da22dccb29838628f04b6f333d052540294889f03876b2ddb143559f9373f4cf44e6afa0be24ad758a5ff
-        c1: Fp::from_raw([
+        c1: Fp::from_raw_unchecked([
             0xfa0be24ad758a5ff,
             0x59f9373f4cf44e6a,
             0x9f03876b2ddb1435,
@@ -68,7 +91,7 @@ pub const FROBENIUS_COEFF_FP12_C1: [Fp2; 12] = [
     // Fp2(v)**(((p^2) - 1) / 6)
     Fp2 {
         // 0x480000000000360001c950000d7ee0e4a803c956d01c903d720dc8ad8b38dffaf50c100004c37fffffff
-        c0: Fp::from_raw([
+        c0: Fp::from_raw_unchecked([
             0x100004c37fffffff,
             0xc8ad8b38dffaf50c,
             0xc956d01c903d720d,
@@ -82,7 +105,7 @@ pub const FROBENIUS_COEFF_FP12_C1: [Fp2; 12] = [
     // Fp2(v)**(((p^3) - 1) / 6)
     Fp2 {
         // 0x1baee9e044d94d205764b80089c40010af5ca1e56a2a81e6a5d8739325984fc889d390efef216fe4f4af912a897f60a128a3be71be4995ca
-        c0: Fp::from_raw([
+        c0: Fp::from_raw_unchecked([
             0x28a3be71be4995ca,
             0xf4af912a897f60a1,
             0x89d390efef216fe4,
@@ -92,7 +115,7 @@ pub const FROBENIUS_COEFF_FP12_C1: [Fp2; 12] = [
             0x1baee9e044d94d20,
         ]),
         // 0x20d4c11700e832829b26f1795339413be65e47a7716bc8bc07cd6b44b03ef1130b3c35a77291b29d6f45d28e4ef1ecb9678f4479a1151232
-        c1: Fp::from_raw([
+        c1: Fp::from_raw_unchecked([
             0x678f4479a1151232,
             0x6f45d28e4ef1ecb9,
             0x0b3c35a77291b29d,
@@ -105,7 +128,7 @@ pub const FROBENIUS_COEFF_FP12_C1: [Fp2; 12] = [
     // Fp2(v)**(((p^4) - 1) / 6)
     Fp2 {
         // 0x480000000000360001c950000d7ee0e4a803c956d01c903d720dc8ad8b38dffaf50c100004c37ffffffe
-        c0: Fp::from_raw([
+        c0: Fp::from_raw_unchecked([
             0x100004c37ffffffe,
             0xc8ad8b38dffaf50c,
             0xc956d01c903d720d,
@@ -119,7 +142,7 @@ pub const FROBENIUS_COEFF_FP12_C1: [Fp2; 12] = [
     // Fp2(v)**(((p^5) - 1) / 6)
     Fp2 {
         // 0x17eb3ca29c1fb06e785dae245592ec43d5d373f7950b517d484ead4b6c8a66d46be33bb7a38302e7a63f2ca466b80fadf9ba5891cf2691f7
-        c0: Fp::from_raw([
+        c0: Fp::from_raw_unchecked([
             0xf9ba5891cf2691f7,
             0xa63f2ca466b80fad,
             0x6be33bb7a38302e7,
@@ -129,7 +152,7 @@ pub const FROBENIUS_COEFF_FP12_C1: [Fp2; 12] = [
             0x17eb3ca29c1fb06e,
         ]),
         // 0xc34e729d46d329af08338673b0b9f0e19abaf6f0edcc40514999af25c3c5c8a6c38ae3c44b69e68154c9b4f01fd9e4e6d83622ec9bc6c33
-        c1: Fp::from_raw([
+        c1: Fp::from_raw_unchecked([
             0x6d83622ec9bc6c33,
             0x154c9b4f01fd9e4e,
             0x6c38ae3c44b69e68,
@@ -142,7 +165,7 @@ pub const FROBENIUS_COEFF_FP12_C1: [Fp2; 12] = [
     // Fp2(v)**(((p^6) - 1) / 6)
     Fp2 {
         // 0x24000000000024000130e0000d7f70e4a803ca76f439266f443f9a5cda8a6c7be4a7a5fe8fadffd6a2a7e8c30006b9459ffffcd300000000
-        c0: Fp::from_raw([
+        c0: Fp::from_raw_unchecked([
             0x9ffffcd300000000,
             0xa2a7e8c30006b945,
             0xe4a7a5fe8fadffd6,
@@ -156,7 +179,7 @@ pub const FROBENIUS_COEFF_FP12_C1: [Fp2; 12] = [
     // Fp2(v)**(((p^7) - 1) / 6)
     Fp2 {
         // 0x203c52c25746874e2229d623d94e5d17ce7a9c891f19f605e6b5d415217c8387c6b750c6440f92d95437843cdd3f6852711696f310dcfc2e
-        c0: Fp::from_raw([
+        c0: Fp::from_raw_unchecked([
             0x711696f310dcfc2e,
             0x5437843cdd3f6852,
             0xc6b750c6440f92d9,
@@ -166,7 +189,7 @@ pub const FROBENIUS_COEFF_FP12_C1: [Fp2; 12] = [
             0x203c52c25746874e,
         ]),
         // 0xf602612d3852418568d26edf551ceb6db51323e91aa21b8510bca0a8687d7f345a41e9361d2eba148aeb183b3126adaa5f41a8828a75a02
-        c1: Fp::from_raw([
+        c1: Fp::from_raw_unchecked([
             0xa5f41a8828a75a02,
             0x48aeb183b3126ada,
             0x45a41e9361d2eba1,
@@ -179,7 +202,7 @@ pub const FROBENIUS_COEFF_FP12_C1: [Fp2; 12] = [
     // Fp2(v)**(((p^8) - 1) / 6)
     Fp2 {
         // 0x24000000000024000130e0000d7f28e4a803ca76be3924a5f43f8cddf9a5c4781b50d5e1ff708dc8d9fa5d8a200bc4398ffff80f80000002
-        c0: Fp::from_raw([
+        c0: Fp::from_raw_unchecked([
             0x8ffff80f80000002,
             0xd9fa5d8a200bc439,
             0x1b50d5e1ff708dc8,
@@ -193,7 +216,7 @@ pub const FROBENIUS_COEFF_FP12_C1: [Fp2; 12] = [
     // Fp2(v)**(((p^9) - 1) / 6)
     Fp2 {
         // 0x851161fbb26d6dfa9cc27ff83bb70d3f8a728918a0ea4889e6726c9b4f21cb35ad4150ea08c8ff1adf85798768758a4775c3e6141b66a37
-        c0: Fp::from_raw([
+        c0: Fp::from_raw_unchecked([
             0x775c3e6141b66a37,
             0xadf85798768758a4,
             0x5ad4150ea08c8ff1,
@@ -203,7 +226,7 @@ pub const FROBENIUS_COEFF_FP12_C1: [Fp2; 12] = [
             0x0851161fbb26d6df,
         ]),
         // 0x32b3ee8ff17f17d6609ee86ba462fa8c1a582cf82cd5db33c722f182a4b7b68d96b70571d1c4d3933621634b114cc8c3870b8595eeaedcf
-        c1: Fp::from_raw([
+        c1: Fp::from_raw_unchecked([
             0x3870b8595eeaedcf,
             0x33621634b114cc8c,
             0xd96b70571d1c4d39,
@@ -216,7 +239,7 @@ pub const FROBENIUS_COEFF_FP12_C1: [Fp2; 12] = [
     // Fp2(v)**(((p^10) - 1) / 6)
     Fp2 {
         // 0x24000000000024000130e0000d7f28e4a803ca76be3924a5f43f8cddf9a5c4781b50d5e1ff708dc8d9fa5d8a200bc4398ffff80f80000003
-        c0: Fp::from_raw([
+        c0: Fp::from_raw_unchecked([
             0x8ffff80f80000003,
             0xd9fa5d8a200bc439,
             0x1b50d5e1ff708dc8,
@@ -230,7 +253,7 @@ pub const FROBENIUS_COEFF_FP12_C1: [Fp2; 12] = [
     // Fp2(v)**(((p^11) - 1) / 6)
     Fp2 {
         // 0xc14c35d63e0739188d331dbb7ec84a0d230567f5f2dd4f1fbf0ed116e0005a778c46a46ec2afceefc68bc1e994ea997a645a44130d96e0a
-        c0: Fp::from_raw([
+        c0: Fp::from_raw_unchecked([
             0xa645a44130d96e0a,
             0xfc68bc1e994ea997,
             0x78c46a46ec2afcee,
@@ -240,7 +263,7 @@ pub const FROBENIUS_COEFF_FP12_C1: [Fp2; 12] = [
             0x0c14c35d63e07391,
         ]),
         // 0x17cb18d62b92f16510ada798d273d1d68e581b07e55c626a2fa5ff6a7e4e0ff1786ef7c24af7616e8d5b4d73fe091af7327c9aa4364393ce
-        c1: Fp::from_raw([
+        c1: Fp::from_raw_unchecked([
             0x327c9aa4364393ce,
             0x8d5b4d73fe091af7,
             0x786ef7c24af7616e,
@@ -256,6 +279,8 @@ pub const FROBENIUS_COEFF_FP12_C1: [Fp2; 12] = [
 mod test {
     use super::*;
     crate::field_testing_suite!(Fp12, "field_arithmetic");
+    crate::field_testing_suite!(Fp12, "ext_sqrt");
+    crate::field_testing_suite!(Fp12, "legendre");
     // extension field-specific
     crate::field_testing_suite!(Fp12, "quadratic_sparse_mul", Fp6, Fp2);
     crate::field_testing_suite!(
@@ -275,4 +300,6 @@ mod test {
             0x2400000000002400,
         ]
     );
+    crate::field_testing_suite!(Fp12, "mul_by_root", Fp12::new(Fp6::ZERO, Fp6::ONE));
+    crate::field_testing_suite!(Fp12, "norm", 2, 6);
 }
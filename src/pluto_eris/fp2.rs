@@ -1,25 +1,76 @@
 use super::fp::Fp;
 use crate::ff::{Field, PrimeField, WithSmallOrderMulGroup};
 use crate::ff_ext::quadratic::{QuadExtField, QuadExtFieldArith, SQRT};
-use crate::ff_ext::{ExtField, Legendre};
+use crate::ff_ext::ExtField;
 use core::convert::TryInto;
-use std::cmp::Ordering;
 use subtle::{Choice, CtOption};
 
 crate::impl_binops_additive!(Fp2, Fp2);
 crate::impl_binops_multiplicative!(Fp2, Fp2);
 crate::impl_binops_calls!(Fp2);
 crate::impl_sum_prod!(Fp2);
-crate::impl_tower2!(Fp, Fp2);
+// `3 + u` generates the multiplicative group of `Fp2`, of order `p^2 - 1 = 2^33 t`.
+crate::impl_tower2!(
+    Fp,
+    Fp2,
+    multiplicative_generator = Fp2::new(Fp::from_raw([3, 0, 0, 0, 0, 0, 0]), Fp::ONE),
+    s = 33,
+    root_of_unity = Fp2::new(
+        Fp::ZERO,
+        Fp::from_raw([
+            0x26dfdb4b75fd0e5d,
+            0x2d671202aca37416,
+            0x4124e172b1e396a0,
+            0x62ad17c1c4bb4b4b,
+            0x7d61abac1173bda2,
+            0x87a1ce88d8ee22ba,
+            0x137402781dab8ce8,
+        ]),
+    ),
+    root_of_unity_inv = Fp2::new(
+        Fp::ZERO,
+        Fp::from_raw([
+            0x33f27fb31896bdcb,
+            0x2eb81983adda874f,
+            0x58cdd7389c8d7be5,
+            0x51fec3a14e187056,
+            0x93d5714f4e794d96,
+            0x251b93b916fb2562,
+            0x056a753e3a8540e7,
+        ]),
+    ),
+    delta = Fp2::new(
+        Fp::from_raw([
+            0x552188320a8f03de,
+            0xcaaefacaf94ab042,
+            0x1f361bf66ecb35f5,
+            0x3614dca7360f1130,
+            0x3b0e0ea392242870,
+            0x6e68245243de2155,
+            0x0e3d9cbfe6ec6199,
+        ]),
+        Fp::from_raw([
+            0x4b26624d38a25007,
+            0x3dfc53563540cc94,
+            0xc758a361967095d7,
+            0xaa75587705f2aac8,
+            0x6443e02347083456,
+            0xe2496871c10144cd,
+            0x0b17c40b77db01af,
+        ]),
+    )
+);
 
 pub type Fp2 = QuadExtField<Fp>;
 
 impl QuadExtFieldArith for Fp2 {
     type Base = Fp;
+    // u
+    const NON_SQUARE: Fp2 = Fp2::new(Fp::ZERO, Fp::ONE);
     const SQRT: SQRT<Fp> = SQRT::Algorithm10 {
         precompute_e: Fp2 {
             c0: Fp::ZERO,
-            c1: Fp::from_raw([
+            c1: Fp::from_raw_unchecked([
                 0x67153f9701e19938,
                 0x5d232408689b4c6c,
                 0x021848271d63f087,
@@ -30,7 +81,7 @@ impl QuadExtFieldArith for Fp2 {
             ]),
         },
         precompute_f: Fp2 {
-            c0: Fp::from_raw([0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
+            c0: Fp::from_raw_unchecked([0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]),
             c1: Fp::ZERO,
         },
         q_minus_1_over_4: &[
@@ -45,9 +96,22 @@ impl QuadExtFieldArith for Fp2 {
     };
 }
 
+/// 57/14
+const FIFTY_SEVEN_OVER_FOURTEEN: Fp = Fp::from_raw_unchecked([
+    0xb49246a5c924924e,
+    0xed83edbdc929dab6,
+    0x583a94b5ba08b6ba,
+    0xfec4426d87239e61,
+    0x5f70b15d76bf307b,
+    0x4a141db6e608b421,
+    0x1c4924924924ae92,
+]);
+
 impl ExtField for Fp2 {
+    type BaseField = Fp;
+
     const NON_RESIDUE: Self = Fp2 {
-        c0: Fp::from_raw([
+        c0: Fp::from_raw_unchecked([
             0xddb6da4b5b6db6e8,
             0x833bf7b35b701d98,
             0x3f6072240ebe2483,
@@ -57,7 +121,7 @@ impl ExtField for Fp2 {
             0x0cdb6db6db6dc3b6,
         ]),
 
-        c1: Fp::from_raw([
+        c1: Fp::from_raw_unchecked([
             0xeb6db62d36db6db3,
             0xb523fb0536dcde8e,
             0x8c6d1148d5a5491b,
@@ -67,11 +131,33 @@ impl ExtField for Fp2 {
             0x07b6db6db6db756d,
         ]),
     };
+    fn mul_by_nonresidue(&self) -> Self {
+        // ξ = 57/(u + 3) = 57/14 * (3 - u)
+        // (a0 + a1 u)(3 - u) = (3 a0 + 5 a1) + (3 a1 - a0) u
+        let c0 = self.c0.double() + self.c0 - self.c1.mul_by_neg5();
+        let c1 = self.c1.double() + self.c1 - self.c0;
+        Fp2 {
+            c0: c0 * FIFTY_SEVEN_OVER_FOURTEEN,
+            c1: c1 * FIFTY_SEVEN_OVER_FOURTEEN,
+        }
+    }
+
     fn frobenius_map(&mut self, power: usize) {
         if power % 2 != 0 {
             self.conjugate();
         }
     }
+
+    #[inline(always)]
+    fn frobenius_map_const<const POWER: usize>(&mut self) {
+        if POWER % 2 != 0 {
+            self.conjugate();
+        }
+    }
+
+    fn norm(&self) -> Fp {
+        QuadExtField::norm(self)
+    }
 }
 
 #[cfg(test)]
@@ -79,8 +165,10 @@ mod test {
 
     use super::*;
     crate::field_testing_suite!(Fp2, "field_arithmetic");
+    crate::field_testing_suite!(Fp2, "ext_sqrt");
     crate::field_testing_suite!(Fp2, "conversion");
     crate::field_testing_suite!(Fp2, "serialization");
+    crate::field_testing_suite!(Fp2, "constants");
     crate::field_testing_suite!(Fp2, "quadratic_residue");
     // crate::field_testing_suite!(Fp2, "sqrt");
     crate::field_testing_suite!(Fp2, "zeta", Fp);
@@ -95,6 +183,8 @@ mod test {
         // p: modulus of base field (Here, Fp::MODULUS)
         Fp::MODULUS_LIMBS
     );
+    crate::field_testing_suite!(Fp2, "mul_by_root", Fp2::new(Fp::ZERO, Fp::ONE));
+    crate::field_testing_suite!(Fp2, "norm", 2, 1);
 
     #[test]
     fn test_fq2_mul_nonresidue() {
@@ -103,4 +193,17 @@ mod test {
         let a1 = e * Fp2::NON_RESIDUE;
         assert_eq!(a0, a1);
     }
+
+    #[test]
+    fn test_mul_by_u_plus_k() {
+        let e = Fp2::random(rand_core::OsRng);
+        for k in [0, 1, 2, 3, 9, 57, u64::MAX] {
+            assert_eq!(e.mul_by_u_plus_k(k), e * Fp2::new(Fp::from(k), Fp::ONE));
+        }
+        // The coefficient b of Triton.
+        assert_eq!(
+            Fp2::ONE.mul_by_u_plus_k(3),
+            <crate::pluto_eris::G2 as crate::CurveExt>::b()
+        );
+    }
 }
@@ -1,7 +1,7 @@
 use super::fp::Fp;
 use super::fp2::Fp2;
 use crate::ff_ext::{
-    cubic::{CubicExtField, CubicExtFieldArith, CubicSparseMul},
+    cubic::{CubicExtField, CubicExtFieldArith, CubicSparseMul, SQRT},
     ExtField,
 };
 use ff::Field;
@@ -17,6 +17,19 @@ pub type Fp6 = CubicExtField<Fp2>;
 
 impl CubicExtFieldArith for Fp6 {
     type Base = Fp2;
+    // v
+    const NON_SQUARE: Fp6 = Fp6::new(Fp2::ZERO, Fp2::ONE, Fp2::ZERO);
+    const SQRT: SQRT = SQRT::Norm {
+        p_minus_1_over_2: &[
+            0xcffffe6980000000,
+            0x5153f46180035ca2,
+            0xf253d2ff47d6ffeb,
+            0xa21fcd2e6d45363d,
+            0x5401e53b7a1c9337,
+            0x0098700006bfb872,
+            0x1200000000001200,
+        ],
+    };
 }
 
 impl CubicSparseMul for Fp6 {
@@ -24,6 +37,8 @@ impl CubicSparseMul for Fp6 {
 }
 
 impl ExtField for Fp6 {
+    type BaseField = Fp2;
+
     const NON_RESIDUE: Self = Fp6::new(Fp2::ZERO, Fp2::ONE, Fp2::ZERO);
 
     fn frobenius_map(&mut self, power: usize) {
@@ -34,12 +49,27 @@ impl ExtField for Fp6 {
         self.c2.mul_assign(&FROBENIUS_COEFF_FP6_C2[power % 6]);
     }
 
+    #[inline(always)]
+    fn frobenius_map_const<const POWER: usize>(&mut self) {
+        if POWER % 6 != 0 {
+            self.c0.frobenius_map_const::<POWER>();
+            self.c1.frobenius_map_const::<POWER>();
+            self.c2.frobenius_map_const::<POWER>();
+            self.c1.mul_assign(&FROBENIUS_COEFF_FP6_C1[POWER % 6]);
+            self.c2.mul_assign(&FROBENIUS_COEFF_FP6_C2[POWER % 6]);
+        }
+    }
+
     fn mul_by_nonresidue(self: &Fp6) -> Fp6 {
         let c0 = self.c2.mul_by_nonresidue();
         let c1 = self.c0;
         let c2 = self.c1;
         Self { c0, c1, c2 }
     }
+
+    fn norm(&self) -> Fp2 {
+        CubicExtField::norm(self)
+    }
 }
 
 /// Fp2 coefficients for the efficient computation of Frobenius Endomorphism in Fp6.
@@ -49,7 +79,7 @@ pub(crate) const FROBENIUS_COEFF_FP6_C1: [Fp2; 6] = [
     // Fp2(v^3)**(((p^1) - 1) / 3)
     Fp2 {
         // 0x120de97f024c55bc3bc0d351f4c70da1e3886170077a50986f93678bc921dcd5041bc4bb14cc42dc52e787634eccc335a001825382850d03
-        c0: Fp::from_raw([
+        c0: Fp::from_raw_unchecked([
             0xa001825382850d03,
             0x52e787634eccc335,
             0x041bc4bb14cc42dc,
@@ -59,7 +89,7 @@ pub(crate) const FROBENIUS_COEFF_FP6_C1: [Fp2; 6] = [
             0x120de97f024c55bc,
         ]),
         // 0x2096f3f804d973afd82becc2ef081b76132461908eadbe3da1a7f5502b7091965efa1ddf4658080413be1b7cd3c9ea0e2772fea378a9b322
-        c1: Fp::from_raw([
+        c1: Fp::from_raw_unchecked([
             0x2772fea378a9b322,
             0x13be1b7cd3c9ea0e,
             0x5efa1ddf46580804,
@@ -72,7 +102,7 @@ pub(crate) const FROBENIUS_COEFF_FP6_C1: [Fp2; 6] = [
     // Fp2(v^3)**(((p^2) - 1) / 3)
     Fp2 {
         // 0x480000000000360001c950000d7ee0e4a803c956d01c903d720dc8ad8b38dffaf50c100004c37ffffffe
-        c0: Fp::from_raw([
+        c0: Fp::from_raw_unchecked([
             0x100004c37ffffffe,
             0xc8ad8b38dffaf50c,
             0xc956d01c903d720d,
@@ -86,7 +116,7 @@ pub(crate) const FROBENIUS_COEFF_FP6_C1: [Fp2; 6] = [
     // Fp2(v^3)**(((p^3) - 1) / 3)
     Fp2 {
         // 0x1f9cd069c59f50a72511749de232911d833b798e78bd98c02913e38315a71c287cd52ae30d09b78a8b43b17b4c3ea938a04518fa783eb497
-        c0: Fp::from_raw([
+        c0: Fp::from_raw_unchecked([
             0xa04518fa783eb497,
             0x8b43b17b4c3ea938,
             0x7cd52ae30d09b78a,
@@ -96,7 +126,7 @@ pub(crate) const FROBENIUS_COEFF_FP6_C1: [Fp2; 6] = [
             0x1f9cd069c59f50a7,
         ]),
         // 0x23affd628747cbaec26943f93dc9eab63f4af36699fe6d74c0aa2122aa7cb689e8faacb3479a973a4a728fcb77b150ee77240d4066e42ac5
-        c1: Fp::from_raw([
+        c1: Fp::from_raw_unchecked([
             0x77240d4066e42ac5,
             0x4a728fcb77b150ee,
             0xe8faacb3479a973a,
@@ -109,7 +139,7 @@ pub(crate) const FROBENIUS_COEFF_FP6_C1: [Fp2; 6] = [
     // Fp2(v^3)**(((p^4) - 1) / 3)
     Fp2 {
         // 0x24000000000024000130e0000d7f28e4a803ca76be3924a5f43f8cddf9a5c4781b50d5e1ff708dc8d9fa5d8a200bc4398ffff80f80000002
-        c0: Fp::from_raw([
+        c0: Fp::from_raw_unchecked([
             0x8ffff80f80000002,
             0xd9fa5d8a200bc439,
             0x1b50d5e1ff708dc8,
@@ -123,7 +153,7 @@ pub(crate) const FROBENIUS_COEFF_FP6_C1: [Fp2; 6] = [
     // Fp2(v^3)**(((p^5) - 1) / 3)
     Fp2 {
         // 0x165546173814a19ca18f781044054309e943b9ef683a6385efd7e9aad64bdffa485e5c5efd860546672498a76502061cffb95e58053c3e68
-        c0: Fp::from_raw([
+        c0: Fp::from_raw_unchecked([
             0xffb95e58053c3e68,
             0x672498a76502061c,
             0x485e5c5efd860546,
@@ -133,7 +163,7 @@ pub(crate) const FROBENIUS_COEFF_FP6_C1: [Fp2; 6] = [
             0x165546173814a19c,
         ]),
         // 0x3b90ea573df08a167cc8f43ee2cdb9cfd983ff6bfc6212c262d1e46df2790d7815a816a9169606ee71f263db492378ea168edc22072221b
-        c1: Fp::from_raw([
+        c1: Fp::from_raw_unchecked([
             0xa168edc22072221b,
             0xe71f263db492378e,
             0x815a816a9169606e,
@@ -152,7 +182,7 @@ pub(crate) const FROBENIUS_COEFF_FP6_C2: [Fp2; 6] = [
     // Fp2(v^3)**(((2p^1) - 2) / 3)
     Fp2 {
         // 0x93733692ce3cdcfc34610bac6bd22c4dc590efb038c82998c9549048e7b424cc00e17ffb4a61950d0ec132a7b38f09db0a818e422737f7c
-        c0: Fp::from_raw([
+        c0: Fp::from_raw_unchecked([
             0xb0a818e422737f7c,
             0xd0ec132a7b38f09d,
             0xc00e17ffb4a61950,
@@ -162,7 +192,7 @@ pub(crate) const FROBENIUS_COEFF_FP6_C2: [Fp2; 6] = [
             0x093733692ce3cdcf,
         ]),
         // 0x12cb19daadc92882ba3593aa6f3e6bf426f29bd46039e3036f61d0bd35f39ebecdac3209d9df546061c90b4940d9031c240ce398421dc7dc
-        c1: Fp::from_raw([
+        c1: Fp::from_raw_unchecked([
             0x240ce398421dc7dc,
             0x61c90b4940d9031c,
             0xcdac3209d9df5460,
@@ -175,7 +205,7 @@ pub(crate) const FROBENIUS_COEFF_FP6_C2: [Fp2; 6] = [
     // Fp2(v^3)**(((2p^2) - 2) / 3)
     Fp2 {
         // 0x24000000000024000130e0000d7f28e4a803ca76be3924a5f43f8cddf9a5c4781b50d5e1ff708dc8d9fa5d8a200bc4398ffff80f80000002
-        c0: Fp::from_raw([
+        c0: Fp::from_raw_unchecked([
             0x8ffff80f80000002,
             0xd9fa5d8a200bc439,
             0x1b50d5e1ff708dc8,
@@ -189,7 +219,7 @@ pub(crate) const FROBENIUS_COEFF_FP6_C2: [Fp2; 6] = [
     // Fp2(v^3)**(((2p^3) - 2) / 3)
     Fp2 {
         // 0x85cc83a7eeba2ef5f7dd2f9f1405312b2ce0cbc85b8561e1657aaf1e85b82299aa5ace8b26b78d88f57e1c7a87f75556885980d6c8d2186
-        c0: Fp::from_raw([
+        c0: Fp::from_raw_unchecked([
             0x6885980d6c8d2186,
             0x8f57e1c7a87f7555,
             0x9aa5ace8b26b78d8,
@@ -199,7 +229,7 @@ pub(crate) const FROBENIUS_COEFF_FP6_C2: [Fp2; 6] = [
             0x085cc83a7eeba2ef,
         ]),
         // 0xda3357ee4e6a9836af75e8ec0dbd23e7abc03d404620899ee0ea8b684b9400d58d5ebe487e523680bbe8a0dd9ea1d312bca2a953ab51c9b
-        c1: Fp::from_raw([
+        c1: Fp::from_raw_unchecked([
             0x2bca2a953ab51c9b,
             0x0bbe8a0dd9ea1d31,
             0x58d5ebe487e52368,
@@ -212,7 +242,7 @@ pub(crate) const FROBENIUS_COEFF_FP6_C2: [Fp2; 6] = [
     // Fp2(v^3)**(((2p^4) - 2) / 3)
     Fp2 {
         // 0x480000000000360001c950000d7ee0e4a803c956d01c903d720dc8ad8b38dffaf50c100004c37ffffffe
-        c0: Fp::from_raw([
+        c0: Fp::from_raw_unchecked([
             0x100004c37ffffffe,
             0xc8ad8b38dffaf50c,
             0xc956d01c903d720d,
@@ -226,7 +256,7 @@ pub(crate) const FROBENIUS_COEFF_FP6_C2: [Fp2; 6] = [
     // Fp2(v^3)**(((2p^5) - 2) / 3)
     Fp2 {
         // 0x126c045c5430b340de6cfc4b5581fb0d18dcaebf6af44db7a152a66663b3a80589f3e116289c6dad4263f3d0dc4e535286d24be170ff5eff
-        c0: Fp::from_raw([
+        c0: Fp::from_raw_unchecked([
             0x86d24be170ff5eff,
             0x4263f3d0dc4e5352,
             0x89f3e116289c6dad,
@@ -236,7 +266,7 @@ pub(crate) const FROBENIUS_COEFF_FP6_C2: [Fp2; 6] = [
             0x126c045c5430b340,
         ]),
         // 0x391b0a66d5051f9dc03edc6dd6532b206552ace8f9d3ad1e6cf20e91fdd8dafbe2588102de9880e3520536be54398f85028eea5832d1b8a
-        c1: Fp::from_raw([
+        c1: Fp::from_raw_unchecked([
             0x5028eea5832d1b8a,
             0x3520536be54398f8,
             0xbe2588102de9880e,
@@ -252,6 +282,8 @@ pub(crate) const FROBENIUS_COEFF_FP6_C2: [Fp2; 6] = [
 mod test {
     use super::*;
     crate::field_testing_suite!(Fp6, "field_arithmetic");
+    crate::field_testing_suite!(Fp6, "ext_sqrt");
+    crate::field_testing_suite!(Fp6, "legendre");
     // extension field-specific
     crate::field_testing_suite!(Fp6, "cubic_sparse_mul", Fp2);
     crate::field_testing_suite!(
@@ -271,6 +303,8 @@ mod test {
             0x2400000000002400,
         ]
     );
+    crate::field_testing_suite!(Fp6, "mul_by_root", Fp6::new(Fp2::ZERO, Fp2::ONE, Fp2::ZERO));
+    crate::field_testing_suite!(Fp6, "norm", 3, 2);
 
     #[test]
     fn test_fq2_mul_nonresidue() {
@@ -286,4 +320,18 @@ mod test {
 
         assert_eq!(a0, a1);
     }
+
+    #[test]
+    fn test_norm() {
+        use ff::Field;
+        for _ in 0..100 {
+            let a = Fp6::random(rand_core::OsRng);
+            // N(a) = a * a^(p^2) * a^(p^4)
+            let mut a_q = a;
+            a_q.frobenius_map_const::<2>();
+            let mut a_q2 = a;
+            a_q2.frobenius_map_const::<4>();
+            assert_eq!(a * a_q * a_q2, Fp6::new(a.norm(), Fp2::ZERO, Fp2::ZERO));
+        }
+    }
 }
@@ -1,6 +1,6 @@
 use core::convert::TryInto;
 use halo2derive::impl_field;
-use rand::RngCore;
+use rand_core::RngCore;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 impl_field!(
@@ -19,7 +19,9 @@ crate::impl_binops_additive!(Fq, Fq);
 crate::impl_binops_multiplicative!(Fq, Fq);
 crate::field_bits!(Fq);
 crate::serialize_deserialize_primefield!(Fq);
+crate::impl_canonical_encoding_field!(Fq, Fq::SIZE);
 crate::impl_from_u64!(Fq);
+crate::impl_random_seeded!(Fq, 72);
 
 #[cfg(test)]
 mod test {
@@ -32,7 +34,43 @@ mod test {
     crate::field_testing_suite!(Fq, "bits");
     crate::field_testing_suite!(Fq, "serialization_check");
     crate::field_testing_suite!(Fq, "constants");
+    crate::field_testing_suite!(Fq, "montgomery_constants");
+    crate::field_testing_suite!(Fq, "mul_u64");
+    crate::field_testing_suite!(Fq, "halve");
+    crate::field_testing_suite!(Fq, "invert");
+    crate::field_testing_suite!(Fq, "from_raw");
+    crate::field_testing_suite!(Fq, "from_wide", from_u896);
     crate::field_testing_suite!(Fq, "sqrt");
     crate::field_testing_suite!(Fq, "zeta");
     crate::field_testing_suite!(Fq, "from_uniform_bytes", 64, 72, 112);
+    crate::field_testing_suite!(
+        Fq,
+        "random_seeded",
+        "122b53634caa73f6b6beaee31b9a481610dc813cb487d6880f3ec6c04c283aaf60a007e38b7278621fb901daf4a153da61eabd830a1e6d46"
+    );
+    crate::field_testing_suite!(Fq, "limb32", LIMB32_VECTORS);
+
+    /// `(a, b, a * b)`, shared by the 64-bit and 32-bit limb backends.
+    const LIMB32_VECTORS: [(&str, &str, &str); 4] = [
+        (
+            "24000000000024000130e0000d7f70e4a803ca76f439266f443f9a5c7a8a6c7be4a775fe8e177fd69ca7e85d60050af41ffffcd300000000",
+            "24000000000024000130e0000d7f70e4a803ca76f439266f443f9a5c7a8a6c7be4a775fe8e177fd69ca7e85d60050af41ffffcd300000000",
+            "0000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000001",
+        ),
+        (
+            "2000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000",
+            "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000ffffffffffffffff",
+            "02f100001400a443c12d8d5cee17e4135834fde5c5e81f1d3b196583791c8d35896642323898e4e832504089db88b59de449c3ef71c80001",
+        ),
+        (
+            "0890dec57579889f13f4930caa822797b2bb3ac6400229c01e5041780cd063288225b711b4a48daab03f64ccd36ee5a1fe88df2c1dbb54f7",
+            "0c5073a801c25ab192b8dcc40a15ecd8a6bf1001e4b0ea56502ebbfd1522fd2fdfa532496d9eb8d26c393a06dc3bd93d0d38277f625e66a4",
+            "1d6ce9e1c55b491d99660bb9296d23f6ce1c4742b4fa0bb59d8caa3f1d1bfb00bfc2ef2bdafaa5c63af51c5f14312083b589f0b74fd3efb0",
+        ),
+        (
+            "0e265299ae0ffcec1c4764735b66f237fdc216352f53ed147a733af6c2d1a7e6a985b47ceabb4ad6ebaed756510c21006af494ea2a06ced5",
+            "15a936cb46c36945cd11ba94be9a765ad75d50d83f43a54e4acb39bf8ef87f2261a7bc41353664f14fe202938782772e7868f1b9a0403305",
+            "154f972efd84ac9d4184872903426276a12ec63af5c99469bcd1bf485189efed00006e3bb0a252981261ae3d40f28a1ddac2c6cc46724878",
+        ),
+    ];
 }
@@ -2,6 +2,15 @@
 //!
 //! Implementation of the Pluto / Eris half-pairing cycle of prime order elliptic curves.
 //!
+//! * Pluto, [`G1`], is defined over [`Fp`] and has [`Fq`] as scalar field. It is the
+//!   pairing-friendly half of the cycle, with [`G2`] (Triton) over [`Fp2`] and the
+//!   [`Pluto`] pairing engine.
+//! * Eris, [`Eris`], is defined over [`Fq`] and has [`Fp`] as scalar field, so the
+//!   scalar multiplications of one curve are native field arithmetic of the other.
+//!
+//! Both curves have `a = 0`, the same `b = 57`, the endomorphism `(x, y) -> (ζx, y)`
+//! and an SVDW hash to curve.
+//!
 //! Supporting evidence: https://github.com/daira/pluto-eris
 //! Field constant derivation: https://github.com/davidnevadoc/ec-constants/tree/main/pluto_eris
 //! Pairing constants derivation: https://github.com/John-Gong-Math/pluto_eris/blob/main/pluto_pairing.ipynb
@@ -16,4 +25,7 @@ mod fq;
 pub use curve::*;
 pub use engine::*;
 pub use fp::*;
+pub use fp12::*;
+pub use fp2::*;
+pub use fp6::*;
 pub use fq::*;
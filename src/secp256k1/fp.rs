@@ -1,6 +1,6 @@
 use core::convert::TryInto;
 use halo2derive::impl_field;
-use rand::RngCore;
+use rand_core::RngCore;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 impl_field!(
@@ -19,7 +19,9 @@ crate::impl_binops_additive!(Fp, Fp);
 crate::impl_binops_multiplicative!(Fp, Fp);
 crate::field_bits!(Fp);
 crate::serialize_deserialize_primefield!(Fp);
+crate::impl_canonical_encoding_field!(Fp, Fp::SIZE);
 crate::impl_from_u64!(Fp);
+crate::impl_random_seeded!(Fp, 64);
 
 #[cfg(test)]
 mod test {
@@ -32,7 +34,18 @@ mod test {
     crate::field_testing_suite!(Fp, "bits");
     crate::field_testing_suite!(Fp, "serialization_check");
     crate::field_testing_suite!(Fp, "constants");
+    crate::field_testing_suite!(Fp, "montgomery_constants");
+    crate::field_testing_suite!(Fp, "mul_u64");
+    crate::field_testing_suite!(Fp, "halve");
+    crate::field_testing_suite!(Fp, "invert");
+    crate::field_testing_suite!(Fp, "from_raw");
+    crate::field_testing_suite!(Fp, "from_wide", from_u512);
     crate::field_testing_suite!(Fp, "sqrt");
     crate::field_testing_suite!(Fp, "zeta");
     crate::field_testing_suite!(Fp, "from_uniform_bytes", 48, 64);
+    crate::field_testing_suite!(
+        Fp,
+        "random_seeded",
+        "25d8258aeb9a9dd908504405f5115e106e3bc640af11d0da7ab3797e3a9cd337"
+    );
 }
@@ -1,6 +1,6 @@
 use core::convert::TryInto;
 use halo2derive::impl_field;
-use rand::RngCore;
+use rand_core::RngCore;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 impl_field!(
@@ -19,7 +19,9 @@ crate::impl_binops_additive!(Fq, Fq);
 crate::impl_binops_multiplicative!(Fq, Fq);
 crate::field_bits!(Fq);
 crate::serialize_deserialize_primefield!(Fq);
+crate::impl_canonical_encoding_field!(Fq, Fq::SIZE);
 crate::impl_from_u64!(Fq);
+crate::impl_random_seeded!(Fq, 64);
 
 #[cfg(test)]
 mod test {
@@ -31,7 +33,18 @@ mod test {
     crate::field_testing_suite!(Fq, "bits");
     crate::field_testing_suite!(Fq, "serialization_check");
     crate::field_testing_suite!(Fq, "constants");
+    crate::field_testing_suite!(Fq, "montgomery_constants");
+    crate::field_testing_suite!(Fq, "mul_u64");
+    crate::field_testing_suite!(Fq, "halve");
+    crate::field_testing_suite!(Fq, "invert");
+    crate::field_testing_suite!(Fq, "from_raw");
+    crate::field_testing_suite!(Fq, "from_wide", from_u512);
     crate::field_testing_suite!(Fq, "sqrt");
     crate::field_testing_suite!(Fq, "zeta");
     crate::field_testing_suite!(Fq, "from_uniform_bytes", 48, 64);
+    crate::field_testing_suite!(
+        Fq,
+        "random_seeded",
+        "a4707f7de5772f579735421972bdc9da9d1381000fa8a185c09193bde62fca7e"
+    );
 }
@@ -1,6 +1,6 @@
 use core::convert::TryInto;
 use halo2derive::impl_field;
-use rand::RngCore;
+use rand_core::RngCore;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 impl_field!(
@@ -19,7 +19,9 @@ crate::impl_binops_additive!(Fp, Fp);
 crate::impl_binops_multiplicative!(Fp, Fp);
 crate::field_bits!(Fp);
 crate::serialize_deserialize_primefield!(Fp);
+crate::impl_canonical_encoding_field!(Fp, Fp::SIZE);
 crate::impl_from_u64!(Fp);
+crate::impl_random_seeded!(Fp, 64);
 
 #[cfg(test)]
 mod test {
@@ -31,7 +33,18 @@ mod test {
     crate::field_testing_suite!(Fp, "bits");
     crate::field_testing_suite!(Fp, "serialization_check");
     crate::field_testing_suite!(Fp, "constants");
+    crate::field_testing_suite!(Fp, "montgomery_constants");
+    crate::field_testing_suite!(Fp, "mul_u64");
+    crate::field_testing_suite!(Fp, "halve");
+    crate::field_testing_suite!(Fp, "invert");
+    crate::field_testing_suite!(Fp, "from_raw");
+    crate::field_testing_suite!(Fp, "from_wide", from_u512);
     crate::field_testing_suite!(Fp, "sqrt");
     crate::field_testing_suite!(Fp, "zeta");
     crate::field_testing_suite!(Fp, "from_uniform_bytes", 48, 64);
+    crate::field_testing_suite!(
+        Fp,
+        "random_seeded",
+        "14a1306a7b3086ca44732e0304df95d78763284333a28673833cb6427edfbe74"
+    );
 }
@@ -1,3 +1,4 @@
+use crate::arithmetic::{mul_512, sbb, CurveEndo, EndoParameters};
 use crate::derive::curve::{IDENTITY_MASK, IDENTITY_SHIFT, SIGN_MASK, SIGN_SHIFT};
 use crate::ff::WithSmallOrderMulGroup;
 use crate::ff::{Field, PrimeField};
@@ -5,7 +6,7 @@ use crate::group::Curve;
 use crate::group::{prime::PrimeCurveAffine, Group, GroupEncoding};
 use crate::secp256k1::{Fp, Fq};
 use crate::{
-    impl_binops_additive, impl_binops_additive_specify_output, impl_binops_multiplicative,
+    endo, impl_binops_additive, impl_binops_additive_specify_output, impl_binops_multiplicative,
     impl_binops_multiplicative_mixed, new_curve_impl,
 };
 use crate::{Coordinates, CurveAffine, CurveExt};
@@ -13,28 +14,28 @@ use core::cmp;
 use core::fmt::Debug;
 use core::iter::Sum;
 use core::ops::{Add, Mul, Neg, Sub};
-use rand::RngCore;
+use rand_core::RngCore;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
-const SECQ_GENERATOR_X: Fq = Fq::from_raw([
+const SECQ_GENERATOR_X: Fq = Fq::from_raw_unchecked([
     0xA24288E37702EDA6,
     0x3134E45A097781A6,
     0xB6B06C87A2CE32E2,
     0x76C39F5585CB160E,
 ]);
 
-const SECQ_GENERATOR_Y: Fq = Fq::from_raw([
+const SECQ_GENERATOR_Y: Fq = Fq::from_raw_unchecked([
     0xA4120DDAD952677F,
     0xD18983D26E8DC055,
     0xDC2D265A8E82A7F7,
     0x3FFC646C7B2918B5,
 ]);
 
-const SECQ_A: Fq = Fq::from_raw([0, 0, 0, 0]);
-const SECQ_B: Fq = Fq::from_raw([7, 0, 0, 0]);
+const SECQ_A: Fq = Fq::from_raw_unchecked([0, 0, 0, 0]);
+const SECQ_B: Fq = Fq::from_raw_unchecked([7, 0, 0, 0]);
 
 new_curve_impl!(
     (pub),
@@ -68,8 +69,10 @@ impl group::cofactor::CofactorGroup for Secq256k1 {
 impl Secq256k1 {
     const SVDW_Z: Fq = Fq::ONE;
 
-    fn default_hash_to_curve_suite() -> crate::hash_to_curve::Suite<Self, sha2::Sha256, 48> {
-        crate::hash_to_curve::Suite::<Self, sha2::Sha256, 48>::new(
+    fn default_hash_to_curve_suite(
+    ) -> crate::hash_to_curve::Suite<Self, crate::hash_to_curve::ExpandMsgXmd<sha2::Sha256>, 48>
+    {
+        crate::hash_to_curve::Suite::<Self, crate::hash_to_curve::ExpandMsgXmd<sha2::Sha256>, 48>::new(
             b"secq256k1_XMD:SHA-256_SVDW_RO_",
             Self::SVDW_Z,
             crate::hash_to_curve::Method::SVDW,
@@ -77,12 +80,47 @@ impl Secq256k1 {
     }
 }
 
+// The short basis of the GLV lattice of `Fp::ZETA`, found with the extended
+// Euclidean algorithm of https://www.iacr.org/archive/crypto2001/21390189.pdf.
+// Secq256k1 swaps the fields of secp256k1, so the basis is close to its one.
+const ENDO_PARAMS_SECQ256K1: EndoParameters = EndoParameters {
+    // round(-b1 * 2^256 / p)
+    gamma1: [0x6f547fa90abfe4c3, 0xe4437ed6010e8828, 0, 0],
+    // round(b2 * 2^256 / p)
+    gamma2: [0xe86c90e49284eb16, 0x3086d221a7d46bcd, 0, 0],
+    // -b1
+    b1: [0x6f547fa90abfe4c3, 0xe4437ed6010e8828, 0, 0],
+    // b2
+    b2: [0xe86c90e49284eb16, 0x3086d221a7d46bcd, 0, 0],
+};
+
+endo!(Secq256k1, Fp, ENDO_PARAMS_SECQ256K1);
+
+impl crate::hash_to_curve::BatchHashToCurve for Secq256k1 {
+    fn batch_hash_to_curve(domain_prefix: &str, messages: &[&[u8]]) -> alloc::vec::Vec<Self> {
+        Self::default_hash_to_curve_suite().batch_hash_to_curve(domain_prefix, messages)
+    }
+}
+
+impl crate::hash_to_curve::MapToCurve for Secq256k1 {
+    fn hash_to_field(domain_prefix: &str, message: &[u8]) -> [Self::Base; 2] {
+        Self::default_hash_to_curve_suite().hash_to_field(domain_prefix, message)
+    }
+
+    fn map_to_curve(u: &Self::Base) -> Self {
+        Self::default_hash_to_curve_suite().map_to_curve(u)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use group::UncompressedEncoding;
     crate::curve_testing_suite!(Secq256k1);
     crate::curve_testing_suite!(Secq256k1, "endo_consistency");
+    crate::curve_testing_suite!(Secq256k1, "endo");
+    crate::curve_testing_suite!(Secq256k1, "batch_hash_to_curve");
+    crate::curve_testing_suite!(Secq256k1, "map_to_curve");
     crate::curve_testing_suite!(
         Secq256k1,
         "constants",
@@ -1,7 +1,7 @@
-use std::{
-    fmt::Debug,
-    io::{self, Read, Write},
-};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+#[cfg(feature = "std")]
+use std::io::{self, Read, Write};
 
 #[derive(Clone, Copy, Debug)]
 pub struct Repr<const T: usize>([u8; T]);
@@ -36,15 +36,15 @@ impl<const T: usize> AsRef<[u8]> for Repr<T> {
     }
 }
 
-impl<const T: usize> std::ops::Index<std::ops::Range<usize>> for Repr<T> {
+impl<const T: usize> core::ops::Index<core::ops::Range<usize>> for Repr<T> {
     type Output = [u8];
 
-    fn index(&self, range: std::ops::Range<usize>) -> &Self::Output {
+    fn index(&self, range: core::ops::Range<usize>) -> &Self::Output {
         &self.0[range]
     }
 }
 
-impl<const T: usize> std::ops::Index<usize> for Repr<T> {
+impl<const T: usize> core::ops::Index<usize> for Repr<T> {
     type Output = u8;
 
     fn index(&self, index: usize) -> &Self::Output {
@@ -52,24 +52,24 @@ impl<const T: usize> std::ops::Index<usize> for Repr<T> {
     }
 }
 
-impl<const T: usize> std::ops::Index<std::ops::RangeTo<usize>> for Repr<T> {
+impl<const T: usize> core::ops::Index<core::ops::RangeTo<usize>> for Repr<T> {
     type Output = [u8];
 
-    fn index(&self, range: std::ops::RangeTo<usize>) -> &Self::Output {
+    fn index(&self, range: core::ops::RangeTo<usize>) -> &Self::Output {
         &self.0[range]
     }
 }
 
-impl<const T: usize> std::ops::Index<std::ops::RangeFrom<usize>> for Repr<T> {
+impl<const T: usize> core::ops::Index<core::ops::RangeFrom<usize>> for Repr<T> {
     type Output = [u8];
 
-    fn index(&self, range: std::ops::RangeFrom<usize>) -> &Self::Output {
+    fn index(&self, range: core::ops::RangeFrom<usize>) -> &Self::Output {
         &self.0[range]
     }
 }
 
-impl<const T: usize> std::ops::IndexMut<std::ops::Range<usize>> for Repr<T> {
-    fn index_mut(&mut self, range: std::ops::Range<usize>) -> &mut Self::Output {
+impl<const T: usize> core::ops::IndexMut<core::ops::Range<usize>> for Repr<T> {
+    fn index_mut(&mut self, range: core::ops::Range<usize>) -> &mut Self::Output {
         &mut self.0[range]
     }
 }
@@ -85,6 +85,9 @@ pub trait SerdeObject: Sized {
     fn from_raw_bytes_unchecked(bytes: &[u8]) -> Self;
     fn from_raw_bytes(bytes: &[u8]) -> Option<Self>;
 
+    /// Returns the internal memory representation. For prime field elements,
+    /// these are the little-endian bytes of their Montgomery limbs, see
+    /// `to_montgomery_limbs`.
     fn to_raw_bytes(&self) -> Vec<u8>;
 
     /// The purpose of unchecked functions is to read the internal memory representation
@@ -92,12 +95,319 @@ pub trait SerdeObject: Sized {
     /// to ensure the bytes represent a valid object. This function should only be used
     /// internally when some machine state cannot be kept in memory (e.g., between runs)
     /// and needs to be reloaded as quickly as possible.
+    #[cfg(feature = "std")]
     fn read_raw_unchecked<R: Read>(reader: &mut R) -> Self;
+    #[cfg(feature = "std")]
     fn read_raw<R: Read>(reader: &mut R) -> io::Result<Self>;
 
+    #[cfg(feature = "std")]
     fn write_raw<W: Write>(&self, writer: &mut W) -> io::Result<()>;
 }
 
+/// How much a decoded point is checked, see [`ValidatedEncoding`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Validation {
+    /// Only the encoding is checked, the point may not even be on the curve. Meant for
+    /// points that are validated elsewhere, e.g. written by the same program.
+    Unchecked,
+    /// The point is on the curve, but may be out of the prime order subgroup.
+    OnCurve,
+    /// The point is in the prime order subgroup.
+    #[default]
+    Subgroup,
+}
+
+/// Decoding of points from the encoding `R` under an explicit [`Validation`]
+/// policy. The points of every curve implement it for their compressed encoding
+/// (`GroupEncoding`), and the short Weierstrass affine points also for their
+/// uncompressed encoding (`UncompressedEncoding`).
+///
+/// Decompression solves the curve equation, so compressed points are always on the
+/// curve and [`Validation::Unchecked`] is the same as [`Validation::OnCurve`] for them.
+pub trait ValidatedEncoding<R>: Sized {
+    /// Decodes `bytes`, failing if the encoding is not valid or if the point does not
+    /// pass `validation`.
+    fn from_bytes_with(bytes: &R, validation: Validation) -> subtle::CtOption<Self>;
+
+    /// Decodes a point of the prime order subgroup, the default policy.
+    fn from_bytes_checked(bytes: &R) -> subtle::CtOption<Self> {
+        Self::from_bytes_with(bytes, Validation::Subgroup)
+    }
+
+    /// Decodes a point of the curve, skipping the subgroup check.
+    fn from_bytes_on_curve(bytes: &R) -> subtle::CtOption<Self> {
+        Self::from_bytes_with(bytes, Validation::OnCurve)
+    }
+}
+
+/// Version of the encoding implemented by [`CanonicalEncoding`]. It is written in the
+/// header of every encoded object and checked on decoding.
+pub const CANONICAL_ENCODING_VERSION: u8 = 1;
+
+/// Options of the canonical encoding. They are stored in the header so the
+/// decoder does not need to know them in advance.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EncodingFlags {
+    /// Encode points in compressed form. It has no effect on field elements.
+    pub compressed: bool,
+    /// Encode every coordinate as a big-endian integer instead of little-endian.
+    pub big_endian: bool,
+}
+
+impl EncodingFlags {
+    const COMPRESSED: u8 = 0b0000_0001;
+    const BIG_ENDIAN: u8 = 0b0000_0010;
+
+    pub const fn new(compressed: bool, big_endian: bool) -> Self {
+        Self {
+            compressed,
+            big_endian,
+        }
+    }
+
+    fn to_byte(self) -> u8 {
+        let mut byte = 0;
+        if self.compressed {
+            byte |= Self::COMPRESSED;
+        }
+        if self.big_endian {
+            byte |= Self::BIG_ENDIAN;
+        }
+        byte
+    }
+
+    fn from_byte(byte: u8) -> Option<Self> {
+        // Unused bits must be zero.
+        (byte & !(Self::COMPRESSED | Self::BIG_ENDIAN) == 0).then_some(Self {
+            compressed: byte & Self::COMPRESSED != 0,
+            big_endian: byte & Self::BIG_ENDIAN != 0,
+        })
+    }
+}
+
+/// Portable, versioned encoding for field elements and curve points.
+///
+/// Unlike [`SerdeObject`], which dumps the internal Montgomery limbs, this
+/// encoding only depends on the canonical value of the object:
+///
+/// ```text
+/// | version (1 byte) | flags (1 byte) | payload |
+/// ```
+///
+/// * `version` is [`CANONICAL_ENCODING_VERSION`].
+/// * `flags` holds the [`EncodingFlags`]: bit 0 is set for compressed points and
+///   bit 1 for big-endian coordinates. The rest of the bits must be zero.
+/// * The payload of a field element is its canonical integer representation
+///   (`PrimeField::to_repr`). Extension field elements are the concatenation of
+///   their coefficients `c0 || c1`.
+/// * The payload of a point is its compressed (`GroupEncoding`) or uncompressed
+///   (`UncompressedEncoding`) encoding, including the identity and sign flags
+///   described in the curve module.
+///
+/// The little-endian payload is the native encoding of the library. The big-endian
+/// payload reverses the bytes of each coordinate, so extension field elements are
+/// written `c1 || c0` and the flag bits of compressed points end up in the first byte.
+pub trait CanonicalEncoding: Sized {
+    /// Little-endian payload of the encoding (without header).
+    fn to_payload(&self, compressed: bool) -> Vec<u8>;
+
+    /// Decodes a little-endian payload. Returns `None` if the bytes do not encode a
+    /// valid object.
+    fn from_payload(bytes: &[u8], compressed: bool) -> Option<Self>;
+
+    /// Byte length of a single coordinate of the payload. The big-endian encoding
+    /// reverses each chunk of this size.
+    fn coordinate_size(compressed: bool) -> usize;
+
+    /// Size of the payload in bytes.
+    fn payload_size(compressed: bool) -> usize;
+
+    /// Returns the encoding of the object, including the header.
+    fn to_canonical_bytes(&self, flags: EncodingFlags) -> Vec<u8> {
+        let mut res = Vec::with_capacity(2 + Self::payload_size(flags.compressed));
+        res.push(CANONICAL_ENCODING_VERSION);
+        res.push(flags.to_byte());
+        let mut payload = self.to_payload(flags.compressed);
+        if flags.big_endian {
+            payload
+                .chunks_mut(Self::coordinate_size(flags.compressed))
+                .for_each(|chunk| chunk.reverse());
+        }
+        res.extend_from_slice(&payload);
+        res
+    }
+
+    /// Decodes an object from its encoding, including the header. Returns `None` if the
+    /// header is not supported or the payload is not valid.
+    fn from_canonical_bytes(bytes: &[u8]) -> Option<Self> {
+        let (header, payload) = (bytes.get(..2)?, &bytes[2..]);
+        if header[0] != CANONICAL_ENCODING_VERSION {
+            return None;
+        }
+        let flags = EncodingFlags::from_byte(header[1])?;
+        if payload.len() != Self::payload_size(flags.compressed) {
+            return None;
+        }
+        let mut payload = payload.to_vec();
+        if flags.big_endian {
+            payload
+                .chunks_mut(Self::coordinate_size(flags.compressed))
+                .for_each(|chunk| chunk.reverse());
+        }
+        Self::from_payload(&payload, flags.compressed)
+    }
+
+    /// Writes the encoding of the object, including the header.
+    #[cfg(feature = "std")]
+    fn write_canonical<W: Write>(&self, writer: &mut W, flags: EncodingFlags) -> io::Result<()> {
+        writer.write_all(&self.to_canonical_bytes(flags))
+    }
+
+    /// Reads an object written with [`CanonicalEncoding::write_canonical`].
+    #[cfg(feature = "std")]
+    fn read_canonical<R: Read>(reader: &mut R) -> io::Result<Self> {
+        let mut header = [0u8; 2];
+        reader.read_exact(&mut header)?;
+        let flags = EncodingFlags::from_byte(header[1]).ok_or(crate::Error::InvalidHeader)?;
+        let mut bytes = vec![0u8; 2 + Self::payload_size(flags.compressed)];
+        bytes[..2].copy_from_slice(&header);
+        reader.read_exact(&mut bytes[2..])?;
+        Self::from_canonical_bytes(&bytes).ok_or_else(|| crate::Error::InvalidEncoding.into())
+    }
+}
+
+/// Fast encoding of the internal Montgomery representation, for prover snapshots.
+///
+/// It dumps the limbs of [`SerdeObject`] behind a short header and loads them back
+/// without converting from or to the canonical form:
+///
+/// ```text
+/// | version (1 byte) | mode (1 byte) | payload |
+/// ```
+///
+/// * `version` is [`VERSION`], checked on decoding so that snapshots of an
+///   incompatible release are rejected instead of misread.
+/// * `mode` is [`MODE`]. Its bits are not valid [`EncodingFlags`], so these bytes
+///   are never mistaken for a [`CanonicalEncoding`] and vice versa.
+/// * The payload is [`SerdeObject::to_raw_bytes`], or for slices the number of
+///   elements (8 bytes, little-endian) followed by the raw bytes of each one.
+///
+/// The payload depends on the Montgomery constant of each field, so it is only
+/// meant to be read back by this library, e.g. to checkpoint the state of a prover
+/// between runs. Data exchanged with other parties should use the portable
+/// [`CanonicalEncoding`] instead, which stays the default of the library.
+pub mod raw_montgomery {
+    use super::SerdeObject;
+    use alloc::vec::Vec;
+    #[cfg(feature = "std")]
+    use std::io::{self, Read, Write};
+
+    /// Version of the encoding, written in the header of every snapshot.
+    pub const VERSION: u8 = 1;
+
+    /// Second byte of the header.
+    pub const MODE: u8 = 0b1000_0000;
+
+    const HEADER: [u8; 2] = [VERSION, MODE];
+
+    fn payload(bytes: &[u8]) -> Option<&[u8]> {
+        bytes.strip_prefix(&HEADER[..])
+    }
+
+    /// Returns the encoding of `t`, including the header.
+    pub fn to_bytes<T: SerdeObject>(t: &T) -> Vec<u8> {
+        let mut res = HEADER.to_vec();
+        res.extend_from_slice(&t.to_raw_bytes());
+        res
+    }
+
+    /// Decodes an object from its encoding, including the header. Returns `None` if
+    /// the header is not supported or the payload is not a valid object.
+    pub fn from_bytes<T: SerdeObject>(bytes: &[u8]) -> Option<T> {
+        payload(bytes).and_then(T::from_raw_bytes)
+    }
+
+    /// Like [`from_bytes`], but only the header is checked. See
+    /// [`SerdeObject::from_raw_bytes_unchecked`] for when this is appropriate.
+    pub fn from_bytes_unchecked<T: SerdeObject>(bytes: &[u8]) -> Option<T> {
+        payload(bytes).map(T::from_raw_bytes_unchecked)
+    }
+
+    #[cfg(feature = "std")]
+    fn read_header<R: Read>(reader: &mut R) -> io::Result<()> {
+        let mut header = [0u8; 2];
+        reader.read_exact(&mut header)?;
+        if header != HEADER {
+            return Err(crate::Error::InvalidHeader.into());
+        }
+        Ok(())
+    }
+
+    /// Writes the encoding of `t`, including the header.
+    #[cfg(feature = "std")]
+    pub fn write<T: SerdeObject, W: Write>(t: &T, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&HEADER)?;
+        t.write_raw(writer)
+    }
+
+    /// Reads an object written with [`write`].
+    #[cfg(feature = "std")]
+    pub fn read<T: SerdeObject, R: Read>(reader: &mut R) -> io::Result<T> {
+        read_header(reader)?;
+        T::read_raw(reader)
+    }
+
+    /// Writes `ts` behind a single header.
+    #[cfg(feature = "std")]
+    pub fn write_slice<T: SerdeObject, W: Write>(ts: &[T], writer: &mut W) -> io::Result<()> {
+        writer.write_all(&HEADER)?;
+        writer.write_all(&(ts.len() as u64).to_le_bytes())?;
+        ts.iter().try_for_each(|t| t.write_raw(writer))
+    }
+
+    // Bound on the number of objects preallocated by `read_vec`, since the length
+    // is read from the input, which may be truncated or corrupt.
+    #[cfg(feature = "std")]
+    const MAX_PREALLOCATED: usize = 1 << 12;
+
+    #[cfg(feature = "std")]
+    fn read_len<R: Read>(reader: &mut R) -> io::Result<usize> {
+        read_header(reader)?;
+        let mut len = [0u8; 8];
+        reader.read_exact(&mut len)?;
+        usize::try_from(u64::from_le_bytes(len)).map_err(|_| crate::Error::InvalidLength.into())
+    }
+
+    /// Reads objects written with [`write_slice`].
+    #[cfg(feature = "std")]
+    pub fn read_vec<T: SerdeObject, R: Read>(reader: &mut R) -> io::Result<Vec<T>> {
+        let len = read_len(reader)?;
+        let mut res = Vec::with_capacity(len.min(MAX_PREALLOCATED));
+        for _ in 0..len {
+            res.push(T::read_raw(reader)?);
+        }
+        Ok(res)
+    }
+
+    /// Like [`read_vec`], but only the header is checked. See
+    /// [`SerdeObject::read_raw_unchecked`] for when this is appropriate. Fails
+    /// if the reader ends before the last object.
+    #[cfg(feature = "std")]
+    pub fn read_vec_unchecked<T: SerdeObject + Default, R: Read>(
+        reader: &mut R,
+    ) -> io::Result<Vec<T>> {
+        let len = read_len(reader)?;
+        // The raw objects of a type all have the same size.
+        let mut buf = T::default().to_raw_bytes();
+        let mut res = Vec::with_capacity(len.min(MAX_PREALLOCATED));
+        for _ in 0..len {
+            reader.read_exact(&mut buf)?;
+            res.push(T::from_raw_bytes_unchecked(&buf));
+        }
+        Ok(res)
+    }
+}
+
 pub(crate) mod endian {
 
     pub trait Endian {
@@ -0,0 +1,227 @@
+//! Boneh–Lynn–Shacham signatures over any pairing engine of the crate.
+//!
+//! Public keys live in `G1` and signatures in `G2`, the "minimal public key size"
+//! variant of the [BLS signature draft](https://datatracker.ietf.org/doc/draft-irtf-cfrg-bls-signature/):
+//!
+//! * `pk = sk * G1`, with `G1` the generator of the first group,
+//! * `sig = sk * H(msg)`, with `H` the hash to curve of `G2`,
+//! * `sig` is valid if `e(pk, H(msg)) = e(G1, sig)`.
+//!
+//! The messages are hashed with the [`CurveExt::hash_to_curve`] suite of `G2`
+//! under the given domain prefix. Signatures over distinct messages can be
+//! aggregated into a single point, checked with one multi-pairing.
+//!
+//! Public keys and signatures are rejected unless they are on the curve and in the
+//! prime order subgroup, as the draft requires for points decoded without checks.
+//!
+//! This module only implements the group operations. Key generation, proofs of
+//! possession and the encoding of keys and signatures are left to the caller.
+//!
+//! BLS12-381 is not implemented in this crate yet, in the meantime these
+//! primitives work with the engines whose `G2` has a hash to curve:
+//! [`bn256`](crate::bn256), [`bls12_377`](crate::bls12_377) and
+//! [`bw6_767`](crate::bw6_767).
+
+use alloc::vec::Vec;
+
+use crate::CurveExt;
+use group::cofactor::CofactorGroup;
+use group::prime::PrimeCurveAffine;
+use group::{Curve, Group};
+use pairing::{MillerLoopResult, MultiMillerLoop};
+
+/// Returns the public key `sk * G1` of the secret key `sk`.
+pub fn public_key<E: MultiMillerLoop>(sk: &E::Fr) -> E::G1Affine {
+    (E::G1Affine::generator() * sk).to_affine()
+}
+
+/// Signs `msg` with `sk`, hashing it to `G2` under `domain_prefix`.
+pub fn sign<E>(sk: &E::Fr, msg: &[u8], domain_prefix: &str) -> E::G2Affine
+where
+    E: MultiMillerLoop,
+    E::G2: CurveExt<AffineExt = E::G2Affine>,
+{
+    (E::G2::hash_to_curve(domain_prefix)(msg) * sk).to_affine()
+}
+
+/// Verifies the signature `sig` of `msg` under `pk`. Identity public keys are
+/// rejected, since they validate the identity as a signature of any message, and
+/// so are the points out of the curve or of the prime order subgroup.
+pub fn verify<E>(pk: &E::G1Affine, msg: &[u8], sig: &E::G2Affine, domain_prefix: &str) -> bool
+where
+    E: MultiMillerLoop,
+    E::G1: CurveExt<AffineExt = E::G1Affine> + CofactorGroup,
+    E::G2: CurveExt<AffineExt = E::G2Affine> + CofactorGroup,
+{
+    aggregate_verify::<E>(&[*pk], &[msg], sig, domain_prefix)
+}
+
+/// Aggregates signatures into a single one, their sum.
+pub fn aggregate<E: MultiMillerLoop>(sigs: &[E::G2Affine]) -> E::G2Affine {
+    sigs.iter()
+        .fold(E::G2::identity(), |acc, sig| acc + sig)
+        .to_affine()
+}
+
+/// Verifies the aggregate `sig` of the signatures of `msgs[i]` under `pks[i]`
+/// with the multi-pairing `e(pk_1, H(msg_1)) ... e(pk_n, H(msg_n)) = e(G1, sig)`.
+///
+/// Returns `false` if the lengths differ, if there are no messages, if any
+/// public key is the identity, if `sig` or any public key is out of the curve or
+/// of the prime order subgroup, or if the messages are not pairwise distinct:
+/// without proofs of possession, repeated messages admit rogue key attacks.
+pub fn aggregate_verify<E>(
+    pks: &[E::G1Affine],
+    msgs: &[&[u8]],
+    sig: &E::G2Affine,
+    domain_prefix: &str,
+) -> bool
+where
+    E: MultiMillerLoop,
+    E::G1: CurveExt<AffineExt = E::G1Affine> + CofactorGroup,
+    E::G2: CurveExt<AffineExt = E::G2Affine> + CofactorGroup,
+{
+    if pks.len() != msgs.len() || pks.is_empty() {
+        return false;
+    }
+    if pks.iter().any(|pk| bool::from(pk.is_identity())) {
+        return false;
+    }
+    if !pks.iter().all(|pk| is_in_subgroup(E::G1::from(*pk))) {
+        return false;
+    }
+    if !is_in_subgroup(E::G2::from(*sig)) {
+        return false;
+    }
+    let mut sorted = msgs.to_vec();
+    sorted.sort_unstable();
+    if sorted.windows(2).any(|pair| pair[0] == pair[1]) {
+        return false;
+    }
+
+    let hasher = E::G2::hash_to_curve(domain_prefix);
+    let hashes = msgs
+        .iter()
+        .map(|msg| E::G2Prepared::from(hasher(msg).to_affine()))
+        .collect::<Vec<_>>();
+    let neg_g1 = -E::G1Affine::generator();
+    let sig = E::G2Prepared::from(*sig);
+
+    let mut terms = pks.iter().zip(hashes.iter()).collect::<Vec<_>>();
+    terms.push((&neg_g1, &sig));
+    bool::from(
+        E::multi_miller_loop(&terms)
+            .final_exponentiation()
+            .is_identity(),
+    )
+}
+
+// Checks that `p` is on the curve and in the prime order subgroup.
+fn is_in_subgroup<C: CurveExt + CofactorGroup>(p: C) -> bool {
+    bool::from(p.is_on_curve() & p.is_torsion_free())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{aggregate, aggregate_verify, public_key, sign, verify};
+    use crate::CurveExt;
+    use ff::Field;
+    use group::cofactor::CofactorGroup;
+    use group::Curve;
+    use pairing::MultiMillerLoop;
+    use rand_core::OsRng;
+
+    const DOMAIN: &str = "halo2curves-bls-sig-test";
+
+    // A random point of the whole curve, out of the prime order subgroup with
+    // overwhelming probability if the cofactor is not 1.
+    fn random_curve_point<C: CurveExt>() -> C {
+        loop {
+            let x = C::Base::random(OsRng);
+            let y2 = x.square() * x + C::a() * x + C::b();
+            if let Some(y) = Option::<C::Base>::from(y2.sqrt()) {
+                return C::new_jacobian(x, y, C::Base::ONE).unwrap();
+            }
+        }
+    }
+
+    fn run_sig_test<E>()
+    where
+        E: MultiMillerLoop,
+        E::G1: CurveExt<AffineExt = E::G1Affine> + CofactorGroup,
+        E::G2: CurveExt<AffineExt = E::G2Affine> + CofactorGroup,
+    {
+        let sk = E::Fr::random(OsRng);
+        let pk = public_key::<E>(&sk);
+        let sig = sign::<E>(&sk, b"message", DOMAIN);
+        assert!(verify::<E>(&pk, b"message", &sig, DOMAIN));
+        assert!(!verify::<E>(&pk, b"other message", &sig, DOMAIN));
+        assert!(!verify::<E>(&pk, b"message", &sig, "other domain"));
+        let other_pk = public_key::<E>(&E::Fr::random(OsRng));
+        assert!(!verify::<E>(&other_pk, b"message", &sig, DOMAIN));
+
+        // The identity key validates the identity signature of every message.
+        let identity_pk = public_key::<E>(&E::Fr::ZERO);
+        let identity_sig = sign::<E>(&E::Fr::ZERO, b"message", DOMAIN);
+        assert!(!verify::<E>(
+            &identity_pk,
+            b"message",
+            &identity_sig,
+            DOMAIN
+        ));
+
+        // Signatures out of the subgroup are rejected.
+        let torsion = random_curve_point::<E::G2>();
+        assert!(!bool::from(torsion.is_torsion_free()));
+        let bad_sig = (E::G2::from(sig) + torsion).to_affine();
+        assert!(!verify::<E>(&pk, b"message", &bad_sig, DOMAIN));
+
+        // So are public keys out of the subgroup, if G1 has a cofactor.
+        let point = random_curve_point::<E::G1>();
+        if !bool::from(point.is_torsion_free()) {
+            let bad_pk = (E::G1::from(pk) + point).to_affine();
+            assert!(!verify::<E>(&bad_pk, b"message", &sig, DOMAIN));
+        }
+
+        let msgs: [&[u8]; 4] = [b"", b"a", b"b", b"message"];
+        let sks = msgs.map(|_| E::Fr::random(OsRng));
+        let pks = sks.map(|sk| public_key::<E>(&sk));
+        let sigs = sks
+            .iter()
+            .zip(msgs.iter())
+            .map(|(sk, msg)| sign::<E>(sk, msg, DOMAIN))
+            .collect::<Vec<_>>();
+        let agg = aggregate::<E>(&sigs);
+        assert!(aggregate_verify::<E>(&pks, &msgs, &agg, DOMAIN));
+
+        // Missing or swapped terms.
+        assert!(!aggregate_verify::<E>(&pks[1..], &msgs[1..], &agg, DOMAIN));
+        let mut swapped = pks;
+        swapped.swap(0, 1);
+        assert!(!aggregate_verify::<E>(&swapped, &msgs, &agg, DOMAIN));
+        assert!(!aggregate_verify::<E>(&pks, &msgs[1..], &agg, DOMAIN));
+        assert!(!aggregate_verify::<E>(
+            &[],
+            &[],
+            &aggregate::<E>(&[]),
+            DOMAIN
+        ));
+
+        // Repeated messages are rejected even if the aggregate is valid.
+        let repeated: [&[u8]; 2] = [b"message", b"message"];
+        let agg = aggregate::<E>(&[sign::<E>(&sks[0], b"message", DOMAIN), sig]);
+        assert!(!aggregate_verify::<E>(
+            &[pks[0], pk],
+            &repeated,
+            &agg,
+            DOMAIN
+        ));
+    }
+
+    #[test]
+    fn test_sig() {
+        run_sig_test::<crate::bn256::Bn256>();
+        run_sig_test::<crate::bls12_377::Bls12377>();
+        run_sig_test::<crate::bw6_767::Bw6767>();
+    }
+}
@@ -0,0 +1,128 @@
+//! Scalars of 128 bits, such as the challenges of folding and IPA verifiers.
+//!
+//! A [`SmallScalar`] multiplies points without being embedded into the scalar field
+//! first, so that only its 128 bits are processed: [`SmallScalar::mul`] takes 128
+//! doublings instead of one per bit of the field, and the multi-scalar
+//! multiplications of [`best_multiexp_small`](crate::msm::best_multiexp_small) and
+//! [`best_multiexp_mixed`](crate::msm::best_multiexp_mixed) run half the windows of
+//! the full width ones over these scalars.
+
+use core::ops::Neg;
+
+use ff::PrimeField;
+
+use crate::arithmetic::{select_signed, signed_radix16};
+use crate::wnaf::wnaf;
+use crate::CurveExt;
+
+/// A scalar of at most 128 bits.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SmallScalar(pub u128);
+
+impl SmallScalar {
+    /// Returns the little-endian bytes of `self`.
+    pub fn to_le_bytes(&self) -> [u8; 16] {
+        self.0.to_le_bytes()
+    }
+
+    /// Embeds `self` into the prime field `F`.
+    pub fn to_field<F: PrimeField>(&self) -> F {
+        F::from_u128(self.0)
+    }
+
+    /// Multiplies `p` by `self` with signed 4-bit windows, in constant time.
+    pub fn mul<C: CurveExt>(&self, p: &C) -> C
+    where
+        for<'a> &'a C: Neg<Output = C>,
+    {
+        // [P, 2P, ..., 8P]
+        let mut table = [*p; 8];
+        for i in 1..8 {
+            table[i] = table[i - 1] + p;
+        }
+
+        let mut acc = C::identity();
+        for digit in signed_radix16(&self.to_le_bytes()).iter().rev() {
+            acc = acc.double().double().double().double();
+            acc += select_signed(&table, C::identity(), *digit);
+        }
+        acc
+    }
+
+    /// Multiplies `p` by `self` with the width-5 NAF, skipping the leading zeros of
+    /// `self`. This runs in variable time.
+    pub fn mul_vartime<C: CurveExt>(&self, p: &C) -> C {
+        // [P, 3P, 5P, ..., 15P]
+        let double = p.double();
+        let mut table = [*p; 8];
+        for i in 1..8 {
+            table[i] = table[i - 1] + double;
+        }
+
+        let mut acc = C::identity();
+        for digit in wnaf::<5>(&self.to_le_bytes()).iter().rev() {
+            acc = acc.double();
+            match digit.signum() {
+                1 => acc += table[*digit as usize / 2],
+                -1 => acc -= table[digit.unsigned_abs() as usize / 2],
+                _ => {}
+            }
+        }
+        acc
+    }
+}
+
+impl From<u64> for SmallScalar {
+    fn from(k: u64) -> Self {
+        Self(k as u128)
+    }
+}
+
+impl From<u128> for SmallScalar {
+    fn from(k: u128) -> Self {
+        Self(k)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SmallScalar;
+    use crate::bn256::{Fr, G1};
+    use crate::pluto_eris::{Fq, G1 as PlutoG1};
+    use crate::CurveExt;
+    use core::ops::Neg;
+    use rand_core::{OsRng, RngCore};
+
+    fn run_small_scalar_test<C: CurveExt>()
+    where
+        for<'a> &'a C: Neg<Output = C>,
+    {
+        let p = C::random(OsRng);
+        let mut inputs = vec![0, 1, u64::MAX as u128, u128::MAX, 1 << 127];
+        for _ in 0..20 {
+            inputs.push(((OsRng.next_u64() as u128) << 64) | OsRng.next_u64() as u128);
+            inputs.push(OsRng.next_u64() as u128);
+        }
+
+        for k in inputs {
+            let k = SmallScalar(k);
+            let expected = p * k.to_field::<C::ScalarExt>();
+            assert_eq!(k.mul(&p), expected);
+            assert_eq!(k.mul_vartime(&p), expected);
+        }
+        assert_eq!(SmallScalar(5).mul(&C::identity()), C::identity());
+    }
+
+    #[test]
+    fn test_small_scalar() {
+        run_small_scalar_test::<G1>();
+        run_small_scalar_test::<PlutoG1>();
+
+        let k = SmallScalar(u128::MAX);
+        assert_eq!(
+            k.to_field::<Fr>(),
+            Fr::from(u64::MAX) * Fr::from(1 << 32).square() + Fr::from(u64::MAX)
+        );
+        assert_eq!(SmallScalar::from(7u64).to_field::<Fq>(), Fq::from(7));
+    }
+}
@@ -0,0 +1,116 @@
+//! Sampling and serialization of structured reference strings.
+//!
+//! A KZG-style reference string is the list of powers of a secret `tau` in the
+//! exponent, `[G, tau * G, tau^2 * G, ..., tau^(n - 1) * G]`, where `G` is the
+//! generator of the group. All the multiplications share the same base, so they
+//! are computed with a single [`CombTable`] and distributed over the threads.
+//!
+//! The serialized form is
+//!
+//! ```text
+//! | n (8 bytes, little-endian) | n compressed points |
+//! ```
+//!
+//! where every point takes `C::Repr` bytes, its `GroupEncoding`. Decoding checks
+//! that every point is a valid, canonical encoding.
+
+use std::io::{self, Read, Write};
+
+use crate::comb::CombTable;
+use crate::CurveAffine;
+use ff::{Field, PrimeField};
+use group::Curve;
+use rayon::prelude::*;
+
+// Bits per comb digit. 256 table entries are a negligible cost next to the
+// reference strings this is meant for, and halve the doublings of a 4-bit comb.
+const COMB_WIDTH: usize = 8;
+
+/// Returns `[tau^i * G for i in 0..n]`, where `G` is the generator of `C`.
+pub fn powers_of_tau<C: CurveAffine>(tau: C::Scalar, n: usize) -> Vec<C> {
+    let mut powers = Vec::with_capacity(n);
+    let mut acc = C::Scalar::ONE;
+    for _ in 0..n {
+        powers.push(acc);
+        acc *= tau;
+    }
+
+    let depth = (C::Scalar::NUM_BITS as usize + COMB_WIDTH - 1) / COMB_WIDTH;
+    let table = CombTable::new(&C::generator(), COMB_WIDTH, depth);
+    let points = powers
+        .par_iter()
+        .map(|power| table.mul(power))
+        .collect::<Vec<_>>();
+
+    let mut res = vec![C::identity(); n];
+    C::Curve::batch_normalize(&points, &mut res);
+    res
+}
+
+/// Writes `points` in the format described in the [module documentation](self).
+pub fn write_points<C: CurveAffine, W: Write>(points: &[C], writer: &mut W) -> io::Result<()> {
+    writer.write_all(&(points.len() as u64).to_le_bytes())?;
+    for point in points {
+        writer.write_all(point.to_bytes().as_ref())?;
+    }
+    Ok(())
+}
+
+/// Reads points written with [`write_points`], failing on invalid encodings.
+pub fn read_points<C: CurveAffine, R: Read>(reader: &mut R) -> io::Result<Vec<C>> {
+    let mut len = [0u8; 8];
+    reader.read_exact(&mut len)?;
+    let len = usize::try_from(u64::from_le_bytes(len)).map_err(|_| crate::Error::InvalidLength)?;
+
+    // Do not trust the length for the allocation, the input may be truncated.
+    let mut points = Vec::new();
+    for _ in 0..len {
+        let mut repr = C::Repr::default();
+        reader.read_exact(repr.as_mut())?;
+        let point = Option::from(C::from_bytes(&repr)).ok_or(crate::Error::InvalidPoint)?;
+        points.push(point);
+    }
+    Ok(points)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{powers_of_tau, read_points, write_points};
+    use crate::tests::assert_decoding_error;
+    use crate::{CurveAffine, Error};
+    use ff::Field;
+    use rand_core::OsRng;
+
+    fn run_srs_test<C: CurveAffine>() {
+        let tau = C::Scalar::random(OsRng);
+        let n = 20;
+        let srs = powers_of_tau::<C>(tau, n);
+        assert_eq!(srs.len(), n);
+
+        let mut expected = C::generator();
+        for point in srs.iter() {
+            assert_eq!(*point, expected);
+            expected = (expected * tau).into();
+        }
+
+        let mut buf = Vec::new();
+        write_points(&srs, &mut buf).unwrap();
+        assert_eq!(read_points::<C, _>(&mut &buf[..]).unwrap(), srs);
+
+        // Truncated inputs and invalid points are rejected.
+        assert!(read_points::<C, _>(&mut &buf[..buf.len() - 1]).is_err());
+        let mut invalid = buf.clone();
+        invalid[8..].fill(0xff);
+        assert_decoding_error(read_points::<C, _>(&mut &invalid[..]), Error::InvalidPoint);
+
+        assert!(powers_of_tau::<C>(tau, 0).is_empty());
+        assert_eq!(powers_of_tau::<C>(C::Scalar::ZERO, 2)[1], C::identity());
+    }
+
+    #[test]
+    fn test_srs() {
+        run_srs_test::<crate::bn256::G1Affine>();
+        run_srs_test::<crate::bn256::G2Affine>();
+        run_srs_test::<crate::pluto_eris::G1Affine>();
+    }
+}
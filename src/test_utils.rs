@@ -0,0 +1,263 @@
+//! Randomized checks of the algebraic laws of fields, curves and pairings, with
+//! the `test-utils` feature, for downstream implementations built with the macros
+//! of this crate, such as `new_curve_impl!`.
+//!
+//! Each function samples `n` random instances of the laws from `rng` and panics on
+//! the first violation. [`algebraic_law_tests!`](crate::algebraic_law_tests)
+//! turns them into `#[test]` functions with one macro call:
+//!
+//! ```ignore
+//! mod tests {
+//!     use my_curve::{Engine, G1, G2};
+//!
+//!     halo2curves::algebraic_law_tests!(G1, G2; rand_core::OsRng);
+//!     halo2curves::algebraic_law_tests!(Engine, "pairing"; rand_core::OsRng);
+//! }
+//! ```
+
+// The laws compare expressions that clippy considers identical, e.g. `a + b` and `b + a`.
+#![allow(clippy::eq_op)]
+
+use core::fmt::Debug;
+
+use ff::{Field, PrimeField};
+use group::prime::PrimeCurveAffine;
+use group::{Curve, Group, GroupEncoding};
+use pairing::{MillerLoopResult, MultiMillerLoop};
+use rand_core::RngCore;
+
+use crate::serde::SerdeObject;
+use crate::{CurveAffine, CurveExt};
+
+/// Checks the field axioms and the consistency of the derived operations,
+/// `square`, `double`, `invert`, `sqrt` and `pow_vartime`.
+pub fn field_laws<F: Field>(mut rng: impl RngCore, n: usize) {
+    assert_eq!(F::ZERO.invert().is_none().unwrap_u8(), 1);
+    assert_eq!(F::ONE.invert().unwrap(), F::ONE);
+    assert_ne!(F::ZERO, F::ONE);
+
+    for _ in 0..n {
+        let a = F::random(&mut rng);
+        let b = F::random(&mut rng);
+        let c = F::random(&mut rng);
+
+        // Addition and multiplication are associative and commutative.
+        assert_eq!((a + b) + c, a + (b + c));
+        assert_eq!(a + b, b + a);
+        assert_eq!((a * b) * c, a * (b * c));
+        assert_eq!(a * b, b * a);
+        assert_eq!(a * (b + c), a * b + a * c);
+
+        // Identities and inverses.
+        assert_eq!(a + F::ZERO, a);
+        assert_eq!(a * F::ONE, a);
+        assert_eq!(a * F::ZERO, F::ZERO);
+        assert_eq!(a + (-a), F::ZERO);
+        assert_eq!(a - b, a + (-b));
+        assert_eq!(-(-a), a);
+        if !bool::from(a.is_zero()) {
+            assert_eq!(a * a.invert().unwrap(), F::ONE);
+        }
+
+        assert_eq!(a.square(), a * a);
+        assert_eq!(a.double(), a + a);
+        assert_eq!(a.cube(), a * a * a);
+        assert_eq!(a.pow_vartime([5]), a.square().square() * a);
+        let root = a.square().sqrt().unwrap();
+        assert!(root == a || root == -a);
+    }
+}
+
+/// Checks [`field_laws`], the canonical encoding, the conversion from integers
+/// and the constants of [`PrimeField`].
+pub fn prime_field_laws<F: PrimeField>(mut rng: impl RngCore, n: usize) {
+    field_laws::<F>(&mut rng, n);
+
+    assert_eq!(F::TWO_INV.double(), F::ONE);
+    assert_eq!(F::ROOT_OF_UNITY * F::ROOT_OF_UNITY_INV, F::ONE);
+    let pow2 = |x: F, k: u32| (0..k).fold(x, |x, _| x.square());
+    assert_eq!(pow2(F::ROOT_OF_UNITY, F::S), F::ONE);
+    assert_ne!(pow2(F::ROOT_OF_UNITY, F::S - 1), F::ONE);
+    assert_eq!(F::DELTA, pow2(F::MULTIPLICATIVE_GENERATOR, F::S));
+    assert_eq!(F::from(0), F::ZERO);
+    assert_eq!(-F::ONE + F::ONE, F::ZERO);
+
+    for _ in 0..n {
+        let a = F::random(&mut rng);
+        assert_eq!(F::from_repr(a.to_repr()).unwrap(), a);
+        assert_eq!(a.is_odd().unwrap_u8() ^ 1, a.is_even().unwrap_u8());
+
+        let (x, y) = (rng.next_u32() as u64, rng.next_u32() as u64);
+        assert_eq!(F::from(x) + F::from(y), F::from(x + y));
+        assert_eq!(F::from(x) * F::from(y), F::from(x * y));
+        assert_eq!(
+            F::from_u128((x as u128) << 64),
+            F::from(x) * F::from(1 << 32).square()
+        );
+    }
+}
+
+/// Checks the group laws in projective, affine and mixed coordinates, the scalar
+/// multiplication and the roundtrips through the compressed encoding.
+pub fn curve_laws<C: CurveExt>(mut rng: impl RngCore, n: usize)
+where
+    C::ScalarExt: PrimeField,
+{
+    let identity = C::identity();
+    assert!(bool::from(identity.is_identity()));
+    assert!(bool::from(identity.to_affine().is_identity()));
+    assert!(bool::from(C::AffineExt::identity().is_on_curve()));
+    assert!(!bool::from(C::generator().is_identity()));
+
+    for _ in 0..n {
+        let a = C::random(&mut rng);
+        let b = C::random(&mut rng);
+        let c = C::random(&mut rng);
+
+        // Group laws.
+        assert_eq!((a + b) + c, a + (b + c));
+        assert_eq!(a + b, b + a);
+        assert_eq!(a + identity, a);
+        assert!(bool::from((a - a).is_identity()));
+        assert_eq!(-(-a), a);
+        assert_eq!(a.double(), a + a);
+
+        // Affine and mixed coordinates.
+        let b_affine = b.to_affine();
+        assert!(bool::from(b_affine.is_on_curve()));
+        assert_eq!(C::from(b_affine), b);
+        assert_eq!(a + b_affine, a + b);
+        assert_eq!(a - b_affine, a - b);
+        assert_eq!((-b).to_affine(), -b_affine);
+
+        // Scalar multiplication.
+        let s = C::ScalarExt::random(&mut rng);
+        let t = C::ScalarExt::random(&mut rng);
+        assert_eq!(a * (s + t), a * s + a * t);
+        assert_eq!(a * (s * t), (a * s) * t);
+        assert_eq!((a + b) * s, a * s + b * s);
+        assert_eq!(b_affine * s, b * s);
+        assert_eq!(a * C::ScalarExt::ONE, a);
+        assert_eq!(a * -C::ScalarExt::ONE, -a);
+        assert!(bool::from((a * C::ScalarExt::ZERO).is_identity()));
+        assert!(bool::from((identity * s).is_identity()));
+
+        // Compressed encoding.
+        assert_eq!(C::from_bytes(&a.to_bytes()).unwrap(), a);
+        assert_eq!(
+            C::AffineExt::from_bytes(&b_affine.to_bytes()).unwrap(),
+            b_affine
+        );
+    }
+    assert_eq!(C::from_bytes(&identity.to_bytes()).unwrap(), identity);
+}
+
+// Checks that the raw bytes of `value` decode back to it.
+fn raw_roundtrip<T: SerdeObject + PartialEq + Debug>(value: &T) {
+    let bytes = value.to_raw_bytes();
+    assert_eq!(T::from_raw_bytes(&bytes).as_ref(), Some(value));
+    assert_eq!(&T::from_raw_bytes_unchecked(&bytes), value);
+}
+
+/// Checks the roundtrips through the raw encoding of [`SerdeObject`] of the
+/// points of `C`, projective and affine, and of the elements of their fields.
+pub fn serialization_laws<C>(mut rng: impl RngCore, n: usize)
+where
+    C: CurveExt + SerdeObject,
+    C::AffineExt: SerdeObject,
+    C::Base: SerdeObject,
+    C::ScalarExt: SerdeObject,
+{
+    raw_roundtrip(&C::identity());
+    raw_roundtrip(&C::AffineExt::identity());
+    for _ in 0..n {
+        let p = C::random(&mut rng);
+        raw_roundtrip(&p);
+        raw_roundtrip(&p.to_affine());
+        raw_roundtrip(&C::Base::random(&mut rng));
+        raw_roundtrip(&C::ScalarExt::random(&mut rng));
+    }
+}
+
+/// Checks that the pairing is bilinear and non-degenerate, and that the
+/// multi-Miller loop agrees with the product of the pairings.
+pub fn pairing_laws<E: MultiMillerLoop>(mut rng: impl RngCore, n: usize) {
+    let g1 = E::G1Affine::generator();
+    let g2 = E::G2Affine::generator();
+    let gt = E::pairing(&g1, &g2);
+    assert!(!bool::from(gt.is_identity()));
+    assert!(bool::from(
+        E::pairing(&E::G1Affine::identity(), &g2).is_identity()
+    ));
+    assert!(bool::from(
+        E::pairing(&g1, &E::G2Affine::identity()).is_identity()
+    ));
+
+    for _ in 0..n {
+        let a = E::Fr::random(&mut rng);
+        let b = E::Fr::random(&mut rng);
+        let p = (g1 * a).to_affine();
+        let q = (g2 * b).to_affine();
+        assert_eq!(E::pairing(&p, &q), gt * (a * b));
+
+        // Linearity in each argument.
+        let p2 = E::G1::random(&mut rng).to_affine();
+        let q2 = E::G2::random(&mut rng).to_affine();
+        let sum1 = (p.to_curve() + p2).to_affine();
+        let sum2 = (q.to_curve() + q2).to_affine();
+        assert_eq!(
+            E::pairing(&sum1, &q),
+            E::pairing(&p, &q) + E::pairing(&p2, &q)
+        );
+        assert_eq!(
+            E::pairing(&p, &sum2),
+            E::pairing(&p, &q) + E::pairing(&p, &q2)
+        );
+
+        let prepared = (E::G2Prepared::from(q), E::G2Prepared::from(q2));
+        assert_eq!(
+            E::multi_miller_loop(&[(&p, &prepared.0), (&p2, &prepared.1)]).final_exponentiation(),
+            E::pairing(&p, &q) + E::pairing(&p2, &q2)
+        );
+    }
+}
+
+/// Instantiates the checks of [`test_utils`](crate::test_utils) as `#[test]`
+/// functions, sampling from the [`RngCore`](rand_core::RngCore) given by `$rng`.
+///
+/// `algebraic_law_tests!(G1, G2; rng)` checks the curves, their fields and their
+/// serialization, and `algebraic_law_tests!(Engine, "pairing"; rng)` checks a
+/// pairing. Each form can be called once per module.
+#[macro_export]
+macro_rules! algebraic_law_tests {
+    ($engine: ty, "pairing"; $rng: expr) => {
+        #[test]
+        fn test_pairing_laws() {
+            $crate::test_utils::pairing_laws::<$engine>($rng, 10);
+        }
+    };
+
+    ($($curve: ty),+; $rng: expr) => {
+        #[test]
+        fn test_field_laws() {
+            $(
+                $crate::test_utils::field_laws::<<$curve as $crate::CurveExt>::Base>($rng, 100);
+                $crate::test_utils::prime_field_laws::<<$curve as $crate::CurveExt>::ScalarExt>($rng, 100);
+            )+
+        }
+
+        #[test]
+        fn test_curve_laws() {
+            $(
+                $crate::test_utils::curve_laws::<$curve>($rng, 50);
+            )+
+        }
+
+        #[test]
+        fn test_serialization_laws() {
+            $(
+                $crate::test_utils::serialization_laws::<$curve>($rng, 50);
+            )+
+        }
+    };
+}
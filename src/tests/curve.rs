@@ -12,9 +12,39 @@ macro_rules! curve_testing_suite {
                     let affine_point: <$c as CurveExt>::AffineExt = point.into();
                     assert!(bool::from(affine_point.is_on_curve()));
                 }
+
+                {
+                    type Affine = <$c as CurveExt>::AffineExt;
+                    let mut points = (0..20).map(|_| Affine::random(OsRng)).collect::<Vec<_>>();
+                    points.push(Affine::identity());
+                    for i in 0..10 {
+                        // Moving a coordinate takes the point out of the curve.
+                        points[2 * i].y += <$c as CurveExt>::Base::ONE;
+                    }
+                    let batch = Affine::batch_is_on_curve(&points);
+                    assert_eq!(batch.len(), points.len());
+                    for (point, on_curve) in points.iter().zip(batch.iter()) {
+                        assert_eq!(bool::from(*on_curve), bool::from(point.is_on_curve()));
+                    }
+                    assert!(!bool::from(batch[0]) && bool::from(batch[1]));
+                }
             }
         }
 
+        macro_rules! random_affine {
+            ($c: ident) => {{
+                use $crate::group::cofactor::CofactorGroup;
+                type Affine = <$c as CurveExt>::AffineExt;
+
+                for _ in 0..100 {
+                    for point in [Affine::random(OsRng), Affine::random_from_scalar(OsRng)] {
+                        assert!(bool::from(point.is_on_curve()));
+                        assert!(bool::from(point.to_curve().is_torsion_free()));
+                    }
+                }
+            }}
+        }
+
         macro_rules! equality {
             ($c: ident) => {
                 let a = $c::generator();
@@ -155,9 +185,27 @@ macro_rules! curve_testing_suite {
                 let c0 = a + b;
                 let c1 = a + $c::from(b);
                 assert_eq!(c0, c1);
+
+                // Complete mixed addition, exceptional inputs included.
+                assert_eq!(a.add_mixed_complete(&b), c0);
+                let identity = <$c as CurveExt>::AffineExt::identity();
+                assert_eq!(a.add_mixed_complete(&identity), a);
+                assert_eq!($c::identity().add_mixed_complete(&b), $c::from(b));
+                assert!(bool::from($c::identity().add_mixed_complete(&identity).is_identity()));
+                assert_eq!($c::from(b).add_mixed_complete(&b), $c::from(b).double());
+                assert!(bool::from((-$c::from(b)).add_mixed_complete(&b).is_identity()));
             }
         }
 
+        macro_rules! operators {
+            ($c: ident) => {
+                let p = $c::random(OsRng);
+                let q = $c::random(OsRng).to_affine();
+                let s = <$c as CurveExt>::ScalarExt::random(OsRng);
+                $crate::tests::ops::point_operators(p, q, s);
+            };
+        }
+
         macro_rules! multiplication {
             ($c: ident) => {
                 for _ in 1..1000 {
@@ -188,6 +236,46 @@ macro_rules! curve_testing_suite {
                     t1 = a * s3;
                     assert_eq!(t0, t1);
                 }
+
+                // Small multiples exercise every digit of the signed window recoding,
+                // negative scalars the carry into the top window.
+                let a = $c::random(OsRng);
+                let mut t0 = $c::identity();
+                for k in 0..40u64 {
+                    let s = <$c as CurveExt>::ScalarExt::from(k);
+                    assert_eq!(a * s, t0);
+                    assert_eq!(a * -s, -t0);
+                    assert_eq!(a.to_affine() * s, t0);
+                    t0 += a;
+                }
+
+                // The variable time multiplication agrees with the constant time one.
+                for k in [0u64, 1, 2, 15, 16, 17] {
+                    let s = <$c as CurveExt>::ScalarExt::from(k);
+                    assert_eq!(a.mul_vartime(&s), a * s);
+                    assert_eq!(a.mul_vartime(&-s), a * -s);
+                }
+                for _ in 0..100 {
+                    let s = <$c as CurveExt>::ScalarExt::random(OsRng);
+                    assert_eq!(a.mul_vartime(&s), a * s);
+                }
+                assert!(bool::from($c::identity().mul_vartime(&-<$c as CurveExt>::ScalarExt::ONE).is_identity()));
+
+                // Generator multiplication must agree with the generic path.
+                for s in [
+                    <$c as CurveExt>::ScalarExt::ZERO,
+                    <$c as CurveExt>::ScalarExt::ONE,
+                    -<$c as CurveExt>::ScalarExt::ONE,
+                ] {
+                    assert_eq!($c::mul_by_generator(&s), $c::generator() * s);
+                }
+                for _ in 0..100 {
+                    let s = <$c as CurveExt>::ScalarExt::random(OsRng);
+                    assert_eq!($c::mul_by_generator(&s), $c::generator() * s);
+                    // `-G` is not the generator and takes the generic path.
+                    assert_eq!($c::generator() * s, -$c::generator() * -s);
+                    assert_eq!($c::generator().to_affine() * s, -$c::generator() * -s);
+                }
             }
         }
 
@@ -279,6 +367,47 @@ macro_rules! curve_testing_suite {
                     assert_eq!(affine_point, affine_point_rec);
                     assert_eq!(affine_point, affine_point_rec_unchecked);
                 }
+
+                {
+                    type Affine = <$c as CurveExt>::AffineExt;
+                    let mut bytes = (0..20).map(|_| Affine::random(OsRng).to_bytes()).collect::<Vec<_>>();
+                    bytes.push(Affine::identity().to_bytes());
+                    for i in 0..10 {
+                        // Some of the corrupted encodings are still valid.
+                        bytes[2 * i].as_mut()[0] ^= 1;
+                    }
+                    let batch = Affine::batch_from_compressed(&bytes);
+                    assert_eq!(batch.len(), bytes.len());
+                    for (bytes, point) in bytes.iter().zip(batch.iter()) {
+                        let expected = Affine::from_bytes(bytes);
+                        assert_eq!(bool::from(point.is_some()), bool::from(expected.is_some()));
+                        assert!(bool::from(point.ct_eq(&expected)));
+                    }
+                    assert!(bool::from(batch[1].is_some()));
+                }
+
+                {
+                    use $crate::serde::{Validation, ValidatedEncoding};
+                    type Affine = <$c as CurveExt>::AffineExt;
+                    let point = Affine::random(OsRng);
+                    let compressed = point.to_bytes();
+                    let uncompressed = point.to_uncompressed();
+                    for validation in [Validation::Unchecked, Validation::OnCurve, Validation::Subgroup] {
+                        assert_eq!(Affine::from_bytes_with(&compressed, validation).unwrap(), point);
+                        assert_eq!(Affine::from_bytes_with(&uncompressed, validation).unwrap(), point);
+                        assert_eq!($c::from_bytes_with(&compressed, validation).unwrap(), $c::from(point));
+                    }
+                    assert_eq!(Affine::from_bytes_checked(&compressed).unwrap(), point);
+                    assert_eq!(Affine::from_bytes_on_curve(&uncompressed).unwrap(), point);
+
+                    // A point off the curve is only decoded unchecked.
+                    let mut off_curve = point;
+                    off_curve.y += <$c as CurveExt>::Base::ONE;
+                    let uncompressed = off_curve.to_uncompressed();
+                    assert_eq!(Affine::from_bytes_with(&uncompressed, Validation::Unchecked).unwrap(), off_curve);
+                    assert!(bool::from(Affine::from_bytes_on_curve(&uncompressed).is_none()));
+                    assert!(bool::from(Affine::from_bytes_checked(&uncompressed).is_none()));
+                }
             }
         }
 
@@ -304,7 +433,31 @@ macro_rules! curve_testing_suite {
                     affine_point.write_raw(&mut buf).unwrap();
                     let affine_point_rec = <$c as CurveExt>::AffineExt::read_raw(&mut &buf[..]).unwrap();
                     assert_eq!(affine_point, affine_point_rec);
+
+                    $crate::tests::run_canonical_encoding_test(&projective_point);
+                    $crate::tests::run_canonical_encoding_test(&affine_point);
+                    $crate::tests::run_raw_montgomery_test(&projective_point);
+                    $crate::tests::run_raw_montgomery_test(&affine_point);
                 }
+                $crate::tests::run_canonical_encoding_test(&$c::identity());
+                $crate::tests::run_canonical_encoding_test(&<$c as CurveExt>::AffineExt::identity());
+
+                // Points off the curve are rejected by the checked decodings.
+                let mut off_curve = $c::random(OsRng);
+                off_curve.y += <$c as CurveExt>::Base::ONE;
+                let mut buf = Vec::new();
+                off_curve.write_raw(&mut buf).unwrap();
+                assert!($c::from_raw_bytes(&buf).is_none());
+                $crate::tests::assert_decoding_error($c::read_raw(&mut &buf[..]), $crate::Error::NotOnCurve);
+                let mut off_curve = $c::random(OsRng).to_affine();
+                off_curve.y += <$c as CurveExt>::Base::ONE;
+                let mut buf = Vec::new();
+                off_curve.write_raw(&mut buf).unwrap();
+                assert!(<$c as CurveExt>::AffineExt::from_raw_bytes(&buf).is_none());
+                $crate::tests::assert_decoding_error(
+                    <$c as CurveExt>::AffineExt::read_raw(&mut &buf[..]),
+                    $crate::Error::NotOnCurve,
+                );
             }
         }
 
@@ -343,6 +496,208 @@ macro_rules! curve_testing_suite {
             }
         }
 
+        macro_rules! order {
+            ($c: ident) => {
+                // Double-and-add by an exponent given as little-endian limbs, which
+                // may exceed the scalar field.
+                let mul_limbs = |p: $c, limbs: &[u64]| {
+                    let mut acc = $c::identity();
+                    for limb in limbs.iter().rev() {
+                        for i in (0..64).rev() {
+                            acc = acc.double();
+                            if (limb >> i) & 1 == 1 {
+                                acc += p;
+                            }
+                        }
+                    }
+                    acc
+                };
+
+                assert_eq!($c::ORDER, <$c as CurveExt>::AffineExt::ORDER);
+                let mut order_minus_one = $c::ORDER.to_vec();
+                order_minus_one[0] -= 1;
+
+                for p in [$c::generator(), $c::random(OsRng)] {
+                    assert_eq!(mul_limbs(p, $c::ORDER), $c::identity());
+                    assert_eq!(mul_limbs(p, &order_minus_one), -p);
+                }
+            }
+        }
+
+        macro_rules! canonical_order {
+            ($c: ident) => {{
+                use std::collections::{hash_map::DefaultHasher, BTreeSet, HashSet};
+                use std::hash::{Hash, Hasher};
+                type Affine = <$c as CurveExt>::AffineExt;
+
+                let hash = |bytes: &dyn Fn(&mut DefaultHasher)| {
+                    let mut hasher = DefaultHasher::new();
+                    bytes(&mut hasher);
+                    hasher.finish()
+                };
+
+                let mut points = (0..50).map(|_| Affine::random(OsRng)).collect::<Vec<_>>();
+                points.push(Affine::identity());
+                points.push(Affine::generator());
+                points.extend_from_slice(&points.clone()[..10]);
+
+                for point in points.iter() {
+                    let bytes = point.as_canonical_bytes();
+                    assert_eq!(bytes.as_ref(), point.to_bytes().as_ref());
+                    assert_eq!(
+                        hash(&|h| point.hash(h)),
+                        hash(&|h| bytes.as_ref().hash(h))
+                    );
+                }
+
+                // Keys are ordered by their compressed bytes, whatever the platform.
+                let mut by_bytes = points
+                    .iter()
+                    .map(|point| point.as_canonical_bytes().as_ref().to_vec())
+                    .collect::<Vec<_>>();
+                by_bytes.sort();
+                by_bytes.dedup();
+                let ordered = points.iter().cloned().collect::<BTreeSet<_>>();
+                assert_eq!(
+                    ordered
+                        .iter()
+                        .map(|point| point.as_canonical_bytes().as_ref().to_vec())
+                        .collect::<Vec<_>>(),
+                    by_bytes
+                );
+                let hashed = points.iter().cloned().collect::<HashSet<_>>();
+                assert_eq!(hashed.len(), ordered.len());
+                assert!(ordered.iter().all(|point| hashed.contains(point)));
+
+                // Projective points behave as their affine form, whatever their `z`.
+                for point in points.iter() {
+                    let p = $c::from(*point);
+                    let k = <$c as CurveExt>::Base::random(OsRng);
+                    let q = $c {
+                        x: p.x * k,
+                        y: p.y * k,
+                        z: p.z * k,
+                    };
+                    assert_eq!(hash(&|h| q.hash(h)), hash(&|h| point.hash(h)));
+                    assert_eq!(q.cmp(&p), std::cmp::Ordering::Equal);
+                    assert_eq!(format!("{}", q), format!("{}", point));
+                    assert_eq!(format!("{:x}", q), format!("{:x}", point));
+                }
+
+                let g = Affine::generator();
+                assert_eq!(format!("{}", g), format!("({}, {})", g.x, g.y));
+                assert_eq!(format!("{:x}", g), format!("({:x}, {:x})", g.x, g.y));
+                assert_eq!(format!("{}", Affine::identity()), "Infinity");
+            }};
+        }
+
+        macro_rules! jacobian {
+            ($c: ident) => {{
+                use $crate::group::{Curve, Group};
+                type Affine = <$c as CurveExt>::AffineExt;
+
+                let identity = $c::identity().into_jacobian();
+                assert!(bool::from(identity.is_identity()));
+                assert!(bool::from(identity.is_on_curve()));
+                assert_eq!(identity.into_homogeneous(), $c::identity());
+                assert_eq!(identity.double(), identity);
+
+                for _ in 0..100 {
+                    let a = $c::random(OsRng);
+                    let b = $c::random(OsRng);
+                    let (ja, jb) = (a.into_jacobian(), b.into_jacobian());
+                    assert!(bool::from(ja.is_on_curve()));
+                    assert_eq!(ja.into_homogeneous(), a);
+                    assert_eq!(ja.to_affine(), a.to_affine());
+                    assert_eq!(Affine::from(ja), a.to_affine());
+
+                    assert_eq!((ja + jb).into_homogeneous(), a + b);
+                    assert_eq!((ja - jb).into_homogeneous(), a - b);
+                    assert_eq!(ja.double().into_homogeneous(), a.double());
+                    assert_eq!((ja + b.to_affine()).into_homogeneous(), a + b);
+                    assert_eq!((ja - b.to_affine()).into_homogeneous(), a - b);
+
+                    // Exceptional cases of the incomplete formulas.
+                    assert_eq!(ja + ja, ja.double());
+                    assert_eq!(ja + a.to_affine(), ja.double());
+                    assert!(bool::from((ja - ja).is_identity()));
+                    assert!(bool::from((ja - a.to_affine()).is_identity()));
+                    assert_eq!(ja + identity, ja);
+                    assert_eq!(identity + ja, ja);
+                    assert_eq!(ja + Affine::identity(), ja);
+                    assert_eq!(identity + a.to_affine(), ja);
+                }
+            }};
+        }
+
+        macro_rules! recover_y {
+            ($c: ident) => {{
+                type Affine = <$c as CurveExt>::AffineExt;
+                type Base = <$c as CurveExt>::Base;
+                for _ in 0..20 {
+                    let p = $c::random(OsRng).to_affine();
+                    let sign = Affine::y_sign(&p.y);
+                    assert_eq!(Affine::recover_y(&p.x, sign).unwrap(), p.y);
+                    assert_eq!(Affine::recover_y(&p.x, !sign).unwrap(), -p.y);
+                    assert_eq!(Affine::from_x(p.x, sign).unwrap(), p);
+
+                    let q = Affine::lift_x(p.x).unwrap();
+                    assert!(!bool::from(Affine::y_sign(&q.y)));
+                    assert!(q == p || q == -p);
+                }
+
+                // Abscissas of no point.
+                let mut x = Base::random(OsRng);
+                while bool::from(Affine::from_x(x, 0.into()).is_some()) {
+                    x = Base::random(OsRng);
+                }
+                assert!(bool::from(Affine::recover_y(&x, 1.into()).is_none()));
+                assert!(bool::from(Affine::lift_x(x).is_none()));
+            }};
+        }
+
+        macro_rules! random_batch {
+            ($c: ident) => {{
+                use $crate::group::cofactor::CofactorGroup;
+                type Affine = <$c as CurveExt>::AffineExt;
+                let points = Affine::random_batch(OsRng, 50);
+                assert_eq!(points.len(), 50);
+                for (i, p) in points.iter().enumerate() {
+                    assert!(bool::from(p.is_on_curve()));
+                    assert!(!bool::from(p.is_identity()));
+                    assert!(bool::from($c::from(*p).is_torsion_free()));
+                    assert!(points[..i].iter().all(|q| q != p));
+                }
+                assert!(Affine::random_batch(OsRng, 0).is_empty());
+            }};
+        }
+
+        macro_rules! slice_as_limbs {
+            ($c: ident) => {{
+                // The limbs of the coordinates, in Montgomery form.
+                let bytes = |limbs: &[u64]| {
+                    limbs.iter().flat_map(|limb| limb.to_le_bytes()).collect::<Vec<_>>()
+                };
+                let points = (0..4).map(|_| $c::random(OsRng)).collect::<Vec<_>>();
+                let expected = points
+                    .iter()
+                    .flat_map(|p| [p.x, p.y, p.z])
+                    .flat_map(|c| c.to_raw_bytes())
+                    .collect::<Vec<_>>();
+                assert_eq!(bytes($c::slice_as_limbs(&points)), expected);
+
+                let points = points.iter().map(|p| p.to_affine()).collect::<Vec<_>>();
+                let expected = points
+                    .iter()
+                    .flat_map(|p| [p.x, p.y])
+                    .flat_map(|c| c.to_raw_bytes())
+                    .collect::<Vec<_>>();
+                type Affine = <$c as CurveExt>::AffineExt;
+                assert_eq!(bytes(Affine::slice_as_limbs(&points)), expected);
+                assert!(Affine::slice_as_limbs(&[]).is_empty());
+            }};
+        }
+
         use $crate::ff::Field;
         use $crate::group::prime::PrimeCurveAffine;
         use $crate::{group::GroupEncoding, serde::SerdeObject};
@@ -354,13 +709,21 @@ macro_rules! curve_testing_suite {
         fn test_curve() {
             $(
                 is_on_curve!($curve);
+                random_affine!($curve);
                 equality!($curve);
                 projective_affine_roundtrip!($curve);
                 projective_addition!($curve);
                 mixed_addition!($curve);
+                operators!($curve);
                 multiplication!($curve);
                 batch_normalize!($curve);
                 serdes!($curve);
+                order!($curve);
+                canonical_order!($curve);
+                jacobian!($curve);
+                slice_as_limbs!($curve);
+                recover_y!($curve);
+                random_batch!($curve);
             )*
         }
 
@@ -399,6 +762,74 @@ macro_rules! curve_testing_suite {
         }
     };
 
+    ($($curve: ident),*, "batch_hash_to_curve") => {
+        macro_rules! batch_hash_to_curve_test {
+            ($c: ident) => {
+                let hasher = $c::hash_to_curve("test");
+                let mut rng = OsRng;
+                let messages = iter::repeat_with(|| {
+                    let len = (rng.next_u32() % 64) as usize;
+                    iter::repeat_with(|| rng.next_u32() as u8)
+                        .take(len)
+                        .collect::<Vec<_>>()
+                })
+                .take(101)
+                .collect::<Vec<_>>();
+                let messages = messages.iter().map(|m| &m[..]).collect::<Vec<_>>();
+
+                let points = $c::batch_hash_to_curve("test", &messages);
+                assert_eq!(points.len(), messages.len());
+                for (point, message) in points.iter().zip(messages.iter()) {
+                    assert_eq!(*point, hasher(message));
+                }
+                assert!($c::batch_hash_to_curve("test", &[]).is_empty());
+            }
+        }
+
+        #[test]
+        fn test_batch_hash_to_curve() {
+            use $crate::hash_to_curve::BatchHashToCurve;
+            use rand_core::{OsRng, RngCore};
+            use std::iter;
+            $(
+                batch_hash_to_curve_test!($curve);
+            )*
+        }
+    };
+
+    ($($curve: ident),*, "map_to_curve") => {
+        macro_rules! map_to_curve_test {
+            ($c: ident) => {
+                let hasher = $c::hash_to_curve("test");
+                let mut rng = OsRng;
+                for _ in 0..100 {
+                    let len = (rng.next_u32() % 64) as usize;
+                    let message = iter::repeat_with(|| rng.next_u32() as u8)
+                        .take(len)
+                        .collect::<Vec<_>>();
+                    let u = $c::hash_to_field("test", &message);
+                    assert_eq!($c::hash_from_field(&u), hasher(&message));
+
+                    let u = <$c as CurveExt>::Base::random(&mut rng);
+                    assert!(bool::from($c::map_to_curve(&u).is_on_curve()));
+                    let p = $c::hash_from_field(&[u, u]);
+                    assert!(bool::from(p.is_on_curve() & p.is_torsion_free()));
+                }
+            }
+        }
+
+        #[test]
+        fn test_map_to_curve() {
+            use $crate::hash_to_curve::MapToCurve;
+            use group::cofactor::CofactorGroup;
+            use rand_core::{OsRng, RngCore};
+            use std::iter;
+            $(
+                map_to_curve_test!($curve);
+            )*
+        }
+    };
+
     ($($curve: ident),*, "clear_cofactor") => {
         #[test]
         fn test_cofactor_clearing() {
@@ -415,6 +846,179 @@ macro_rules! curve_testing_suite {
         }
     };
 
+    ($curve: ident, "compressed_flags") => {
+        #[test]
+        fn test_compressed_flags() {
+            use group::GroupEncoding;
+            use rand_core::OsRng;
+            type Affine = <$curve as CurveExt>::AffineExt;
+            type Base = <$curve as CurveExt>::Base;
+            type Repr = <Affine as GroupEncoding>::Repr;
+
+            // The flags are the two spare bits of the x-coordinate: the sign in bit 7
+            // and the identity in bit 6 of its most significant byte.
+            assert!(Base::SIZE * 8 - Base::NUM_BITS as usize >= 2);
+            assert_eq!(Repr::SIZE, Base::SIZE);
+            let last = Repr::SIZE - 1;
+            let encode = |x: &Base, sign: u8, identity: u8| {
+                let mut bytes = Repr::default();
+                bytes.as_mut().copy_from_slice(&x.to_bytes());
+                bytes.as_mut()[last] |= sign << 7 | identity << 6;
+                bytes
+            };
+
+            for _ in 0..10 {
+                let point = Affine::random(OsRng);
+                let sign = Affine::y_sign(&point.y).unwrap_u8();
+                assert_ne!(Affine::y_sign(&-point.y).unwrap_u8(), sign);
+                assert_eq!(point.to_bytes(), encode(&point.x, sign, 0));
+                assert_eq!((-point).to_bytes(), encode(&point.x, 1 - sign, 0));
+                assert_eq!(Repr::default().as_ref().len(), Repr::SIZE);
+
+                // Every combination of flags on the x-coordinate of a point and on zero.
+                for x in [point.x, Base::ZERO] {
+                    for (sign, identity) in [(0, 0), (1, 0), (0, 1), (1, 1)] {
+                        let decoded = Affine::from_bytes_unchecked(&encode(&x, sign, identity));
+                        if identity == 1 {
+                            // Only the canonical encoding of the identity is accepted.
+                            let valid = sign == 0 && x == Base::ZERO;
+                            assert_eq!(bool::from(decoded.is_some()), valid);
+                            if valid {
+                                assert!(bool::from(decoded.unwrap().is_identity()));
+                            }
+                        } else {
+                            let expected = Affine::from_x(x, sign.into());
+                            assert_eq!(bool::from(decoded.is_some()), bool::from(expected.is_some()));
+                            if x == point.x {
+                                let expected = if sign == Affine::y_sign(&point.y).unwrap_u8() { point } else { -point };
+                                assert_eq!(decoded.unwrap(), expected);
+                            }
+                        }
+                    }
+                }
+            }
+
+            // A non-canonical x-coordinate is rejected whatever the flags.
+            let mut bytes = Repr::default();
+            bytes.as_mut().fill(0xff);
+            for flags in [0, 0b0100_0000, 0b1000_0000] {
+                bytes.as_mut()[last] = 0b0011_1111 | flags;
+                assert!(bool::from(Affine::from_bytes_unchecked(&bytes).is_none()));
+            }
+            assert_eq!(Affine::identity().to_bytes(), encode(&Base::ZERO, 0, 1));
+        }
+    };
+
+    ($curve: ident, "torsion_free") => {
+        #[test]
+        fn test_torsion_free() {
+            use group::cofactor::CofactorGroup;
+            use group::{GroupEncoding, UncompressedEncoding};
+            use rand_core::OsRng;
+            type Affine = <$curve as CurveExt>::AffineExt;
+            type Base = <$curve as CurveExt>::Base;
+
+            // Reference check, multiplication by the group order.
+            let order_mul = |p: &$curve| {
+                let mut acc = $curve::identity();
+                for limb in $curve::ORDER.iter().rev() {
+                    for i in (0..64).rev() {
+                        acc = acc.double();
+                        if (limb >> i) & 1 == 1 {
+                            acc += p;
+                        }
+                    }
+                }
+                acc
+            };
+
+            assert!(bool::from($curve::identity().is_torsion_free()));
+            assert!(bool::from($curve::generator().is_torsion_free()));
+            for _ in 0..20 {
+                // A point of the whole curve is out of the subgroup with
+                // overwhelming probability.
+                let point = loop {
+                    let x = Base::random(OsRng);
+                    let y2 = x.square() * x + $curve::a() * x + $curve::b();
+                    if let Some(y) = Option::<Base>::from(y2.sqrt()) {
+                        break $curve::from(Affine::from_xy(x, y).unwrap());
+                    }
+                };
+                assert!(!bool::from(order_mul(&point).is_identity()));
+                assert!(!bool::from(point.is_torsion_free()));
+                assert!(bool::from(point.into_subgroup().is_none()));
+
+                // Checked decoding rejects points out of the subgroup.
+                let affine = Affine::from(point);
+                let compressed = affine.to_bytes();
+                assert!(bool::from(Affine::from_bytes(&compressed).is_none()));
+                assert_eq!(Affine::from_bytes_unchecked(&compressed).unwrap(), affine);
+                let uncompressed = affine.to_uncompressed();
+                assert!(bool::from(Affine::from_uncompressed(&uncompressed).is_none()));
+                assert_eq!(
+                    Affine::from_uncompressed_unchecked(&uncompressed).unwrap(),
+                    affine
+                );
+
+                // So does the default validation policy, but not the on-curve one.
+                {
+                    use $crate::serde::ValidatedEncoding;
+                    assert!(bool::from(Affine::from_bytes_checked(&compressed).is_none()));
+                    assert!(bool::from($curve::from_bytes_checked(&compressed).is_none()));
+                    assert!(bool::from(Affine::from_bytes_checked(&uncompressed).is_none()));
+                    assert_eq!(Affine::from_bytes_on_curve(&compressed).unwrap(), affine);
+                    assert_eq!($curve::from_bytes_on_curve(&compressed).unwrap(), point);
+                    assert_eq!(Affine::from_bytes_on_curve(&uncompressed).unwrap(), affine);
+                }
+
+                let cleared = point.clear_cofactor();
+                assert!(bool::from(order_mul(&cleared).is_identity()));
+                assert!(bool::from(cleared.is_torsion_free()));
+                assert_eq!(cleared.into_subgroup().unwrap(), cleared);
+            }
+        }
+    };
+
+    ($curve: ident, "psi", $eigenvalue: expr) => {
+        #[test]
+        fn test_psi() {
+            use rand_core::OsRng;
+            type Affine = <$curve as CurveExt>::AffineExt;
+            type Base = <$curve as CurveExt>::Base;
+
+            let lambda = $eigenvalue;
+            assert!(bool::from($curve::identity().psi().is_identity()));
+            assert!(bool::from($curve::identity().psi2().is_identity()));
+            assert!(bool::from(Affine::identity().psi().is_identity()));
+            assert!(bool::from(Affine::identity().psi2().is_identity()));
+            for _ in 0..10 {
+                // On the subgroup, `psi` is the multiplication by its eigenvalue.
+                let p = $curve::random(OsRng);
+                assert_eq!(p.psi(), p * lambda);
+                assert_eq!(p.psi2(), p * lambda.square());
+
+                // A point of the whole twist, out of the subgroup with overwhelming
+                // probability, on which `psi` is still an endomorphism.
+                let q = loop {
+                    let x = Base::random(OsRng);
+                    let y2 = x.square() * x + $curve::a() * x + $curve::b();
+                    if let Some(y) = Option::<Base>::from(y2.sqrt()) {
+                        break $curve::from(Affine::from_xy(x, y).unwrap());
+                    }
+                };
+                assert!(bool::from(q.psi().is_on_curve()));
+                assert_eq!((p + q).psi(), p.psi() + q.psi());
+                assert_eq!(q.psi2(), q.psi().psi());
+
+                // The affine maps agree with the projective ones.
+                for r in [p, q] {
+                    assert_eq!(r.to_affine().psi(), r.psi().to_affine());
+                    assert_eq!(r.to_affine().psi2(), r.psi2().to_affine());
+                }
+            }
+        }
+    };
+
     ($($curve: ident),*, "endo_consistency") => {
         #[test]
         fn test_endo_consistency() {
@@ -439,7 +1043,7 @@ macro_rules! curve_testing_suite {
             let z_impl = <$curve as CurveExt>::ScalarExt::ZETA;
             assert_eq!(z_impl * z_impl + z_impl, -<$curve as CurveExt>::ScalarExt::ONE);
             $(
-                let z_other = <$curve as CurveExt>::ScalarExt::from_raw($z_other_raw as [u64; 4]);
+                let z_other = <$curve as CurveExt>::ScalarExt::from_raw_unchecked($z_other_raw as [u64; 4]);
                 assert_eq!(z_other * z_other + z_other, -<$curve as CurveExt>::ScalarExt::ONE);
             )*
 
@@ -456,6 +1060,33 @@ macro_rules! curve_testing_suite {
                     assert_eq!(k, <$curve as CurveExt>::ScalarExt::from_u128(k1) - <$curve as CurveExt>::ScalarExt::ZETA * <$curve as CurveExt>::ScalarExt::from_u128(k2))
                 }
             }
+
+            for _ in 0..100 {
+                use $crate::CurveEndo;
+                let k = <$curve as CurveExt>::ScalarExt::random(OsRng);
+                let p = $curve::random(OsRng);
+                let [(k1, p1), (k2, p2)] = p.glv_recode(&k);
+                assert_eq!(
+                    p1 * <$curve as CurveExt>::ScalarExt::from_u128(k1)
+                        + p2 * <$curve as CurveExt>::ScalarExt::from_u128(k2),
+                    p * k
+                );
+                assert!(p1 == p || p1 == -p);
+                assert!(p2 == p.endo() || p2 == -p.endo());
+                assert_eq!(p.mul_glv(&k), p * k);
+            }
+
+            // Scalars with small halves of every sign.
+            for (k1, k2) in [(0i64, 0i64), (1, 0), (-1, 0), (0, 1), (0, -1), (3, -5), (-7, 2)] {
+                use $crate::CurveEndo;
+                let scalar = |v: i64| {
+                    let s = <$curve as CurveExt>::ScalarExt::from(v.unsigned_abs());
+                    if v < 0 { -s } else { s }
+                };
+                let k = scalar(k1) + <$curve as CurveExt>::ScalarExt::ZETA * scalar(k2);
+                let p = $curve::random(OsRng);
+                assert_eq!(p.mul_glv(&k), p * k);
+            }
         }
     };
 
@@ -506,10 +1137,11 @@ macro_rules! curve_testing_suite {
                     let u_1 = msg_hash * s_inv;
                     let u_2 = r * s_inv;
 
-                    let v_1 = g * u_1;
-                    let v_2 = pk * u_2;
+                    // The scalars are public, `u_1 G + u_2 pk` can run in variable time.
+                    let v = $crate::multi_mul::multi_mul_vartime([(g, u_1), ($curve::from(pk), u_2)]);
+                    assert_eq!(v, g * u_1 + pk * u_2);
 
-                    let r_point = (v_1 + v_2).to_affine().coordinates().unwrap();
+                    let r_point = v.to_affine().coordinates().unwrap();
                     let x_candidate = r_point.x();
                     let r_candidate = mod_n(*x_candidate);
 
@@ -540,21 +1172,194 @@ macro_rules! curve_testing_suite {
     };
 }
 
-use crate::{CurveAffine, CurveExt};
-use group::Curve;
+#[macro_export]
+macro_rules! edwards_testing_suite {
+    ($curve: ident, $affine: ident, $base: ident, $scalar: ident) => {
+        use rand_core::OsRng;
 
-pub(crate) struct TestH2C<C: CurveAffine> {
-    msg: &'static [u8],
-    expect: C,
-}
+        #[test]
+        fn test_arithmetic() {
+            let identity = $curve::identity();
+            for _ in 0..100 {
+                let a = $curve::random(OsRng);
+                let b = $curve::random(OsRng);
+                assert!(bool::from(a.is_on_curve()));
+                assert_eq!(a + b, b + a);
+                assert_eq!(a + a, a.double());
+                assert_eq!(a - a, identity);
+                assert_eq!(a + identity, a);
+                assert_eq!(a + b.to_affine(), a + b);
+                assert_eq!(a.mul_by_order(), identity);
+
+                let s = $scalar::random(OsRng);
+                let t = $scalar::random(OsRng);
+                assert_eq!(a * s + a * t, a * (s + t));
+                assert_eq!(a * s * t, a * (s * t));
+                assert_eq!(a.to_affine() * s, a * s);
+                $crate::tests::ops::point_operators(a, b.to_affine(), s);
+            }
+            assert_eq!(identity.double(), identity);
+            assert_eq!($curve::generator() * -$scalar::ONE, -$curve::generator());
+
+            // Complete formulas on the points of small order.
+            let order_two = $curve::from($affine {
+                x: $base::ZERO,
+                y: -$base::ONE,
+            });
+            assert!(bool::from(order_two.is_on_curve()));
+            assert_eq!(order_two.double(), identity);
+            assert_eq!(order_two + order_two, identity);
+        }
+
+        #[test]
+        fn test_batch_normalize() {
+            let points = (0..10)
+                .map(|_| $curve::random(OsRng).double())
+                .collect::<Vec<_>>();
+            let mut affine = vec![$affine::identity(); points.len()];
+            $curve::batch_normalize(&points, &mut affine);
+            for (p, q) in points.iter().zip(affine.iter()) {
+                assert_eq!(p.to_affine(), *q);
+            }
+        }
+
+        #[test]
+        fn test_ord_hash_fmt() {
+            use std::collections::{BTreeSet, HashSet};
+
+            let mut points = (0..20).map(|_| $curve::random(OsRng)).collect::<Vec<_>>();
+            points.push($curve::identity());
+            points.extend_from_slice(&points.clone()[..5]);
+            let affine = points.iter().map(|p| p.to_affine()).collect::<Vec<_>>();
+            let ordered = points.iter().collect::<BTreeSet<_>>();
+            assert_eq!(ordered.len(), 21);
+            assert_eq!(points.iter().collect::<HashSet<_>>().len(), 21);
+            assert_eq!(affine.iter().collect::<HashSet<_>>().len(), 21);
+            assert!(ordered
+                .iter()
+                .map(|p| p.to_bytes())
+                .collect::<Vec<_>>()
+                .windows(2)
+                .all(|w| w[0] < w[1]));
+
+            let p = affine[0];
+            assert_eq!(format!("{}", p), format!("({}, {})", p.x, p.y));
+            assert_eq!(format!("{:x}", p), format!("({:x}, {:x})", p.x, p.y));
+            assert_eq!(format!("{}", points[0]), format!("{}", p));
+        }
+
+        #[test]
+        fn test_slice_as_limbs() {
+            let points = (0..4).map(|_| $curve::random(OsRng)).collect::<Vec<_>>();
+            let expected = points
+                .iter()
+                .flat_map(|p| [p.x, p.y, p.t, p.z])
+                .flat_map(|c| c.to_montgomery_limbs())
+                .collect::<Vec<_>>();
+            assert_eq!($curve::slice_as_limbs(&points), expected);
+
+            let points = points.iter().map(|p| p.to_affine()).collect::<Vec<_>>();
+            let expected = points
+                .iter()
+                .flat_map(|p| [p.x, p.y])
+                .flat_map(|c| c.to_montgomery_limbs())
+                .collect::<Vec<_>>();
+            assert_eq!($affine::slice_as_limbs(&points), expected);
+        }
 
-impl<C: CurveAffine> TestH2C<C> {
-    pub(crate) fn new(msg: &'static [u8], expect: C) -> Self {
-        Self { msg, expect }
-    }
+        #[test]
+        fn test_encoding() {
+            for _ in 0..100 {
+                let p = $curve::random(OsRng);
+                let bytes = p.to_bytes();
+                assert_eq!($curve::from_bytes(&bytes).unwrap(), p);
+                assert_eq!($curve::from_bytes_unchecked(&bytes).unwrap(), p);
+                assert_eq!(
+                    bytes[$base::SIZE - 1] >> 7,
+                    u8::from(bool::from(is_negative(&p.to_affine().x)))
+                );
+
+                // A point out of the subgroup is only accepted by unchecked decoding.
+                let q = p + $affine {
+                    x: $base::ZERO,
+                    y: -$base::ONE,
+                };
+                let bytes = q.to_bytes();
+                assert!(bool::from($curve::from_bytes(&bytes).is_none()));
+                assert_eq!($curve::from_bytes_unchecked(&bytes).unwrap(), q);
 
-    pub(crate) fn run(&self, domain_prefix: &str) {
-        let r0 = C::CurveExt::hash_to_curve(domain_prefix)(self.msg);
-        assert_eq!(r0.to_affine(), self.expect);
-    }
+                {
+                    use $crate::serde::{ValidatedEncoding, Validation};
+                    assert!(bool::from($affine::from_bytes_checked(&bytes).is_none()));
+                    assert_eq!($affine::from_bytes_on_curve(&bytes).unwrap(), q.to_affine());
+                    assert_eq!(
+                        $curve::from_bytes_with(&bytes, Validation::Unchecked).unwrap(),
+                        q
+                    );
+                    let bytes = p.to_bytes();
+                    assert_eq!($curve::from_bytes_checked(&bytes).unwrap(), p);
+                }
+            }
+            // Batched decoding, with points out of the subgroup and invalid encodings.
+            let mut bytes = (0..20)
+                .map(|_| $curve::random(OsRng).to_affine().to_bytes())
+                .collect::<Vec<_>>();
+            for i in 0..10 {
+                bytes[2 * i][0] ^= 1;
+            }
+            bytes.push($affine::identity().to_bytes());
+            bytes.push(
+                ($curve::random(OsRng)
+                    + $affine {
+                        x: $base::ZERO,
+                        y: -$base::ONE,
+                    })
+                .to_bytes(),
+            );
+            let batch = $affine::batch_from_compressed(&bytes);
+            assert_eq!(batch.len(), bytes.len());
+            for (bytes, p) in bytes.iter().zip(batch.iter()) {
+                let expected = $affine::from_bytes(bytes);
+                assert_eq!(bool::from(p.is_some()), bool::from(expected.is_some()));
+                assert!(bool::from(p.ct_eq(&expected)));
+            }
+            assert!(bool::from(
+                batch[1].is_some() & batch[20].is_some() & batch[21].is_none()
+            ));
+
+            let identity = $curve::identity().to_bytes();
+            assert_eq!($curve::from_bytes(&identity).unwrap(), $curve::identity());
+
+            // The identity with the sign bit set is not canonical.
+            let mut bytes = identity;
+            bytes[$base::SIZE - 1] |= 0x80;
+            assert!(bool::from($curve::from_bytes_unchecked(&bytes).is_none()));
+        }
+
+        #[test]
+        fn test_montgomery() {
+            for _ in 0..100 {
+                let p = $curve::random(OsRng).to_affine();
+                let (u, v) = p.to_montgomery().unwrap();
+                assert_eq!($affine::from_montgomery(&u, &v).unwrap(), p);
+                assert!(bool::from(
+                    $affine::from_montgomery(&u, &(v + $base::ONE)).is_none()
+                ));
+            }
+
+            let order_two = $affine {
+                x: $base::ZERO,
+                y: -$base::ONE,
+            };
+            assert_eq!(
+                order_two.to_montgomery().unwrap(),
+                ($base::ZERO, $base::ZERO)
+            );
+            assert_eq!(
+                $affine::from_montgomery(&$base::ZERO, &$base::ZERO).unwrap(),
+                order_two
+            );
+            assert!(bool::from($affine::identity().to_montgomery().is_none()));
+        }
+    };
 }
@@ -144,6 +144,26 @@ macro_rules! field_testing_suite {
             }
         }
 
+        fn random_pow_tests<F: Field, R: rand_core::RngCore>(mut rng: R, n: usize) {
+            use $crate::ff_ext::Pow;
+
+            for _ in 0..n {
+                let a = F::random(&mut rng);
+                let exp = [rng.next_u64(), rng.next_u64(), rng.next_u64(), rng.next_u64()];
+                assert_eq!(a.pow_ct(&exp), a.pow_vartime(exp));
+                assert_eq!(a.pow_ct(&exp), a.pow(exp));
+                let k = rng.next_u64();
+                assert_eq!(a.pow_u64(k), a.pow_vartime([k]));
+            }
+
+            let a = F::random(&mut rng);
+            assert_eq!(a.pow_ct(&[]), F::ONE);
+            assert_eq!(a.pow_u64(0), F::ONE);
+            assert_eq!(a.pow_u64(1), a);
+            assert_eq!(a.pow_u64(2), a.square());
+            assert_eq!(F::ZERO.pow_u64(5), F::ZERO);
+        }
+
         fn zero_tests<F: Field, R: rand_core::RngCore>(mut rng: R) {
             assert_eq!(F::ZERO.is_zero().unwrap_u8(), 1);
             {
@@ -213,10 +233,13 @@ macro_rules! field_testing_suite {
             random_squaring_tests::<$field, _>(&mut rng, n);
             random_inversion_tests::<$field, _>(&mut rng, n);
             random_expansion_tests::<$field, _>(&mut rng, n);
+            random_pow_tests::<$field, _>(&mut rng, n / 10000);
 
             // edge cases
             zero_tests::<$field, _>(&mut rng);
             one_tests::<$field, _>(&mut rng);
+
+            $crate::tests::ops::field_operators($field::random(&mut rng), $field::random(&mut rng));
         }
     };
 
@@ -254,6 +277,18 @@ macro_rules! field_testing_suite {
                     let b = $f::read_raw(&mut &buf[..]).unwrap();
                     assert_eq!(a, b);
                 }
+                // The limbs of all ones are not smaller than the modulus.
+                let ones = vec![0xff; $f::ZERO.to_raw_bytes().len()];
+                assert!($f::from_raw_bytes(&ones).is_none());
+                $crate::tests::assert_decoding_error(
+                    $f::read_raw(&mut &ones[..]),
+                    $crate::Error::NotCanonical,
+                );
+                for _ in 0..1000 {
+                    let a = $f::random(&mut rng);
+                    $crate::tests::run_canonical_encoding_test(&a);
+                    $crate::tests::run_raw_montgomery_test(&a);
+                }
             };
         }
 
@@ -307,11 +342,45 @@ macro_rules! field_testing_suite {
                 let elem = $field::random(&mut rng);
                 let is_quad_res_or_zero: bool = elem.sqrt().is_some().into();
                 let is_quad_non_res: bool = elem.ct_quadratic_non_residue().into();
-                assert_eq!(!is_quad_non_res, is_quad_res_or_zero)
+                assert_eq!(!is_quad_non_res, is_quad_res_or_zero);
+                assert_eq!(bool::from(elem.is_quadratic_residue()), is_quad_res_or_zero);
             }
         }
     };
 
+    // For fields without square root, check the multiplicativity of the Legendre symbol.
+    ($field: ident, "legendre") => {
+        #[test]
+        fn test_legendre() {
+            use $crate::ff_ext::Legendre;
+            use ff::Field;
+            use rand_core::SeedableRng;
+            use rand_xorshift::XorShiftRng;
+
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54,
+                0x06, 0xbc, 0xe5,
+            ]);
+
+            assert_eq!($field::ZERO.legendre(), 0);
+            assert!(bool::from($field::ZERO.is_quadratic_residue()));
+            assert_eq!($field::ONE.legendre(), 1);
+
+            let mut non_residues = 0;
+            for _ in 0..1000 {
+                let a = $field::random(&mut rng);
+                let b = $field::random(&mut rng);
+                assert_eq!(a.square().legendre(), 1);
+                assert_eq!((a * b).legendre(), a.legendre() * b.legendre());
+                if a.legendre() == -1 {
+                    non_residues += 1;
+                }
+            }
+            // Roughly half of the elements are non residues.
+            assert!(non_residues > 400 && non_residues < 600);
+        }
+    };
+
     ($field: ident, "bits") => {
         #[test]
         #[cfg(feature = "bits")]
@@ -370,6 +439,13 @@ macro_rules! field_testing_suite {
             );
             assert_eq!($field::from(2) * $field::TWO_INV, $field::ONE);
             if $field::S != 0 {
+                // `ROOT_OF_UNITY` has order exactly `2^S`, which holds for a quadratic
+                // non-residue generator.
+                assert!(bool::from($field::MULTIPLICATIVE_GENERATOR.sqrt().is_none()));
+                assert_eq!(
+                    $field::ROOT_OF_UNITY.pow_vartime([1 << ($field::S - 1)]),
+                    -$field::one()
+                );
                 assert_eq!(
                     $field::ROOT_OF_UNITY.pow_vartime([1 << $field::S]),
                     $field::one()
@@ -382,6 +458,244 @@ macro_rules! field_testing_suite {
         }
     };
 
+    ($field: ident, "montgomery_constants") => {
+        #[test]
+        fn test_montgomery_constants() {
+            use num_bigint::BigUint;
+
+            let modulus = $crate::tests::modulus::<$field>();
+            let limbs = |e: &BigUint| {
+                let mut limbs = e.to_u64_digits();
+                limbs.resize($field::NUM_LIMBS, 0);
+                limbs
+            };
+
+            // R = 2^(64 * NUM_LIMBS) mod p
+            let r = (BigUint::from(1u64) << (64 * $field::NUM_LIMBS)) % &modulus;
+            assert_eq!($field::MODULUS_LIMBS.to_vec(), limbs(&modulus));
+            assert_eq!($field::R.0.to_vec(), limbs(&r));
+            assert_eq!($field::R2.0.to_vec(), limbs(&(&r * &r % &modulus)));
+            assert_eq!($field::R3.0.to_vec(), limbs(&(&r * &r * &r % &modulus)));
+
+            // INV = -p^{-1} mod 2^64
+            let t = BigUint::from(1u64) << 64;
+            assert_eq!(
+                (&modulus * BigUint::from($field::INV) + 1u64) % &t,
+                BigUint::from(0u64)
+            );
+        }
+    };
+
+    ($field: ident, "from_raw") => {
+        #[test]
+        fn test_from_raw() {
+            use $crate::serde::SerdeObject;
+            use ff::Field;
+            use rand_core::SeedableRng;
+            use rand_xorshift::XorShiftRng;
+
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+                0xbc, 0xe5,
+            ]);
+            for _ in 0..1000 {
+                let a = $field::random(&mut rng);
+                let limbs = a.to_raw();
+                assert_eq!(limbs, a.from_mont());
+                assert_eq!($field::from_raw_checked(limbs).unwrap(), a);
+                assert_eq!($field::from_raw_unchecked(limbs), a);
+                assert_eq!($field::from_raw(limbs).to_raw(), limbs);
+
+                let mont = a.to_montgomery_limbs();
+                assert_eq!($field::from_montgomery_limbs(mont).unwrap(), a);
+                assert_eq!(
+                    $field::from_raw_bytes(&a.to_raw_bytes()).unwrap().to_montgomery_limbs(),
+                    mont
+                );
+                // The Montgomery limbs are the integer a R mod p.
+                let r = $field::from_raw($field::ONE.to_montgomery_limbs());
+                assert_eq!($field::from_raw(mont), a * r);
+            }
+            assert_eq!($field::ONE.to_raw()[0], 1);
+
+            let elements = [$field::ONE, -$field::ONE, $field::random(&mut rng)];
+            let limbs = $field::slice_as_limbs(&elements);
+            assert_eq!(limbs.len(), 3 * $field::NUM_LIMBS);
+            for (chunk, a) in limbs.chunks($field::NUM_LIMBS).zip(elements.iter()) {
+                assert_eq!(chunk, a.to_montgomery_limbs());
+            }
+
+            let modulus = $field::MODULUS_LIMBS;
+            assert!(bool::from($field::from_raw_checked(modulus).is_none()));
+            assert!(bool::from($field::from_raw_checked([u64::MAX; $field::NUM_LIMBS]).is_none()));
+            assert_eq!($field::from_raw_unchecked(modulus), $field::ZERO);
+            assert!(bool::from($field::from_montgomery_limbs(modulus).is_none()));
+
+            let mut modulus_minus_one = modulus;
+            modulus_minus_one[0] -= 1;
+            assert_eq!(
+                $field::from_raw_checked(modulus_minus_one).unwrap(),
+                -$field::ONE
+            );
+
+            #[cfg(feature = "num-bigint")]
+            {
+                use num_bigint::BigUint;
+
+                let p = BigUint::from_bytes_le(
+                    &$field::MODULUS_LIMBS
+                        .iter()
+                        .flat_map(|limb| limb.to_le_bytes())
+                        .collect::<Vec<_>>(),
+                );
+                for _ in 0..100 {
+                    let a = $field::random(&mut rng);
+                    let b = $field::random(&mut rng);
+                    let big = a.to_canonical_biguint();
+                    assert!(big < p);
+                    assert_eq!(BigUint::from(a), big);
+                    assert_eq!($field::from_biguint(&big).unwrap(), a);
+                    assert_eq!($field::try_from(big.clone()).unwrap(), a);
+                    assert_eq!(
+                        (a * b).to_canonical_biguint(),
+                        (big * b.to_canonical_biguint()) % &p
+                    );
+                }
+
+                // Lowercase hexadecimal of the canonical integer.
+                let a = $field::random(&mut rng);
+                let hex = format!("{:x}", a);
+                assert!(hex.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f')));
+                assert_eq!(
+                    BigUint::parse_bytes(hex.as_bytes(), 16).unwrap(),
+                    a.to_canonical_biguint()
+                );
+                assert_eq!(format!("{:#x}", a), format!("0x{}", hex));
+                assert_eq!(format!("{}", a), format!("{:#x}", a));
+                assert_eq!(format!("{:?}", a), format!("{:#x}", a));
+                assert_eq!($field::ZERO.to_canonical_biguint(), BigUint::from(0u8));
+                assert_eq!((-$field::ONE).to_canonical_biguint(), &p - 1u8);
+                assert!($field::from_biguint(&p).is_none());
+                assert_eq!($field::try_from(&p << 64).unwrap_err(), &p << 64);
+            }
+        }
+    };
+
+    ($field: ident, "mul_u64") => {
+        #[test]
+        fn test_mul_u64() {
+            use ff::{Field, PrimeField};
+            use rand_core::{RngCore, SeedableRng};
+            use rand_xorshift::XorShiftRng;
+
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+                0xbc, 0xe5,
+            ]);
+            let edges = [$field::ZERO, $field::ONE, -$field::ONE, $field::TWO_INV];
+            let small = [0, 1, 2, 3, 5, 9, u32::MAX as u64, u64::MAX - 1, u64::MAX];
+            for a in edges {
+                for k in small {
+                    assert_eq!(a.mul_u64(k), a * $field::from(k));
+                }
+            }
+            for _ in 0..10000 {
+                let a = $field::random(&mut rng);
+                let k = rng.next_u64() >> (rng.next_u32() % 64);
+                assert_eq!(a.mul_u64(k), a * $field::from(k));
+            }
+        }
+    };
+
+    ($field: ident, "halve") => {
+        #[test]
+        fn test_halve() {
+            use ff::{Field, PrimeField};
+            use rand_core::SeedableRng;
+            use rand_xorshift::XorShiftRng;
+
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+                0xbc, 0xe5,
+            ]);
+            let edges = [$field::ZERO, $field::ONE, -$field::ONE, $field::TWO_INV];
+            for a in edges
+                .into_iter()
+                .chain((0..1000).map(|_| $field::random(&mut rng)))
+            {
+                assert_eq!(a.halve(), a * $field::TWO_INV);
+                assert_eq!(a.halve().double(), a);
+            }
+
+            let mut expected = $field::ONE;
+            for k in 0..=64 {
+                assert_eq!($field::two_inv_pow(k), expected);
+                assert_eq!($field::two_inv_pow(k) * $field::from(2).pow([k as u64]), $field::ONE);
+                expected *= $field::TWO_INV;
+            }
+        }
+    };
+
+    // `$vectors` lists `(a, b, a * b)` as big-endian hex strings. Both limb backends must agree
+    // with them, and with each other on random inputs.
+    ($field: ident, "limb32", $vectors: expr) => {
+        #[test]
+        fn test_limb32() {
+            use ff::Field;
+            use rand_core::SeedableRng;
+            use rand_xorshift::XorShiftRng;
+
+            for (a, b, ab) in $vectors.iter() {
+                let a: $field = $crate::tests::hex_to_field(a);
+                let b: $field = $crate::tests::hex_to_field(b);
+                let ab: $field = $crate::tests::hex_to_field(ab);
+                assert_eq!(a * b, ab);
+                assert_eq!(a.mul_limb32(&b), ab);
+            }
+
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54,
+                0x06, 0xbc, 0xe5,
+            ]);
+            let edge = [$field::ZERO, $field::ONE, -$field::ONE];
+            for (a, b) in edge.iter().flat_map(|a| edge.iter().map(move |b| (*a, *b))) {
+                assert_eq!(a * b, a.mul_limb32(&b));
+            }
+            for _ in 0..1000 {
+                let a = $field::random(&mut rng);
+                let b = $field::random(&mut rng);
+                assert_eq!(a * b, a.mul_limb32(&b));
+                assert_eq!(a.square(), a.mul_limb32(&a));
+            }
+        }
+    };
+
+    ($field: ident, "invert") => {
+        #[test]
+        fn test_invert() {
+            use ff::{Field, PrimeField};
+            use rand_core::SeedableRng;
+            use rand_xorshift::XorShiftRng;
+
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+                0xbc, 0xe5,
+            ]);
+            // p - 2
+            let exp = ($crate::tests::modulus::<$field>() - 2u64).to_u64_digits();
+
+            let edges = [$field::ONE, -$field::ONE, $field::TWO_INV, $field::from(2), $field::ROOT_OF_UNITY];
+            for a in edges.into_iter().chain((0..1000).map(|_| $field::random(&mut rng))) {
+                let inv = a.invert().unwrap();
+                assert_eq!(a * inv, $field::ONE);
+                assert_eq!(a.invert_vartime().unwrap(), inv);
+                assert_eq!(a.pow_vartime(&exp), inv);
+            }
+            assert!(bool::from($field::ZERO.invert().is_none()));
+            assert!($field::ZERO.invert_vartime().is_none());
+        }
+    };
+
     ($field: ident, "sqrt") => {
         #[test]
         fn test_sqrt() {
@@ -431,6 +745,52 @@ macro_rules! field_testing_suite {
         }
     };
 
+    ($field: ident, "ext_sqrt") => {
+        #[test]
+        fn test_ext_sqrt() {
+            use $crate::ff_ext::{Legendre, MulByRoot};
+            use rand_core::OsRng;
+
+            assert_eq!($field::ZERO.sqrt().unwrap(), $field::ZERO);
+
+            for _ in 0..100 {
+                let a = $field::random(OsRng);
+                let b = a.square();
+                let root = b.sqrt().unwrap();
+                assert!(root == a || root == -a);
+
+                let c = $field::random(OsRng);
+                assert_eq!(bool::from(c.sqrt().is_some()), c.legendre() == 1);
+            }
+
+            // Elements of the base, whether they are squares in it or not.
+            let a = $field::random(OsRng);
+            let b = $field::ONE.double().double() + $field::ONE;
+            let root = $field::ONE.mul_by_root();
+            for c in [b, b * b.square(), root.square(), a.square().double()] {
+                assert_eq!(c.sqrt().unwrap().square(), c);
+            }
+
+            let (is_square, root) = $field::sqrt_ratio(&$field::ZERO, &a);
+            assert!(bool::from(is_square) && bool::from(root.is_zero()));
+            let (is_square, root) = $field::sqrt_ratio(&a, &$field::ZERO);
+            assert!(!bool::from(is_square) && bool::from(root.is_zero()));
+            for _ in 0..10 {
+                let num = $field::random(OsRng);
+                let div = $field::random(OsRng);
+                let ratio = num * div.invert().unwrap();
+                let (is_square, root) = $field::sqrt_ratio(&num, &div);
+                assert_eq!(bool::from(is_square), ratio.legendre() == 1);
+                if bool::from(is_square) {
+                    assert_eq!(root.square(), ratio);
+                } else {
+                    assert_eq!(root.square().legendre(), 1);
+                    assert_eq!((root.square() * ratio.invert().unwrap()).legendre(), -1);
+                }
+            }
+        }
+    };
+
     ($field: ident, "zeta" $(, $base_field: ident)*) => {
         #[test]
         fn test_zeta() {
@@ -444,6 +804,30 @@ macro_rules! field_testing_suite {
         }
     };
 
+    ($field: ident, "from_wide", $from_wide:ident) => {
+        #[test]
+        fn test_from_wide() {
+            use num_bigint::BigUint;
+            use rand_core::RngCore;
+
+            let mut rng = rand_core::OsRng;
+            let edges = [[0; $field::NUM_LIMBS * 2], [u64::MAX; $field::NUM_LIMBS * 2]];
+            for i in 0..1000 {
+                let limbs = edges.get(i).copied().unwrap_or_else(|| {
+                    let mut limbs = [0; $field::NUM_LIMBS * 2];
+                    limbs.iter_mut().for_each(|limb| *limb = rng.next_u64());
+                    limbs
+                });
+                let bytes = limbs
+                    .iter()
+                    .flat_map(|limb| limb.to_le_bytes())
+                    .collect::<Vec<_>>();
+                let e: $field = $crate::tests::big_to_fe(&BigUint::from_bytes_le(&bytes));
+                assert_eq!($field::$from_wide(limbs), e);
+            }
+        }
+    };
+
     ($field: ident, "from_uniform_bytes", $($L:expr),* $(,)?) => {
 
         #[test]
@@ -454,7 +838,97 @@ macro_rules! field_testing_suite {
         }
     };
 
+    ($field: ident, "random_seeded", $expected:expr) => {
+        #[test]
+        fn test_random_seeded() {
+            use $crate::ff::PrimeField;
+            use $crate::ff_ext::RandomSeeded;
+            use num_bigint::BigUint;
+
+            let a = $field::random_seeded(b"halo2curves-random-seeded", 1);
+            assert_eq!(a, $field::random_seeded(b"halo2curves-random-seeded", 1));
+            assert_ne!(a, $field::random_seeded(b"halo2curves-random-seeded", 2));
+            assert_ne!(a, $field::random_seeded(b"halo2curves-random-seeded-2", 1));
+
+            // `$expected` is the big-endian hex of the element, computed with an
+            // independent implementation of RFC 9380.
+            let expected = BigUint::parse_bytes($expected.as_bytes(), 16).unwrap();
+            assert_eq!(BigUint::from_bytes_le(a.to_repr().as_ref()), expected);
+
+            // `random_seeded` is `hash_to_field` of the big-endian counter.
+            let u = $crate::ff_ext::hash_to_field::<$field>(
+                b"halo2curves-random-seeded",
+                &1u64.to_be_bytes(),
+                1,
+            );
+            assert_eq!(u, [a]);
+
+            // Each element is the reduction of its chunk of the expanded message.
+            use $crate::hash_to_curve::{ExpandMsg, ExpandMsgXmd};
+            let l = $field::UNIFORM_BYTES;
+            let u = $crate::ff_ext::hash_to_field::<$field>(b"halo2curves-h2f", b"abc", 3);
+            let bytes =
+                ExpandMsgXmd::<sha2::Sha256>::expand_message(&[], b"halo2curves-h2f", b"abc", 3 * l);
+            assert_eq!(u.len(), 3);
+            for (u, chunk) in u.iter().zip(bytes.chunks(l)) {
+                let e: $field = $crate::tests::big_to_fe(&BigUint::from_bytes_be(chunk));
+                assert_eq!(*u, e);
+            }
+        }
+    };
+
     ($ext_field: ident, "f2_tests", $base_field: ident) => {
+        #[test]
+        fn test_sgn0() {
+            use ff::PrimeField;
+
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+                0xe5,
+            ]);
+
+            // The sign of `c0`, or of `c1` when `c0` is zero, so that only zero has the
+            // same sign as its opposite.
+            for _ in 0..100 {
+                let a = $ext_field::random(&mut rng);
+                let b = $ext_field::new($base_field::ZERO, a.c1);
+                assert_eq!(a.is_odd().unwrap_u8(), a.c0.is_odd().unwrap_u8());
+                assert_eq!(b.is_odd().unwrap_u8(), a.c1.is_odd().unwrap_u8());
+                assert_ne!(a.is_odd().unwrap_u8(), (-a).is_odd().unwrap_u8());
+                assert_ne!(b.is_odd().unwrap_u8(), (-b).is_odd().unwrap_u8());
+            }
+            assert!(!bool::from($ext_field::ZERO.is_odd()));
+        }
+
+        #[test]
+        fn test_from_repr_non_canonical() {
+            use ff::PrimeField;
+
+            // Either half not smaller than the modulus is rejected.
+            let a = $ext_field::new($base_field::ONE, $base_field::ONE).to_repr();
+            for half in [0..$base_field::SIZE, $base_field::SIZE..2 * $base_field::SIZE] {
+                let mut repr = a;
+                repr.as_mut()[half].fill(0xff);
+                assert!(bool::from($ext_field::from_repr(repr).is_none()));
+            }
+            assert!(bool::from($ext_field::from_repr(a).is_some()));
+        }
+
+        #[test]
+        fn test_two_adic_constants() {
+            use ff::PrimeField;
+
+            // The multiplicative group of the extension has order `q^2 - 1 = 2^S t`.
+            let q = $crate::tests::modulus::<$base_field>();
+            let order = &q * &q - 1u64;
+            let t = order >> $ext_field::S;
+            assert_eq!(t.trailing_zeros(), Some(0));
+            assert_eq!(
+                $ext_field::MULTIPLICATIVE_GENERATOR.pow_vartime(t.to_u64_digits()),
+                $ext_field::ROOT_OF_UNITY
+            );
+        }
+
         #[test]
         fn test_ser() {
             let mut rng = XorShiftRng::from_seed([
@@ -468,8 +942,29 @@ macro_rules! field_testing_suite {
             assert_eq!(a0, a1);
         }
 
+        #[test]
+        fn test_f2_fmt_and_hash() {
+            use std::collections::HashSet;
+
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+                0xbc, 0xe5,
+            ]);
+            let a = $ext_field::random(&mut rng);
+            assert_eq!(format!("{}", a), format!("({}, {})", a.c0, a.c1));
+            assert_eq!(format!("{:x}", a), format!("({:x}, {:x})", a.c0, a.c1));
+            assert_eq!(format!("{:#x}", a), format!("({:#x}, {:#x})", a.c0, a.c1));
+
+            let elements = (0..20).map(|_| $ext_field::random(&mut rng)).collect::<Vec<_>>();
+            let set = elements.iter().chain(elements.iter()).collect::<HashSet<_>>();
+            assert_eq!(set.len(), elements.len());
+            assert!(elements.iter().all(|a| set.contains(a)));
+        }
+
         #[test]
         fn test_f2_ordering() {
+            use core::cmp::Ordering;
+
             let mut a = $ext_field {
                 c0: $base_field::zero(),
                 c1: $base_field::zero(),
@@ -522,6 +1017,56 @@ macro_rules! field_testing_suite {
         }
     };
 
+    ($ext_field: ident, "mul_by_root", $root: expr) => {
+        #[test]
+        fn test_mul_by_root() {
+            use rand_core::SeedableRng;
+            use rand_xorshift::XorShiftRng;
+            use $crate::ff_ext::MulByRoot;
+
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+                0xbc, 0xe5,
+            ]);
+            let root: $ext_field = $root;
+            for _ in 0..1000 {
+                let e = $ext_field::random(&mut rng);
+                assert_eq!(e.mul_by_root(), e * root);
+            }
+        }
+    };
+
+    ($ext_field: ident, "norm", $degree: expr, $q_power: expr) => {
+        #[test]
+        fn test_ext_field_norm() {
+            use rand_core::SeedableRng;
+            use rand_xorshift::XorShiftRng;
+            use $crate::ff_ext::ExtField;
+
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+                0xbc, 0xe5,
+            ]);
+            for _ in 0..100 {
+                let a = $ext_field::random(&mut rng);
+                let b = $ext_field::random(&mut rng);
+
+                // The product of the conjugates `a^(q^i)`, an element of the base field.
+                let mut conjugate = a;
+                let mut product = a;
+                for _ in 1..$degree {
+                    conjugate.frobenius_map($q_power);
+                    product *= conjugate;
+                }
+                assert_eq!(product.c0, ExtField::norm(&a));
+                assert!(bool::from(product.c1.is_zero()));
+
+                assert_eq!(ExtField::norm(&(a * b)), ExtField::norm(&a) * ExtField::norm(&b));
+            }
+            assert_eq!(ExtField::norm(&$ext_field::ONE), <$ext_field as ExtField>::BaseField::ONE);
+        }
+    };
+
     ($ext_field: ident, "cubic_sparse_mul", $base_field: ident) => {
         #[test]
         fn test_cubic_sparse_mul() {
@@ -553,6 +1098,33 @@ macro_rules! field_testing_suite {
                 };
 
                 assert_eq!(a0, a1);
+
+                let a0 = $ext_field::mul_by_0(&e, &c0);
+                let a1 = e * $ext_field {
+                    c0,
+                    c1: $base_field::zero(),
+                    c2: $base_field::zero(),
+                };
+
+                assert_eq!(a0, a1);
+
+                let a0 = $ext_field::mul_by_2(&e, &c1);
+                let a1 = e * $ext_field {
+                    c0: $base_field::zero(),
+                    c1: $base_field::zero(),
+                    c2: c1,
+                };
+
+                assert_eq!(a0, a1);
+
+                let a0 = $ext_field::mul_by_12(&e, &c0, &c1);
+                let a1 = e * $ext_field {
+                    c0: $base_field::zero(),
+                    c1: c0,
+                    c2: c1,
+                };
+
+                assert_eq!(a0, a1);
             }
         }
     };
@@ -604,6 +1176,72 @@ macro_rules! field_testing_suite {
                 assert_eq!(a0, a1);
             }
         }
+
+        #[test]
+        fn test_mul_by_line() {
+            use $crate::ff_ext::quadratic::TwistType;
+
+            // The lines of both twists over the same tower.
+            struct Twist<const M: bool>;
+            impl<const M: bool> QuadSparseMul for Twist<M> {
+                type Base = $base_field_2;
+                const TWIST_TYPE: TwistType = if M { TwistType::M } else { TwistType::D };
+            }
+
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+                0xe5,
+            ]);
+
+            for _ in 0..100 {
+                let c_y = $base_field_2::random(&mut rng);
+                let c_x = $base_field_2::random(&mut rng);
+                let c = $base_field_2::random(&mut rng);
+                let f = $ext_field::random(&mut rng);
+
+                // `c_y + (c_x + c v) w` on a D-type twist.
+                let d = $ext_field {
+                    c0: $base_field_1 {
+                        c0: c_y,
+                        c1: $base_field_2::zero(),
+                        c2: $base_field_2::zero(),
+                    },
+                    c1: $base_field_1 {
+                        c0: c_x,
+                        c1: c,
+                        c2: $base_field_2::zero(),
+                    },
+                };
+                // `c + c_x v + c_y v w` on an M-type twist.
+                let m = $ext_field {
+                    c0: $base_field_1 {
+                        c0: c,
+                        c1: c_x,
+                        c2: $base_field_2::zero(),
+                    },
+                    c1: $base_field_1 {
+                        c0: $base_field_2::zero(),
+                        c1: c_y,
+                        c2: $base_field_2::zero(),
+                    },
+                };
+
+                let mut a = f;
+                Twist::<false>::mul_by_line(&mut a, &c_y, &c_x, &c);
+                assert_eq!(a, f * d);
+                let mut a = f;
+                Twist::<true>::mul_by_line(&mut a, &c_y, &c_x, &c);
+                assert_eq!(a, f * m);
+
+                let mut a = f;
+                $ext_field::mul_by_line(&mut a, &c_y, &c_x, &c);
+                let line = match <$ext_field as QuadSparseMul>::TWIST_TYPE {
+                    TwistType::D => d,
+                    TwistType::M => m,
+                };
+                assert_eq!(a, f * line);
+            }
+        }
     };
 
     ($ext_field: ident, "frobenius", $frobenius_param: expr) => {
@@ -625,6 +1263,32 @@ macro_rules! field_testing_suite {
                     assert_eq!(a, b);
                 }
             }
+
+            // The powers known at compile time.
+            fn frobenius_map_const<const POWER: usize>(a: &$ext_field) -> $ext_field {
+                let mut a = *a;
+                a.frobenius_map_const::<POWER>();
+                a
+            }
+            for _ in 0..10 {
+                let a = $ext_field::random(&mut rng);
+                let res = [
+                    frobenius_map_const::<0>(&a),
+                    frobenius_map_const::<1>(&a),
+                    frobenius_map_const::<2>(&a),
+                    frobenius_map_const::<3>(&a),
+                    frobenius_map_const::<4>(&a),
+                    frobenius_map_const::<5>(&a),
+                    frobenius_map_const::<6>(&a),
+                    frobenius_map_const::<7>(&a),
+                    frobenius_map_const::<12>(&a),
+                ];
+                for (res, i) in res.iter().zip([0, 1, 2, 3, 4, 5, 6, 7, 12]) {
+                    let mut b = a;
+                    b.frobenius_map(i);
+                    assert_eq!(*res, b);
+                }
+            }
         }
     };
 }
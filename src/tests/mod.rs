@@ -4,7 +4,10 @@ use pasta_curves::arithmetic::CurveAffine;
 
 pub mod curve;
 pub mod field;
+pub(crate) mod op_count;
+pub(crate) mod ops;
 pub mod pairing;
+pub mod vectors;
 
 pub(crate) fn hex_to_bytes(hex: &str) -> Vec<u8> {
     let bytes = hex.as_bytes().to_vec();
@@ -28,6 +31,13 @@ pub(crate) fn point_from_hex<C: CurveAffine>(x: &str, y: &str) -> C {
     C::from_xy(x, y).unwrap()
 }
 
+/// Checks that `res` is an `InvalidData` error wrapping `err`.
+pub(crate) fn assert_decoding_error<T>(res: std::io::Result<T>, err: crate::Error) {
+    let res = res.err().expect("the decoding should fail");
+    assert_eq!(res.kind(), std::io::ErrorKind::InvalidData);
+    assert_eq!(res.get_ref().and_then(|e| e.downcast_ref()), Some(&err));
+}
+
 pub(crate) fn fe_to_big<F: PrimeField>(fe: &F) -> BigUint {
     BigUint::from_bytes_le(fe.to_repr().as_ref())
 }
@@ -43,3 +53,107 @@ pub fn big_to_fe<F: PrimeField>(e: &BigUint) -> F {
 pub(crate) fn modulus<F: PrimeField>() -> BigUint {
     fe_to_big(&-F::ONE) + 1usize
 }
+
+/// Checks the round trip of the canonical encoding with every option, and that
+/// malformed headers are rejected.
+pub(crate) fn run_canonical_encoding_test<T>(a: &T)
+where
+    T: crate::serde::CanonicalEncoding + PartialEq + std::fmt::Debug,
+{
+    use crate::serde::{EncodingFlags, CANONICAL_ENCODING_VERSION};
+
+    for compressed in [false, true] {
+        let le = a.to_canonical_bytes(EncodingFlags::new(compressed, false));
+        let be = a.to_canonical_bytes(EncodingFlags::new(compressed, true));
+        assert_eq!(le.len(), 2 + T::payload_size(compressed));
+        assert_eq!(le[0], CANONICAL_ENCODING_VERSION);
+        assert_eq!(le[1], compressed as u8);
+        assert_eq!(be[1], (compressed as u8) | 0b10);
+
+        // Big-endian payload reverses each coordinate.
+        let size = T::coordinate_size(compressed);
+        for (le, be) in le[2..].chunks(size).zip(be[2..].chunks(size)) {
+            assert!(le.iter().eq(be.iter().rev()));
+        }
+
+        for bytes in [&le, &be] {
+            assert_eq!(&T::from_canonical_bytes(bytes).unwrap(), a);
+            let mut buf = Vec::new();
+            buf.extend_from_slice(bytes);
+            assert_eq!(&T::read_canonical(&mut &buf[..]).unwrap(), a);
+
+            // Unknown version.
+            let mut bad = bytes.clone();
+            bad[0] = CANONICAL_ENCODING_VERSION + 1;
+            assert!(T::from_canonical_bytes(&bad).is_none());
+            // Unknown flags.
+            let mut bad = bytes.clone();
+            bad[1] |= 0b100;
+            assert!(T::from_canonical_bytes(&bad).is_none());
+            // Wrong length.
+            assert!(T::from_canonical_bytes(&bytes[..bytes.len() - 1]).is_none());
+        }
+    }
+}
+
+/// Checks the round trip of the raw Montgomery encoding, alone and through the
+/// canonical encoding, and that neither decodes the bytes of the other.
+pub(crate) fn run_raw_montgomery_test<T>(a: &T)
+where
+    T: crate::serde::CanonicalEncoding
+        + crate::serde::SerdeObject
+        + Clone
+        + Default
+        + PartialEq
+        + std::fmt::Debug,
+{
+    use crate::serde::{raw_montgomery, EncodingFlags};
+
+    let raw = raw_montgomery::to_bytes(a);
+    assert_eq!(&raw_montgomery::from_bytes::<T>(&raw).unwrap(), a);
+    assert_eq!(&raw_montgomery::from_bytes_unchecked::<T>(&raw).unwrap(), a);
+    let mut buf = Vec::new();
+    raw_montgomery::write(a, &mut buf).unwrap();
+    assert_eq!(buf, raw);
+    assert_eq!(&raw_montgomery::read::<T, _>(&mut &buf[..]).unwrap(), a);
+
+    // Unknown version.
+    let mut bad = raw.clone();
+    bad[0] = raw_montgomery::VERSION + 1;
+    assert!(raw_montgomery::from_bytes::<T>(&bad).is_none());
+    assert!(raw_montgomery::read::<T, _>(&mut &bad[..]).is_err());
+
+    for flags in [
+        EncodingFlags::new(false, false),
+        EncodingFlags::new(true, true),
+    ] {
+        let canonical = a.to_canonical_bytes(flags);
+        assert!(T::from_canonical_bytes(&raw).is_none());
+        assert!(raw_montgomery::from_bytes::<T>(&canonical).is_none());
+
+        // Canonical -> raw -> canonical.
+        let b = T::from_canonical_bytes(&canonical).unwrap();
+        let c = raw_montgomery::from_bytes::<T>(&raw_montgomery::to_bytes(&b)).unwrap();
+        assert_eq!(c.to_canonical_bytes(flags), canonical);
+    }
+
+    let slice = vec![a.clone(); 3];
+    let mut buf = Vec::new();
+    raw_montgomery::write_slice(&slice, &mut buf).unwrap();
+    assert_eq!(
+        raw_montgomery::read_vec::<T, _>(&mut &buf[..]).unwrap(),
+        slice
+    );
+    assert_eq!(
+        raw_montgomery::read_vec_unchecked::<T, _>(&mut &buf[..]).unwrap(),
+        slice
+    );
+    assert!(raw_montgomery::read_vec::<T, _>(&mut &buf[..buf.len() - 1]).is_err());
+    assert!(raw_montgomery::read_vec_unchecked::<T, _>(&mut &buf[..buf.len() - 1]).is_err());
+
+    // A corrupt length fails on the end of the input instead of allocating it.
+    let mut corrupt = buf.clone();
+    corrupt[2..10].copy_from_slice(&u64::MAX.to_le_bytes());
+    assert!(raw_montgomery::read_vec::<T, _>(&mut &corrupt[..]).is_err());
+    assert!(raw_montgomery::read_vec_unchecked::<T, _>(&mut &corrupt[..]).is_err());
+}
@@ -0,0 +1,52 @@
+//! Test-only instrumentation counting the degree-12 extension operations
+//! performed by the pairing code.
+//!
+//! The counters are thread local, so tests running in parallel do not see
+//! each other's operations.
+
+use std::cell::Cell;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub(crate) struct OpCounts {
+    /// Sparse multiplications by a line evaluation (`mul_by_014`, `mul_by_034`).
+    pub(crate) sparse_mul: usize,
+    /// Full multiplications.
+    pub(crate) dense_mul: usize,
+    /// Full squarings.
+    pub(crate) square: usize,
+    /// Granger-Scott squarings in the cyclotomic subgroup.
+    pub(crate) cyclotomic_square: usize,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Op {
+    SparseMul,
+    DenseMul,
+    Square,
+    CyclotomicSquare,
+}
+
+thread_local! {
+    static COUNTS: Cell<OpCounts> = Cell::new(OpCounts::default());
+}
+
+pub(crate) fn record(op: Op) {
+    COUNTS.with(|counts| {
+        let mut c = counts.get();
+        match op {
+            Op::SparseMul => c.sparse_mul += 1,
+            Op::DenseMul => c.dense_mul += 1,
+            Op::Square => c.square += 1,
+            Op::CyclotomicSquare => c.cyclotomic_square += 1,
+        }
+        counts.set(c);
+    });
+}
+
+/// Runs `f` and returns its result together with the operations it performed.
+pub(crate) fn count_ops<R>(f: impl FnOnce() -> R) -> (R, OpCounts) {
+    let before = COUNTS.with(|counts| counts.replace(OpCounts::default()));
+    let res = f();
+    let counted = COUNTS.with(|counts| counts.replace(before));
+    (res, counted)
+}
@@ -0,0 +1,166 @@
+//! Checks of the operator matrix of fields, points and pairing outputs: every
+//! operator takes its operands by value and by reference, in the four
+//! combinations, and has its assign variants. The bounds of the functions fail to
+//! compile when a form is missing, and their bodies check that all the forms agree.
+
+use core::fmt::Debug;
+use core::iter::{Product, Sum};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+// Checks that `a op b` gives the same result for owned and borrowed operands, and
+// returns it.
+macro_rules! binop {
+    ($a: expr, $op: tt, $b: expr) => {{
+        let (a, b) = ($a, $b);
+        let r = a $op b;
+        assert_eq!(a $op &b, r);
+        assert_eq!(&a $op b, r);
+        assert_eq!(&a $op &b, r);
+        r
+    }};
+}
+
+// Checks that `a op= b` gives `expected` for an owned and a borrowed `b`.
+macro_rules! assign {
+    ($a: expr, $op: tt, $b: expr, $expected: expr) => {{
+        let mut x = $a;
+        x $op $b;
+        assert_eq!(x, $expected);
+        let mut x = $a;
+        x $op &$b;
+        assert_eq!(x, $expected);
+    }};
+}
+
+/// Checks the arithmetic operators of a field, and `Sum` and `Product` over owned
+/// and borrowed items.
+pub(crate) fn field_operators<F>(a: F, b: F)
+where
+    F: Copy + PartialEq + Debug + Neg<Output = F>,
+    F: Add<Output = F> + for<'a> Add<&'a F, Output = F> + AddAssign + for<'a> AddAssign<&'a F>,
+    F: Sub<Output = F> + for<'a> Sub<&'a F, Output = F> + SubAssign + for<'a> SubAssign<&'a F>,
+    F: Mul<Output = F> + for<'a> Mul<&'a F, Output = F> + MulAssign + for<'a> MulAssign<&'a F>,
+    F: Sum + for<'a> Sum<&'a F> + Product + for<'a> Product<&'a F>,
+    for<'a, 'b> &'a F: Neg<Output = F>
+        + Add<F, Output = F>
+        + Add<&'b F, Output = F>
+        + Sub<F, Output = F>
+        + Sub<&'b F, Output = F>
+        + Mul<F, Output = F>
+        + Mul<&'b F, Output = F>,
+{
+    let sum = binop!(a, +, b);
+    let difference = binop!(a, -, b);
+    let product = binop!(a, *, b);
+    assign!(a, +=, b, sum);
+    assign!(a, -=, b, difference);
+    assign!(a, *=, b, product);
+    assert_eq!(-&a, -a);
+    assert_eq!(sum + difference, a + a);
+
+    let items = [a, b, a];
+    assert_eq!(items.iter().sum::<F>(), sum + a);
+    assert_eq!(items.into_iter().sum::<F>(), sum + a);
+    assert_eq!(items.iter().product::<F>(), product * a);
+    assert_eq!(items.into_iter().product::<F>(), product * a);
+}
+
+/// Checks the additive operators of the projective points `P` with the projective
+/// and affine points `A`, on either side, and their multiplication by scalars `S`.
+pub(crate) fn point_operators<P, A, S>(p: P, q: A, s: S)
+where
+    P: Copy + PartialEq + Debug + From<A> + Neg<Output = P>,
+    P: Add<Output = P> + for<'a> Add<&'a P, Output = P> + Add<A, Output = P>,
+    P: for<'a> Add<&'a A, Output = P> + AddAssign + for<'a> AddAssign<&'a P>,
+    P: AddAssign<A> + for<'a> AddAssign<&'a A>,
+    P: Sub<Output = P> + for<'a> Sub<&'a P, Output = P> + Sub<A, Output = P>,
+    P: for<'a> Sub<&'a A, Output = P> + SubAssign + for<'a> SubAssign<&'a P>,
+    P: SubAssign<A> + for<'a> SubAssign<&'a A>,
+    P: Mul<S, Output = P> + for<'a> Mul<&'a S, Output = P> + MulAssign<S>,
+    P: for<'a> MulAssign<&'a S> + Sum + for<'a> Sum<&'a P>,
+    A: Copy + PartialEq + Debug + Neg<Output = A>,
+    A: Add<P, Output = P> + for<'a> Add<&'a P, Output = P>,
+    A: Add<A, Output = P> + for<'a> Add<&'a A, Output = P>,
+    A: Sub<P, Output = P> + for<'a> Sub<&'a P, Output = P>,
+    A: Sub<A, Output = P> + for<'a> Sub<&'a A, Output = P>,
+    A: Mul<S, Output = P> + for<'a> Mul<&'a S, Output = P>,
+    S: Copy,
+    for<'a, 'b> &'a P: Neg<Output = P>
+        + Add<P, Output = P>
+        + Add<&'b P, Output = P>
+        + Add<A, Output = P>
+        + Add<&'b A, Output = P>
+        + Sub<P, Output = P>
+        + Sub<&'b P, Output = P>
+        + Sub<A, Output = P>
+        + Sub<&'b A, Output = P>
+        + Mul<S, Output = P>
+        + Mul<&'b S, Output = P>,
+    for<'a, 'b> &'a A: Neg<Output = A>
+        + Add<P, Output = P>
+        + Add<&'b P, Output = P>
+        + Add<A, Output = P>
+        + Add<&'b A, Output = P>
+        + Sub<P, Output = P>
+        + Sub<&'b P, Output = P>
+        + Sub<A, Output = P>
+        + Sub<&'b A, Output = P>
+        + Mul<S, Output = P>
+        + Mul<&'b S, Output = P>,
+{
+    let q_projective = P::from(q);
+    let sum = binop!(p, +, q_projective);
+    assert_eq!(binop!(p, +, q), sum);
+    assert_eq!(binop!(q, +, p), sum);
+    let difference = binop!(p, -, q_projective);
+    assert_eq!(binop!(p, -, q), difference);
+    assert_eq!(binop!(q, -, p), -difference);
+    assert_eq!(binop!(q, +, q), q_projective + q_projective);
+    assert_eq!(binop!(q, -, q) + p, p);
+    assign!(p, +=, q_projective, sum);
+    assign!(p, +=, q, sum);
+    assign!(p, -=, q_projective, difference);
+    assign!(p, -=, q, difference);
+    assert_eq!(-&p, -p);
+    assert_eq!(-&q, -q);
+
+    let multiple = binop!(p, *, s);
+    assert_eq!(binop!(q, *, s), q_projective * s);
+    assign!(p, *=, s, multiple);
+
+    let items = [p, q_projective, p];
+    assert_eq!(items.iter().sum::<P>(), sum + p);
+    assert_eq!(items.into_iter().sum::<P>(), sum + p);
+}
+
+/// Checks the operators of the additive group `G`, e.g. the target group of a
+/// pairing, and its multiplication by scalars `S`.
+pub(crate) fn group_operators<G, S>(a: G, b: G, s: S)
+where
+    G: Copy + PartialEq + Debug + Neg<Output = G>,
+    G: Add<Output = G> + for<'a> Add<&'a G, Output = G> + AddAssign + for<'a> AddAssign<&'a G>,
+    G: Sub<Output = G> + for<'a> Sub<&'a G, Output = G> + SubAssign + for<'a> SubAssign<&'a G>,
+    G: Mul<S, Output = G> + for<'a> Mul<&'a S, Output = G> + MulAssign<S>,
+    G: for<'a> MulAssign<&'a S> + Sum + for<'a> Sum<&'a G>,
+    S: Copy,
+    for<'a, 'b> &'a G: Neg<Output = G>
+        + Add<G, Output = G>
+        + Add<&'b G, Output = G>
+        + Sub<G, Output = G>
+        + Sub<&'b G, Output = G>
+        + Mul<S, Output = G>
+        + Mul<&'b S, Output = G>,
+{
+    let sum = binop!(a, +, b);
+    let difference = binop!(a, -, b);
+    let multiple = binop!(a, *, s);
+    assign!(a, +=, b, sum);
+    assign!(a, -=, b, difference);
+    assign!(a, *=, s, multiple);
+    assert_eq!(-&a, -a);
+    assert_eq!(sum + difference, a + a);
+
+    let items = [a, b, a];
+    assert_eq!(items.iter().sum::<G>(), sum + a);
+    assert_eq!(items.into_iter().sum::<G>(), sum + a);
+}
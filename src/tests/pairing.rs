@@ -9,6 +9,147 @@ macro_rules! test_pairing {
     $base:ident,
     $target:ident,
     $scalar:ident
+    ) => {
+        $crate::test_pairing!(
+            $engine, $g1, $g1affine, $g2, $g2affine, $base, $target, $scalar, "pairing"
+        );
+        $crate::test_pairing!(
+            $engine,
+            $g1,
+            $g1affine,
+            $g2,
+            $g2affine,
+            $base,
+            $target,
+            $scalar,
+            "line_evaluator"
+        );
+    };
+    (
+    $engine:ident,
+    $g1:ident,
+    $g1affine:ident,
+    $g2:ident,
+    $g2affine:ident,
+    $base:ident,
+    $target:ident,
+    $scalar:ident,
+    "line_evaluator"
+    ) => {
+        #[test]
+        fn test_line_evaluator() {
+            use $crate::line::LineEvaluator;
+
+            let g1 = $g1::generator().to_affine();
+            let g2 = $g2::generator().to_affine();
+            let points = (0..3)
+                .map(|_| {
+                    (
+                        (g1 * $scalar::random(OsRng)).to_affine(),
+                        (g2 * $scalar::random(OsRng)).to_affine(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            let lines = points
+                .iter()
+                .map(|(_, q)| $engine::prepare(q))
+                .collect::<Vec<_>>();
+
+            // The prepared lines reproduce the loop.
+            for (p, q) in points.iter() {
+                assert_eq!(
+                    $engine::miller_loop_prepared(&[(p, &$engine::prepare(q))]),
+                    multi_miller_loop(&[(p, q)])
+                );
+            }
+            let terms = points.iter().map(|(p, q)| (p, q)).collect::<Vec<_>>();
+            let prepared = points
+                .iter()
+                .zip(lines.iter())
+                .map(|((p, _), lines)| (p, &lines[..]))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                $engine::miller_loop_prepared(&prepared),
+                multi_miller_loop(&terms)
+            );
+
+            // The first steps of the loop with the raw lines.
+            let (p, q) = points[0];
+            let mut r = $crate::line::JacobianPoint::from(&q);
+            let mut f = $base::one();
+            $engine::evaluate(&mut f, &$engine::double_step(&mut r), &p);
+            let mut expected = $base::one();
+            $engine::evaluate(&mut expected, &lines[0][0], &p);
+            assert_eq!(f, expected);
+            let double = ($g2::from(q).double()).to_affine();
+            assert_eq!(r.coordinates().unwrap(), (double.x, double.y));
+            $engine::add_step(&mut r, &q);
+            let triple = ($g2::from(q) * $scalar::from(3)).to_affine();
+            assert_eq!(r.coordinates().unwrap(), (triple.x, triple.y));
+            assert!(bool::from(
+                $crate::line::JacobianPoint::from(&$g2affine::identity())
+                    .coordinates()
+                    .is_none()
+            ));
+
+            // Identity terms have no lines.
+            assert!($engine::prepare(&$g2affine::identity()).is_empty());
+            let identity = $g1affine::identity();
+            assert_eq!(
+                $engine::miller_loop_prepared(&[
+                    (&identity, &lines[0][..]),
+                    (&points[1].0, &[]),
+                    (&points[2].0, &lines[2][..]),
+                ]),
+                multi_miller_loop(&[(&points[2].0, &points[2].1)])
+            );
+        }
+
+        #[test]
+        fn test_line_steps() {
+            use $crate::line::LineEvaluator;
+
+            let p = ($g1::generator() * $scalar::random(OsRng)).to_affine();
+            let q = ($g2::generator() * $scalar::random(OsRng)).to_affine();
+
+            // The schedule of the loop with the raw steps.
+            let mut r = $crate::line::JacobianPoint::from(&q);
+            let mut f = $base::one();
+            let mut lines = Vec::new();
+            let mut step = |f: &mut $base, line| {
+                $engine::evaluate(f, &line, &p);
+                lines.push(line);
+            };
+            for (i, x) in $engine::LOOP_NAF.iter().rev().skip(1).enumerate() {
+                if i != 0 {
+                    f = f.square();
+                }
+                step(&mut f, $engine::double_step(&mut r));
+                match x {
+                    1 => step(&mut f, $engine::add_step(&mut r, &q)),
+                    -1 => step(&mut f, $engine::add_step(&mut r, &-q)),
+                    _ => {}
+                }
+            }
+            for q in $engine::frobenius_points(&q).iter() {
+                step(&mut f, $engine::add_step(&mut r, q));
+            }
+
+            assert_eq!(lines, $engine::prepare(&q));
+            assert_eq!(f, multi_miller_loop(&[(&p, &q)]));
+            assert_eq!(f.final_exponentiation(), $engine::pairing(&p, &q));
+        }
+    };
+    (
+    $engine:ident,
+    $g1:ident,
+    $g1affine:ident,
+    $g2:ident,
+    $g2affine:ident,
+    $base:ident,
+    $target:ident,
+    $scalar:ident,
+    "pairing"
     ) => {
         #[test]
         fn test_miller_loop_identity() {
@@ -40,6 +181,85 @@ macro_rules! test_pairing {
             );
         }
 
+        #[test]
+        fn test_multi_miller_loop_identity_terms() {
+            use ff::Field;
+
+            let g1 = $g1::generator().to_affine();
+            let g2 = $g2::generator().to_affine();
+            let (id1, id2) = ($g1affine::identity(), $g2affine::identity());
+            let points = (0..3)
+                .map(|_| {
+                    (
+                        (g1 * $scalar::random(OsRng)).to_affine(),
+                        (g2 * $scalar::random(OsRng)).to_affine(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            let expected = points.iter().fold($target::identity(), |acc, (p, q)| {
+                acc + $engine::pairing(p, q)
+            });
+
+            // Terms with an identity point don't contribute, wherever they are.
+            let (a, b, c) = (&points[0], &points[1], &points[2]);
+            let terms = [
+                vec![(&id1, &b.1), (&a.0, &a.1), (&b.0, &b.1), (&c.0, &c.1)],
+                vec![(&a.0, &a.1), (&b.0, &id2), (&b.0, &b.1), (&c.0, &c.1)],
+                vec![(&a.0, &a.1), (&b.0, &b.1), (&c.0, &c.1), (&id1, &id2)],
+                vec![
+                    (&a.0, &a.1),
+                    (&id1, &c.1),
+                    (&b.0, &b.1),
+                    (&c.0, &id2),
+                    (&c.0, &c.1),
+                ],
+            ];
+            for terms in terms.iter() {
+                assert_eq!(multi_miller_loop(terms).final_exponentiation(), expected);
+            }
+            assert_eq!(
+                multi_miller_loop(&[(&id1, &b.1), (&a.0, &a.1), (&c.0, &id2)]),
+                multi_miller_loop(&[(&a.0, &a.1)])
+            );
+
+            // Only identity terms, or none at all, give one.
+            assert_eq!(multi_miller_loop(&[]), $base::ONE);
+            assert_eq!(
+                multi_miller_loop(&[(&id1, &a.1), (&b.0, &id2), (&id1, &id2)]),
+                $base::ONE
+            );
+        }
+
+        #[test]
+        fn test_multi_miller_loop_chunks() {
+            use ff::Field;
+
+            let g1 = $g1::generator().to_affine();
+            let g2 = $g2::generator().to_affine();
+            let points = (0..7)
+                .map(|_| {
+                    (
+                        (g1 * $scalar::random(OsRng)).to_affine(),
+                        (g2 * $scalar::random(OsRng)).to_affine(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            let terms = points.iter().map(|(p, q)| (p, q)).collect::<Vec<_>>();
+            let expected = multi_miller_loop(&terms);
+
+            // The product of the chunks is the loop over all the terms.
+            for chunk_size in [1, 2, 3, 7, 10] {
+                let chunks = $engine::multi_miller_loop_chunks(&terms, chunk_size);
+                assert_eq!(chunks.fold($base::ONE, |acc, f| acc * f), expected);
+            }
+            assert_eq!($engine::multi_miller_loop_par(&terms), expected);
+            assert_eq!(
+                $engine::multi_miller_loop_par(&terms[..1]),
+                multi_miller_loop(&terms[..1])
+            );
+            assert_eq!($engine::multi_miller_loop_par(&[]), $base::ONE);
+        }
+
         #[test]
         fn test_unitary() {
             let g = $g1affine::generator();
@@ -114,6 +334,139 @@ macro_rules! test_pairing {
             }
         }
 
+        #[test]
+        fn test_gt_pow_vartime() {
+            use $crate::ff::{Field, PrimeField};
+
+            let gt = $engine::pairing(&$g1affine::generator(), &$g2affine::generator());
+            for s in [
+                $scalar::ZERO,
+                $scalar::ONE,
+                -$scalar::ONE,
+                $scalar::random(OsRng),
+            ] {
+                let limbs = s
+                    .to_repr()
+                    .as_ref()
+                    .chunks(8)
+                    .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+                    .collect::<Vec<_>>();
+                assert_eq!(gt.pow_vartime(&limbs), gt * s);
+            }
+            assert_eq!(gt.pow_vartime($scalar::MODULUS_LIMBS), $target::identity());
+        }
+
+        #[test]
+        fn test_gt_ord_hash_fmt() {
+            use std::collections::{BTreeSet, HashSet};
+
+            let gt = $engine::pairing(&$g1affine::generator(), &$g2affine::generator());
+            let elements = [$target::identity(), gt, gt.double(), -gt, gt];
+            let ordered = elements.iter().collect::<BTreeSet<_>>();
+            let hashed = elements.iter().collect::<HashSet<_>>();
+            assert_eq!(ordered.len(), 4);
+            assert_eq!(hashed.len(), 4);
+            assert_eq!(format!("{}", gt), format!("{}", gt.0));
+            assert_eq!(format!("{:x}", gt), format!("{:x}", gt.0));
+        }
+
+        #[test]
+        fn test_gt_order() {
+            // The three groups of the pairing share the same prime order.
+            assert_eq!($g1::ORDER, $g2::ORDER);
+            assert_eq!($g1::ORDER, &$scalar::MODULUS_LIMBS[..]);
+
+            let gt = $engine::pairing(&$g1affine::generator(), &$g2affine::generator());
+            assert_ne!(gt, $target::identity());
+            assert_eq!(gt.pow_vartime($g1::ORDER), $target::identity());
+
+            // `gt` is not the identity and the order is prime, so it has exactly
+            // that order and `gt^(r - 1)` is its inverse.
+            let mut order_minus_one = $g1::ORDER.to_vec();
+            order_minus_one[0] -= 1;
+            assert_eq!(gt.pow_vartime(&order_minus_one), -gt);
+        }
+
+        #[test]
+        fn test_gt_operators() {
+            let gt = $engine::pairing(&$g1affine::generator(), &$g2affine::generator());
+            let a = gt * $scalar::random(OsRng);
+            let b = gt * $scalar::random(OsRng);
+            $crate::tests::ops::group_operators(a, b, $scalar::random(OsRng));
+        }
+
+        #[test]
+        fn test_gt_compression() {
+            use $crate::ff::Field;
+
+            assert_eq!($target::COMPRESSED_SIZE * 2, core::mem::size_of::<$base>());
+            for _ in 0..10 {
+                let g = $g1::random(OsRng).to_affine();
+                let h = $g2::random(OsRng).to_affine();
+                let gt = $engine::pairing(&g, &h);
+                let bytes = gt.to_compressed();
+                assert_eq!($target::from_compressed(&bytes).unwrap(), gt);
+            }
+
+            let identity = $target::identity().to_compressed();
+            assert!(identity.iter().all(|b| *b == 0));
+            assert_eq!(
+                $target::from_compressed(&identity).unwrap(),
+                $target::identity()
+            );
+
+            // Random encodings decompress to elements of the torus, which are out of
+            // the group with overwhelming probability.
+            for _ in 0..10 {
+                let x = $base::random(OsRng).final_exponentiation();
+                let mut bytes = x.to_compressed();
+                bytes[0] ^= 1;
+                assert!(bool::from($target::from_compressed(&bytes).is_none()));
+            }
+        }
+
+        #[test]
+        fn test_gt_bytes() {
+            use $crate::ff::Field;
+
+            assert_eq!($target::SIZE, core::mem::size_of::<$base>());
+            for _ in 0..10 {
+                let gt = $target::random(OsRng);
+                assert_eq!($target::from_bytes(&gt.to_bytes()).unwrap(), gt);
+            }
+            let identity = $target::identity();
+            assert_eq!($target::from_bytes(&identity.to_bytes()).unwrap(), identity);
+
+            // Zero and elements out of the cyclotomic subgroup are rejected.
+            assert!(bool::from(
+                $target::from_bytes(&[0u8; $target::SIZE]).is_none()
+            ));
+            for _ in 0..10 {
+                let x = $target($base::random(OsRng));
+                assert!(bool::from($target::from_bytes(&x.to_bytes()).is_none()));
+            }
+
+            // Non-canonical coefficients are rejected.
+            let bytes = [0xffu8; $target::SIZE];
+            assert!(bool::from($target::from_bytes(&bytes).is_none()));
+        }
+
+        #[test]
+        fn test_gt_multi_exp() {
+            use $crate::ff::Field;
+
+            assert_eq!($target::multi_exp(&[], &[]), $target::identity());
+            for n in [1, 2, 5] {
+                let bases = (0..n).map(|_| $target::random(OsRng)).collect::<Vec<_>>();
+                let scalars = (0..n).map(|_| $scalar::random(OsRng)).collect::<Vec<_>>();
+                let expected = bases
+                    .iter()
+                    .zip(scalars.iter())
+                    .fold($target::identity(), |acc, (b, s)| acc + b * s);
+                assert_eq!($target::multi_exp(&bases, &scalars), expected);
+            }
+        }
+
         #[test]
         fn test_pairing_check() {
             let n = 10;
@@ -145,3 +498,56 @@ macro_rules! test_pairing {
         }
     };
 }
+
+#[macro_export]
+macro_rules! test_pairing_op_count {
+    (
+    $g1affine:ident,
+    $g2affine:ident,
+    miller_loop: { sparse_mul: $ml_sparse:expr, $(dense_mul: $ml_dense:expr,)? square: $ml_square:expr },
+    final_exponentiation: { dense_mul: $fe_mul:expr, cyclotomic_square: $fe_cyclotomic:expr }
+    ) => {
+        #[test]
+        fn test_pairing_op_count() {
+            use $crate::tests::op_count::{count_ops, OpCounts};
+
+            let g1 = $g1affine::generator();
+            let g2 = $g2affine::generator();
+
+            // Squarings of the accumulator are shared between all pairs, there's one line
+            // evaluation per pair for each doubling and addition step.
+            for n in 1..=3 {
+                let terms = vec![(&g1, &g2); n];
+                let (f, counts) = count_ops(|| multi_miller_loop(&terms));
+                assert_eq!(
+                    counts,
+                    OpCounts {
+                        sparse_mul: n * $ml_sparse,
+                        dense_mul: 0 $(+ $ml_dense)?,
+                        square: $ml_square,
+                        cyclotomic_square: 0,
+                    },
+                    "miller loop with {} pairs",
+                    n
+                );
+
+                let (_, counts) = count_ops(|| f.final_exponentiation());
+                assert_eq!(
+                    counts,
+                    OpCounts {
+                        sparse_mul: 0,
+                        dense_mul: $fe_mul,
+                        square: 0,
+                        cyclotomic_square: $fe_cyclotomic,
+                    },
+                    "final exponentiation"
+                );
+            }
+
+            // Identity terms are skipped.
+            let (_, counts) =
+                count_ops(|| multi_miller_loop(&[(&g1, &g2), (&$g1affine::identity(), &g2)]));
+            assert_eq!(counts.sparse_mul, $ml_sparse);
+        }
+    };
+}
@@ -0,0 +1,169 @@
+//! Known-answer test vectors, stored as JSON or CSV files in this directory.
+//!
+//! A vector set is embedded and parsed with [`test_vectors!`](crate::test_vectors).
+//! JSON files are read as they are. CSV files have a header row and become an
+//! object whose `"vectors"` array holds one object of strings per row. Field
+//! elements are big-endian hex strings with an optional `0x` prefix, and the
+//! coefficients of extension field elements are separated by commas, as in
+//! RFC 9380.
+//!
+//! The checks below run the vector sets of a given layout against a curve.
+//! A new curve plugs in by adding its files next to the others and calling them
+//! from its tests. The files are:
+//!
+//! * `<suite ID with ':' replaced by '_'>.json`: hash to curve suites, in the
+//!   layout of the JSON vectors of RFC 9380, checked by [`check_hash_to_curve`];
+//! * `<curve>_mul.csv`: multiples `k * G` of the generator, with the columns `k`,
+//!   `x` and `y`, checked by [`check_generator_multiples`];
+//! * `pluto_pairing.json`: Pluto pairings, generated by
+//!   `script/pluto_pairing_vectors.py`;
+//! * `bw6_767_*`: BW6-767 multiples and pairings, generated with arkworks by
+//!   `script/bw6_767_vectors.rs`.
+
+use core::ops::Index;
+
+use ff::PrimeField;
+use group::Curve;
+use pasta_curves::arithmetic::{CurveAffine, CurveExt};
+use serde_json::{Map, Value};
+
+/// Embeds the vector file `$file` of `src/tests/vectors` and parses it into
+/// [`Vectors`](crate::tests::vectors::Vectors).
+#[macro_export]
+macro_rules! test_vectors {
+    ($file: literal) => {
+        $crate::tests::vectors::Vectors::parse(
+            $file,
+            include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/src/tests/vectors/",
+                $file
+            )),
+        )
+    };
+}
+
+/// A parsed vector file, indexed by its top-level keys.
+pub(crate) struct Vectors {
+    name: &'static str,
+    root: Value,
+}
+
+impl Vectors {
+    /// Parses `text`, as CSV if `name` ends with `.csv` and as JSON otherwise.
+    pub(crate) fn parse(name: &'static str, text: &str) -> Self {
+        let root = if name.ends_with(".csv") {
+            parse_csv(text)
+        } else {
+            serde_json::from_str(text).unwrap_or_else(|e| panic!("{name}: {e}"))
+        };
+        Self { name, root }
+    }
+
+    /// The name of the file, for the messages of failed checks.
+    pub(crate) fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// The string at the top-level `key`.
+    pub(crate) fn str(&self, key: &str) -> &str {
+        self[key]
+            .as_str()
+            .unwrap_or_else(|| panic!("{}: `{key}` is not a string", self.name))
+    }
+
+    /// The elements of the array at the top-level `key`.
+    pub(crate) fn cases(&self, key: &str) -> &[Value] {
+        self[key]
+            .as_array()
+            .unwrap_or_else(|| panic!("{}: `{key}` is not an array", self.name))
+    }
+}
+
+impl Index<&str> for Vectors {
+    type Output = Value;
+
+    fn index(&self, key: &str) -> &Value {
+        self.root
+            .get(key)
+            .unwrap_or_else(|| panic!("{}: no `{key}`", self.name))
+    }
+}
+
+fn parse_csv(text: &str) -> Value {
+    let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty());
+    let header = lines
+        .next()
+        .map_or(vec![], |line| line.split(',').collect());
+    let rows = lines
+        .map(|line| {
+            let cells = line.split(',').map(|cell| Value::from(cell.trim()));
+            Value::Object(header.iter().map(|h| h.to_string()).zip(cells).collect())
+        })
+        .collect();
+    Value::Object(Map::from_iter([(
+        "vectors".to_string(),
+        Value::Array(rows),
+    )]))
+}
+
+/// Parses a big-endian hex string, with an optional `0x` prefix.
+pub(crate) fn field<F: PrimeField>(hex: &str) -> F {
+    crate::tests::hex_to_field(hex.trim().trim_start_matches("0x"))
+}
+
+/// Parses the two coefficients `c0,c1` of an element of a quadratic extension.
+pub(crate) fn field2<F: PrimeField>(hex: &str) -> (F, F) {
+    let (c0, c1) = hex.split_once(',').expect("two coefficients");
+    (field(c0), field(c1))
+}
+
+/// Checks a hash to curve suite in the layout of RFC 9380: the `"vectors"` are
+/// the messages `"msg"` and their hashes `"P"`, under the domain separation tag
+/// `"dst"`, made of a prefix and of the `"ciphersuite"` ID. `coordinate` parses the
+/// coordinates of `"P"`.
+pub(crate) fn check_hash_to_curve<C: CurveAffine>(
+    vectors: &Vectors,
+    coordinate: impl Fn(&str) -> C::Base,
+) {
+    let prefix = vectors
+        .str("dst")
+        .strip_suffix(vectors.str("ciphersuite"))
+        .expect("the DST ends with the suite ID");
+    let hasher = C::CurveExt::hash_to_curve(prefix);
+
+    for (i, vector) in vectors.cases("vectors").iter().enumerate() {
+        let msg = vector["msg"].as_str().unwrap();
+        let p = &vector["P"];
+        let expected = C::from_xy(
+            coordinate(p["x"].as_str().unwrap()),
+            coordinate(p["y"].as_str().unwrap()),
+        )
+        .unwrap();
+        assert_eq!(
+            hasher(msg.as_bytes()).to_affine(),
+            expected,
+            "{}: vector {i}",
+            vectors.name()
+        );
+    }
+}
+
+/// Checks the multiples `k * G` of the generator, with `k` in decimal and the
+/// coordinates `x` and `y` of prime fields.
+pub(crate) fn check_generator_multiples<C: CurveAffine>(vectors: &Vectors)
+where
+    C::Base: PrimeField,
+{
+    for (i, vector) in vectors.cases("vectors").iter().enumerate() {
+        let cell = |key: &str| vector[key].as_str().unwrap();
+        let k = C::Scalar::from_str_vartime(cell("k")).unwrap();
+        let expected = C::from_xy(field(cell("x")), field(cell("y"))).unwrap();
+        assert_eq!(
+            (C::generator() * k).to_affine(),
+            expected,
+            "{}: vector {i}",
+            vectors.name()
+        );
+    }
+}
@@ -0,0 +1,272 @@
+//! Signed-digit recodings of scalars.
+//!
+//! Both recodings rewrite a little-endian integer `k` as `k = sum_i d_i * 2^(s * i)`
+//! with signed digits, least significant first, so that tables only need the
+//! positive multiples of a point and negative digits negate the selected entry.
+//!
+//! * [`wnaf`] is the width-`W` non-adjacent form, with `s = 1`: every non-zero
+//!   digit is odd and smaller than `2^(W - 1)` in absolute value, and any `W`
+//!   consecutive digits contain at most one of them. It minimizes the additions of
+//!   variable time multiplications.
+//! * [`signed_windows`] has one digit per `W`-bit window, with `s = W`, in
+//!   `[-2^(W - 1), 2^(W - 1))`, plus a top digit for the carry. Its regular shape
+//!   suits constant time multiplications and fixed-base tables; the scalar
+//!   multiplication of the curves uses `W = 4`.
+//!
+//! [`ToWnaf`] recodes prime field elements. The 128-bit halves of
+//! [`CurveEndo::glv_recode`](crate::CurveEndo::glv_recode) are recoded from their
+//! bytes, e.g. `wnaf::<W>(&k1.to_le_bytes())`.
+//!
+//! Variable time multiplications can skip the leading zero windows of short scalars,
+//! such as 128-bit challenges, with [`BitLength::bits_used`].
+
+use alloc::vec::Vec;
+
+use ff::PrimeField;
+
+// Reads the `w <= 16` bits of `bytes` from bit `pos`, zero beyond the end.
+fn window(bytes: &[u8], pos: usize, w: usize) -> u32 {
+    let mut v = [0u8; 4];
+    for (dst, src) in v.iter_mut().zip(bytes.iter().skip(pos / 8)) {
+        *dst = *src;
+    }
+    (u32::from_le_bytes(v) >> (pos % 8)) & ((1 << w) - 1)
+}
+
+/// Returns the bit length of the little-endian integer `bytes`, `0` for zero. This
+/// runs in variable time.
+pub fn bits_used(bytes: &[u8]) -> u32 {
+    bytes
+        .iter()
+        .rposition(|b| *b != 0)
+        .map_or(0, |i| i as u32 * 8 + 8 - bytes[i].leading_zeros())
+}
+
+/// Returns the width-`W` NAF digits of the little-endian integer `bytes`, least
+/// significant first and without trailing zeros. This runs in variable time.
+///
+/// # Panics
+///
+/// Panics if `W` is not in `2..=16`.
+pub fn wnaf<const W: usize>(bytes: &[u8]) -> Vec<i32> {
+    assert!((2..=16).contains(&W));
+    let width = 1u32 << W;
+    let bits = bits_used(bytes) as usize;
+
+    let mut digits = Vec::with_capacity(bits + 1);
+    let mut carry = 0;
+    let mut pos = 0;
+    while pos < bits || carry != 0 {
+        let v = window(bytes, pos, W) + carry;
+        if v & 1 == 0 {
+            // The carry, if any, moves to the next bit.
+            digits.push(0);
+            pos += 1;
+        } else {
+            if v < width / 2 {
+                digits.push(v as i32);
+                carry = 0;
+            } else {
+                digits.push(v as i32 - width as i32);
+                carry = 1;
+            }
+            digits.extend(core::iter::repeat(0).take(W - 1));
+            pos += W;
+        }
+    }
+
+    while digits.last() == Some(&0) {
+        digits.pop();
+    }
+    digits
+}
+
+/// Returns the signed `W`-bit window digits of the little-endian integer `bytes`,
+/// least significant first: one digit in `[-2^(W - 1), 2^(W - 1))` per window and
+/// a last one in `{0, 1}` for the carry. The recoding doesn't branch on the bits.
+///
+/// # Panics
+///
+/// Panics if `W` is not in `1..=16`.
+pub fn signed_windows<const W: usize>(bytes: &[u8]) -> Vec<i32> {
+    assert!((1..=16).contains(&W));
+    let windows = (bytes.len() * 8 + W - 1) / W;
+
+    let mut digits = Vec::with_capacity(windows + 1);
+    let mut carry = 0;
+    for i in 0..windows {
+        // v in [0, 2^W]
+        let v = window(bytes, i * W, W) + carry;
+        carry = (v + (1 << (W - 1))) >> W;
+        digits.push(v as i32 - (carry << W) as i32);
+    }
+    digits.push(carry as i32);
+    digits
+}
+
+/// Signed-digit recodings of scalars.
+pub trait ToWnaf {
+    /// Returns the width-`W` NAF of `self`, see [`wnaf`].
+    fn to_wnaf<const W: usize>(&self) -> Vec<i32>;
+
+    /// Returns the signed `W`-bit windows of `self`, see [`signed_windows`].
+    fn to_signed_windows<const W: usize>(&self) -> Vec<i32>;
+}
+
+impl<F: PrimeField> ToWnaf for F {
+    fn to_wnaf<const W: usize>(&self) -> Vec<i32> {
+        wnaf::<W>(self.to_repr().as_ref())
+    }
+
+    fn to_signed_windows<const W: usize>(&self) -> Vec<i32> {
+        signed_windows::<W>(self.to_repr().as_ref())
+    }
+}
+
+/// Bit lengths of the canonical values of prime field elements, to size the windows of
+/// variable time scalar multiplications.
+pub trait BitLength {
+    /// Returns the bit length of the canonical value of `self`, `0` for zero.
+    fn bits_used(&self) -> u32;
+
+    /// Returns the number of leading zeros of `self` out of `NUM_BITS` bits.
+    fn leading_zeros(&self) -> u32;
+}
+
+impl<F: PrimeField> BitLength for F {
+    fn bits_used(&self) -> u32 {
+        bits_used(self.to_repr().as_ref())
+    }
+
+    fn leading_zeros(&self) -> u32 {
+        F::NUM_BITS - self.bits_used()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{bits_used, signed_windows, wnaf, BitLength, ToWnaf};
+    use crate::bn256::{Fr, G1};
+    use crate::CurveEndo;
+    use ff::{Field, PrimeField};
+    use group::Group;
+    use num_bigint::{BigInt, BigUint};
+    use rand_core::{OsRng, RngCore};
+
+    fn recompose(digits: &[i32], step: usize) -> BigInt {
+        digits
+            .iter()
+            .rev()
+            .fold(BigInt::from(0), |acc, d| (acc << step) + d)
+    }
+
+    fn check_wnaf<const W: usize>(bytes: &[u8]) {
+        let digits = wnaf::<W>(bytes);
+        assert_eq!(recompose(&digits, 1), BigUint::from_bytes_le(bytes).into());
+        assert!(digits.last() != Some(&0));
+        for (i, d) in digits.iter().enumerate().filter(|(_, d)| **d != 0) {
+            assert!(d % 2 != 0 && d.abs() < 1 << (W - 1));
+            assert!(digits[i + 1..].iter().take(W - 1).all(|d| *d == 0));
+        }
+    }
+
+    fn check_signed_windows<const W: usize>(bytes: &[u8]) {
+        let digits = signed_windows::<W>(bytes);
+        assert_eq!(digits.len(), (bytes.len() * 8 + W - 1) / W + 1);
+        assert_eq!(recompose(&digits, W), BigUint::from_bytes_le(bytes).into());
+        let (carry, digits) = digits.split_last().unwrap();
+        assert!(*carry == 0 || *carry == 1);
+        assert!(digits
+            .iter()
+            .all(|d| -(1 << (W - 1)) <= *d && *d < 1 << (W - 1)));
+    }
+
+    #[test]
+    fn test_recodings() {
+        let mut inputs = vec![vec![], vec![0u8; 32], vec![0xff; 32], vec![0xff; 16]];
+        for _ in 0..50 {
+            inputs.push(Fr::random(OsRng).to_repr().as_ref().to_vec());
+            inputs.push(OsRng.next_u64().to_le_bytes().to_vec());
+            let mut bytes = [0u8; 16];
+            OsRng.fill_bytes(&mut bytes);
+            inputs.push(bytes.to_vec());
+        }
+
+        for bytes in inputs.iter() {
+            check_wnaf::<2>(bytes);
+            check_wnaf::<3>(bytes);
+            check_wnaf::<5>(bytes);
+            check_wnaf::<16>(bytes);
+            check_signed_windows::<1>(bytes);
+            check_signed_windows::<4>(bytes);
+            check_signed_windows::<7>(bytes);
+            check_signed_windows::<16>(bytes);
+        }
+
+        // The trait recodes the canonical representation.
+        let k = Fr::random(OsRng);
+        assert_eq!(k.to_wnaf::<4>(), wnaf::<4>(k.to_repr().as_ref()));
+        assert_eq!(
+            k.to_signed_windows::<5>(),
+            signed_windows::<5>(k.to_repr().as_ref())
+        );
+        assert!(Fr::ZERO.to_wnaf::<4>().is_empty());
+    }
+
+    // Variable time multiplications with the width-`W` NAF.
+    fn mul_wnaf<const W: usize>(digits: &[i32], p: &G1) -> G1 {
+        // [P, 3P, 5P, ...]
+        let mut table = vec![*p];
+        for i in 1..1 << (W - 2) {
+            table.push(table[i - 1] + p.double());
+        }
+        let mut acc = G1::identity();
+        for d in digits.iter().rev() {
+            acc = acc.double();
+            match d.signum() {
+                1 => acc += table[(*d as usize) / 2],
+                -1 => acc -= table[(-*d as usize) / 2],
+                _ => {}
+            }
+        }
+        acc
+    }
+
+    #[test]
+    fn test_bits_used() {
+        assert_eq!(bits_used(&[]), 0);
+        assert_eq!(bits_used(&[0; 32]), 0);
+        assert_eq!(bits_used(&[1, 0, 0]), 1);
+        assert_eq!(bits_used(&[0xff, 0x80, 0]), 16);
+        assert_eq!(bits_used(&[0xff; 32]), 256);
+
+        assert_eq!(Fr::ZERO.bits_used(), 0);
+        assert_eq!(Fr::ONE.bits_used(), 1);
+        assert_eq!((-Fr::ONE).bits_used(), Fr::NUM_BITS);
+        assert_eq!(Fr::ZERO.leading_zeros(), Fr::NUM_BITS);
+        for _ in 0..50 {
+            let k = OsRng.next_u64();
+            assert_eq!(Fr::from(k).bits_used(), 64 - k.leading_zeros());
+            let k = Fr::random(OsRng);
+            assert_eq!(
+                k.bits_used() as u64,
+                BigUint::from_bytes_le(k.to_repr().as_ref()).bits()
+            );
+        }
+    }
+
+    #[test]
+    fn test_wnaf_mul() {
+        let p = G1::random(OsRng);
+        for _ in 0..10 {
+            let k = Fr::random(OsRng);
+            assert_eq!(mul_wnaf::<5>(&k.to_wnaf::<5>(), &p), p * k);
+
+            // The halves of the GLV decomposition.
+            let [(k1, p1), (k2, p2)] = p.glv_recode(&k);
+            let res = mul_wnaf::<4>(&wnaf::<4>(&k1.to_le_bytes()), &p1)
+                + mul_wnaf::<4>(&wnaf::<4>(&k2.to_le_bytes()), &p2);
+            assert_eq!(res, p * k);
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//! Scrubbing of secret values from memory, with the `zeroize` feature.
+//!
+//! Field elements, extension field elements, points and pairing outputs implement
+//! [`Zeroize`], which overwrites them with their default value using volatile
+//! writes the compiler cannot elide. [`Zeroizing`] wraps a value to scrub it when
+//! it is dropped, e.g. a secret key or the trapdoor of a KZG setup:
+//!
+//! ```
+//! use halo2curves::bn256::Fr;
+//! use halo2curves::ff::Field;
+//! use halo2curves::zeroize::Zeroizing;
+//!
+//! let tau = Zeroizing::new(Fr::random(rand_core::OsRng));
+//! let tau2 = Zeroizing::new(tau.square());
+//! ```
+//!
+//! The traits are those of the `zeroize` crate, which this module re-exports.
+
+pub use ::zeroize::{DefaultIsZeroes, Zeroize, ZeroizeOnDrop, Zeroizing};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bls12_377::{Fq12, G2};
+    use crate::bn256::{Bn256, Fq2, Fr, G1Affine, G2Affine, G1};
+    use crate::ed_on_bls12_377::EdOnBls12377;
+    use crate::ff::Field;
+    use crate::group::{prime::PrimeCurveAffine, Curve, Group};
+    use crate::pairing::Engine;
+    use rand_core::OsRng;
+
+    fn assert_zeroized<Z: Zeroize + Default + PartialEq + core::fmt::Debug>(mut z: Z) {
+        z.zeroize();
+        assert_eq!(z, Z::default());
+    }
+
+    #[test]
+    fn test_zeroize() {
+        assert_zeroized(Fr::random(OsRng));
+        assert_zeroized(Fq2::random(OsRng));
+        assert_zeroized(Fq12::random(OsRng));
+        assert_zeroized(G1::random(OsRng));
+        assert_zeroized(G1::random(OsRng).to_affine());
+        assert_zeroized(G2::random(OsRng));
+        assert_zeroized(EdOnBls12377::random(OsRng));
+        assert_zeroized(Bn256::pairing(
+            &G1Affine::generator(),
+            &G2Affine::generator(),
+        ));
+
+        // The default values are the zero and the identity.
+        assert_eq!(Fr::default(), Fr::ZERO);
+        assert_eq!(G1Affine::default(), G1Affine::identity());
+        assert_eq!(G1::default(), G1::identity());
+
+        let mut scalars = [Fr::random(OsRng); 4];
+        scalars.zeroize();
+        assert_eq!(scalars, [Fr::ZERO; 4]);
+        let mut scalars = (0..4).map(|_| Fr::random(OsRng)).collect::<Vec<_>>();
+        scalars.zeroize();
+        assert!(scalars.is_empty());
+    }
+
+    #[test]
+    fn test_zeroizing() {
+        let mut s = Zeroizing::new(Fr::random(OsRng));
+        let t = *s;
+        *s = s.square();
+        assert_eq!(*s, t.square());
+        s.zeroize();
+        assert_eq!(*s, Fr::ZERO);
+    }
+}